@@ -12,12 +12,25 @@
 #[cfg(feature = "cache_me")]
 pub mod cache_me;
 
+/// [`Cache`] bot adaptor which caches requests for chat metadata.
+///
+/// [`Cache`]: cache::Cache
+#[cfg(feature = "cache")]
+pub mod cache;
+
 /// [`Trace`] bot adaptor which traces requests.
 ///
 /// [`Trace`]: trace::Trace
 #[cfg(feature = "trace_adaptor")]
 pub mod trace;
 
+/// [`Tracing`] bot adaptor which creates [`tracing`] spans for requests.
+///
+/// [`Tracing`]: tracing::Tracing
+/// [`tracing`]: https://docs.rs/tracing
+#[cfg(feature = "tracing")]
+pub mod tracing;
+
 /// [`ErasedRequester`] bot adaptor which allows to erase type of
 /// [`Requester`].
 ///
@@ -33,15 +46,45 @@ pub mod erased;
 #[cfg(feature = "throttle")]
 pub mod throttle;
 
+/// [`Metrics`] bot adaptor which collects metrics of requests.
+///
+/// [`Metrics`]: metrics::Metrics
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+/// [`Recorder`] bot adaptor which records requests to a cassette and
+/// [`Replayer`] which replays them.
+///
+/// [`Recorder`]: cassette::Recorder
+/// [`Replayer`]: cassette::Replayer
+#[cfg(feature = "cassette")]
+pub mod cassette;
+
+/// [`Retry`] bot adaptor which allows automatically retry failed requests.
+///
+/// [`Retry`]: retry::Retry
+#[cfg(feature = "retry")]
+pub mod retry;
+
 mod parse_mode;
 
+#[cfg(feature = "cache")]
+pub use cache::Cache;
 #[cfg(feature = "cache_me")]
 pub use cache_me::CacheMe;
+#[cfg(feature = "cassette")]
+pub use cassette::{Recorder, Replayer};
 #[cfg(feature = "erased")]
 pub use erased::ErasedRequester;
+#[cfg(feature = "metrics")]
+pub use metrics::Metrics;
+#[cfg(feature = "retry")]
+pub use retry::Retry;
 #[cfg(feature = "throttle")]
 pub use throttle::Throttle;
 #[cfg(feature = "trace_adaptor")]
 pub use trace::Trace;
+#[cfg(feature = "tracing")]
+pub use tracing::Tracing;
 
 pub use parse_mode::DefaultParseMode;
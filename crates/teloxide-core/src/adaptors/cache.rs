@@ -0,0 +1,1157 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    future::{Future, IntoFuture},
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{self, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::{
+    future::{self, Ready},
+    TryFuture,
+};
+use url::Url;
+
+use crate::{
+    payloads::{
+        ApproveChatJoinRequest, BanChatMember, DeleteChatPhoto, DeleteChatStickerSet, GetChat,
+        GetChatAdministrators, GetChatMember, GetChatMemberCount, KickChatMember, LeaveChat,
+        PinChatMessage, PromoteChatMember, RestrictChatMember, SetChatAdministratorCustomTitle,
+        SetChatDescription, SetChatPermissions, SetChatPhoto, SetChatStickerSet, SetChatTitle,
+        UnbanChatMember, UnpinAllChatMessages, UnpinChatMessage,
+    },
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+};
+
+/// Caches responses of requests for chat metadata.
+///
+/// Responses of [`get_chat`], [`get_chat_administrators`], [`get_chat_member`]
+/// and [`get_chat_member_count`] are cached for a time to live, configured per
+/// method in [`Settings`]. The number of cached responses of each method is
+/// bounded as well, when the bound is reached, expired responses and then the
+/// responses which would expire first are evicted.
+///
+/// Cached responses are invalidated:
+/// - when a request, which changes the chat, is successfully sent through this
+///   adaptor (e.g. [`promote_chat_member`], [`ban_chat_member`] or
+///   [`set_chat_title`])
+/// - when an update, which reports changes of the chat, is passed to
+///   [`Cache::invalidate_update`] (e.g. [`UpdateKind::ChatMember`] or
+///   [`UpdateKind::MyChatMember`])
+/// - manually, see [`Cache::invalidate_chat`] and
+///   [`Cache::invalidate_chat_member`]
+///
+/// Responses are cached by the [`Recipient`] used in the request, so responses
+/// of requests using a channel username are only invalidated by requests and
+/// calls which use the same username.
+///
+/// The cache is shared between clones of the adaptor.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide_core::{adaptors::cache::Settings, prelude::*, types::Update};
+///
+/// # async fn run(update: Update) -> Result<(), teloxide_core::RequestError> {
+/// let bot = Bot::new("TOKEN").cache(Settings::default());
+///
+/// // Call this for every update, e.g. at the beginning of your handler tree
+/// bot.invalidate_update(&update);
+///
+/// if let Some(chat) = update.chat() {
+///     // Only the first call in 5 minutes is sent to Telegram
+///     let admins = bot.get_chat_administrators(chat.id).await?;
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`get_chat`]: crate::requests::Requester::get_chat
+/// [`get_chat_administrators`]: crate::requests::Requester::get_chat_administrators
+/// [`get_chat_member`]: crate::requests::Requester::get_chat_member
+/// [`get_chat_member_count`]: crate::requests::Requester::get_chat_member_count
+/// [`promote_chat_member`]: crate::requests::Requester::promote_chat_member
+/// [`ban_chat_member`]: crate::requests::Requester::ban_chat_member
+/// [`set_chat_title`]: crate::requests::Requester::set_chat_title
+#[derive(Clone, Debug)]
+pub struct Cache<B> {
+    inner: B,
+    caches: Arc<Mutex<Caches>>,
+}
+
+impl<B> Cache<B> {
+    /// Creates new cache.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::cache`] instead.
+    ///
+    /// [`RequesterExt::cache`]: crate::requests::RequesterExt::cache
+    pub fn new(inner: B, settings: Settings) -> Self {
+        let caches = Caches {
+            chats: Entries::new(settings.chat),
+            administrators: Entries::new(settings.administrators),
+            members: Entries::new(settings.member),
+            member_counts: Entries::new(settings.member_count),
+            in_flight: HashMap::new(),
+        };
+
+        Self { inner, caches: Arc::new(Mutex::new(caches)) }
+    }
+
+    /// Allows to access inner bot
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Unwraps inner bot
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    /// Removes all cached responses for the chat.
+    pub fn invalidate_chat<C>(&self, chat_id: C)
+    where
+        C: Into<Recipient>,
+    {
+        lock(&self.caches).invalidate(&Key { chat: chat_id.into(), user: None });
+    }
+
+    /// Removes the cached member of the chat, as well as cached administrators
+    /// and member count of the chat.
+    pub fn invalidate_chat_member<C>(&self, chat_id: C, user_id: UserId)
+    where
+        C: Into<Recipient>,
+    {
+        lock(&self.caches).invalidate(&Key { chat: chat_id.into(), user: Some(user_id) });
+    }
+
+    /// Removes cached responses, which are outdated by the update.
+    ///
+    /// This handles [`UpdateKind::ChatMember`] and [`UpdateKind::MyChatMember`]
+    /// updates, as well as messages about new and left chat members, changes
+    /// of the chat title and photo, pinned messages and chat migrations.
+    pub fn invalidate_update(&self, update: &Update) {
+        let mut caches = lock(&self.caches);
+
+        match &update.kind {
+            UpdateKind::ChatMember(upd) | UpdateKind::MyChatMember(upd) => {
+                caches.invalidate(&Key::member(upd.chat.id, upd.new_chat_member.user.id));
+            }
+            UpdateKind::Message(msg) | UpdateKind::ChannelPost(msg) => {
+                let members = msg.new_chat_members().unwrap_or_default();
+                for user in members.iter().chain(msg.left_chat_member()) {
+                    caches.invalidate(&Key::member(msg.chat.id, user.id));
+                }
+
+                if msg.new_chat_title().is_some()
+                    || msg.new_chat_photo().is_some()
+                    || msg.delete_chat_photo().is_some()
+                    || msg.pinned_message().is_some()
+                    || msg.migrate_to_chat_id().is_some()
+                {
+                    caches.invalidate(&Key::chat(msg.chat.id));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Removes all cached responses.
+    pub fn clear(&self) {
+        let mut caches = lock(&self.caches);
+
+        caches.chats.clear();
+        caches.administrators.clear();
+        caches.members.clear();
+        caches.member_counts.clear();
+        caches.in_flight.values_mut().for_each(|chat| chat.generation += 1);
+    }
+}
+
+/// [`Cache`] settings.
+///
+/// By default responses of [`GetChat`] and [`GetChatAdministrators`] are
+/// cached for 5 minutes, responses of [`GetChatMember`] and
+/// [`GetChatMemberCount`] are cached for 1 minute. At most 1000 responses of
+/// each method are cached, except for [`GetChatMember`], for which the bound
+/// is 10000.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    chat: Limits,
+    administrators: Limits,
+    member: Limits,
+    member_count: Limits,
+}
+
+/// A method, which responses are cached by [`Cache`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CachedMethod {
+    GetChat,
+    GetChatAdministrators,
+    GetChatMember,
+    GetChatMemberCount,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Limits {
+    ttl: Duration,
+    capacity: usize,
+}
+
+impl Settings {
+    /// Sets the time for which responses of the method are cached.
+    ///
+    /// [`Duration::ZERO`] disables caching of the method.
+    #[must_use]
+    pub fn ttl(mut self, method: CachedMethod, ttl: Duration) -> Self {
+        self.limits_mut(method).ttl = ttl;
+        self
+    }
+
+    /// Sets the maximum number of cached responses of the method.
+    ///
+    /// `0` disables caching of the method.
+    #[must_use]
+    pub fn capacity(mut self, method: CachedMethod, capacity: usize) -> Self {
+        self.limits_mut(method).capacity = capacity;
+        self
+    }
+
+    fn limits_mut(&mut self, method: CachedMethod) -> &mut Limits {
+        match method {
+            CachedMethod::GetChat => &mut self.chat,
+            CachedMethod::GetChatAdministrators => &mut self.administrators,
+            CachedMethod::GetChatMember => &mut self.member,
+            CachedMethod::GetChatMemberCount => &mut self.member_count,
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+
+        Self {
+            chat: Limits { ttl: minutes(5), capacity: 1000 },
+            administrators: Limits { ttl: minutes(5), capacity: 1000 },
+            member: Limits { ttl: minutes(1), capacity: 10_000 },
+            member_count: Limits { ttl: minutes(1), capacity: 1000 },
+        }
+    }
+}
+
+/// Identifies a cached response: the chat and, for chat members, the user.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Key {
+    chat: Recipient,
+    user: Option<UserId>,
+}
+
+impl Key {
+    fn chat(chat_id: ChatId) -> Self {
+        Self { chat: chat_id.into(), user: None }
+    }
+
+    fn member(chat_id: ChatId, user_id: UserId) -> Self {
+        Self { chat: chat_id.into(), user: Some(user_id) }
+    }
+}
+
+struct Caches {
+    chats: Entries<ChatFullInfo>,
+    administrators: Entries<Vec<ChatMember>>,
+    members: Entries<ChatMember>,
+    member_counts: Entries<u32>,
+    /// Chats, for which cached requests are being sent.
+    in_flight: HashMap<Recipient, InFlight>,
+}
+
+/// Cached requests for a chat, which are being sent.
+struct InFlight {
+    requests: usize,
+    /// Incremented when responses about the chat are invalidated, so responses
+    /// of requests, which were sent before that, are not cached.
+    generation: u64,
+}
+
+impl Caches {
+    /// Registers a cached request, which is being sent, and returns the current
+    /// generation of the chat (see [`Store::insert`]).
+    fn start_request(&mut self, chat: &Recipient) -> u64 {
+        let in_flight =
+            self.in_flight.entry(chat.clone()).or_insert(InFlight { requests: 0, generation: 0 });
+        in_flight.requests += 1;
+        in_flight.generation
+    }
+
+    fn finish_request(&mut self, chat: &Recipient) {
+        if let Some(in_flight) = self.in_flight.get_mut(chat) {
+            in_flight.requests -= 1;
+            if in_flight.requests == 0 {
+                self.in_flight.remove(chat);
+            }
+        }
+    }
+
+    /// Removes all responses about the chat if `key.user` is `None`, otherwise
+    /// removes responses which may change when the user's membership changes.
+    fn invalidate(&mut self, key: &Key) {
+        if let Some(in_flight) = self.in_flight.get_mut(&key.chat) {
+            in_flight.generation += 1;
+        }
+
+        let chat = Key { chat: key.chat.clone(), user: None };
+
+        match key.user {
+            None => {
+                self.chats.remove(&chat);
+                self.members.retain(|k| k.chat != key.chat);
+            }
+            Some(_) => {
+                self.members.remove(key);
+            }
+        }
+
+        self.administrators.remove(&chat);
+        self.member_counts.remove(&chat);
+    }
+}
+
+impl fmt::Debug for Caches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Caches")
+            .field("chats", &self.chats.map.len())
+            .field("administrators", &self.administrators.map.len())
+            .field("members", &self.members.map.len())
+            .field("member_counts", &self.member_counts.map.len())
+            .finish()
+    }
+}
+
+struct Entries<T> {
+    map: HashMap<Key, Entry<T>>,
+    /// Keys of entries ordered by their expiration time. Ids make keys of this
+    /// map unique, since entries can expire at the same time.
+    expirations: BTreeMap<(Instant, u64), Key>,
+    next_id: u64,
+    limits: Limits,
+}
+
+struct Entry<T> {
+    value: T,
+    expires_at: Instant,
+    id: u64,
+}
+
+impl<T: Clone> Entries<T> {
+    fn new(limits: Limits) -> Self {
+        Self { map: HashMap::new(), expirations: BTreeMap::new(), next_id: 0, limits }
+    }
+
+    fn get(&mut self, key: &Key) -> Option<T> {
+        match self.map.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.value.clone()),
+            Some(_) => {
+                self.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&mut self, key: Key, value: T) {
+        let Limits { ttl, capacity } = self.limits;
+        if ttl.is_zero() || capacity == 0 {
+            return;
+        }
+
+        self.remove(&key);
+
+        // Remove expired entries or, if there are none, the one that expires first
+        let now = Instant::now();
+        while let Some(entry) = self.expirations.first_entry() {
+            let &(expires_at, _) = entry.key();
+            if expires_at > now && self.map.len() < capacity {
+                break;
+            }
+
+            let key = entry.remove();
+            self.map.remove(&key);
+        }
+
+        let expires_at = now + ttl;
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.expirations.insert((expires_at, id), key.clone());
+        self.map.insert(key, Entry { value, expires_at, id });
+    }
+
+    fn remove(&mut self, key: &Key) {
+        if let Some(entry) = self.map.remove(key) {
+            self.expirations.remove(&(entry.expires_at, entry.id));
+        }
+    }
+
+    fn retain(&mut self, mut f: impl FnMut(&Key) -> bool) {
+        self.map.retain(|key, _| f(key));
+        self.expirations.retain(|_, key| f(key));
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.expirations.clear();
+    }
+}
+
+fn lock(caches: &Mutex<Caches>) -> MutexGuard<'_, Caches> {
+    caches.lock().unwrap()
+}
+
+/// Payloads, responses of which are cached.
+trait Cached: Payload {
+    fn key(&self) -> Key;
+
+    fn entries(caches: &mut Caches) -> &mut Entries<Self::Output>;
+}
+
+impl Cached for GetChat {
+    fn key(&self) -> Key {
+        Key { chat: self.chat_id.clone(), user: None }
+    }
+
+    fn entries(caches: &mut Caches) -> &mut Entries<ChatFullInfo> {
+        &mut caches.chats
+    }
+}
+
+impl Cached for GetChatAdministrators {
+    fn key(&self) -> Key {
+        Key { chat: self.chat_id.clone(), user: None }
+    }
+
+    fn entries(caches: &mut Caches) -> &mut Entries<Vec<ChatMember>> {
+        &mut caches.administrators
+    }
+}
+
+impl Cached for GetChatMember {
+    fn key(&self) -> Key {
+        Key { chat: self.chat_id.clone(), user: Some(self.user_id) }
+    }
+
+    fn entries(caches: &mut Caches) -> &mut Entries<ChatMember> {
+        &mut caches.members
+    }
+}
+
+impl Cached for GetChatMemberCount {
+    fn key(&self) -> Key {
+        Key { chat: self.chat_id.clone(), user: None }
+    }
+
+    fn entries(caches: &mut Caches) -> &mut Entries<u32> {
+        &mut caches.member_counts
+    }
+}
+
+/// Payloads of requests, which outdate cached responses.
+trait Invalidates: Payload {
+    /// See [`Caches::invalidate`].
+    fn key(&self) -> Key;
+}
+
+macro_rules! invalidates {
+    (members: $($member:ident),*; chats: $($chat:ident),* $(;)?) => {
+        $(
+            impl Invalidates for $member {
+                fn key(&self) -> Key {
+                    Key { chat: self.chat_id.clone(), user: Some(self.user_id) }
+                }
+            }
+        )*
+        $(
+            impl Invalidates for $chat {
+                fn key(&self) -> Key {
+                    Key { chat: self.chat_id.clone(), user: None }
+                }
+            }
+        )*
+    };
+}
+
+invalidates! {
+    members:
+        KickChatMember,
+        BanChatMember,
+        UnbanChatMember,
+        RestrictChatMember,
+        PromoteChatMember,
+        SetChatAdministratorCustomTitle,
+        ApproveChatJoinRequest;
+    chats:
+        SetChatPermissions,
+        SetChatPhoto,
+        DeleteChatPhoto,
+        SetChatTitle,
+        SetChatDescription,
+        SetChatStickerSet,
+        DeleteChatStickerSet,
+        PinChatMessage,
+        UnpinChatMessage,
+        UnpinAllChatMessages,
+        LeaveChat;
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        $this.inner().$m($($arg),*)
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+macro_rules! fcached {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        CachedRequest::new($this.inner().$m($($arg),*), &$this.caches)
+    };
+}
+
+macro_rules! ftycached {
+    ($T:ident) => {
+        CachedRequest<B::$T>
+    };
+}
+
+macro_rules! finvalidating {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        InvalidatingRequest::new($this.inner().$m($($arg),*), &$this.caches)
+    };
+}
+
+macro_rules! ftyinvalidating {
+    ($T:ident) => {
+        InvalidatingRequest<B::$T>
+    };
+}
+
+impl<B> Requester for Cache<B>
+where
+    B: Requester,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_chat,
+        get_chat_administrators,
+        get_chat_member,
+        get_chat_member_count
+        => fcached, ftycached
+    }
+
+    requester_forward! {
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        approve_chat_join_request,
+        set_chat_permissions,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat
+        => finvalidating, ftyinvalidating
+    }
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        forward_messages,
+        copy_message,
+        copy_messages,
+        send_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_paid_media,
+        send_media_group,
+        send_location,
+        edit_message_live_location,
+        edit_message_live_location_inline,
+        stop_message_live_location,
+        stop_message_live_location_inline,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_checklist,
+        send_dice,
+        send_chat_action,
+        set_message_reaction,
+        get_user_profile_photos,
+        set_user_emoji_status,
+        get_file,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
+        revoke_chat_invite_link,
+        get_chat_members_count,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        answer_callback_query,
+        get_user_chat_boosts,
+        set_my_commands,
+        get_business_connection,
+        get_my_commands,
+        set_my_name,
+        get_my_name,
+        set_my_description,
+        get_my_description,
+        set_my_short_description,
+        get_my_short_description,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        save_prepared_inline_message,
+        edit_message_text,
+        edit_message_text_inline,
+        edit_message_caption,
+        edit_message_caption_inline,
+        edit_message_media,
+        edit_message_media_inline,
+        edit_message_checklist,
+        edit_message_reply_markup,
+        edit_message_reply_markup_inline,
+        stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
+        delete_message,
+        delete_messages,
+        get_available_gifts,
+        send_gift,
+        send_gift_chat,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
+        send_sticker,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        replace_sticker_in_set,
+        set_sticker_set_thumbnail,
+        set_custom_emoji_sticker_set_thumbnail,
+        set_sticker_set_title,
+        delete_sticker_set,
+        set_sticker_emoji_list,
+        set_sticker_keywords,
+        set_sticker_mask_position,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
+        edit_user_star_subscription,
+        set_passport_data_errors,
+        send_game,
+        set_game_score,
+        set_game_score_inline,
+        get_game_high_scores,
+        decline_chat_join_request
+        => f, fty
+    }
+}
+
+download_forward! {
+    B
+    Cache<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`Cache`] for methods, which responses are cached.
+#[must_use = "Requests are lazy and do nothing unless sent"]
+pub struct CachedRequest<R: HasPayload> {
+    inner: R,
+    caches: Arc<Mutex<Caches>>,
+    key: fn(&R::Payload) -> Key,
+    entries: fn(&mut Caches) -> &mut Entries<Output<R>>,
+}
+
+impl<R: HasPayload> CachedRequest<R> {
+    fn new(inner: R, caches: &Arc<Mutex<Caches>>) -> Self
+    where
+        R::Payload: Cached,
+    {
+        Self {
+            inner,
+            caches: Arc::clone(caches),
+            key: <R::Payload as Cached>::key,
+            entries: <R::Payload as Cached>::entries,
+        }
+    }
+}
+
+impl<R> CachedRequest<R>
+where
+    R: Request,
+    Output<R>: Clone,
+{
+    fn cached(&self) -> Result<Output<R>, Store<Output<R>>> {
+        let key = (self.key)(self.inner.payload_ref());
+        let mut caches = lock(&self.caches);
+
+        match (self.entries)(&mut caches).get(&key) {
+            Some(value) => Ok(value),
+            None => {
+                let generation = caches.start_request(&key.chat);
+                drop(caches);
+
+                Err(Store {
+                    caches: Arc::clone(&self.caches),
+                    key,
+                    entries: self.entries,
+                    generation,
+                })
+            }
+        }
+    }
+}
+
+impl<R: HasPayload> HasPayload for CachedRequest<R> {
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for CachedRequest<R>
+where
+    R: Request,
+    Output<R>: Clone + Send,
+{
+    type Err = R::Err;
+    type Send = CachedSend<R::Send>;
+    type SendRef = CachedSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        match self.cached() {
+            Ok(value) => CachedSend(future::Either::Left(future::ok(value))),
+            Err(store) => CachedSend(future::Either::Right(Insert(self.inner.send(), Some(store)))),
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        match self.cached() {
+            Ok(value) => CachedSend(future::Either::Left(future::ok(value))),
+            Err(store) => {
+                CachedSend(future::Either::Right(Insert(self.inner.send_ref(), Some(store))))
+            }
+        }
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_timeout(timeout)
+    }
+}
+
+impl<R> IntoFuture for CachedRequest<R>
+where
+    R: Request,
+    Output<R>: Clone + Send,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+type ReadyOutput<F> = Ready<Result<<F as TryFuture>::Ok, <F as TryFuture>::Error>>;
+
+/// Future returned by [`CachedRequest`]s.
+#[pin_project::pin_project]
+pub struct CachedSend<F: TryFuture>(#[pin] future::Either<ReadyOutput<F>, Insert<F, F::Ok>>);
+
+impl<F> Future for CachedSend<F>
+where
+    F: TryFuture,
+    F::Ok: Clone,
+{
+    type Output = Result<F::Ok, F::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        self.project().0.poll(cx)
+    }
+}
+
+/// Stores a response in the cache.
+struct Store<T> {
+    caches: Arc<Mutex<Caches>>,
+    key: Key,
+    entries: fn(&mut Caches) -> &mut Entries<T>,
+    /// The generation of the chat, when the request was sent.
+    generation: u64,
+}
+
+impl<T: Clone> Store<T> {
+    /// Stores the response, unless responses about the chat were invalidated
+    /// since the request was sent, in which case the response may be outdated.
+    fn insert(self, value: T) {
+        let mut caches = lock(&self.caches);
+
+        let generation = caches.in_flight.get(&self.key.chat).map(|chat| chat.generation);
+        if generation == Some(self.generation) {
+            (self.entries)(&mut caches).insert(self.key.clone(), value);
+        }
+    }
+}
+
+impl<T> Drop for Store<T> {
+    fn drop(&mut self) {
+        lock(&self.caches).finish_request(&self.key.chat);
+    }
+}
+
+#[pin_project::pin_project]
+struct Insert<F, T>(#[pin] F, Option<Store<T>>);
+
+impl<F, T> Future for Insert<F, T>
+where
+    F: TryFuture<Ok = T>,
+    T: Clone,
+{
+    type Output = Result<T, F::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let res = futures::ready!(this.0.try_poll(cx));
+
+        if let (Ok(value), Some(store)) = (&res, this.1.take()) {
+            store.insert(value.clone());
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+/// Request returned by [`Cache`] for methods, which outdate cached responses.
+#[must_use = "Requests are lazy and do nothing unless sent"]
+pub struct InvalidatingRequest<R: HasPayload> {
+    inner: R,
+    caches: Arc<Mutex<Caches>>,
+    key: fn(&R::Payload) -> Key,
+}
+
+impl<R: HasPayload> InvalidatingRequest<R> {
+    fn new(inner: R, caches: &Arc<Mutex<Caches>>) -> Self
+    where
+        R::Payload: Invalidates,
+    {
+        Self { inner, caches: Arc::clone(caches), key: <R::Payload as Invalidates>::key }
+    }
+}
+
+impl<R: Request> InvalidatingRequest<R> {
+    fn invalidate(&self) -> Invalidate {
+        Invalidate { caches: Arc::clone(&self.caches), key: (self.key)(self.inner.payload_ref()) }
+    }
+}
+
+impl<R: HasPayload> HasPayload for InvalidatingRequest<R> {
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R: Request> Request for InvalidatingRequest<R> {
+    type Err = R::Err;
+    type Send = InvalidatingSend<R::Send>;
+    type SendRef = InvalidatingSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        let invalidate = self.invalidate();
+        InvalidatingSend(self.inner.send(), Some(invalidate))
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        InvalidatingSend(self.inner.send_ref(), Some(self.invalidate()))
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_timeout(timeout)
+    }
+}
+
+impl<R: Request> IntoFuture for InvalidatingRequest<R> {
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+/// Removes outdated responses from the cache.
+struct Invalidate {
+    caches: Arc<Mutex<Caches>>,
+    key: Key,
+}
+
+/// Future returned by [`InvalidatingRequest`]s.
+#[pin_project::pin_project]
+pub struct InvalidatingSend<F>(#[pin] F, Option<Invalidate>);
+
+impl<F: TryFuture> Future for InvalidatingSend<F> {
+    type Output = Result<F::Ok, F::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let res = futures::ready!(this.0.try_poll(cx));
+
+        if let (Ok(_), Some(invalidate)) = (&res, this.1.take()) {
+            lock(&invalidate.caches).invalidate(&invalidate.key);
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        future::{ready, IntoFuture, Ready},
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use crate::{
+        adaptors::cache::{
+            lock, Cache, CachedMethod, CachedRequest, InvalidatingRequest, Settings,
+        },
+        payloads::{BanChatMember, GetChatMemberCount},
+        requests::{HasPayload, Output, Payload, Request, ResponseResult},
+        types::{ChatId, True, UserId},
+        ApiError, RequestError,
+    };
+
+    /// A request which returns pre-defined results.
+    #[derive(Clone)]
+    struct Fake<P: Payload> {
+        payload: P,
+        results: Arc<Mutex<VecDeque<ResponseResult<P::Output>>>>,
+    }
+
+    impl<P: Payload> Fake<P> {
+        fn new(payload: P, results: Vec<ResponseResult<P::Output>>) -> Self {
+            Self { payload, results: Arc::new(Mutex::new(results.into())) }
+        }
+
+        fn remaining(&self) -> usize {
+            self.results.lock().unwrap().len()
+        }
+    }
+
+    impl<P: Payload> HasPayload for Fake<P> {
+        type Payload = P;
+
+        fn payload_mut(&mut self) -> &mut P {
+            &mut self.payload
+        }
+
+        fn payload_ref(&self) -> &P {
+            &self.payload
+        }
+    }
+
+    impl<P> Request for Fake<P>
+    where
+        P: Payload + Clone,
+        P::Output: Send,
+    {
+        type Err = RequestError;
+        type Send = Ready<ResponseResult<P::Output>>;
+        type SendRef = Ready<ResponseResult<P::Output>>;
+
+        fn send(self) -> Self::Send {
+            self.send_ref()
+        }
+
+        fn send_ref(&self) -> Self::SendRef {
+            ready(self.results.lock().unwrap().pop_front().expect("unexpected request"))
+        }
+
+        fn set_timeout(&mut self, _: Duration) {}
+    }
+
+    impl<P> IntoFuture for Fake<P>
+    where
+        P: Payload + Clone,
+        P::Output: Send,
+    {
+        type Output = Result<Output<Self>, RequestError>;
+        type IntoFuture = <Self as Request>::Send;
+
+        fn into_future(self) -> Self::IntoFuture {
+            self.send()
+        }
+    }
+
+    async fn member_count(cache: &Cache<()>, fake: &Fake<GetChatMemberCount>, chat: i64) -> u32 {
+        let mut fake = fake.clone();
+        fake.payload.chat_id = ChatId(chat).into();
+
+        CachedRequest::new(fake, &cache.caches).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn caches_responses() {
+        let cache = Cache::new((), Settings::default());
+        let fake = Fake::new(
+            GetChatMemberCount::new(ChatId(1)),
+            vec![Err(RequestError::Api(ApiError::ChatNotFound)), Ok(3), Ok(4)],
+        );
+
+        // Errors are not cached
+        let res = CachedRequest::new(fake.clone(), &cache.caches).await;
+        assert!(matches!(res, Err(RequestError::Api(ApiError::ChatNotFound))));
+
+        assert_eq!(member_count(&cache, &fake, 1).await, 3);
+        assert_eq!(member_count(&cache, &fake, 1).await, 3);
+        assert_eq!(fake.remaining(), 1);
+
+        cache.invalidate_chat_member(ChatId(1), UserId(2));
+        assert_eq!(member_count(&cache, &fake, 1).await, 4);
+    }
+
+    #[tokio::test]
+    async fn skips_outdated_responses() {
+        let cache = Cache::new((), Settings::default());
+        let fake = Fake::new(GetChatMemberCount::new(ChatId(1)), vec![Ok(3), Ok(4)]);
+        let ban = Fake::new(BanChatMember::new(ChatId(1), UserId(2)), vec![Ok(True)]);
+
+        // The response is received after the member is banned
+        let outdated = CachedRequest::new(fake.clone(), &cache.caches).send();
+        InvalidatingRequest::new(ban, &cache.caches).await.unwrap();
+        assert_eq!(outdated.await.unwrap(), 3);
+
+        assert_eq!(member_count(&cache, &fake, 1).await, 4);
+        assert_eq!(fake.remaining(), 0);
+        assert!(lock(&cache.caches).in_flight.is_empty());
+    }
+
+    #[tokio::test]
+    async fn respects_settings() {
+        let settings = Settings::default().ttl(CachedMethod::GetChatMemberCount, Duration::ZERO);
+        let cache = Cache::new((), settings);
+        let fake = Fake::new(GetChatMemberCount::new(ChatId(1)), vec![Ok(3), Ok(4)]);
+
+        assert_eq!(member_count(&cache, &fake, 1).await, 3);
+        assert_eq!(member_count(&cache, &fake, 1).await, 4);
+
+        let settings = Settings::default().capacity(CachedMethod::GetChatMemberCount, 1);
+        let cache = Cache::new((), settings);
+        let fake = Fake::new(GetChatMemberCount::new(ChatId(1)), vec![Ok(3), Ok(4), Ok(5)]);
+
+        assert_eq!(member_count(&cache, &fake, 1).await, 3);
+        assert_eq!(member_count(&cache, &fake, 2).await, 4);
+        assert_eq!(member_count(&cache, &fake, 2).await, 4);
+        // The first response was evicted
+        assert_eq!(member_count(&cache, &fake, 1).await, 5);
+        assert_eq!(fake.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn invalidates_on_changes() {
+        let cache = Cache::new((), Settings::default());
+        let fake = Fake::new(GetChatMemberCount::new(ChatId(1)), vec![Ok(3), Ok(4)]);
+        assert_eq!(member_count(&cache, &fake, 1).await, 3);
+
+        // Failed requests don't change anything
+        let ban = Fake::new(
+            BanChatMember::new(ChatId(1), UserId(2)),
+            vec![Err(RequestError::Api(ApiError::UserNotFound)), Ok(True)],
+        );
+        let res = InvalidatingRequest::new(ban.clone(), &cache.caches).await;
+        assert!(res.is_err());
+        assert_eq!(member_count(&cache, &fake, 1).await, 3);
+
+        InvalidatingRequest::new(ban, &cache.caches).await.unwrap();
+        assert_eq!(member_count(&cache, &fake, 1).await, 4);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{future::IntoFuture, pin::Pin, sync::Arc};
+use std::{future::IntoFuture, pin::Pin, sync::Arc, time::Duration};
 
 use futures::{
     future,
@@ -105,6 +105,7 @@ where
         send_animation,
         send_voice,
         send_video_note,
+        send_paid_media,
         send_media_group,
         send_location,
         edit_message_live_location,
@@ -114,10 +115,12 @@ where
         send_venue,
         send_contact,
         send_poll,
+        send_checklist,
         send_dice,
         send_chat_action,
         set_message_reaction,
         get_user_profile_photos,
+        set_user_emoji_status,
         get_file,
         kick_chat_member,
         ban_chat_member,
@@ -131,6 +134,8 @@ where
         export_chat_invite_link,
         create_chat_invite_link,
         edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
         revoke_chat_invite_link,
         set_chat_photo,
         delete_chat_photo,
@@ -178,17 +183,45 @@ where
         delete_my_commands,
         answer_inline_query,
         answer_web_app_query,
+        save_prepared_inline_message,
         edit_message_text,
         edit_message_text_inline,
         edit_message_caption,
         edit_message_caption_inline,
         edit_message_media,
         edit_message_media_inline,
+        edit_message_checklist,
         edit_message_reply_markup,
         edit_message_reply_markup_inline,
         stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
         delete_message,
         delete_messages,
+        get_available_gifts,
+        send_gift,
+        send_gift_chat,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
         send_sticker,
         get_sticker_set,
         get_custom_emoji_stickers,
@@ -211,6 +244,7 @@ where
         answer_pre_checkout_query,
         get_star_transactions,
         refund_star_payment,
+        edit_user_star_subscription,
         set_passport_data_errors,
         send_game,
         set_game_score,
@@ -261,6 +295,13 @@ where
         };
         SendRef(fut)
     }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        match &mut self.0 {
+            Inner::Ready(_) => {}
+            Inner::Pending(req, _) => req.set_timeout(timeout),
+        }
+    }
 }
 
 impl<R: Request<Payload = GetMe>> HasPayload for CachedMeRequest<R> {
@@ -0,0 +1,1151 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    fs::File,
+    future::{Future, IntoFuture},
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{self, Poll},
+    time::Duration,
+};
+
+use futures::{future, ready, TryFuture};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::{
+    net::TelegramResponse,
+    requests::{HasPayload, Output, Payload, Request, Requester, ResponseResult},
+    types::*,
+    ApiError, Bot, RequestError,
+};
+
+use self::never_sent::NeverSent;
+
+/// Records requests and their responses to a cassette.
+///
+/// A cassette is a [JSON Lines] file with an [`Entry`] per request, which can
+/// later be replayed with a [`Replayer`], e.g. to capture a real conversation
+/// once and then run it as an offline test against new versions of handlers.
+///
+/// Entries are written when responses are received, so the order of entries is
+/// the order in which requests were completed ([`Replayer`] doesn't require
+/// concurrent requests to complete in the same order). Requests which were
+/// dropped before a response was received are not recorded. Downloads of files
+/// are not recorded either.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide_core::{prelude::*, types::ChatId};
+///
+/// # async {
+/// let bot = Bot::new("TOKEN").record(std::fs::File::create("cassette.jsonl")?);
+///
+/// bot.send_message(ChatId(0), "Hi!").await?;
+/// # Ok::<_, Box<dyn std::error::Error>>(()) };
+/// ```
+///
+/// [JSON Lines]: https://jsonlines.org
+#[derive(Clone)]
+pub struct Recorder<B> {
+    inner: B,
+    cassette: Cassette,
+}
+
+impl<B> Recorder<B> {
+    /// Creates a new adaptor, which writes the cassette to `writer`.
+    ///
+    /// `writer` is flushed after every entry.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::record`] instead.
+    ///
+    /// [`RequesterExt::record`]: crate::requests::RequesterExt::record
+    pub fn new<W>(inner: B, writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self { inner, cassette: Cassette(Arc::new(Mutex::new(Box::new(writer)))) }
+    }
+
+    /// Creates a new adaptor, which writes the cassette to a file at `path`.
+    ///
+    /// The file is created if it doesn't exist and is truncated if it does.
+    pub fn create<P>(inner: B, path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self::new(inner, File::create(path)?))
+    }
+
+    /// Allows to access inner bot
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Unwraps inner bot
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for Recorder<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recorder").field("inner", &self.inner).finish_non_exhaustive()
+    }
+}
+
+/// A single request in a cassette, see [`Recorder`] and [`Replayer`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// The name of the method, i.e. [`Payload::NAME`] (e.g. `"SendMessage"`).
+    pub method: String,
+
+    /// The payload of the request serialized as JSON.
+    ///
+    /// Uploaded files are serialized as `"attach://"`, without their
+    /// (randomly generated) identifiers. Contents of files are not recorded.
+    pub payload: Value,
+
+    /// The response in the format of the Bot API, e.g.
+    /// `{"ok":true,"result":...}` or
+    /// `{"ok":false,"description":"Forbidden: bot was blocked by the user"}`.
+    ///
+    /// Errors which weren't returned by Telegram (e.g. network errors) are
+    /// recorded as `{"ok":false,"error":{"kind":"network","message":"..."}}`,
+    /// where `kind` is one of `"network"`, `"invalid_json"` (which also has
+    /// the `"raw"` response) or `"io"`.
+    pub response: Value,
+}
+
+impl Entry {
+    fn new<T: Serialize>(
+        method: &str,
+        payload: Value,
+        response: &ResponseResult<T>,
+    ) -> serde_json::Result<Self> {
+        let response = match response {
+            Ok(result) => {
+                serde_json::json!({ "ok": true, "result": serde_json::to_value(result)? })
+            }
+            Err(RequestError::Api(err)) => {
+                serde_json::json!({ "ok": false, "description": description(err) })
+            }
+            Err(RequestError::MigrateToChatId(chat_id)) => serde_json::json!({
+                "ok": false,
+                "description": "Bad Request: group chat was upgraded to a supergroup chat",
+                "parameters": ResponseParameters::MigrateToChatId(*chat_id),
+            }),
+            Err(RequestError::RetryAfter(secs)) => serde_json::json!({
+                "ok": false,
+                "description": format!("Too Many Requests: retry after {}", secs.seconds()),
+                "parameters": ResponseParameters::RetryAfter(*secs),
+            }),
+            Err(RequestError::Network(err)) => {
+                RecordedError::Network { message: err.to_string() }.into_response()?
+            }
+            Err(RequestError::InvalidJson { source, raw }) => {
+                RecordedError::InvalidJson { message: source.to_string(), raw: raw.to_string() }
+                    .into_response()?
+            }
+            Err(RequestError::Io(err)) => {
+                RecordedError::Io { message: err.to_string() }.into_response()?
+            }
+        };
+
+        Ok(Self { method: method.to_owned(), payload, response })
+    }
+
+    /// Returns the recorded response, as it would be returned by [`Bot`].
+    fn response<T: DeserializeOwned>(&self) -> ResponseResult<T> {
+        if let Some(error) = self.response.get("error") {
+            if let Ok(error) = RecordedError::deserialize(error) {
+                return Err(error.into());
+            }
+        }
+
+        match TelegramResponse::deserialize(&self.response) {
+            Ok(response) => response.into(),
+            Err(source) => {
+                Err(RequestError::InvalidJson { source, raw: self.response.to_string().into() })
+            }
+        }
+    }
+}
+
+/// An error which wasn't returned by Telegram, see [`Entry::response`].
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum RecordedError {
+    Network { message: String },
+    InvalidJson { message: String, raw: String },
+    Io { message: String },
+}
+
+impl RecordedError {
+    fn into_response(self) -> serde_json::Result<Value> {
+        Ok(serde_json::json!({ "ok": false, "error": serde_json::to_value(self)? }))
+    }
+}
+
+impl From<RecordedError> for RequestError {
+    fn from(error: RecordedError) -> Self {
+        match error {
+            RecordedError::Network { message } => Self::Network(message.into()),
+            RecordedError::InvalidJson { message, raw } => {
+                Self::InvalidJson { source: serde::de::Error::custom(message), raw: raw.into() }
+            }
+            RecordedError::Io { message } => Self::Io(io::Error::other(message)),
+        }
+    }
+}
+
+/// Returns the description of the error, as sent by Telegram.
+fn description(err: &ApiError) -> String {
+    match err {
+        // These are the only errors, messages of which differ from descriptions
+        ApiError::InvalidToken => "Unauthorized".to_owned(),
+        ApiError::Unknown(description) => description.clone(),
+        err => err.to_string(),
+    }
+}
+
+/// Serializes the payload, omitting identifiers of uploaded files.
+fn payload<P: Serialize>(payload: &P) -> Value {
+    fn strip_attachments(value: &mut Value) {
+        const PREFIX: &str = "attach://";
+
+        match value {
+            Value::String(s) if s.starts_with(PREFIX) => s.truncate(PREFIX.len()),
+            Value::Array(values) => values.iter_mut().for_each(strip_attachments),
+            Value::Object(values) => values.values_mut().for_each(strip_attachments),
+            _ => {}
+        }
+    }
+
+    let mut value = serde_json::to_value(payload).unwrap_or(Value::Null);
+    strip_attachments(&mut value);
+    value
+}
+
+/// The destination of recorded entries.
+#[derive(Clone)]
+struct Cassette(Arc<Mutex<Box<dyn Write + Send>>>);
+
+impl Cassette {
+    fn write(&self, entry: serde_json::Result<Entry>) {
+        let res = entry.map_err(io::Error::from).and_then(|entry| {
+            let mut line = serde_json::to_vec(&entry)?;
+            line.push(b'\n');
+
+            let mut writer = self.0.lock().unwrap();
+            writer.write_all(&line)?;
+            writer.flush()
+        });
+
+        if let Err(err) = res {
+            log::error!("Couldn't record a request: {err}");
+        }
+    }
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        RecorderRequest<B::$T>
+    };
+}
+
+macro_rules! fwd_inner {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        RecorderRequest { inner: $this.inner().$m($($arg),*), cassette: $this.cassette.clone() }
+    };
+}
+
+impl<B> Requester for Recorder<B>
+where
+    B: Requester<Err = RequestError>,
+{
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        forward_messages,
+        copy_message,
+        copy_messages,
+        send_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_paid_media,
+        send_media_group,
+        send_location,
+        edit_message_live_location,
+        edit_message_live_location_inline,
+        stop_message_live_location,
+        stop_message_live_location_inline,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_checklist,
+        send_dice,
+        send_chat_action,
+        set_message_reaction,
+        get_user_profile_photos,
+        set_user_emoji_status,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        answer_callback_query,
+        get_user_chat_boosts,
+        set_my_commands,
+        get_business_connection,
+        get_my_commands,
+        set_my_name,
+        get_my_name,
+        set_my_description,
+        get_my_description,
+        set_my_short_description,
+        get_my_short_description,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        save_prepared_inline_message,
+        edit_message_text,
+        edit_message_text_inline,
+        edit_message_caption,
+        edit_message_caption_inline,
+        edit_message_media,
+        edit_message_media_inline,
+        edit_message_checklist,
+        edit_message_reply_markup,
+        edit_message_reply_markup_inline,
+        stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
+        delete_message,
+        delete_messages,
+        get_available_gifts,
+        send_gift,
+        send_gift_chat,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
+        send_sticker,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        replace_sticker_in_set,
+        set_sticker_set_thumbnail,
+        set_custom_emoji_sticker_set_thumbnail,
+        set_sticker_set_title,
+        delete_sticker_set,
+        set_sticker_emoji_list,
+        set_sticker_keywords,
+        set_sticker_mask_position,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
+        edit_user_star_subscription,
+        set_passport_data_errors,
+        send_game,
+        set_game_score,
+        set_game_score_inline,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fwd_inner, fty
+    }
+}
+
+download_forward! {
+    B
+    Recorder<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`Recorder`] methods.
+#[must_use = "Requests are lazy and do nothing unless sent"]
+#[derive(Clone)]
+pub struct RecorderRequest<R> {
+    inner: R,
+    cassette: Cassette,
+}
+
+impl<R> HasPayload for RecorderRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for RecorderRequest<R>
+where
+    R: Request<Err = RequestError>,
+    R::Payload: Serialize,
+    Output<R>: Serialize,
+{
+    type Err = RequestError;
+    type Send = RecorderSend<R::Send>;
+    type SendRef = RecorderSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        let payload = payload(self.inner.payload_ref());
+        RecorderSend::new(self.inner.send(), self.cassette, R::Payload::NAME, payload)
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        let payload = payload(self.inner.payload_ref());
+        RecorderSend::new(self.inner.send_ref(), self.cassette.clone(), R::Payload::NAME, payload)
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_timeout(timeout)
+    }
+}
+
+impl<R> IntoFuture for RecorderRequest<R>
+where
+    R: Request<Err = RequestError>,
+    R::Payload: Serialize,
+    Output<R>: Serialize,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+/// Future returned by [`RecorderRequest`]s.
+#[pin_project::pin_project]
+pub struct RecorderSend<F> {
+    #[pin]
+    inner: F,
+    cassette: Cassette,
+    method: &'static str,
+    /// Taken when the entry is recorded.
+    payload: Option<Value>,
+}
+
+impl<F> RecorderSend<F> {
+    fn new(inner: F, cassette: Cassette, method: &'static str, payload: Value) -> Self {
+        Self { inner, cassette, method, payload: Some(payload) }
+    }
+}
+
+impl<F> Future for RecorderSend<F>
+where
+    F: TryFuture<Error = RequestError>,
+    F::Ok: Serialize,
+{
+    type Output = ResponseResult<F::Ok>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let res = ready!(this.inner.try_poll(cx));
+
+        if let Some(payload) = this.payload.take() {
+            this.cassette.write(Entry::new(this.method, payload, &res));
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+/// Serves responses from a cassette, recorded by a [`Recorder`].
+///
+/// Each request is served by the first entry of the cassette, which wasn't
+/// replayed yet and has the same method and payload. This way requests, which
+/// were made concurrently (e.g. by handlers of different chats), don't have to
+/// complete in the same order as they did when recorded, while identical
+/// requests are still served in the recorded order. If there is no such entry,
+/// the request fails with [`RequestError::Io`] and the failure is remembered,
+/// see [`Replayer::assert_finished`].
+///
+/// Nothing is sent to Telegram. Since `get_updates` requests are recorded too,
+/// a bot using polling receives the recorded updates, so a whole conversation
+/// can be replayed against new versions of handlers. Note that once all updates
+/// are replayed, polling keeps failing with "unexpected request" errors until
+/// the dispatcher is stopped.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide_core::{adaptors::cassette::Replayer, prelude::*, types::ChatId};
+///
+/// # async {
+/// let bot = Replayer::open("cassette.jsonl")?;
+///
+/// // Returns the recorded response
+/// bot.send_message(ChatId(0), "Hi!").await?;
+///
+/// bot.assert_finished();
+/// # Ok::<_, Box<dyn std::error::Error>>(()) };
+/// ```
+#[derive(Clone, Debug)]
+pub struct Replayer {
+    /// Used only to build requests, which are never sent.
+    bot: Bot<NeverSent>,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    entries: VecDeque<Entry>,
+    failures: Vec<String>,
+}
+
+impl Replayer {
+    /// Creates a replayer, which serves `entries`.
+    pub fn new<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = Entry>,
+    {
+        let state = State { entries: entries.into_iter().collect(), failures: Vec::new() };
+
+        Self { bot: Bot::with_transport("", NeverSent), state: Arc::new(Mutex::new(state)) }
+    }
+
+    /// Creates a replayer from a cassette read from `reader`.
+    ///
+    /// Empty lines are ignored. Lines, which are not valid entries, are
+    /// reported as [`io::ErrorKind::InvalidData`] errors.
+    pub fn from_reader<R>(reader: R) -> io::Result<Self>
+    where
+        R: BufRead,
+    {
+        let mut entries = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                entries.push(serde_json::from_str(&line)?);
+            }
+        }
+
+        Ok(Self::new(entries))
+    }
+
+    /// Creates a replayer from a cassette stored in a file at `path`.
+    pub fn open<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Returns the number of entries which weren't replayed yet.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Panics if there were unexpected requests or if not all entries were
+    /// replayed.
+    #[track_caller]
+    pub fn assert_finished(&self) {
+        let state = self.state.lock().unwrap();
+
+        if !state.failures.is_empty() {
+            panic!("unexpected requests were made:\n{}", state.failures.join("\n"));
+        }
+        if let Some(next) = state.entries.front() {
+            panic!(
+                "{} entries weren't replayed, the next one is `{}` with payload {}",
+                state.entries.len(),
+                next.method,
+                next.payload
+            );
+        }
+    }
+}
+
+macro_rules! fty_replay {
+    ($T:ident) => {
+        ReplayerRequest<<Bot<NeverSent> as Requester>::$T>
+    };
+}
+
+macro_rules! fwd_replay {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        ReplayerRequest { inner: $this.bot.$m($($arg),*), state: Arc::clone(&$this.state) }
+    };
+}
+
+impl Requester for Replayer {
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        forward_messages,
+        copy_message,
+        copy_messages,
+        send_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_paid_media,
+        send_media_group,
+        send_location,
+        edit_message_live_location,
+        edit_message_live_location_inline,
+        stop_message_live_location,
+        stop_message_live_location_inline,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_checklist,
+        send_dice,
+        send_chat_action,
+        set_message_reaction,
+        get_user_profile_photos,
+        set_user_emoji_status,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        answer_callback_query,
+        get_user_chat_boosts,
+        set_my_commands,
+        get_business_connection,
+        get_my_commands,
+        set_my_name,
+        get_my_name,
+        set_my_description,
+        get_my_description,
+        set_my_short_description,
+        get_my_short_description,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        save_prepared_inline_message,
+        edit_message_text,
+        edit_message_text_inline,
+        edit_message_caption,
+        edit_message_caption_inline,
+        edit_message_media,
+        edit_message_media_inline,
+        edit_message_checklist,
+        edit_message_reply_markup,
+        edit_message_reply_markup_inline,
+        stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
+        delete_message,
+        delete_messages,
+        get_available_gifts,
+        send_gift,
+        send_gift_chat,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
+        send_sticker,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        replace_sticker_in_set,
+        set_sticker_set_thumbnail,
+        set_custom_emoji_sticker_set_thumbnail,
+        set_sticker_set_title,
+        delete_sticker_set,
+        set_sticker_emoji_list,
+        set_sticker_keywords,
+        set_sticker_mask_position,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
+        edit_user_star_subscription,
+        set_passport_data_errors,
+        send_game,
+        set_game_score,
+        set_game_score_inline,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fwd_replay, fty_replay
+    }
+}
+
+/// Request returned by [`Replayer`] methods.
+#[must_use = "Requests are lazy and do nothing unless sent"]
+#[derive(Clone)]
+pub struct ReplayerRequest<R> {
+    inner: R,
+    state: Arc<Mutex<State>>,
+}
+
+impl<R> ReplayerRequest<R>
+where
+    R: HasPayload,
+    R::Payload: Serialize,
+    Output<R>: DeserializeOwned,
+{
+    fn replay(&self) -> ResponseResult<Output<R>> {
+        let method = R::Payload::NAME;
+        let payload = payload(self.inner.payload_ref());
+
+        let mut state = self.state.lock().unwrap();
+        let position =
+            state.entries.iter().position(|e| e.method == method && e.payload == payload);
+        if let Some(entry) = position.and_then(|i| state.entries.remove(i)) {
+            return entry.response();
+        }
+
+        let failure = match state.entries.front() {
+            Some(next) => format!(
+                "unexpected request `{method}` with payload {payload}, the next entry is `{}` \
+                 with payload {}",
+                next.method, next.payload
+            ),
+            None => format!(
+                "unexpected request `{method}` with payload {payload}, the cassette is over"
+            ),
+        };
+
+        state.failures.push(failure.clone());
+        Err(RequestError::Io(io::Error::other(failure)))
+    }
+}
+
+impl<R> HasPayload for ReplayerRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for ReplayerRequest<R>
+where
+    R: Request,
+    R::Payload: Serialize,
+    Output<R>: DeserializeOwned + Send,
+{
+    type Err = RequestError;
+    type Send = future::Ready<ResponseResult<Output<R>>>;
+    type SendRef = future::Ready<ResponseResult<Output<R>>>;
+
+    fn send(self) -> Self::Send {
+        future::ready(self.replay())
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        future::ready(self.replay())
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_timeout(timeout)
+    }
+}
+
+impl<R> IntoFuture for ReplayerRequest<R>
+where
+    R: Request,
+    R::Payload: Serialize,
+    Output<R>: DeserializeOwned + Send,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+mod never_sent {
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use futures::{future::BoxFuture, stream::BoxStream};
+    use url::Url;
+
+    use crate::{
+        net::{multipart::Form, Transport},
+        DownloadError, RequestError,
+    };
+
+    /// A [`Transport`] of the [`Bot`] used by [`Replayer`] to build requests.
+    ///
+    /// [`Replayer`] answers requests itself, so this transport is never used.
+    /// It's public only because it appears in the request types of
+    /// [`Replayer`], but it can't be named outside of this module.
+    ///
+    /// [`Bot`]: crate::Bot
+    /// [`Replayer`]: super::Replayer
+    #[derive(Clone, Debug)]
+    pub struct NeverSent;
+
+    impl Transport for NeverSent {
+        fn request_json(
+            &self,
+            _: Url,
+            _: Vec<u8>,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            unreachable!("requests of `Replayer` are never sent")
+        }
+
+        fn request_multipart(
+            &self,
+            _: Url,
+            _: Form,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            unreachable!("requests of `Replayer` are never sent")
+        }
+
+        fn download(&self, _: Url, _: u64) -> BoxStream<'static, Result<Bytes, DownloadError>> {
+            unreachable!("`Replayer` doesn't download files")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        panic::AssertUnwindSafe,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use bytes::Bytes;
+    use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+    use url::Url;
+
+    use super::*;
+    use crate::{
+        net::{multipart::Form, Transport},
+        types::{ChatId, InputFile, MessageId},
+        DownloadError,
+    };
+
+    /// A transport which responds to `DeleteMessage` with `true` and fails all
+    /// other requests as if the bot was blocked.
+    #[derive(Clone)]
+    struct Fake;
+
+    impl Fake {
+        fn respond(url: &Url) -> BoxFuture<'static, Result<String, RequestError>> {
+            let response = if url.path().ends_with("/DeleteMessage") {
+                r#"{"ok":true,"result":true}"#
+            } else {
+                r#"{"ok":false,"error_code":403,"description":"Forbidden: bot was blocked by the user"}"#
+            };
+
+            futures::future::ok(response.to_owned()).boxed()
+        }
+    }
+
+    impl Transport for Fake {
+        fn request_json(
+            &self,
+            url: Url,
+            _: Vec<u8>,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            Self::respond(&url)
+        }
+
+        fn request_multipart(
+            &self,
+            url: Url,
+            _: Form,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            Self::respond(&url)
+        }
+
+        fn download(&self, _: Url, _: u64) -> BoxStream<'static, Result<Bytes, DownloadError>> {
+            futures::stream::empty().boxed()
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let buffer = Buffer::default();
+        let recorder = Recorder::new(Bot::with_transport("TOKEN", Fake), buffer.clone());
+
+        recorder.delete_message(ChatId(1), MessageId(2)).await.unwrap();
+        let photo = InputFile::memory(&b"photo"[..]);
+        assert!(matches!(
+            recorder.send_photo(ChatId(1), photo).await,
+            Err(RequestError::Api(ApiError::BotBlocked))
+        ));
+
+        let cassette = buffer.0.lock().unwrap().clone();
+        assert_eq!(cassette.iter().filter(|&&b| b == b'\n').count(), 2);
+
+        let replayer = Replayer::from_reader(&cassette[..]).unwrap();
+        assert_eq!(replayer.remaining(), 2);
+
+        // Payloads must match
+        let err = replayer.delete_message(ChatId(1), MessageId(3)).await.unwrap_err();
+        assert!(matches!(err, RequestError::Io(_)));
+        assert_eq!(replayer.remaining(), 2);
+
+        replayer.delete_message(ChatId(1), MessageId(2)).await.unwrap();
+        // Identifiers of uploaded files are ignored
+        let photo = InputFile::memory(&b"another photo"[..]);
+        assert!(matches!(
+            replayer.send_photo(ChatId(1), photo).await,
+            Err(RequestError::Api(ApiError::BotBlocked))
+        ));
+        assert_eq!(replayer.remaining(), 0);
+
+        // The unexpected request is reported
+        let res = std::panic::catch_unwind(AssertUnwindSafe(|| replayer.assert_finished()));
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn interleaved_requests() {
+        let buffer = Buffer::default();
+        let recorder = Recorder::new(Bot::with_transport("TOKEN", Fake), buffer.clone());
+
+        let first = recorder.delete_message(ChatId(1), MessageId(1));
+        let second = recorder.send_message(ChatId(2), "hi");
+        let (first, second) = futures::join!(first.into_future(), second.into_future());
+        first.unwrap();
+        second.unwrap_err();
+
+        let cassette = buffer.0.lock().unwrap().clone();
+        let replayer = Replayer::from_reader(&cassette[..]).unwrap();
+
+        // Requests are made in the order, opposite to the recorded one
+        let second = replayer.send_message(ChatId(2), "hi").into_future();
+        let first = replayer.delete_message(ChatId(1), MessageId(1)).into_future();
+        let (second, first) = futures::join!(second, first);
+        first.unwrap();
+        assert!(matches!(second, Err(RequestError::Api(ApiError::BotBlocked))));
+
+        replayer.assert_finished();
+    }
+
+    #[test]
+    fn errors_round_trip() {
+        let errors = [
+            RequestError::Api(ApiError::InvalidToken),
+            RequestError::Api(ApiError::MessageNotModified),
+            RequestError::Api(ApiError::CantParseEntities(
+                "Bad Request: can't parse entities: Unsupported start tag \"foo\" at byte offset 0"
+                    .to_owned(),
+            )),
+            RequestError::Api(ApiError::Unknown("Some new error".to_owned())),
+            RequestError::MigrateToChatId(ChatId(-100)),
+            RequestError::RetryAfter(Seconds::from_seconds(10)),
+            RequestError::Network("connection reset".into()),
+            RequestError::InvalidJson {
+                source: serde_json::from_str::<Value>("{").unwrap_err(),
+                raw: "{".into(),
+            },
+            RequestError::Io(io::Error::other("file not found")),
+        ];
+
+        for err in errors {
+            let expected = err.to_string();
+            let expected_variant = std::mem::discriminant(&err);
+            let entry = Entry::new("GetMe", Value::Null, &Err::<True, _>(err)).unwrap();
+
+            // Entries must survive being written to and read from a cassette
+            let entry: Entry =
+                serde_json::from_str(&serde_json::to_string(&entry).unwrap()).unwrap();
+
+            let actual = entry.response::<True>().unwrap_err();
+            assert_eq!(std::mem::discriminant(&actual), expected_variant);
+            assert_eq!(actual.to_string(), expected);
+        }
+    }
+}
@@ -1,7 +1,7 @@
-use std::{future::IntoFuture, sync::Arc};
+use std::{future::IntoFuture, sync::Arc, time::Duration};
 
 use futures::{future::BoxFuture, FutureExt};
-use reqwest::Url;
+use url::Url;
 
 use crate::{
     errors::AsResponseParameters,
@@ -88,6 +88,10 @@ where
     fn send_ref(&self) -> Self::SendRef {
         self.inner.send_ref()
     }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_timeout(timeout)
+    }
 }
 
 impl<T, E> IntoFuture for ErasedRequest<'_, T, E>
@@ -113,6 +117,8 @@ trait ErasableRequest<'a>: HasPayload {
     fn send_box(self: Box<Self>) -> BoxFuture<'a, Result<Output<Self>, Self::Err>>;
 
     fn send_ref(&self) -> BoxFuture<'a, Result<Output<Self>, Self::Err>>;
+
+    fn set_timeout(&mut self, timeout: Duration);
 }
 
 impl<'a, R> ErasableRequest<'a> for R
@@ -130,6 +136,10 @@ where
     fn send_ref(&self) -> BoxFuture<'a, Result<Output<Self>, Self::Err>> {
         Request::send_ref(self).boxed()
     }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        Request::set_timeout(self, timeout)
+    }
 }
 
 macro_rules! fty {
@@ -146,6 +156,9 @@ macro_rules! fwd_erased {
     (@convert send_media_group, $arg:ident, media : $T:ty) => {
         $arg.into_iter().collect()
     };
+    (@convert send_paid_media, $arg:ident, media : $T:ty) => {
+        $arg.into_iter().collect()
+    };
     (@convert $m:ident, $arg:ident, options : $T:ty) => {
         $arg.into_iter().collect()
     };
@@ -204,6 +217,7 @@ where
         send_animation,
         send_voice,
         send_video_note,
+        send_paid_media,
         send_media_group,
         send_location,
         edit_message_live_location,
@@ -213,10 +227,12 @@ where
         send_venue,
         send_contact,
         send_poll,
+        send_checklist,
         send_dice,
         send_chat_action,
         set_message_reaction,
         get_user_profile_photos,
+        set_user_emoji_status,
         get_file,
         kick_chat_member,
         ban_chat_member,
@@ -230,6 +246,8 @@ where
         export_chat_invite_link,
         create_chat_invite_link,
         edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
         revoke_chat_invite_link,
         set_chat_photo,
         delete_chat_photo,
@@ -277,17 +295,45 @@ where
         delete_my_commands,
         answer_inline_query,
         answer_web_app_query,
+        save_prepared_inline_message,
         edit_message_text,
         edit_message_text_inline,
         edit_message_caption,
         edit_message_caption_inline,
         edit_message_media,
         edit_message_media_inline,
+        edit_message_checklist,
         edit_message_reply_markup,
         edit_message_reply_markup_inline,
         stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
         delete_message,
         delete_messages,
+        get_available_gifts,
+        send_gift,
+        send_gift_chat,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
         send_sticker,
         get_sticker_set,
         get_custom_emoji_stickers,
@@ -310,6 +356,7 @@ where
         answer_pre_checkout_query,
         get_star_transactions,
         refund_star_payment,
+        edit_user_star_subscription,
         set_passport_data_errors,
         send_game,
         set_game_score,
@@ -416,6 +463,13 @@ trait ErasableRequester<'a> {
         video_note: InputFile,
     ) -> ErasedRequest<'a, SendVideoNote, Self::Err>;
 
+    fn send_paid_media(
+        &self,
+        chat_id: Recipient,
+        star_count: u32,
+        media: Vec<InputPaidMedia>,
+    ) -> ErasedRequest<'a, SendPaidMedia, Self::Err>;
+
     fn send_media_group(
         &self,
         chat_id: Recipient,
@@ -478,6 +532,13 @@ trait ErasableRequester<'a> {
         options: Vec<InputPollOption>,
     ) -> ErasedRequest<'a, SendPoll, Self::Err>;
 
+    fn send_checklist(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: ChatId,
+        checklist: InputChecklist,
+    ) -> ErasedRequest<'a, SendChecklist, Self::Err>;
+
     fn send_dice(&self, chat_id: Recipient) -> ErasedRequest<'a, SendDice, Self::Err>;
 
     fn send_chat_action(
@@ -497,6 +558,11 @@ trait ErasableRequester<'a> {
         user_id: UserId,
     ) -> ErasedRequest<'a, GetUserProfilePhotos, Self::Err>;
 
+    fn set_user_emoji_status(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, SetUserEmojiStatus, Self::Err>;
+
     fn get_file(&self, file_id: String) -> ErasedRequest<'a, GetFile, Self::Err>;
 
     fn ban_chat_member(
@@ -571,6 +637,19 @@ trait ErasableRequester<'a> {
         invite_link: String,
     ) -> ErasedRequest<'a, EditChatInviteLink, Self::Err>;
 
+    fn create_chat_subscription_invite_link(
+        &self,
+        chat_id: Recipient,
+        subscription_period: Seconds,
+        subscription_price: u32,
+    ) -> ErasedRequest<'a, CreateChatSubscriptionInviteLink, Self::Err>;
+
+    fn edit_chat_subscription_invite_link(
+        &self,
+        chat_id: Recipient,
+        invite_link: String,
+    ) -> ErasedRequest<'a, EditChatSubscriptionInviteLink, Self::Err>;
+
     fn revoke_chat_invite_link(
         &self,
         chat_id: Recipient,
@@ -799,6 +878,12 @@ trait ErasableRequester<'a> {
         result: InlineQueryResult,
     ) -> ErasedRequest<'a, AnswerWebAppQuery, Self::Err>;
 
+    fn save_prepared_inline_message(
+        &self,
+        user_id: UserId,
+        result: InlineQueryResult,
+    ) -> ErasedRequest<'a, SavePreparedInlineMessage, Self::Err>;
+
     fn edit_message_text(
         &self,
         chat_id: Recipient,
@@ -836,6 +921,14 @@ trait ErasableRequester<'a> {
         media: InputMedia,
     ) -> ErasedRequest<'a, EditMessageMediaInline, Self::Err>;
 
+    fn edit_message_checklist(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: ChatId,
+        message_id: MessageId,
+        checklist: InputChecklist,
+    ) -> ErasedRequest<'a, EditMessageChecklist, Self::Err>;
+
     fn edit_message_reply_markup(
         &self,
         chat_id: Recipient,
@@ -853,6 +946,18 @@ trait ErasableRequester<'a> {
         message_id: MessageId,
     ) -> ErasedRequest<'a, StopPoll, Self::Err>;
 
+    fn approve_suggested_post(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, ApproveSuggestedPost, Self::Err>;
+
+    fn decline_suggested_post(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, DeclineSuggestedPost, Self::Err>;
+
     fn delete_message(
         &self,
         chat_id: Recipient,
@@ -865,6 +970,133 @@ trait ErasableRequester<'a> {
         message_ids: Vec<MessageId>,
     ) -> ErasedRequest<'a, DeleteMessages, Self::Err>;
 
+    fn get_available_gifts(&self) -> ErasedRequest<'a, GetAvailableGifts, Self::Err>;
+
+    fn send_gift(&self, user_id: UserId, gift_id: String)
+        -> ErasedRequest<'a, SendGift, Self::Err>;
+
+    fn send_gift_chat(
+        &self,
+        chat_id: Recipient,
+        gift_id: String,
+    ) -> ErasedRequest<'a, SendGiftChat, Self::Err>;
+
+    fn verify_user(&self, user_id: UserId) -> ErasedRequest<'a, VerifyUser, Self::Err>;
+
+    fn verify_chat(&self, chat_id: Recipient) -> ErasedRequest<'a, VerifyChat, Self::Err>;
+
+    fn remove_user_verification(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, RemoveUserVerification, Self::Err>;
+
+    fn remove_chat_verification(
+        &self,
+        chat_id: Recipient,
+    ) -> ErasedRequest<'a, RemoveChatVerification, Self::Err>;
+
+    fn read_business_message(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, ReadBusinessMessage, Self::Err>;
+
+    fn delete_business_messages(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        message_ids: Vec<MessageId>,
+    ) -> ErasedRequest<'a, DeleteBusinessMessages, Self::Err>;
+
+    fn set_business_account_name(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        first_name: String,
+    ) -> ErasedRequest<'a, SetBusinessAccountName, Self::Err>;
+
+    fn set_business_account_username(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, SetBusinessAccountUsername, Self::Err>;
+
+    fn set_business_account_bio(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, SetBusinessAccountBio, Self::Err>;
+
+    fn set_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        photo: InputProfilePhoto,
+    ) -> ErasedRequest<'a, SetBusinessAccountProfilePhoto, Self::Err>;
+
+    fn remove_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, RemoveBusinessAccountProfilePhoto, Self::Err>;
+
+    fn set_business_account_gift_settings(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        show_gift_button: bool,
+        accepted_gift_types: AcceptedGiftTypes,
+    ) -> ErasedRequest<'a, SetBusinessAccountGiftSettings, Self::Err>;
+
+    fn get_business_account_star_balance(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, GetBusinessAccountStarBalance, Self::Err>;
+
+    fn transfer_business_account_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        star_count: u32,
+    ) -> ErasedRequest<'a, TransferBusinessAccountStars, Self::Err>;
+
+    fn get_business_account_gifts(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, GetBusinessAccountGifts, Self::Err>;
+
+    fn convert_gift_to_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+    ) -> ErasedRequest<'a, ConvertGiftToStars, Self::Err>;
+
+    fn upgrade_gift(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+    ) -> ErasedRequest<'a, UpgradeGift, Self::Err>;
+
+    fn transfer_gift(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+        new_owner_chat_id: ChatId,
+    ) -> ErasedRequest<'a, TransferGift, Self::Err>;
+
+    fn post_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        content: InputStoryContent,
+        active_period: Seconds,
+    ) -> ErasedRequest<'a, PostStory, Self::Err>;
+
+    fn edit_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+        content: InputStoryContent,
+    ) -> ErasedRequest<'a, EditStory, Self::Err>;
+
+    fn delete_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+    ) -> ErasedRequest<'a, DeleteStory, Self::Err>;
+
     fn send_sticker(
         &self,
         chat_id: Recipient,
@@ -999,6 +1231,13 @@ trait ErasableRequester<'a> {
         telegram_payment_charge_id: String,
     ) -> ErasedRequest<'a, RefundStarPayment, Self::Err>;
 
+    fn edit_user_star_subscription(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: String,
+        is_canceled: bool,
+    ) -> ErasedRequest<'a, EditUserStarSubscription, Self::Err>;
+
     fn set_passport_data_errors(
         &self,
         user_id: UserId,
@@ -1167,6 +1406,15 @@ where
         Requester::send_video_note(self, chat_id, video_note).erase()
     }
 
+    fn send_paid_media(
+        &self,
+        chat_id: Recipient,
+        star_count: u32,
+        media: Vec<InputPaidMedia>,
+    ) -> ErasedRequest<'a, SendPaidMedia, Self::Err> {
+        Requester::send_paid_media(self, chat_id, star_count, media).erase()
+    }
+
     fn send_media_group(
         &self,
         chat_id: Recipient,
@@ -1249,6 +1497,15 @@ where
         Requester::send_poll(self, chat_id, question, options).erase()
     }
 
+    fn send_checklist(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: ChatId,
+        checklist: InputChecklist,
+    ) -> ErasedRequest<'a, SendChecklist, Self::Err> {
+        Requester::send_checklist(self, business_connection_id, chat_id, checklist).erase()
+    }
+
     fn send_dice(&self, chat_id: Recipient) -> ErasedRequest<'a, SendDice, Self::Err> {
         Requester::send_dice(self, chat_id).erase()
     }
@@ -1276,6 +1533,13 @@ where
         Requester::get_user_profile_photos(self, user_id).erase()
     }
 
+    fn set_user_emoji_status(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, SetUserEmojiStatus, Self::Err> {
+        Requester::set_user_emoji_status(self, user_id).erase()
+    }
+
     fn get_file(&self, file_id: String) -> ErasedRequest<'a, GetFile, Self::Err> {
         Requester::get_file(self, file_id).erase()
     }
@@ -1376,6 +1640,29 @@ where
         Requester::edit_chat_invite_link(self, chat_id, invite_link).erase()
     }
 
+    fn create_chat_subscription_invite_link(
+        &self,
+        chat_id: Recipient,
+        subscription_period: Seconds,
+        subscription_price: u32,
+    ) -> ErasedRequest<'a, CreateChatSubscriptionInviteLink, Self::Err> {
+        Requester::create_chat_subscription_invite_link(
+            self,
+            chat_id,
+            subscription_period,
+            subscription_price,
+        )
+        .erase()
+    }
+
+    fn edit_chat_subscription_invite_link(
+        &self,
+        chat_id: Recipient,
+        invite_link: String,
+    ) -> ErasedRequest<'a, EditChatSubscriptionInviteLink, Self::Err> {
+        Requester::edit_chat_subscription_invite_link(self, chat_id, invite_link).erase()
+    }
+
     fn revoke_chat_invite_link(
         &self,
         chat_id: Recipient,
@@ -1702,6 +1989,14 @@ where
         Requester::answer_web_app_query(self, web_app_query_id, result).erase()
     }
 
+    fn save_prepared_inline_message(
+        &self,
+        user_id: UserId,
+        result: InlineQueryResult,
+    ) -> ErasedRequest<'a, SavePreparedInlineMessage, Self::Err> {
+        Requester::save_prepared_inline_message(self, user_id, result).erase()
+    }
+
     fn edit_message_text(
         &self,
         chat_id: Recipient,
@@ -1751,6 +2046,23 @@ where
         Requester::edit_message_media_inline(self, inline_message_id, media).erase()
     }
 
+    fn edit_message_checklist(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: ChatId,
+        message_id: MessageId,
+        checklist: InputChecklist,
+    ) -> ErasedRequest<'a, EditMessageChecklist, Self::Err> {
+        Requester::edit_message_checklist(
+            self,
+            business_connection_id,
+            chat_id,
+            message_id,
+            checklist,
+        )
+        .erase()
+    }
+
     fn edit_message_reply_markup(
         &self,
         chat_id: Recipient,
@@ -1774,6 +2086,22 @@ where
         Requester::stop_poll(self, chat_id, message_id).erase()
     }
 
+    fn approve_suggested_post(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, ApproveSuggestedPost, Self::Err> {
+        Requester::approve_suggested_post(self, chat_id, message_id).erase()
+    }
+
+    fn decline_suggested_post(
+        &self,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, DeclineSuggestedPost, Self::Err> {
+        Requester::decline_suggested_post(self, chat_id, message_id).erase()
+    }
+
     fn delete_message(
         &self,
         chat_id: Recipient,
@@ -1790,6 +2118,191 @@ where
         Requester::delete_messages(self, chat_id, message_ids).erase()
     }
 
+    fn get_available_gifts(&self) -> ErasedRequest<'a, GetAvailableGifts, Self::Err> {
+        Requester::get_available_gifts(self).erase()
+    }
+
+    fn send_gift(
+        &self,
+        user_id: UserId,
+        gift_id: String,
+    ) -> ErasedRequest<'a, SendGift, Self::Err> {
+        Requester::send_gift(self, user_id, gift_id).erase()
+    }
+
+    fn send_gift_chat(
+        &self,
+        chat_id: Recipient,
+        gift_id: String,
+    ) -> ErasedRequest<'a, SendGiftChat, Self::Err> {
+        Requester::send_gift_chat(self, chat_id, gift_id).erase()
+    }
+
+    fn verify_user(&self, user_id: UserId) -> ErasedRequest<'a, VerifyUser, Self::Err> {
+        Requester::verify_user(self, user_id).erase()
+    }
+
+    fn verify_chat(&self, chat_id: Recipient) -> ErasedRequest<'a, VerifyChat, Self::Err> {
+        Requester::verify_chat(self, chat_id).erase()
+    }
+
+    fn remove_user_verification(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, RemoveUserVerification, Self::Err> {
+        Requester::remove_user_verification(self, user_id).erase()
+    }
+
+    fn remove_chat_verification(
+        &self,
+        chat_id: Recipient,
+    ) -> ErasedRequest<'a, RemoveChatVerification, Self::Err> {
+        Requester::remove_chat_verification(self, chat_id).erase()
+    }
+
+    fn read_business_message(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, ReadBusinessMessage, Self::Err> {
+        Requester::read_business_message(self, business_connection_id, chat_id, message_id).erase()
+    }
+
+    fn delete_business_messages(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        message_ids: Vec<MessageId>,
+    ) -> ErasedRequest<'a, DeleteBusinessMessages, Self::Err> {
+        Requester::delete_business_messages(self, business_connection_id, message_ids).erase()
+    }
+
+    fn set_business_account_name(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        first_name: String,
+    ) -> ErasedRequest<'a, SetBusinessAccountName, Self::Err> {
+        Requester::set_business_account_name(self, business_connection_id, first_name).erase()
+    }
+
+    fn set_business_account_username(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, SetBusinessAccountUsername, Self::Err> {
+        Requester::set_business_account_username(self, business_connection_id).erase()
+    }
+
+    fn set_business_account_bio(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, SetBusinessAccountBio, Self::Err> {
+        Requester::set_business_account_bio(self, business_connection_id).erase()
+    }
+
+    fn set_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        photo: InputProfilePhoto,
+    ) -> ErasedRequest<'a, SetBusinessAccountProfilePhoto, Self::Err> {
+        Requester::set_business_account_profile_photo(self, business_connection_id, photo).erase()
+    }
+
+    fn remove_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, RemoveBusinessAccountProfilePhoto, Self::Err> {
+        Requester::remove_business_account_profile_photo(self, business_connection_id).erase()
+    }
+
+    fn set_business_account_gift_settings(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        show_gift_button: bool,
+        accepted_gift_types: AcceptedGiftTypes,
+    ) -> ErasedRequest<'a, SetBusinessAccountGiftSettings, Self::Err> {
+        Requester::set_business_account_gift_settings(
+            self,
+            business_connection_id,
+            show_gift_button,
+            accepted_gift_types,
+        )
+        .erase()
+    }
+
+    fn get_business_account_star_balance(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, GetBusinessAccountStarBalance, Self::Err> {
+        Requester::get_business_account_star_balance(self, business_connection_id).erase()
+    }
+
+    fn transfer_business_account_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        star_count: u32,
+    ) -> ErasedRequest<'a, TransferBusinessAccountStars, Self::Err> {
+        Requester::transfer_business_account_stars(self, business_connection_id, star_count).erase()
+    }
+
+    fn get_business_account_gifts(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, GetBusinessAccountGifts, Self::Err> {
+        Requester::get_business_account_gifts(self, business_connection_id).erase()
+    }
+
+    fn convert_gift_to_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+    ) -> ErasedRequest<'a, ConvertGiftToStars, Self::Err> {
+        Requester::convert_gift_to_stars(self, business_connection_id, owned_gift_id).erase()
+    }
+
+    fn upgrade_gift(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+    ) -> ErasedRequest<'a, UpgradeGift, Self::Err> {
+        Requester::upgrade_gift(self, business_connection_id, owned_gift_id).erase()
+    }
+
+    fn transfer_gift(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+        new_owner_chat_id: ChatId,
+    ) -> ErasedRequest<'a, TransferGift, Self::Err> {
+        Requester::transfer_gift(self, business_connection_id, owned_gift_id, new_owner_chat_id)
+            .erase()
+    }
+
+    fn post_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        content: InputStoryContent,
+        active_period: Seconds,
+    ) -> ErasedRequest<'a, PostStory, Self::Err> {
+        Requester::post_story(self, business_connection_id, content, active_period).erase()
+    }
+
+    fn edit_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+        content: InputStoryContent,
+    ) -> ErasedRequest<'a, EditStory, Self::Err> {
+        Requester::edit_story(self, business_connection_id, story_id, content).erase()
+    }
+
+    fn delete_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+    ) -> ErasedRequest<'a, DeleteStory, Self::Err> {
+        Requester::delete_story(self, business_connection_id, story_id).erase()
+    }
+
     fn send_sticker(
         &self,
         chat_id: Recipient,
@@ -1985,6 +2498,21 @@ where
         Requester::refund_star_payment(self, user_id, telegram_payment_charge_id).erase()
     }
 
+    fn edit_user_star_subscription(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: String,
+        is_canceled: bool,
+    ) -> ErasedRequest<'a, EditUserStarSubscription, Self::Err> {
+        Requester::edit_user_star_subscription(
+            self,
+            user_id,
+            telegram_payment_charge_id,
+            is_canceled,
+        )
+        .erase()
+    }
+
     fn set_passport_data_errors(
         &self,
         user_id: UserId,
@@ -0,0 +1,848 @@
+use std::{
+    cell::Cell,
+    collections::BTreeMap,
+    fmt::Write as _,
+    future::{Future, IntoFuture},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{self, Poll},
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use futures::{future::BoxFuture, ready, stream::BoxStream, FutureExt, TryFuture, TryFutureExt};
+use url::Url;
+
+use crate::{
+    net::{multipart::Form, Transport},
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+    Bot, DownloadError, RequestError,
+};
+
+/// Collects metrics of requests.
+///
+/// For each Bot API method this adaptor records the number of requests, a
+/// histogram of their latency, a histogram of response sizes and the number
+/// of errors, broken down by [`ErrorLabel`]s (e.g. the [`RequestError`]
+/// variant and the [`ApiError`] kind).
+///
+/// Metrics are stored in a [`Registry`], which can be shared between bots (see
+/// [`Metrics::with_registry`]) and can be used to take [`Snapshot`]s, e.g. to
+/// export them in the [Prometheus text format].
+///
+/// Only completed requests are recorded, i.e. requests which were dropped
+/// before a response was received are not.
+///
+/// Sizes of responses are measured by [`MetricsTransport`], which is
+/// installed by [`Bot::metrics`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide_core::{prelude::*, types::ChatId};
+///
+/// # async {
+/// let bot = Bot::new("TOKEN").metrics();
+///
+/// bot.send_message(ChatId(0), "Hi!").await?;
+///
+/// let snapshot = bot.snapshot();
+/// println!("{} requests", snapshot.methods["SendMessage"].requests);
+/// println!("{}", snapshot.to_prometheus());
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+///
+/// [`ApiError`]: crate::ApiError
+/// [Prometheus text format]: https://prometheus.io/docs/instrumenting/exposition_formats/#text-based-format
+#[derive(Clone, Debug)]
+pub struct Metrics<B> {
+    inner: B,
+    registry: Registry,
+}
+
+impl<B> Metrics<B> {
+    /// Creates a new adaptor with its own [`Registry`].
+    ///
+    /// Note: it's recommended to use [`RequesterExt::metrics`] instead.
+    ///
+    /// [`RequesterExt::metrics`]: crate::requests::RequesterExt::metrics
+    pub fn new(inner: B) -> Self {
+        Self::with_registry(inner, Registry::new())
+    }
+
+    /// Creates a new adaptor, which records metrics into `registry`.
+    pub fn with_registry(inner: B, registry: Registry) -> Self {
+        Self { inner, registry }
+    }
+
+    /// Allows to access inner bot
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Unwraps inner bot
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    /// Returns the registry metrics are recorded into.
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Returns current metrics, see [`Registry::snapshot`].
+    #[must_use]
+    pub fn snapshot(&self) -> Snapshot {
+        self.registry.snapshot()
+    }
+}
+
+/// Upper bounds (in seconds) of buckets of [`MethodMetrics::latency`].
+pub const LATENCY_BUCKETS: &[f64] =
+    &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+/// Upper bounds (in bytes) of buckets of [`MethodMetrics::response_size`].
+pub const RESPONSE_SIZE_BUCKETS: &[f64] =
+    &[128.0, 512.0, 1024.0, 4096.0, 16384.0, 65536.0, 262144.0, 1048576.0];
+
+tokio::task_local! {
+    /// Size of the last response body received by a [`MetricsTransport`]
+    /// while polling the inner request of a [`MetricsSend`].
+    static RESPONSE_SIZE: Cell<Option<usize>>;
+}
+
+impl<C> Bot<C>
+where
+    C: Transport,
+{
+    /// Collect metrics of requests, see [`Metrics`] for more.
+    ///
+    /// Unlike [`RequesterExt::metrics`], this also wraps the transport of the
+    /// bot into [`MetricsTransport`], so sizes of responses are recorded too.
+    ///
+    /// [`RequesterExt::metrics`]: crate::requests::RequesterExt::metrics
+    #[must_use]
+    pub fn metrics(self) -> Metrics<Bot<MetricsTransport<C>>> {
+        Metrics::new(self.map_transport(MetricsTransport::new))
+    }
+}
+
+/// A [`Transport`], which measures sizes of response bodies for the
+/// [`Metrics`] adaptor.
+///
+/// It's installed by [`Bot::metrics`]. If the bot is wrapped into other
+/// adaptors before [`Metrics`], the transport can be wrapped manually, e.g.
+/// with `bot.map_transport(MetricsTransport::new)`.
+#[derive(Clone, Debug)]
+pub struct MetricsTransport<C> {
+    inner: C,
+}
+
+impl<C> MetricsTransport<C> {
+    /// Wraps `inner` transport.
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    /// Allows to access inner transport
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Unwraps inner transport
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C> Transport for MetricsTransport<C>
+where
+    C: Transport,
+{
+    fn request_json(
+        &self,
+        url: Url,
+        body: Vec<u8>,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'static, Result<String, RequestError>> {
+        self.inner
+            .request_json(url, body, timeout)
+            .inspect_ok(|text| record_response_size(text.len()))
+            .boxed()
+    }
+
+    fn request_multipart(
+        &self,
+        url: Url,
+        form: Form,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'static, Result<String, RequestError>> {
+        self.inner
+            .request_multipart(url, form, timeout)
+            .inspect_ok(|text| record_response_size(text.len()))
+            .boxed()
+    }
+
+    fn download(&self, url: Url, offset: u64) -> BoxStream<'static, Result<Bytes, DownloadError>> {
+        self.inner.download(url, offset)
+    }
+}
+
+/// Records the size of a response body for the [`Metrics`] adaptor, which is
+/// sending the request (if any).
+fn record_response_size(size: usize) {
+    let _ = RESPONSE_SIZE.try_with(|cell| cell.set(Some(size)));
+}
+
+/// Storage of metrics, recorded by [`Metrics`].
+///
+/// `Registry` is cheap to clone, all clones refer to the same storage.
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    methods: Arc<Mutex<BTreeMap<&'static str, MethodMetrics>>>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns current metrics.
+    #[must_use]
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot { methods: self.methods.lock().unwrap().clone() }
+    }
+
+    /// Removes all recorded metrics.
+    pub fn reset(&self) {
+        self.methods.lock().unwrap().clear();
+    }
+
+    fn record(
+        &self,
+        method: &'static str,
+        latency: Duration,
+        response_size: Option<usize>,
+        result: Result<(), ErrorLabel>,
+    ) {
+        let mut methods = self.methods.lock().unwrap();
+        let metrics = methods.entry(method).or_insert_with(MethodMetrics::new);
+
+        metrics.requests += 1;
+        metrics.latency.observe(latency.as_secs_f64());
+        if let Some(size) = response_size {
+            metrics.response_size.observe(size as f64);
+        }
+        if let Err(label) = result {
+            *metrics.errors.entry(label).or_default() += 1;
+        }
+    }
+}
+
+/// Metrics of all methods at some point in time, see [`Registry::snapshot`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Snapshot {
+    /// Metrics of methods, by their [`Payload::NAME`] (e.g. `"SendMessage"`).
+    ///
+    /// Methods which weren't called are absent.
+    pub methods: BTreeMap<&'static str, MethodMetrics>,
+}
+
+/// Metrics of a single method.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct MethodMetrics {
+    /// The number of completed requests, including failed ones.
+    pub requests: u64,
+
+    /// The number of failed requests by the kind of the error.
+    pub errors: BTreeMap<ErrorLabel, u64>,
+
+    /// Latency of requests in seconds, see [`LATENCY_BUCKETS`].
+    pub latency: Histogram,
+
+    /// Sizes of response bodies in bytes, see [`RESPONSE_SIZE_BUCKETS`].
+    ///
+    /// Sizes are measured by [`MetricsTransport`], so they are only recorded
+    /// if the transport of the bot is wrapped into it (see [`Bot::metrics`]),
+    /// and only for requests which received a response.
+    pub response_size: Histogram,
+}
+
+impl MethodMetrics {
+    fn new() -> Self {
+        Self {
+            requests: 0,
+            errors: BTreeMap::new(),
+            latency: Histogram::new(LATENCY_BUCKETS),
+            response_size: Histogram::new(RESPONSE_SIZE_BUCKETS),
+        }
+    }
+
+    /// Returns the total number of failed requests.
+    #[must_use]
+    pub fn error_count(&self) -> u64 {
+        self.errors.values().sum()
+    }
+}
+
+/// A histogram of observed values.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Histogram {
+    /// Upper bounds of buckets.
+    pub bounds: &'static [f64],
+
+    /// The number of observed values in each bucket, i.e. `buckets[i]` values
+    /// were `<= bounds[i]` and `> bounds[i - 1]`. The last bucket (with index
+    /// `bounds.len()`) counts values greater than all bounds.
+    pub buckets: Vec<u64>,
+
+    /// The number of observed values.
+    pub count: u64,
+
+    /// The sum of observed values.
+    pub sum: f64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self { bounds, buckets: vec![0; bounds.len() + 1], count: 0, sum: 0.0 }
+    }
+
+    fn observe(&mut self, value: f64) {
+        let bucket = self.bounds.partition_point(|&bound| bound < value);
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.sum += value;
+    }
+}
+
+/// A kind of an error, see [`MetricsError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErrorLabel {
+    /// The kind of the error, e.g. the name of the [`RequestError`] variant
+    /// (`"Api"`, `"Network"`, ...).
+    pub kind: &'static str,
+
+    /// The kind of the API error, e.g. the name of the [`ApiError`] variant
+    /// (`"BotBlocked"`, ...), if the error is an API error.
+    ///
+    /// [`ApiError`]: crate::ApiError
+    pub api_error: Option<&'static str>,
+}
+
+/// Errors which can be broken down by the [`Metrics`] adaptor.
+pub trait MetricsError {
+    fn error_label(&self) -> ErrorLabel;
+}
+
+impl MetricsError for RequestError {
+    fn error_label(&self) -> ErrorLabel {
+        let kind = match self {
+            Self::Api(err) => return ErrorLabel { kind: "Api", api_error: Some(err.name()) },
+            Self::MigrateToChatId(_) => "MigrateToChatId",
+            Self::RetryAfter(_) => "RetryAfter",
+            Self::Network(_) => "Network",
+            Self::InvalidJson { .. } => "InvalidJson",
+            Self::Io(_) => "Io",
+        };
+
+        ErrorLabel { kind, api_error: None }
+    }
+}
+
+impl Snapshot {
+    /// Returns metrics in the [Prometheus text format].
+    ///
+    /// The following metrics are exported, labeled by `method`:
+    /// - `teloxide_requests_total` (counter)
+    /// - `teloxide_request_errors_total` (counter), additionally labeled by
+    ///   `error` and `api_error` (see [`ErrorLabel`])
+    /// - `teloxide_request_duration_seconds` (histogram)
+    /// - `teloxide_response_size_bytes` (histogram)
+    ///
+    /// [Prometheus text format]: https://prometheus.io/docs/instrumenting/exposition_formats/#text-based-format
+    #[must_use]
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        header(&mut out, "teloxide_requests_total", "counter", "Number of Bot API requests.");
+        for (method, metrics) in &self.methods {
+            let _ = writeln!(
+                out,
+                "teloxide_requests_total{{method=\"{method}\"}} {}",
+                metrics.requests
+            );
+        }
+
+        header(
+            &mut out,
+            "teloxide_request_errors_total",
+            "counter",
+            "Number of failed Bot API requests.",
+        );
+        for (method, metrics) in &self.methods {
+            for (label, count) in &metrics.errors {
+                let _ = write!(
+                    out,
+                    "teloxide_request_errors_total{{method=\"{method}\",error=\"{}\"",
+                    label.kind
+                );
+                if let Some(api_error) = label.api_error {
+                    let _ = write!(out, ",api_error=\"{api_error}\"");
+                }
+                let _ = writeln!(out, "}} {count}");
+            }
+        }
+
+        header(
+            &mut out,
+            "teloxide_request_duration_seconds",
+            "histogram",
+            "Latency of Bot API requests.",
+        );
+        for (method, metrics) in &self.methods {
+            histogram(&mut out, "teloxide_request_duration_seconds", method, &metrics.latency);
+        }
+
+        header(&mut out, "teloxide_response_size_bytes", "histogram", "Size of Bot API responses.");
+        for (method, metrics) in &self.methods {
+            histogram(&mut out, "teloxide_response_size_bytes", method, &metrics.response_size);
+        }
+
+        out
+    }
+}
+
+fn header(out: &mut String, name: &str, ty: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {ty}");
+}
+
+fn histogram(out: &mut String, name: &str, method: &str, histogram: &Histogram) {
+    let mut cumulative = 0;
+    for (bound, count) in histogram.bounds.iter().zip(&histogram.buckets) {
+        cumulative += count;
+        let _ = writeln!(out, "{name}_bucket{{method=\"{method}\",le=\"{bound}\"}} {cumulative}");
+    }
+    let _ = writeln!(out, "{name}_bucket{{method=\"{method}\",le=\"+Inf\"}} {}", histogram.count);
+    let _ = writeln!(out, "{name}_sum{{method=\"{method}\"}} {}", histogram.sum);
+    let _ = writeln!(out, "{name}_count{{method=\"{method}\"}} {}", histogram.count);
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        MetricsRequest<B::$T>
+    };
+}
+
+macro_rules! fwd_inner {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        MetricsRequest {
+            inner: $this.inner().$m($($arg),*),
+            registry: $this.registry.clone()
+        }
+    };
+}
+
+impl<B> Requester for Metrics<B>
+where
+    B: Requester,
+    B::Err: MetricsError,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        forward_messages,
+        copy_message,
+        copy_messages,
+        send_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_paid_media,
+        send_media_group,
+        send_location,
+        edit_message_live_location,
+        edit_message_live_location_inline,
+        stop_message_live_location,
+        stop_message_live_location_inline,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_checklist,
+        send_dice,
+        send_chat_action,
+        set_message_reaction,
+        get_user_profile_photos,
+        set_user_emoji_status,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        answer_callback_query,
+        get_user_chat_boosts,
+        set_my_commands,
+        get_business_connection,
+        get_my_commands,
+        set_my_name,
+        get_my_name,
+        set_my_description,
+        get_my_description,
+        set_my_short_description,
+        get_my_short_description,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        save_prepared_inline_message,
+        edit_message_text,
+        edit_message_text_inline,
+        edit_message_caption,
+        edit_message_caption_inline,
+        edit_message_media,
+        edit_message_media_inline,
+        edit_message_checklist,
+        edit_message_reply_markup,
+        edit_message_reply_markup_inline,
+        stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
+        delete_message,
+        delete_messages,
+        get_available_gifts,
+        send_gift,
+        send_gift_chat,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
+        send_sticker,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        replace_sticker_in_set,
+        set_sticker_set_thumbnail,
+        set_custom_emoji_sticker_set_thumbnail,
+        set_sticker_set_title,
+        delete_sticker_set,
+        set_sticker_emoji_list,
+        set_sticker_keywords,
+        set_sticker_mask_position,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
+        edit_user_star_subscription,
+        set_passport_data_errors,
+        send_game,
+        set_game_score,
+        set_game_score_inline,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fwd_inner, fty
+    }
+}
+
+download_forward! {
+    B
+    Metrics<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`Metrics`] methods.
+#[must_use = "Requests are lazy and do nothing unless sent"]
+#[derive(Clone)]
+pub struct MetricsRequest<R> {
+    inner: R,
+    registry: Registry,
+}
+
+impl<R> HasPayload for MetricsRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for MetricsRequest<R>
+where
+    R: Request,
+    R::Err: MetricsError,
+{
+    type Err = R::Err;
+    type Send = MetricsSend<R::Send>;
+    type SendRef = MetricsSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        MetricsSend::new(self.inner.send(), self.registry, R::Payload::NAME)
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        MetricsSend::new(self.inner.send_ref(), self.registry.clone(), R::Payload::NAME)
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_timeout(timeout)
+    }
+}
+
+impl<R> IntoFuture for MetricsRequest<R>
+where
+    R: Request,
+    R::Err: MetricsError,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+/// Future returned by [`MetricsRequest`]s.
+#[pin_project::pin_project]
+pub struct MetricsSend<F> {
+    #[pin]
+    inner: F,
+    registry: Registry,
+    method: &'static str,
+    /// Set when the future is polled for the first time.
+    started: Option<Instant>,
+    /// Size of the last response body received by the inner request.
+    response_size: Option<usize>,
+}
+
+impl<F> MetricsSend<F> {
+    fn new(inner: F, registry: Registry, method: &'static str) -> Self {
+        Self { inner, registry, method, started: None, response_size: None }
+    }
+}
+
+impl<F> Future for MetricsSend<F>
+where
+    F: TryFuture,
+    F::Error: MetricsError,
+{
+    type Output = Result<F::Ok, F::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let started = *this.started.get_or_insert_with(Instant::now);
+
+        let inner = this.inner;
+        let (poll, response_size) = RESPONSE_SIZE
+            .sync_scope(Cell::new(*this.response_size), || {
+                (inner.try_poll(cx), RESPONSE_SIZE.with(Cell::get))
+            });
+        *this.response_size = response_size;
+
+        let res = ready!(poll);
+        let result = res.as_ref().map(drop).map_err(MetricsError::error_label);
+        this.registry.record(this.method, started.elapsed(), response_size, result);
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::ready, time::Duration};
+
+    use bytes::Bytes;
+    use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+    use url::Url;
+
+    use crate::{
+        adaptors::metrics::{ErrorLabel, MetricsSend, Registry},
+        net::{multipart::Form, Transport},
+        prelude::*,
+        types::ChatId,
+        ApiError, DownloadError, RequestError,
+    };
+
+    const RESPONSE: &str = r#"{"ok":true,"result":"https://t.me/+link"}"#;
+
+    /// A transport which responds with `RESPONSE` to all requests.
+    #[derive(Clone)]
+    struct Fake;
+
+    impl Transport for Fake {
+        fn request_json(
+            &self,
+            _: Url,
+            _: Vec<u8>,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            async { Ok(String::from(RESPONSE)) }.boxed()
+        }
+
+        fn request_multipart(
+            &self,
+            _: Url,
+            _: Form,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            async { Ok(String::from(RESPONSE)) }.boxed()
+        }
+
+        fn download(&self, _: Url, _: u64) -> BoxStream<'static, Result<Bytes, DownloadError>> {
+            futures::stream::empty().boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn records_requests() {
+        let registry = Registry::new();
+
+        let ok = ready(Ok::<_, RequestError>(String::from("link")));
+        MetricsSend::new(ok, registry.clone(), "ExportChatInviteLink").await.unwrap();
+
+        let err = ready(Err::<String, _>(RequestError::Api(ApiError::BotBlocked)));
+        let res = MetricsSend::new(err, registry.clone(), "ExportChatInviteLink").await;
+        assert!(res.is_err());
+
+        let snapshot = registry.snapshot();
+        let metrics = &snapshot.methods["ExportChatInviteLink"];
+        assert_eq!(metrics.requests, 2);
+        assert_eq!(metrics.latency.count, 2);
+        assert_eq!(metrics.error_count(), 1);
+        assert_eq!(metrics.errors[&ErrorLabel { kind: "Api", api_error: Some("BotBlocked") }], 1);
+        // Sizes are only measured by `MetricsTransport`
+        assert_eq!(metrics.response_size.count, 0);
+
+        registry.reset();
+        assert!(registry.snapshot().methods.is_empty());
+    }
+
+    #[tokio::test]
+    async fn records_response_size() {
+        let bot = Bot::with_transport("TOKEN", Fake).metrics();
+
+        bot.export_chat_invite_link(ChatId(0)).await.unwrap();
+        bot.export_chat_invite_link(ChatId(0)).await.unwrap();
+
+        let snapshot = bot.snapshot();
+        let response_size = &snapshot.methods["ExportChatInviteLink"].response_size;
+        assert_eq!(response_size.count, 2);
+        assert_eq!(response_size.sum, 2.0 * RESPONSE.len() as f64);
+        assert_eq!(response_size.buckets[0], 2);
+    }
+
+    #[tokio::test]
+    async fn prometheus() {
+        let registry = Registry::new();
+
+        let err = ready(Err::<String, _>(RequestError::Api(ApiError::BotBlocked)));
+        let _ = MetricsSend::new(err, registry.clone(), "SendMessage").await;
+
+        let text = registry.snapshot().to_prometheus();
+        let lines: Vec<_> = text.lines().collect();
+
+        assert!(lines.contains(&"# TYPE teloxide_requests_total counter"));
+        assert!(lines.contains(&r#"teloxide_requests_total{method="SendMessage"} 1"#));
+        assert!(lines.contains(
+            &r#"teloxide_request_errors_total{method="SendMessage",error="Api",api_error="BotBlocked"} 1"#
+        ));
+        assert!(lines.contains(
+            &r#"teloxide_request_duration_seconds_bucket{method="SendMessage",le="+Inf"} 1"#
+        ));
+        assert!(
+            lines.contains(&r#"teloxide_request_duration_seconds_count{method="SendMessage"} 1"#)
+        );
+        assert!(lines.contains(&"# TYPE teloxide_response_size_bytes histogram"));
+        assert!(lines.contains(&r#"teloxide_response_size_bytes_count{method="SendMessage"} 0"#));
+    }
+}
@@ -1,13 +1,14 @@
-use std::future::IntoFuture;
+use std::{future::IntoFuture, time::Duration};
 
 use url::Url;
 
 use crate::{
     payloads::{
         AnswerInlineQuery, AnswerWebAppQuery, CopyMessage, EditMessageCaption,
-        EditMessageCaptionInline, EditMessageMedia, EditMessageMediaInline, EditMessageText,
-        EditMessageTextInline, SendAnimation, SendAudio, SendDocument, SendMediaGroup, SendMessage,
-        SendPhoto, SendPoll, SendVideo, SendVoice,
+        EditMessageCaptionInline, EditMessageChecklist, EditMessageMedia, EditMessageMediaInline,
+        EditMessageText, EditMessageTextInline, EditStory, PostStory, SavePreparedInlineMessage,
+        SendAnimation, SendAudio, SendChecklist, SendDocument, SendGift, SendGiftChat,
+        SendMediaGroup, SendMessage, SendPaidMedia, SendPhoto, SendPoll, SendVideo, SendVoice,
     },
     prelude::Requester,
     requests::{HasPayload, Output, Request},
@@ -74,6 +75,10 @@ where
         // There is no other way to change the payload, given a `&self` :(
         self.clone().send()
     }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.req.set_timeout(timeout)
+    }
 }
 
 impl<R> IntoFuture for DefaultParseModeRequest<R>
@@ -140,14 +145,22 @@ where
     B::SendDocument: Clone,
     B::SendAnimation: Clone,
     B::SendVoice: Clone,
+    B::SendPaidMedia: Clone,
+    B::SendGift: Clone,
+    B::SendGiftChat: Clone,
+    B::PostStory: Clone,
+    B::EditStory: Clone,
     B::EditMessageText: Clone,
     B::EditMessageTextInline: Clone,
     B::EditMessageCaption: Clone,
     B::EditMessageCaptionInline: Clone,
     B::SendPoll: Clone,
+    B::SendChecklist: Clone,
+    B::EditMessageChecklist: Clone,
     B::CopyMessage: Clone,
     B::AnswerInlineQuery: Clone,
     B::AnswerWebAppQuery: Clone,
+    B::SavePreparedInlineMessage: Clone,
     B::EditMessageMedia: Clone,
     B::EditMessageMediaInline: Clone,
     B::SendMediaGroup: Clone,
@@ -162,7 +175,13 @@ where
         send_document,
         send_animation,
         send_voice,
+        send_paid_media,
         send_poll,
+        send_checklist,
+        send_gift,
+        send_gift_chat,
+        post_story,
+        edit_story,
         edit_message_text,
         edit_message_text_inline,
         edit_message_caption,
@@ -170,9 +189,11 @@ where
         copy_message,
         answer_inline_query,
         answer_web_app_query,
+        save_prepared_inline_message,
         send_media_group,
         edit_message_media,
         edit_message_media_inline,
+        edit_message_checklist,
         => f, fty
     }
 
@@ -199,6 +220,7 @@ where
         send_chat_action,
         set_message_reaction,
         get_user_profile_photos,
+        set_user_emoji_status,
         get_file,
         kick_chat_member,
         ban_chat_member,
@@ -212,6 +234,8 @@ where
         export_chat_invite_link,
         create_chat_invite_link,
         edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
         revoke_chat_invite_link,
         set_chat_photo,
         delete_chat_photo,
@@ -260,8 +284,30 @@ where
         edit_message_reply_markup,
         edit_message_reply_markup_inline,
         stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
         delete_message,
         delete_messages,
+        get_available_gifts,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        delete_story,
         send_sticker,
         get_sticker_set,
         get_custom_emoji_stickers,
@@ -284,6 +330,7 @@ where
         answer_pre_checkout_query,
         get_star_transactions,
         refund_star_payment,
+        edit_user_star_subscription,
         set_passport_data_errors,
         send_game,
         set_game_score,
@@ -336,6 +383,7 @@ impl_visit_parse_modes! {
     SendDocument => [parse_mode],
     SendAnimation => [parse_mode],
     SendVoice => [parse_mode],
+    SendPaidMedia => [parse_mode],
     EditMessageText => [parse_mode],
     EditMessageTextInline => [parse_mode],
     EditMessageCaption => [parse_mode],
@@ -344,6 +392,10 @@ impl_visit_parse_modes! {
     //        (and if it does, maybe not call visitor if `self.caption.is_none()`)
     CopyMessage => [parse_mode],
     SendPoll => [explanation_parse_mode],
+    SendGift => [text_parse_mode],
+    SendGiftChat => [text_parse_mode],
+    PostStory => [parse_mode],
+    EditStory => [parse_mode],
 }
 
 impl VisitParseModes for AnswerInlineQuery {
@@ -354,12 +406,30 @@ impl VisitParseModes for AnswerInlineQuery {
     }
 }
 
+impl VisitParseModes for SendChecklist {
+    fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
+        visit_parse_modes_in_input_checklist(&mut self.checklist, &mut visitor);
+    }
+}
+
+impl VisitParseModes for EditMessageChecklist {
+    fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
+        visit_parse_modes_in_input_checklist(&mut self.checklist, &mut visitor);
+    }
+}
+
 impl VisitParseModes for AnswerWebAppQuery {
     fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
         visit_parse_modes_in_inline_query_result(&mut self.result, &mut visitor);
     }
 }
 
+impl VisitParseModes for SavePreparedInlineMessage {
+    fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
+        visit_parse_modes_in_inline_query_result(&mut self.result, &mut visitor);
+    }
+}
+
 impl VisitParseModes for SendMediaGroup {
     fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
         self.media
@@ -448,3 +518,11 @@ fn visit_parse_modes_in_input_media(
 
     visitor(parse_mode);
 }
+
+fn visit_parse_modes_in_input_checklist(
+    checklist: &mut InputChecklist,
+    visitor: &mut impl FnMut(&mut Option<ParseMode>),
+) {
+    visitor(&mut checklist.parse_mode);
+    checklist.tasks.iter_mut().for_each(|task| visitor(&mut task.parse_mode));
+}
@@ -0,0 +1,1025 @@
+use std::{
+    fmt,
+    future::{Future, IntoFuture},
+    io,
+    pin::Pin,
+    sync::Arc,
+    task::{self, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::future::BoxFuture;
+use url::Url;
+
+use crate::{
+    errors::AsResponseParameters,
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+    ApiError, RequestError,
+};
+
+/// Automatically retry failed requests.
+///
+/// Requests that failed because of a network error, a server-side error or a
+/// flood control (`RetryAfter`) are retried according to [`Settings`]: the
+/// delay between attempts is determined by a backoff strategy (or by the
+/// `retry_after` value returned by Telegram), and retrying stops when either
+/// the maximum number of attempts or the deadline is reached.
+///
+/// Not all requests can be retried safely. For example if a `send_message`
+/// request timed out, the message may have been sent anyway and retrying would
+/// send it twice. Because of this, requests are classified by their
+/// [`Idempotency`] (see [`default_idempotency`]) and non-idempotent requests
+/// are only retried if the request provably never reached Telegram, i.e. if
+/// the connection could not be established or Telegram asked to retry later.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use teloxide_core::{adaptors::retry::Settings, prelude::*, types::ChatId};
+///
+/// # #[tokio::main] async fn main() -> Result<(), teloxide_core::RequestError> {
+/// let bot = Bot::new("TOKEN")
+///     .retry(Settings::default().max_attempts(5).deadline(Duration::from_secs(60)));
+///
+/// // Retried on network errors only if the request couldn't be sent at all
+/// bot.send_message(ChatId(0), "Hi!").await?;
+/// # Ok(()) }
+/// ```
+///
+/// Note that `RetryAfter` errors can be handled by [`Throttle`] as well, if
+/// you use both adaptors, `Retry` should wrap `Throttle`, not the other way
+/// around.
+///
+/// [`Throttle`]: crate::adaptors::Throttle
+#[derive(Clone, Debug)]
+pub struct Retry<B> {
+    inner: B,
+    settings: Arc<Settings>,
+}
+
+impl<B> Retry<B> {
+    /// Creates a new adaptor.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::retry`] instead.
+    ///
+    /// [`RequesterExt::retry`]: crate::requests::RequesterExt::retry
+    pub fn new(inner: B, settings: Settings) -> Self {
+        Self { inner, settings: Arc::new(settings) }
+    }
+
+    /// Allows to access inner bot
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Unwraps inner bot
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    /// Returns settings of the adaptor.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+}
+
+/// [`Retry`] settings.
+///
+/// By default requests are attempted at most 3 times with an exponential
+/// backoff, without a deadline, and are classified with
+/// [`default_idempotency`].
+#[derive(Clone)]
+pub struct Settings {
+    backoff: Arc<dyn Fn(u32) -> Duration + Send + Sync>,
+    max_attempts: u32,
+    deadline: Option<Duration>,
+    idempotency: fn(&'static str) -> Idempotency,
+}
+
+impl Settings {
+    /// Sets the backoff strategy, used to calculate the delay before the next
+    /// attempt from the number of failed attempts so far.
+    ///
+    /// This has the same shape as `teloxide::backoff::BackoffStrategy`, so
+    /// e.g. `teloxide::backoff::exponential_backoff_strategy` can be used
+    /// directly. By default, the equivalent of it is used.
+    ///
+    /// The backoff is not used when Telegram specifies the delay itself via
+    /// [`RequestError::RetryAfter`].
+    #[must_use]
+    pub fn backoff(self, backoff: impl Fn(u32) -> Duration + Send + Sync + 'static) -> Self {
+        Self { backoff: Arc::new(backoff), ..self }
+    }
+
+    /// Sets the maximum number of attempts (including the first one).
+    ///
+    /// `0` is treated as `1`, i.e. no retries.
+    #[must_use]
+    pub fn max_attempts(self, max_attempts: u32) -> Self {
+        Self { max_attempts, ..self }
+    }
+
+    /// Sets the total time budget for a request.
+    ///
+    /// The request won't be retried if the next attempt would start after
+    /// `deadline` has passed since the first attempt.
+    #[must_use]
+    pub fn deadline(self, deadline: Duration) -> Self {
+        Self { deadline: Some(deadline), ..self }
+    }
+
+    /// Sets the function used to classify requests by their [`Payload::NAME`]
+    /// (e.g. `"SendMessage"`).
+    ///
+    /// By default [`default_idempotency`] is used.
+    #[must_use]
+    pub fn idempotency(self, idempotency: fn(&'static str) -> Idempotency) -> Self {
+        Self { idempotency, ..self }
+    }
+
+    /// Returns the delay before the next attempt or `None` if the request
+    /// should not be retried.
+    fn delay<E: RetryableError>(
+        &self,
+        method: &'static str,
+        error: &E,
+        failed_attempts: u32,
+        started: Instant,
+    ) -> Option<Duration> {
+        if failed_attempts >= self.max_attempts {
+            return None;
+        }
+
+        let retry = match error.error_class() {
+            ErrorClass::NotDelivered => true,
+            ErrorClass::Transient => (self.idempotency)(method) == Idempotency::Idempotent,
+            ErrorClass::Permanent => false,
+        };
+        if !retry {
+            return None;
+        }
+
+        let delay = match error.retry_after() {
+            Some(retry_after) => retry_after.duration(),
+            None => (self.backoff)(failed_attempts),
+        };
+
+        match self.deadline {
+            Some(deadline) if started.elapsed() + delay > deadline => None,
+            _ => Some(delay),
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            // Same as `teloxide::backoff::exponential_backoff_strategy`
+            backoff: Arc::new(|failed_attempts| {
+                Duration::from_secs(1_u64 << failed_attempts.min(6))
+            }),
+            max_attempts: 3,
+            deadline: None,
+            idempotency: default_idempotency,
+        }
+    }
+}
+
+impl fmt::Debug for Settings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Settings")
+            .field("max_attempts", &self.max_attempts)
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether a request can be safely repeated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Idempotency {
+    /// Repeating the request has the same effect as making it once, e.g.
+    /// `get_chat` or `delete_message`.
+    Idempotent,
+
+    /// Repeating the request may have additional effects, e.g. `send_message`
+    /// may send the same message twice.
+    NonIdempotent,
+}
+
+/// Classifies requests by their [`Payload::NAME`].
+///
+/// Requests which create something new (`send_*`, `forward_*`, `copy_*`,
+/// `create_*`, `post_story`, etc.) or transfer something (`transfer_gift`,
+/// `refund_star_payment`, etc.) are non-idempotent, as well as
+/// `unpin_chat_message`, everything else is considered idempotent.
+pub fn default_idempotency(method: &'static str) -> Idempotency {
+    match method {
+        // Only shows the action for a few seconds
+        "SendChatAction" => Idempotency::Idempotent,
+        // Without `message_id` unpins the most recent pinned message, so
+        // repeating it may unpin another one
+        "UnpinChatMessage" => Idempotency::NonIdempotent,
+        "ExportChatInviteLink"
+        | "UploadStickerFile"
+        | "AddStickerToSet"
+        | "SavePreparedInlineMessage"
+        | "ConvertGiftToStars"
+        | "UpgradeGift"
+        | "TransferGift"
+        | "TransferBusinessAccountStars"
+        | "RefundStarPayment"
+        | "LogOut"
+        | "Close" => Idempotency::NonIdempotent,
+        _ if ["Send", "Forward", "Copy", "Create", "Post"]
+            .iter()
+            .any(|prefix| method.starts_with(prefix)) =>
+        {
+            Idempotency::NonIdempotent
+        }
+        _ => Idempotency::Idempotent,
+    }
+}
+
+/// What is known about a failed request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// The request provably wasn't processed by Telegram, e.g. the connection
+    /// couldn't be established or Telegram asked to retry later.
+    ///
+    /// Such requests can be retried regardless of their [`Idempotency`].
+    NotDelivered,
+
+    /// The request may or may not have been processed, but retrying it may
+    /// succeed, e.g. a timeout or a server-side error.
+    ///
+    /// Such requests are only retried if they are [idempotent].
+    ///
+    /// [idempotent]: Idempotency::Idempotent
+    Transient,
+
+    /// Retrying the request won't help, e.g. a bad request.
+    ///
+    /// Such requests are never retried.
+    Permanent,
+}
+
+/// Errors which can be classified by the [`Retry`] adaptor.
+pub trait RetryableError: AsResponseParameters {
+    /// Returns what is known about the request which failed with this error,
+    /// i.e. whether [`Retry`] may retry it.
+    ///
+    /// - [`ErrorClass::NotDelivered`] must only be returned when the request
+    ///   provably never reached Telegram (e.g. the connection couldn't be
+    ///   established) or Telegram explicitly asked to retry it. Requests with
+    ///   such errors are retried even if they are [not idempotent], so if this
+    ///   is returned for a request which was in fact processed, the action
+    ///   (e.g. sending a message) is performed twice.
+    /// - [`ErrorClass::Transient`] should be returned when the request may have
+    ///   been processed, but retrying it may succeed (e.g. a timeout). Only
+    ///   [idempotent] requests are retried then.
+    /// - [`ErrorClass::Permanent`] should be returned when retrying won't help.
+    ///   Requests with such errors are never retried.
+    ///
+    /// When in doubt, prefer [`ErrorClass::Transient`] over
+    /// [`ErrorClass::NotDelivered`].
+    ///
+    /// The delay before a retry is taken from
+    /// [`AsResponseParameters::retry_after`], if it's set.
+    ///
+    /// [not idempotent]: Idempotency::NonIdempotent
+    /// [idempotent]: Idempotency::Idempotent
+    fn error_class(&self) -> ErrorClass;
+}
+
+impl RetryableError for RequestError {
+    fn error_class(&self) -> ErrorClass {
+        match self {
+            Self::RetryAfter(_) => ErrorClass::NotDelivered,
+            Self::Network(err) => network_error_class(&**err),
+            // Usually means that a proxy in front of the Bot API server has
+            // returned an HTML error page (e.g. `502 Bad Gateway`)
+            Self::InvalidJson { .. } => ErrorClass::Transient,
+            Self::Api(ApiError::Unknown(description)) if is_server_error(description) => {
+                ErrorClass::Transient
+            }
+            Self::Io(err) => io_error_class(err),
+            Self::Api(_) | Self::MigrateToChatId(_) => ErrorClass::Permanent,
+        }
+    }
+}
+
+/// Classifies errors reported by transports, see `net::Transport`.
+fn network_error_class(err: &(dyn std::error::Error + Send + Sync + 'static)) -> ErrorClass {
+    #[cfg(feature = "reqwest")]
+    if let Some(err) = err.downcast_ref::<reqwest::Error>() {
+        return match () {
+            _ if err.is_connect() => ErrorClass::NotDelivered,
+            _ if err.is_builder() => ErrorClass::Permanent,
+            _ => ErrorClass::Transient,
+        };
+    }
+
+    match err.downcast_ref::<io::Error>() {
+        Some(err) => io_error_class(err),
+        None => ErrorClass::Transient,
+    }
+}
+
+fn io_error_class(err: &io::Error) -> ErrorClass {
+    match err.kind() {
+        io::ErrorKind::ConnectionRefused
+        | io::ErrorKind::NotConnected
+        | io::ErrorKind::AddrNotAvailable => ErrorClass::NotDelivered,
+        io::ErrorKind::TimedOut
+        | io::ErrorKind::ConnectionReset
+        | io::ErrorKind::ConnectionAborted
+        | io::ErrorKind::BrokenPipe
+        | io::ErrorKind::UnexpectedEof
+        | io::ErrorKind::Interrupted => ErrorClass::Transient,
+        _ => ErrorClass::Permanent,
+    }
+}
+
+/// Returns `true` if the error description looks like a 5xx error.
+fn is_server_error(description: &str) -> bool {
+    ["Internal Server Error", "Bad Gateway", "Service Unavailable", "Gateway Timeout"]
+        .iter()
+        .any(|e| description.starts_with(e))
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        RetryRequest<B::$T>
+    };
+}
+
+macro_rules! fwd_inner {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        RetryRequest {
+            inner: Arc::new($this.inner().$m($($arg),*)),
+            settings: Arc::clone(&$this.settings)
+        }
+    };
+}
+
+impl<B> Requester for Retry<B>
+where
+    B: Requester,
+    B::Err: RetryableError,
+    B::GetUpdates: Clone + Send + Sync + 'static,
+    B::SetWebhook: Clone + Send + Sync + 'static,
+    B::DeleteWebhook: Clone + Send + Sync + 'static,
+    B::GetWebhookInfo: Clone + Send + Sync + 'static,
+    B::GetMe: Clone + Send + Sync + 'static,
+    B::LogOut: Clone + Send + Sync + 'static,
+    B::Close: Clone + Send + Sync + 'static,
+    B::SendMessage: Clone + Send + Sync + 'static,
+    B::ForwardMessage: Clone + Send + Sync + 'static,
+    B::ForwardMessages: Clone + Send + Sync + 'static,
+    B::CopyMessage: Clone + Send + Sync + 'static,
+    B::CopyMessages: Clone + Send + Sync + 'static,
+    B::SendPhoto: Clone + Send + Sync + 'static,
+    B::SendAudio: Clone + Send + Sync + 'static,
+    B::SendDocument: Clone + Send + Sync + 'static,
+    B::SendVideo: Clone + Send + Sync + 'static,
+    B::SendAnimation: Clone + Send + Sync + 'static,
+    B::SendVoice: Clone + Send + Sync + 'static,
+    B::SendVideoNote: Clone + Send + Sync + 'static,
+    B::SendPaidMedia: Clone + Send + Sync + 'static,
+    B::SendMediaGroup: Clone + Send + Sync + 'static,
+    B::SendLocation: Clone + Send + Sync + 'static,
+    B::EditMessageLiveLocation: Clone + Send + Sync + 'static,
+    B::EditMessageLiveLocationInline: Clone + Send + Sync + 'static,
+    B::StopMessageLiveLocation: Clone + Send + Sync + 'static,
+    B::StopMessageLiveLocationInline: Clone + Send + Sync + 'static,
+    B::SendVenue: Clone + Send + Sync + 'static,
+    B::SendContact: Clone + Send + Sync + 'static,
+    B::SendPoll: Clone + Send + Sync + 'static,
+    B::SendChecklist: Clone + Send + Sync + 'static,
+    B::SendDice: Clone + Send + Sync + 'static,
+    B::SendChatAction: Clone + Send + Sync + 'static,
+    B::SetMessageReaction: Clone + Send + Sync + 'static,
+    B::GetUserProfilePhotos: Clone + Send + Sync + 'static,
+    B::SetUserEmojiStatus: Clone + Send + Sync + 'static,
+    B::GetFile: Clone + Send + Sync + 'static,
+    B::BanChatMember: Clone + Send + Sync + 'static,
+    B::KickChatMember: Clone + Send + Sync + 'static,
+    B::UnbanChatMember: Clone + Send + Sync + 'static,
+    B::RestrictChatMember: Clone + Send + Sync + 'static,
+    B::PromoteChatMember: Clone + Send + Sync + 'static,
+    B::SetChatAdministratorCustomTitle: Clone + Send + Sync + 'static,
+    B::BanChatSenderChat: Clone + Send + Sync + 'static,
+    B::UnbanChatSenderChat: Clone + Send + Sync + 'static,
+    B::SetChatPermissions: Clone + Send + Sync + 'static,
+    B::ExportChatInviteLink: Clone + Send + Sync + 'static,
+    B::CreateChatInviteLink: Clone + Send + Sync + 'static,
+    B::EditChatInviteLink: Clone + Send + Sync + 'static,
+    B::CreateChatSubscriptionInviteLink: Clone + Send + Sync + 'static,
+    B::EditChatSubscriptionInviteLink: Clone + Send + Sync + 'static,
+    B::RevokeChatInviteLink: Clone + Send + Sync + 'static,
+    B::ApproveChatJoinRequest: Clone + Send + Sync + 'static,
+    B::DeclineChatJoinRequest: Clone + Send + Sync + 'static,
+    B::SetChatPhoto: Clone + Send + Sync + 'static,
+    B::DeleteChatPhoto: Clone + Send + Sync + 'static,
+    B::SetChatTitle: Clone + Send + Sync + 'static,
+    B::SetChatDescription: Clone + Send + Sync + 'static,
+    B::PinChatMessage: Clone + Send + Sync + 'static,
+    B::UnpinChatMessage: Clone + Send + Sync + 'static,
+    B::UnpinAllChatMessages: Clone + Send + Sync + 'static,
+    B::LeaveChat: Clone + Send + Sync + 'static,
+    B::GetChat: Clone + Send + Sync + 'static,
+    B::GetChatAdministrators: Clone + Send + Sync + 'static,
+    B::GetChatMemberCount: Clone + Send + Sync + 'static,
+    B::GetChatMembersCount: Clone + Send + Sync + 'static,
+    B::GetChatMember: Clone + Send + Sync + 'static,
+    B::SetChatStickerSet: Clone + Send + Sync + 'static,
+    B::DeleteChatStickerSet: Clone + Send + Sync + 'static,
+    B::GetForumTopicIconStickers: Clone + Send + Sync + 'static,
+    B::CreateForumTopic: Clone + Send + Sync + 'static,
+    B::EditForumTopic: Clone + Send + Sync + 'static,
+    B::CloseForumTopic: Clone + Send + Sync + 'static,
+    B::ReopenForumTopic: Clone + Send + Sync + 'static,
+    B::DeleteForumTopic: Clone + Send + Sync + 'static,
+    B::UnpinAllForumTopicMessages: Clone + Send + Sync + 'static,
+    B::EditGeneralForumTopic: Clone + Send + Sync + 'static,
+    B::CloseGeneralForumTopic: Clone + Send + Sync + 'static,
+    B::ReopenGeneralForumTopic: Clone + Send + Sync + 'static,
+    B::HideGeneralForumTopic: Clone + Send + Sync + 'static,
+    B::UnhideGeneralForumTopic: Clone + Send + Sync + 'static,
+    B::UnpinAllGeneralForumTopicMessages: Clone + Send + Sync + 'static,
+    B::AnswerCallbackQuery: Clone + Send + Sync + 'static,
+    B::GetUserChatBoosts: Clone + Send + Sync + 'static,
+    B::SetMyCommands: Clone + Send + Sync + 'static,
+    B::GetBusinessConnection: Clone + Send + Sync + 'static,
+    B::GetMyCommands: Clone + Send + Sync + 'static,
+    B::SetMyName: Clone + Send + Sync + 'static,
+    B::GetMyName: Clone + Send + Sync + 'static,
+    B::SetMyDescription: Clone + Send + Sync + 'static,
+    B::GetMyDescription: Clone + Send + Sync + 'static,
+    B::SetMyShortDescription: Clone + Send + Sync + 'static,
+    B::GetMyShortDescription: Clone + Send + Sync + 'static,
+    B::SetChatMenuButton: Clone + Send + Sync + 'static,
+    B::GetChatMenuButton: Clone + Send + Sync + 'static,
+    B::SetMyDefaultAdministratorRights: Clone + Send + Sync + 'static,
+    B::GetMyDefaultAdministratorRights: Clone + Send + Sync + 'static,
+    B::DeleteMyCommands: Clone + Send + Sync + 'static,
+    B::AnswerInlineQuery: Clone + Send + Sync + 'static,
+    B::AnswerWebAppQuery: Clone + Send + Sync + 'static,
+    B::SavePreparedInlineMessage: Clone + Send + Sync + 'static,
+    B::EditMessageText: Clone + Send + Sync + 'static,
+    B::EditMessageTextInline: Clone + Send + Sync + 'static,
+    B::EditMessageCaption: Clone + Send + Sync + 'static,
+    B::EditMessageCaptionInline: Clone + Send + Sync + 'static,
+    B::EditMessageMedia: Clone + Send + Sync + 'static,
+    B::EditMessageMediaInline: Clone + Send + Sync + 'static,
+    B::EditMessageChecklist: Clone + Send + Sync + 'static,
+    B::EditMessageReplyMarkup: Clone + Send + Sync + 'static,
+    B::EditMessageReplyMarkupInline: Clone + Send + Sync + 'static,
+    B::StopPoll: Clone + Send + Sync + 'static,
+    B::ApproveSuggestedPost: Clone + Send + Sync + 'static,
+    B::DeclineSuggestedPost: Clone + Send + Sync + 'static,
+    B::DeleteMessage: Clone + Send + Sync + 'static,
+    B::DeleteMessages: Clone + Send + Sync + 'static,
+    B::GetAvailableGifts: Clone + Send + Sync + 'static,
+    B::SendGift: Clone + Send + Sync + 'static,
+    B::SendGiftChat: Clone + Send + Sync + 'static,
+    B::VerifyUser: Clone + Send + Sync + 'static,
+    B::VerifyChat: Clone + Send + Sync + 'static,
+    B::RemoveUserVerification: Clone + Send + Sync + 'static,
+    B::RemoveChatVerification: Clone + Send + Sync + 'static,
+    B::ReadBusinessMessage: Clone + Send + Sync + 'static,
+    B::DeleteBusinessMessages: Clone + Send + Sync + 'static,
+    B::SetBusinessAccountName: Clone + Send + Sync + 'static,
+    B::SetBusinessAccountUsername: Clone + Send + Sync + 'static,
+    B::SetBusinessAccountBio: Clone + Send + Sync + 'static,
+    B::SetBusinessAccountProfilePhoto: Clone + Send + Sync + 'static,
+    B::RemoveBusinessAccountProfilePhoto: Clone + Send + Sync + 'static,
+    B::SetBusinessAccountGiftSettings: Clone + Send + Sync + 'static,
+    B::GetBusinessAccountStarBalance: Clone + Send + Sync + 'static,
+    B::TransferBusinessAccountStars: Clone + Send + Sync + 'static,
+    B::GetBusinessAccountGifts: Clone + Send + Sync + 'static,
+    B::ConvertGiftToStars: Clone + Send + Sync + 'static,
+    B::UpgradeGift: Clone + Send + Sync + 'static,
+    B::TransferGift: Clone + Send + Sync + 'static,
+    B::PostStory: Clone + Send + Sync + 'static,
+    B::EditStory: Clone + Send + Sync + 'static,
+    B::DeleteStory: Clone + Send + Sync + 'static,
+    B::SendSticker: Clone + Send + Sync + 'static,
+    B::GetStickerSet: Clone + Send + Sync + 'static,
+    B::GetCustomEmojiStickers: Clone + Send + Sync + 'static,
+    B::UploadStickerFile: Clone + Send + Sync + 'static,
+    B::CreateNewStickerSet: Clone + Send + Sync + 'static,
+    B::AddStickerToSet: Clone + Send + Sync + 'static,
+    B::SetStickerPositionInSet: Clone + Send + Sync + 'static,
+    B::DeleteStickerFromSet: Clone + Send + Sync + 'static,
+    B::ReplaceStickerInSet: Clone + Send + Sync + 'static,
+    B::SetStickerSetThumbnail: Clone + Send + Sync + 'static,
+    B::SetCustomEmojiStickerSetThumbnail: Clone + Send + Sync + 'static,
+    B::SetStickerSetTitle: Clone + Send + Sync + 'static,
+    B::DeleteStickerSet: Clone + Send + Sync + 'static,
+    B::SetStickerEmojiList: Clone + Send + Sync + 'static,
+    B::SetStickerKeywords: Clone + Send + Sync + 'static,
+    B::SetStickerMaskPosition: Clone + Send + Sync + 'static,
+    B::SendInvoice: Clone + Send + Sync + 'static,
+    B::CreateInvoiceLink: Clone + Send + Sync + 'static,
+    B::AnswerShippingQuery: Clone + Send + Sync + 'static,
+    B::AnswerPreCheckoutQuery: Clone + Send + Sync + 'static,
+    B::GetStarTransactions: Clone + Send + Sync + 'static,
+    B::RefundStarPayment: Clone + Send + Sync + 'static,
+    B::EditUserStarSubscription: Clone + Send + Sync + 'static,
+    B::SetPassportDataErrors: Clone + Send + Sync + 'static,
+    B::SendGame: Clone + Send + Sync + 'static,
+    B::SetGameScore: Clone + Send + Sync + 'static,
+    B::SetGameScoreInline: Clone + Send + Sync + 'static,
+    B::GetGameHighScores: Clone + Send + Sync + 'static,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        forward_messages,
+        copy_message,
+        copy_messages,
+        send_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_paid_media,
+        send_media_group,
+        send_location,
+        edit_message_live_location,
+        edit_message_live_location_inline,
+        stop_message_live_location,
+        stop_message_live_location_inline,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_checklist,
+        send_dice,
+        send_chat_action,
+        set_message_reaction,
+        get_user_profile_photos,
+        set_user_emoji_status,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        answer_callback_query,
+        get_user_chat_boosts,
+        set_my_commands,
+        get_business_connection,
+        get_my_commands,
+        set_my_name,
+        get_my_name,
+        set_my_description,
+        get_my_description,
+        set_my_short_description,
+        get_my_short_description,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        save_prepared_inline_message,
+        edit_message_text,
+        edit_message_text_inline,
+        edit_message_caption,
+        edit_message_caption_inline,
+        edit_message_media,
+        edit_message_media_inline,
+        edit_message_checklist,
+        edit_message_reply_markup,
+        edit_message_reply_markup_inline,
+        stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
+        delete_message,
+        delete_messages,
+        get_available_gifts,
+        send_gift,
+        send_gift_chat,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
+        send_sticker,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        replace_sticker_in_set,
+        set_sticker_set_thumbnail,
+        set_custom_emoji_sticker_set_thumbnail,
+        set_sticker_set_title,
+        delete_sticker_set,
+        set_sticker_emoji_list,
+        set_sticker_keywords,
+        set_sticker_mask_position,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
+        edit_user_star_subscription,
+        set_passport_data_errors,
+        send_game,
+        set_game_score,
+        set_game_score_inline,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fwd_inner, fty
+    }
+}
+
+download_forward! {
+    B
+    Retry<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`Retry`] methods.
+#[must_use = "Requests are lazy and do nothing unless sent"]
+#[derive(Clone)]
+pub struct RetryRequest<R> {
+    inner: Arc<R>,
+    settings: Arc<Settings>,
+}
+
+impl<R> HasPayload for RetryRequest<R>
+where
+    R: HasPayload + Clone,
+{
+    type Payload = R::Payload;
+
+    /// Note that if this request was already executed via `send_ref` and it
+    /// didn't yet completed, this method will clone the underlying request.
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        Arc::make_mut(&mut self.inner).payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for RetryRequest<R>
+where
+    R: Request + Clone + Send + Sync + 'static,
+    R::Err: RetryableError,
+    Output<R>: Send,
+{
+    type Err = R::Err;
+    type Send = RetrySend<R>;
+    type SendRef = RetrySend<R>;
+
+    fn send(self) -> Self::Send {
+        RetrySend(Box::pin(send(self.inner, self.settings)))
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        RetrySend(Box::pin(send(Arc::clone(&self.inner), Arc::clone(&self.settings))))
+    }
+
+    /// Sets the timeout of each attempt, see [`Settings::deadline`] to limit
+    /// the total time spent on retries.
+    fn set_timeout(&mut self, timeout: Duration) {
+        Arc::make_mut(&mut self.inner).set_timeout(timeout)
+    }
+}
+
+impl<R> IntoFuture for RetryRequest<R>
+where
+    R: Request + Clone + Send + Sync + 'static,
+    R::Err: RetryableError,
+    Output<R>: Send,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+/// Future returned by [`RetryRequest`]s.
+#[pin_project::pin_project]
+pub struct RetrySend<R: Request>(#[pin] BoxFuture<'static, Result<Output<R>, R::Err>>);
+
+impl<R: Request> Future for RetrySend<R> {
+    type Output = Result<Output<R>, R::Err>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        self.project().0.poll(cx)
+    }
+}
+
+/// Actual implementation of the `RetrySend` future
+async fn send<R>(request: Arc<R>, settings: Arc<Settings>) -> Result<Output<R>, R::Err>
+where
+    R: Request + Send + Sync + 'static,
+    R::Err: RetryableError,
+    Output<R>: Send,
+{
+    let method = <R::Payload as Payload>::NAME;
+    let started = Instant::now();
+    let mut failed_attempts = 0;
+
+    loop {
+        let err = match request.send_ref().await {
+            Ok(output) => return Ok(output),
+            Err(err) => err,
+        };
+        failed_attempts += 1;
+
+        let Some(delay) = settings.delay(method, &err, failed_attempts, started) else {
+            return Err(err);
+        };
+
+        log::warn!(
+            "`{method}` request failed (attempt {failed_attempts}), retrying in {delay:?}: {err}"
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        future::{ready, IntoFuture, Ready},
+        io,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use crate::{
+        adaptors::retry::{default_idempotency, send, Idempotency, Settings},
+        payloads::{DeleteMessage, ExportChatInviteLink},
+        requests::{HasPayload, Output, Payload, Request, ResponseResult},
+        types::{ChatId, MessageId, Seconds},
+        ApiError, RequestError,
+    };
+
+    /// A request which returns pre-defined results and counts attempts.
+    #[derive(Clone)]
+    struct Fake<P: Payload> {
+        payload: P,
+        results: Arc<Mutex<VecDeque<ResponseResult<P::Output>>>>,
+    }
+
+    impl<P: Payload> Fake<P> {
+        fn new(payload: P, results: Vec<ResponseResult<P::Output>>) -> Arc<Self> {
+            Arc::new(Self { payload, results: Arc::new(Mutex::new(results.into())) })
+        }
+
+        fn remaining(&self) -> usize {
+            self.results.lock().unwrap().len()
+        }
+    }
+
+    impl<P: Payload> HasPayload for Fake<P> {
+        type Payload = P;
+
+        fn payload_mut(&mut self) -> &mut P {
+            &mut self.payload
+        }
+
+        fn payload_ref(&self) -> &P {
+            &self.payload
+        }
+    }
+
+    impl<P> Request for Fake<P>
+    where
+        P: Payload + Clone,
+        P::Output: Send,
+    {
+        type Err = RequestError;
+        type Send = Ready<ResponseResult<P::Output>>;
+        type SendRef = Ready<ResponseResult<P::Output>>;
+
+        fn send(self) -> Self::Send {
+            self.send_ref()
+        }
+
+        fn send_ref(&self) -> Self::SendRef {
+            ready(self.results.lock().unwrap().pop_front().expect("unexpected attempt"))
+        }
+
+        fn set_timeout(&mut self, _: Duration) {}
+    }
+
+    impl<P> IntoFuture for Fake<P>
+    where
+        P: Payload + Clone,
+        P::Output: Send,
+    {
+        type Output = Result<Output<Self>, RequestError>;
+        type IntoFuture = <Self as Request>::Send;
+
+        fn into_future(self) -> Self::IntoFuture {
+            self.send()
+        }
+    }
+
+    fn settings() -> Arc<Settings> {
+        Arc::new(Settings::default().backoff(|_| Duration::ZERO))
+    }
+
+    fn transient() -> RequestError {
+        RequestError::InvalidJson {
+            source: serde_json::from_str::<()>("").unwrap_err(),
+            raw: "<html>502 Bad Gateway</html>".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_idempotent() {
+        let request = Fake::new(
+            DeleteMessage::new(ChatId(0), MessageId(0)),
+            vec![Err(transient()), Err(transient()), Ok(crate::types::True)],
+        );
+
+        send(Arc::clone(&request), settings()).await.unwrap();
+        assert_eq!(request.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn doesnt_retry_non_idempotent() {
+        let request = Fake::new(
+            ExportChatInviteLink::new(ChatId(0)),
+            vec![Err(transient()), Ok(String::from("link"))],
+        );
+
+        let res = send(Arc::clone(&request), settings()).await;
+        assert!(matches!(res, Err(RequestError::InvalidJson { .. })));
+        assert_eq!(request.remaining(), 1);
+
+        // Telegram didn't process the request, so it's safe to retry
+        let request = Fake::new(
+            ExportChatInviteLink::new(ChatId(0)),
+            vec![Err(RequestError::RetryAfter(Seconds::from_seconds(0))), Ok(String::from("link"))],
+        );
+
+        assert_eq!(send(Arc::clone(&request), settings()).await.unwrap(), "link");
+        assert_eq!(request.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn respects_budget() {
+        let request = Fake::new(
+            DeleteMessage::new(ChatId(0), MessageId(0)),
+            vec![Err(transient()), Err(transient()), Err(transient()), Ok(crate::types::True)],
+        );
+
+        let res = send(Arc::clone(&request), settings()).await;
+        assert!(matches!(res, Err(RequestError::InvalidJson { .. })));
+        assert_eq!(request.remaining(), 1);
+
+        let request = Fake::new(
+            DeleteMessage::new(ChatId(0), MessageId(0)),
+            vec![Err(transient()), Ok(crate::types::True)],
+        );
+        let settings = Settings::default().deadline(Duration::from_millis(100));
+
+        // The default backoff would wait for 2 seconds
+        let res = send(Arc::clone(&request), Arc::new(settings)).await;
+        assert!(matches!(res, Err(RequestError::InvalidJson { .. })));
+        assert_eq!(request.remaining(), 1);
+    }
+
+    #[tokio::test]
+    async fn doesnt_retry_permanent() {
+        let request = Fake::new(
+            DeleteMessage::new(ChatId(0), MessageId(0)),
+            vec![Err(RequestError::Api(ApiError::BotBlocked)), Ok(crate::types::True)],
+        );
+
+        let res = send(Arc::clone(&request), settings()).await;
+        assert!(matches!(res, Err(RequestError::Api(ApiError::BotBlocked))));
+        assert_eq!(request.remaining(), 1);
+    }
+
+    #[tokio::test]
+    async fn retries_undelivered_io() {
+        let io = |kind: io::ErrorKind| Err(RequestError::Network(Box::new(io::Error::from(kind))));
+
+        // Errors of custom transports are classified by their kind
+        let request = Fake::new(
+            ExportChatInviteLink::new(ChatId(0)),
+            vec![io(io::ErrorKind::ConnectionRefused), Ok(String::from("link"))],
+        );
+        assert_eq!(send(Arc::clone(&request), settings()).await.unwrap(), "link");
+
+        let request = Fake::new(
+            ExportChatInviteLink::new(ChatId(0)),
+            vec![io(io::ErrorKind::TimedOut), Ok(String::from("link"))],
+        );
+        let res = send(Arc::clone(&request), settings()).await;
+        assert!(matches!(res, Err(RequestError::Network(_))));
+        assert_eq!(request.remaining(), 1);
+    }
+
+    #[test]
+    fn idempotency() {
+        use Idempotency::*;
+
+        let cases = [
+            ("SendMessage", NonIdempotent),
+            ("ForwardMessages", NonIdempotent),
+            ("CreateChatInviteLink", NonIdempotent),
+            ("PostStory", NonIdempotent),
+            ("TransferGift", NonIdempotent),
+            ("UnpinChatMessage", NonIdempotent),
+            ("SendChatAction", Idempotent),
+            ("GetChat", Idempotent),
+            ("EditMessageText", Idempotent),
+            ("DeleteMessage", Idempotent),
+            ("PinChatMessage", Idempotent),
+            ("UnpinAllChatMessages", Idempotent),
+            ("SetMyCommands", Idempotent),
+        ];
+
+        for (method, expected) in cases {
+            assert_eq!(default_idempotency(method), expected, "{method}");
+        }
+    }
+}
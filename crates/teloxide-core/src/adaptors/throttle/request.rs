@@ -2,7 +2,7 @@ use std::{
     future::{Future, IntoFuture},
     pin::Pin,
     sync::Arc,
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use futures::{
@@ -78,6 +78,12 @@ where
 
         ThrottlingSend(Box::pin(fut))
     }
+
+    /// Note that if this request was already executed via `send_ref` and it
+    /// didn't yet completed, this method will clone the underlying request.
+    fn set_timeout(&mut self, timeout: Duration) {
+        Arc::make_mut(&mut self.request).set_timeout(timeout)
+    }
 }
 
 impl<R> IntoFuture for ThrottlingRequest<R>
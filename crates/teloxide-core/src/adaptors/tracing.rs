@@ -0,0 +1,603 @@
+use std::{
+    fmt::Display,
+    future::{Future, IntoFuture},
+    pin::Pin,
+    task::{self, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::{ready, TryFuture};
+use tracing::{field::Empty, Span};
+use url::Url;
+
+use crate::{
+    payloads::HasChatId,
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+};
+
+/// Creates a [`tracing`] span for every request.
+///
+/// Spans are named `request` and have the following fields:
+/// - `method`, the name of the method, e.g. `SendMessage`
+/// - `chat_id`, the `chat_id` parameter of the request, if it has one
+/// - `duration_ms`, the time it took to receive a response, in milliseconds
+/// - `outcome`, either `ok` or `error`
+/// - `error`, the error, if the request has failed
+///
+/// Spans are created when a request is sent, so they are children of the span
+/// which is current at that moment. For example, when used with `teloxide`'s
+/// `Dispatcher` (with the `tracing` feature enabled), spans of requests made by
+/// handlers are children of the span of the update which is being handled.
+///
+/// Unlike [`Trace`], which writes `log` messages, this adaptor is intended to
+/// be used with `tracing` collectors, e.g. to export traces with OpenTelemetry.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide_core::{prelude::*, types::ChatId};
+///
+/// # async {
+/// let bot = Bot::new("TOKEN").tracing();
+///
+/// // Recorded as a `request` span with `method = "SendMessage"` and `chat_id = 0`
+/// bot.send_message(ChatId(0), "Hi!").await?;
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+///
+/// [`Trace`]: crate::adaptors::Trace
+#[derive(Clone, Debug)]
+pub struct Tracing<B> {
+    inner: B,
+}
+
+impl<B> Tracing<B> {
+    /// Creates a new adaptor.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::tracing`] instead.
+    ///
+    /// [`RequesterExt::tracing`]: crate::requests::RequesterExt::tracing
+    pub fn new(inner: B) -> Self {
+        Self { inner }
+    }
+
+    /// Allows to access inner bot
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Unwraps inner bot
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        TracingRequest<B::$T>
+    };
+}
+
+macro_rules! fwd_inner {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        TracingRequest { inner: $this.inner().$m($($arg),*) }
+    };
+}
+
+impl<B> Requester for Tracing<B>
+where
+    B: Requester,
+    B::Err: Display,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        forward_messages,
+        copy_message,
+        copy_messages,
+        send_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_paid_media,
+        send_media_group,
+        send_location,
+        edit_message_live_location,
+        edit_message_live_location_inline,
+        stop_message_live_location,
+        stop_message_live_location_inline,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_checklist,
+        send_dice,
+        send_chat_action,
+        set_message_reaction,
+        get_user_profile_photos,
+        set_user_emoji_status,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        answer_callback_query,
+        get_user_chat_boosts,
+        set_my_commands,
+        get_business_connection,
+        get_my_commands,
+        set_my_name,
+        get_my_name,
+        set_my_description,
+        get_my_description,
+        set_my_short_description,
+        get_my_short_description,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        save_prepared_inline_message,
+        edit_message_text,
+        edit_message_text_inline,
+        edit_message_caption,
+        edit_message_caption_inline,
+        edit_message_media,
+        edit_message_media_inline,
+        edit_message_checklist,
+        edit_message_reply_markup,
+        edit_message_reply_markup_inline,
+        stop_poll,
+        approve_suggested_post,
+        decline_suggested_post,
+        delete_message,
+        delete_messages,
+        get_available_gifts,
+        send_gift,
+        send_gift_chat,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
+        send_sticker,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        replace_sticker_in_set,
+        set_sticker_set_thumbnail,
+        set_custom_emoji_sticker_set_thumbnail,
+        set_sticker_set_title,
+        delete_sticker_set,
+        set_sticker_emoji_list,
+        set_sticker_keywords,
+        set_sticker_mask_position,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
+        edit_user_star_subscription,
+        set_passport_data_errors,
+        send_game,
+        set_game_score,
+        set_game_score_inline,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fwd_inner, fty
+    }
+}
+
+download_forward! {
+    B
+    Tracing<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`Tracing`] methods.
+#[must_use = "Requests are lazy and do nothing unless sent"]
+#[derive(Clone)]
+pub struct TracingRequest<R> {
+    inner: R,
+}
+
+impl<R> TracingRequest<R>
+where
+    R: Request,
+    R::Payload: HasChatId,
+{
+    fn span(&self) -> Span {
+        let span = tracing::info_span!(
+            "request",
+            method = R::Payload::NAME,
+            chat_id = Empty,
+            duration_ms = Empty,
+            outcome = Empty,
+            error = Empty,
+        );
+
+        if !span.is_disabled() {
+            record_chat_id(&span, self.inner.payload_ref());
+        }
+
+        span
+    }
+}
+
+/// Records the `chat_id` parameter of the payload, if it has one.
+fn record_chat_id<P: HasChatId>(span: &Span, payload: &P) {
+    match payload.recipient() {
+        Some(Recipient::Id(ChatId(id))) => {
+            span.record("chat_id", id);
+        }
+        Some(Recipient::ChannelUsername(username)) => {
+            span.record("chat_id", username.as_str());
+        }
+        None => {}
+    }
+}
+
+impl<R> HasPayload for TracingRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for TracingRequest<R>
+where
+    R: Request,
+    R::Payload: HasChatId,
+    R::Err: Display,
+{
+    type Err = R::Err;
+    type Send = TracingSend<R::Send>;
+    type SendRef = TracingSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        let span = self.span();
+        TracingSend::new(span.in_scope(|| self.inner.send()), span)
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        let span = self.span();
+        TracingSend::new(span.in_scope(|| self.inner.send_ref()), span)
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_timeout(timeout)
+    }
+}
+
+impl<R> IntoFuture for TracingRequest<R>
+where
+    R: Request,
+    R::Payload: HasChatId,
+    R::Err: Display,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+/// Future returned by [`TracingRequest`]s.
+#[pin_project::pin_project]
+pub struct TracingSend<F> {
+    #[pin]
+    inner: F,
+    span: Span,
+    /// Set when the future is polled for the first time.
+    started: Option<Instant>,
+}
+
+impl<F> TracingSend<F> {
+    fn new(inner: F, span: Span) -> Self {
+        Self { inner, span, started: None }
+    }
+}
+
+impl<F> Future for TracingSend<F>
+where
+    F: TryFuture,
+    F::Error: Display,
+{
+    type Output = Result<F::Ok, F::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let _enter = this.span.enter();
+        let started = *this.started.get_or_insert_with(Instant::now);
+
+        let res = ready!(this.inner.try_poll(cx));
+
+        this.span.record("duration_ms", started.elapsed().as_millis() as u64);
+        match &res {
+            Ok(_) => {
+                this.span.record("outcome", "ok");
+            }
+            Err(err) => {
+                this.span.record("outcome", "error");
+                this.span.record("error", tracing::field::display(err));
+            }
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        fmt,
+        future::{ready, IntoFuture, Ready},
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Instrument, Subscriber,
+    };
+    use tracing_subscriber::{
+        layer::{Context, SubscriberExt},
+        registry::LookupSpan,
+        Layer, Registry,
+    };
+
+    use crate::{
+        adaptors::tracing::TracingRequest,
+        payloads::{BanChatMember, DeleteMessage},
+        requests::{HasPayload, Output, Payload, Request, ResponseResult},
+        types::{ChatId, MessageId, True, UserId},
+        ApiError, RequestError,
+    };
+
+    /// A request which succeeds or fails as if the bot was blocked.
+    struct Fake<P> {
+        payload: P,
+        ok: bool,
+    }
+
+    impl<P: Payload> HasPayload for Fake<P> {
+        type Payload = P;
+
+        fn payload_mut(&mut self) -> &mut P {
+            &mut self.payload
+        }
+
+        fn payload_ref(&self) -> &P {
+            &self.payload
+        }
+    }
+
+    impl<P: Payload<Output = True>> Request for Fake<P> {
+        type Err = RequestError;
+        type Send = Ready<ResponseResult<True>>;
+        type SendRef = Ready<ResponseResult<True>>;
+
+        fn send(self) -> Self::Send {
+            self.send_ref()
+        }
+
+        fn send_ref(&self) -> Self::SendRef {
+            // Events, emitted while a request is sent, belong to its span
+            tracing::info!("sending");
+
+            ready(if self.ok { Ok(True) } else { Err(RequestError::Api(ApiError::BotBlocked)) })
+        }
+
+        fn set_timeout(&mut self, _: Duration) {}
+    }
+
+    impl<P: Payload<Output = True>> IntoFuture for Fake<P> {
+        type Output = Result<Output<Self>, RequestError>;
+        type IntoFuture = <Self as Request>::Send;
+
+        fn into_future(self) -> Self::IntoFuture {
+            self.send()
+        }
+    }
+
+    /// A closed span, captured by [`Spans`].
+    #[derive(Debug, Default)]
+    struct SpanData {
+        name: &'static str,
+        parent: Option<&'static str>,
+        fields: HashMap<&'static str, String>,
+        events: usize,
+    }
+
+    impl Visit for SpanData {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields.insert(field.name(), value.to_owned());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.fields.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    /// A layer which captures spans, when they are closed.
+    #[derive(Clone, Default)]
+    struct Spans(Arc<Mutex<Vec<SpanData>>>);
+
+    impl Spans {
+        fn take(&self) -> Vec<SpanData> {
+            std::mem::take(&mut self.0.lock().unwrap())
+        }
+    }
+
+    impl<S> Layer<S> for Spans
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let span = ctx.span(id).unwrap();
+            let mut data = SpanData {
+                name: attrs.metadata().name(),
+                parent: span.parent().map(|parent| parent.name()),
+                ..SpanData::default()
+            };
+            attrs.record(&mut data);
+            span.extensions_mut().insert(data);
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+            let span = ctx.span(id).unwrap();
+            values.record(span.extensions_mut().get_mut::<SpanData>().unwrap());
+        }
+
+        fn on_event(&self, _: &tracing::Event<'_>, ctx: Context<'_, S>) {
+            if let Some(span) = ctx.lookup_current() {
+                span.extensions_mut().get_mut::<SpanData>().unwrap().events += 1;
+            }
+        }
+
+        fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+            let span = ctx.span(&id).unwrap();
+            let data = span.extensions_mut().remove::<SpanData>().unwrap();
+            self.0.lock().unwrap().push(data);
+        }
+    }
+
+    #[tokio::test]
+    async fn records_requests() {
+        let spans = Spans::default();
+        let _guard = tracing::subscriber::set_default(Registry::default().with(spans.clone()));
+
+        let ok = TracingRequest {
+            inner: Fake { payload: DeleteMessage::new(ChatId(1), MessageId(2)), ok: true },
+        };
+        let err = TracingRequest {
+            inner: Fake {
+                payload: BanChatMember::new("@channel".to_owned(), UserId(3)),
+                ok: false,
+            },
+        };
+
+        // Spans are created when requests are sent, not when they are built
+        async {
+            ok.await.unwrap();
+            err.await.unwrap_err();
+        }
+        .instrument(tracing::info_span!("update"))
+        .await;
+
+        let ok = TracingRequest {
+            inner: Fake { payload: DeleteMessage::new(ChatId(4), MessageId(5)), ok: true },
+        };
+        ok.await.unwrap();
+
+        let spans = spans.take();
+        let requests: Vec<_> = spans.iter().filter(|s| s.name == "request").collect();
+        assert_eq!(requests.len(), 3);
+
+        let fields = &requests[0].fields;
+        assert_eq!(fields["method"], "DeleteMessage");
+        assert_eq!(fields["chat_id"], "1");
+        assert_eq!(fields["outcome"], "ok");
+        assert!(fields.contains_key("duration_ms"));
+        assert!(!fields.contains_key("error"));
+        assert_eq!(requests[0].parent, Some("update"));
+        assert_eq!(requests[0].events, 1);
+
+        let fields = &requests[1].fields;
+        assert_eq!(fields["method"], "BanChatMember");
+        assert_eq!(fields["chat_id"], "@channel");
+        assert_eq!(fields["outcome"], "error");
+        assert_eq!(fields["error"], RequestError::Api(ApiError::BotBlocked).to_string());
+        assert_eq!(requests[1].parent, Some("update"));
+        assert_eq!(requests[1].events, 1);
+
+        assert_eq!(requests[2].fields["chat_id"], "4");
+        assert_eq!(requests[2].parent, None);
+    }
+}
@@ -1,19 +1,22 @@
-use std::{future::Future, sync::Arc};
+use std::{future::Future, sync::Arc, time::Duration};
 
-use reqwest::Client;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    net,
-    requests::{MultipartPayload, Payload, ResponseResult},
+    net::{self, multipart::ProgressObserver, Transport},
+    requests::{MultipartPayload, Payload, Requester, ResponseResult},
     serde_multipart,
 };
 
 mod api;
 mod download;
 
+#[cfg(feature = "reqwest")]
 const TELOXIDE_TOKEN: &str = "TELOXIDE_TOKEN";
+#[cfg(feature = "reqwest")]
 const TELOXIDE_API_URL: &str = "TELOXIDE_API_URL";
+#[cfg(feature = "reqwest")]
+const TELOXIDE_TEST_ENV: &str = "TELOXIDE_TEST_ENV";
 
 /// A requests sender.
 ///
@@ -51,16 +54,82 @@ const TELOXIDE_API_URL: &str = "TELOXIDE_API_URL";
 /// recommended to clone it, instead of wrapping it in [`Arc<_>`].
 ///
 /// [`Arc`]: std::sync::Arc
+///
+/// ## Transport
+///
+/// By default `Bot` uses [`reqwest::Client`] to make HTTP requests (when the
+/// `reqwest` feature is enabled), but it can be replaced by any other
+/// [`Transport`], see [`Bot::with_transport`].
+///
 /// [Telegram Bot API]: https://core.telegram.org/bots/api
 #[must_use]
 #[derive(Debug, Clone)]
-pub struct Bot {
+pub struct Bot<C = net::DefaultTransport> {
     token: Arc<str>,
-    api_url: Arc<reqwest::Url>,
-    client: Client,
+    api_url: Arc<url::Url>,
+    api_mode: ApiMode,
+    api_env: ApiEnvironment,
+    capture_unknown_fields: bool,
+    client: C,
+}
+
+/// The kind of the Bot API server a [`Bot`] is talking to.
+///
+/// See [`Bot::with_local_server`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ApiMode {
+    /// The cloud Bot API server, e.g. <https://api.telegram.org>.
+    #[default]
+    Cloud,
+
+    /// A [local Bot API server][tbas], running on the same machine with the
+    /// `--local` flag.
+    ///
+    /// In this mode:
+    /// - [`Download`] reads files directly from the file system, since
+    ///   [`GetFile`] returns absolute paths
+    /// - [`InputFile::file`]s are sent as `file://` URIs instead of being
+    ///   uploaded, so the server reads them from the file system
+    /// - Cloud size limits (20 MB for downloads and 50 MB for uploads) don't
+    ///   apply, since no file is transferred over HTTP
+    ///
+    /// [tbas]: https://github.com/tdlib/telegram-bot-api
+    /// [`Download`]: crate::net::Download
+    /// [`GetFile`]: crate::payloads::GetFile
+    /// [`InputFile::file`]: crate::types::InputFile::file
+    Local,
+}
+
+/// The Telegram environment a [`Bot`] works in.
+///
+/// See [`Bot::set_api_environment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ApiEnvironment {
+    /// The production environment, which is used by all Telegram users.
+    #[default]
+    Production,
+
+    /// The [test environment], which has separate accounts and bots.
+    ///
+    /// In this environment `/test` is appended to the bot's token in method
+    /// and file URLs, e.g. `/bot<token>/test/getMe`.
+    ///
+    /// [test environment]: https://core.telegram.org/bots/webapps#using-bots-in-the-test-environment
+    Test,
+}
+
+impl ApiEnvironment {
+    /// Returns the segment, appended to the bot's token in URLs.
+    pub(crate) fn path_segment(self) -> &'static str {
+        match self {
+            Self::Production => "",
+            Self::Test => "/test",
+        }
+    }
 }
 
 /// Constructors
+#[cfg(feature = "reqwest")]
 impl Bot {
     /// Creates a new `Bot` with the specified token and the default
     /// [http-client](reqwest::Client).
@@ -87,25 +156,23 @@ impl Bot {
     ///
     /// [`reqwest::Client`]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
     /// [issue 223]: https://github.com/teloxide/teloxide/issues/223
-    pub fn with_client<S>(token: S, client: Client) -> Self
+    pub fn with_client<S>(token: S, client: reqwest::Client) -> Self
     where
         S: Into<String>,
     {
-        let token = Into::<String>::into(token).into();
-        let api_url = Arc::new(
-            reqwest::Url::parse(net::TELEGRAM_API_URL)
-                .expect("Failed to parse the default TBA URL"),
-        );
-
-        Self { token, api_url, client }
+        Self::with_transport(token, client)
     }
 
     /// Creates a new `Bot` with the `TELOXIDE_TOKEN` & `TELOXIDE_API_URL` &
-    /// `TELOXIDE_PROXY` environmental variables (the bot's token & the bot's
-    /// API URL & the proxy) and the default [`reqwest::Client`].
+    /// `TELOXIDE_TEST_ENV` & `TELOXIDE_PROXY` environmental variables (the
+    /// bot's token & the bot's API URL & whether to use the test environment &
+    /// the proxy) and the default [`reqwest::Client`].
     ///
     /// If `TELOXIDE_API_URL` doesn't exist, returns to the default TBA URL.
     ///
+    /// If `TELOXIDE_TEST_ENV` is `true` or `1`, the bot works in the
+    /// [`ApiEnvironment::Test`].
+    ///
     /// This function passes the value of `TELOXIDE_PROXY` into
     /// [`reqwest::Proxy::all`], if it exists, otherwise returns the default
     /// client.
@@ -113,6 +180,7 @@ impl Bot {
     /// # Panics
     ///  - If cannot get the `TELOXIDE_TOKEN`  environmental variable.
     ///  - If `TELOXIDE_API_URL` exists, but isn't a correct URL.
+    ///  - If `TELOXIDE_TEST_ENV` exists, but isn't `true`, `false`, `1` or `0`.
     ///  - If it cannot create [`reqwest::Client`].
     ///
     /// [`reqwest::Client`]: https://docs.rs/reqwest/0.10.1/reqwest/struct.Client.html
@@ -123,13 +191,18 @@ impl Bot {
 
     /// Creates a new `Bot` with the `TELOXIDE_TOKEN` environmental variable
     /// (the bot's token), `TELOXIDE_API_URL` environmental variable (the bot's
-    /// API URL) and your [`reqwest::Client`].
+    /// API URL), `TELOXIDE_TEST_ENV` environmental variable (whether to use
+    /// the test environment) and your [`reqwest::Client`].
     ///
     /// If `TELOXIDE_API_URL` doesn't exist, returns to the default TBA URL.
     ///
+    /// If `TELOXIDE_TEST_ENV` is `true` or `1`, the bot works in the
+    /// [`ApiEnvironment::Test`].
+    ///
     /// # Panics
     ///  - If cannot get the `TELOXIDE_TOKEN` environmental variable.
     ///  - If `TELOXIDE_API_URL` exists, but isn't a correct URL.
+    ///  - If `TELOXIDE_TEST_ENV` exists, but isn't `true`, `false`, `1` or `0`.
     ///
     /// # Caution
     /// Your custom client might not be configured correctly to be able to work
@@ -137,18 +210,73 @@ impl Bot {
     ///
     /// [`reqwest::Client`]: https://docs.rs/reqwest/0.10.1/reqwest/struct.Client.html
     /// [issue 223]: https://github.com/teloxide/teloxide/issues/223
-    pub fn from_env_with_client(client: Client) -> Self {
+    pub fn from_env_with_client(client: reqwest::Client) -> Self {
         let bot = Self::with_client(get_env(TELOXIDE_TOKEN), client);
 
-        match std::env::var(TELOXIDE_API_URL) {
+        let bot = match std::env::var(TELOXIDE_API_URL) {
             Ok(env_api_url) => {
-                let api_url = reqwest::Url::parse(&env_api_url)
+                let api_url = url::Url::parse(&env_api_url)
                     .expect("Failed to parse the `TELOXIDE_API_URL` env variable");
                 bot.set_api_url(api_url)
             }
             Err(_) => bot,
+        };
+
+        match std::env::var(TELOXIDE_TEST_ENV).as_deref() {
+            Ok("true" | "1") => bot.set_api_environment(ApiEnvironment::Test),
+            Ok("false" | "0") | Err(_) => bot,
+            Ok(_) => panic!("Failed to parse the `TELOXIDE_TEST_ENV` env variable"),
         }
     }
+}
+
+impl<C> Bot<C>
+where
+    C: Transport,
+{
+    /// Creates a new `Bot` with the specified token and a custom
+    /// [`Transport`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{net::default_reqwest_settings, Bot};
+    ///
+    /// // `reqwest::Client` is the default transport, but any other type
+    /// // implementing `Transport` can be used here
+    /// let client = default_reqwest_settings().build().unwrap();
+    /// let bot: Bot<reqwest::Client> = Bot::with_transport("TOKEN", client);
+    /// ```
+    pub fn with_transport<S>(token: S, transport: C) -> Self
+    where
+        S: Into<String>,
+    {
+        let token = Into::<String>::into(token).into();
+        let api_url = Arc::new(
+            url::Url::parse(net::TELEGRAM_API_URL).expect("Failed to parse the default TBA URL"),
+        );
+
+        Self {
+            token,
+            api_url,
+            api_mode: ApiMode::Cloud,
+            api_env: ApiEnvironment::Production,
+            capture_unknown_fields: false,
+            client: transport,
+        }
+    }
+
+    /// Replaces the [`Transport`] of the bot with the one returned by `f`,
+    /// keeping other settings.
+    pub fn map_transport<F, D>(self, f: F) -> Bot<D>
+    where
+        F: FnOnce(C) -> D,
+        D: Transport,
+    {
+        let Self { token, api_url, api_mode, api_env, capture_unknown_fields, client } = self;
+
+        Bot { token, api_url, api_mode, api_env, capture_unknown_fields, client: f(client) }
+    }
 
     /// Sets a custom API URL.
     ///
@@ -166,7 +294,7 @@ impl Bot {
     /// };
     ///
     /// # async {
-    /// let url = reqwest::Url::parse("https://localhost/tbas").unwrap();
+    /// let url = url::Url::parse("https://localhost/tbas").unwrap();
     /// let bot = Bot::new("TOKEN").set_api_url(url);
     /// // From now all methods will use "https://localhost/tbas" as an API URL.
     /// bot.get_me().await
@@ -183,43 +311,180 @@ impl Bot {
     ///
     /// let bot = Bot::new("TOKEN");
     /// let bot2 = bot.clone();
-    /// let bot = bot.set_api_url(reqwest::Url::parse("https://example.com/").unwrap());
+    /// let bot = bot.set_api_url(url::Url::parse("https://example.com/").unwrap());
     ///
     /// assert_eq!(bot.api_url().as_str(), "https://example.com/");
     /// assert_eq!(bot.clone().api_url().as_str(), "https://example.com/");
     /// assert_ne!(bot2.api_url().as_str(), "https://example.com/");
     /// ```
-    pub fn set_api_url(mut self, url: reqwest::Url) -> Self {
+    pub fn set_api_url(mut self, url: url::Url) -> Self {
         self.api_url = Arc::new(url);
         self
     }
+
+    /// Sets the [`ApiMode`].
+    ///
+    /// Note that this doesn't change the API URL, see also
+    /// [`Bot::with_local_server`].
+    pub fn set_api_mode(mut self, api_mode: ApiMode) -> Self {
+        self.api_mode = api_mode;
+        self
+    }
+
+    /// Sets the [`ApiEnvironment`].
+    ///
+    /// Use [`ApiEnvironment::Test`] to work with bots, created in the Telegram
+    /// test environment. Note that this doesn't change the API URL, the cloud
+    /// Bot API server serves both environments.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{ApiEnvironment, Bot};
+    ///
+    /// let bot = Bot::new("TOKEN").set_api_environment(ApiEnvironment::Test);
+    ///
+    /// assert_eq!(bot.api_environment(), ApiEnvironment::Test);
+    /// ```
+    pub fn set_api_environment(mut self, api_env: ApiEnvironment) -> Self {
+        self.api_env = api_env;
+        self
+    }
+
+    /// Enables or disables capturing of unknown fields of updates.
+    ///
+    /// When enabled, updates received by [`get_updates`] have their
+    /// [`Update::extra`] filled with all the data `teloxide` doesn't know
+    /// about, including unknown fields of nested objects (e.g. of
+    /// [`Message`]), see [`Update::fill_unknown_fields`]. This requires to
+    /// serialize each update back, so it's disabled by default.
+    ///
+    /// [`get_updates`]: crate::requests::Requester::get_updates
+    /// [`Update::extra`]: crate::types::Update::extra
+    /// [`Message`]: crate::types::Message
+    /// [`Update::fill_unknown_fields`]: crate::types::Update::fill_unknown_fields
+    pub fn set_capture_unknown_fields(mut self, capture: bool) -> Self {
+        self.capture_unknown_fields = capture;
+        self
+    }
+
+    /// Sets the URL of a [local Bot API server][tbas] and switches to
+    /// [`ApiMode::Local`].
+    ///
+    /// Note that the bot must be logged out from the cloud Bot API server
+    /// before it can be used with a local one, see
+    /// [`Bot::migrate_to_local_server`].
+    ///
+    /// [tbas]: https://github.com/tdlib/telegram-bot-api
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{ApiMode, Bot};
+    ///
+    /// let url = url::Url::parse("http://localhost:8081").unwrap();
+    /// let bot = Bot::new("TOKEN").with_local_server(url);
+    ///
+    /// assert_eq!(bot.api_url().as_str(), "http://localhost:8081/");
+    /// assert_eq!(bot.api_mode(), ApiMode::Local);
+    /// ```
+    pub fn with_local_server(self, url: url::Url) -> Self {
+        self.set_api_url(url).set_api_mode(ApiMode::Local)
+    }
+}
+
+/// Migration between Bot API servers
+impl<C> Bot<C>
+where
+    C: Transport,
+{
+    /// Moves the bot to the [local Bot API server][tbas] at `url`.
+    ///
+    /// This calls [`log_out`] if the bot currently uses the cloud Bot API
+    /// server or [`close`] if it uses another local server, and then returns
+    /// the bot, set up with [`Bot::with_local_server`].
+    ///
+    /// Note that after logging out the bot can't log in back to the cloud Bot
+    /// API server for 10 minutes.
+    ///
+    /// [tbas]: https://github.com/tdlib/telegram-bot-api
+    /// [`log_out`]: crate::requests::Requester::log_out
+    /// [`close`]: crate::requests::Requester::close
+    pub async fn migrate_to_local_server(self, url: url::Url) -> ResponseResult<Self> {
+        match self.api_mode {
+            ApiMode::Cloud => self.log_out().await?,
+            ApiMode::Local => self.close().await?,
+        };
+
+        Ok(self.with_local_server(url))
+    }
+
+    /// Moves the bot from a local Bot API server back to the cloud one.
+    ///
+    /// This calls [`close`] and then returns the bot, set up with the default
+    /// API URL and [`ApiMode::Cloud`].
+    ///
+    /// Note that the bot can't log in back to the cloud Bot API server for 10
+    /// minutes after it was logged out from it.
+    ///
+    /// [`close`]: crate::requests::Requester::close
+    pub async fn migrate_to_cloud(self) -> ResponseResult<Self> {
+        self.close().await?;
+
+        let url =
+            url::Url::parse(net::TELEGRAM_API_URL).expect("Failed to parse the default TBA URL");
+        Ok(self.set_api_url(url).set_api_mode(ApiMode::Cloud))
+    }
 }
 
 /// Getters
-impl Bot {
+impl<C> Bot<C> {
     /// Returns currently used token.
     #[must_use]
     pub fn token(&self) -> &str {
         &self.token
     }
 
-    /// Returns currently used http-client.
+    /// Returns currently used http-client (see [`Transport`]).
     #[must_use]
-    pub fn client(&self) -> &Client {
+    pub fn client(&self) -> &C {
         &self.client
     }
 
     /// Returns currently used token API URL.
     #[must_use]
-    pub fn api_url(&self) -> reqwest::Url {
-        reqwest::Url::clone(&*self.api_url)
+    pub fn api_url(&self) -> url::Url {
+        url::Url::clone(&*self.api_url)
+    }
+
+    /// Returns currently used [`ApiMode`].
+    #[must_use]
+    pub fn api_mode(&self) -> ApiMode {
+        self.api_mode
+    }
+
+    /// Returns currently used [`ApiEnvironment`].
+    #[must_use]
+    pub fn api_environment(&self) -> ApiEnvironment {
+        self.api_env
+    }
+
+    /// Returns `true` if capturing of unknown fields of updates is enabled,
+    /// see [`Bot::set_capture_unknown_fields`].
+    #[must_use]
+    pub fn captures_unknown_fields(&self) -> bool {
+        self.capture_unknown_fields
     }
 }
 
-impl Bot {
+impl<C> Bot<C>
+where
+    C: Transport,
+{
     pub(crate) fn execute_json<P>(
         &self,
         payload: &P,
+        timeout: Option<Duration>,
     ) -> impl Future<Output = ResponseResult<P::Output>> + 'static
     where
         P: Payload + Serialize,
@@ -228,8 +493,10 @@ impl Bot {
         let client = self.client.clone();
         let token = Arc::clone(&self.token);
         let api_url = Arc::clone(&self.api_url);
+        let api_env = self.api_env;
+        let capture_unknown_fields = self.capture_unknown_fields;
 
-        let timeout_hint = payload.timeout_hint();
+        let timeout = timeout.or_else(|| net::default_timeout(payload.timeout_hint(), 0));
         let params = serde_json::to_vec(payload)
             // this `expect` should be ok since we don't write request those may trigger error here
             .expect("serialization of request to be infallible");
@@ -239,10 +506,12 @@ impl Bot {
             net::request_json(
                 &client,
                 token.as_ref(),
-                reqwest::Url::clone(&*api_url),
+                url::Url::clone(&*api_url),
                 P::NAME,
+                api_env,
                 params,
-                timeout_hint,
+                timeout,
+                capture_unknown_fields,
             )
             .await
         }
@@ -251,6 +520,8 @@ impl Bot {
     pub(crate) fn execute_multipart<P>(
         &self,
         payload: &mut P,
+        timeout: Option<Duration>,
+        on_progress: Option<ProgressObserver>,
     ) -> impl Future<Output = ResponseResult<P::Output>>
     where
         P: MultipartPayload + Serialize,
@@ -259,20 +530,30 @@ impl Bot {
         let client = self.client.clone();
         let token = Arc::clone(&self.token);
         let api_url = Arc::clone(&self.api_url);
+        let api_mode = self.api_mode;
+        let api_env = self.api_env;
 
         let timeout_hint = payload.timeout_hint();
-        let params = serde_multipart::to_form(payload);
+        let params = serde_multipart::to_form(payload, api_mode == ApiMode::Local);
 
         // async move to capture client&token&api_url&params
         async move {
             let params = params?.await;
+            let timeout = timeout
+                .or_else(|| net::default_timeout(timeout_hint, params.upload_size(api_mode)));
+            let params = match on_progress {
+                Some(on_progress) => params.observe(on_progress),
+                None => params,
+            };
+
             net::request_multipart(
                 &client,
                 token.as_ref(),
-                reqwest::Url::clone(&*api_url),
+                url::Url::clone(&*api_url),
                 P::NAME,
+                api_env,
                 params,
-                timeout_hint,
+                timeout,
             )
             .await
         }
@@ -281,6 +562,8 @@ impl Bot {
     pub(crate) fn execute_multipart_ref<P>(
         &self,
         payload: &P,
+        timeout: Option<Duration>,
+        on_progress: Option<ProgressObserver>,
     ) -> impl Future<Output = ResponseResult<P::Output>>
     where
         P: MultipartPayload + Serialize,
@@ -289,26 +572,37 @@ impl Bot {
         let client = self.client.clone();
         let token = Arc::clone(&self.token);
         let api_url = self.api_url.clone();
+        let api_mode = self.api_mode;
+        let api_env = self.api_env;
 
         let timeout_hint = payload.timeout_hint();
-        let params = serde_multipart::to_form_ref(payload);
+        let params = serde_multipart::to_form_ref(payload, api_mode == ApiMode::Local);
 
         // async move to capture client&token&api_url&params
         async move {
             let params = params?.await;
+            let timeout = timeout
+                .or_else(|| net::default_timeout(timeout_hint, params.upload_size(api_mode)));
+            let params = match on_progress {
+                Some(on_progress) => params.observe(on_progress),
+                None => params,
+            };
+
             net::request_multipart(
                 &client,
                 token.as_ref(),
-                reqwest::Url::clone(&*api_url),
+                url::Url::clone(&*api_url),
                 P::NAME,
+                api_env,
                 params,
-                timeout_hint,
+                timeout,
             )
             .await
         }
     }
 }
 
+#[cfg(feature = "reqwest")]
 fn get_env(env: &'static str) -> String {
     std::env::var(env).unwrap_or_else(|_| panic!("Cannot get the {env} env variable"))
 }
@@ -1,51 +1,56 @@
 use url::Url;
 
 use crate::{
+    net::Transport,
     payloads,
     prelude::Requester,
     requests::{JsonRequest, MultipartRequest},
     types::{
-        BotCommand, BusinessConnectionId, ChatId, ChatPermissions, InlineQueryResult, InputFile,
-        InputMedia, InputPollOption, InputSticker, LabeledPrice, MessageId, Recipient, Rgb,
-        StickerFormat, ThreadId, UserId,
+        AcceptedGiftTypes, BotCommand, BusinessConnectionId, ChatId, ChatPermissions,
+        InlineQueryResult, InputChecklist, InputFile, InputMedia, InputPaidMedia, InputPollOption,
+        InputProfilePhoto, InputSticker, InputStoryContent, LabeledPrice, MessageId, Recipient,
+        Rgb, Seconds, StickerFormat, StoryId, ThreadId, UserId,
     },
     Bot,
 };
 
-impl Requester for Bot {
+impl<Tr> Requester for Bot<Tr>
+where
+    Tr: Transport,
+{
     type Err = crate::errors::RequestError;
 
-    type GetUpdates = JsonRequest<payloads::GetUpdates>;
+    type GetUpdates = JsonRequest<payloads::GetUpdates, Tr>;
 
     fn get_updates(&self) -> Self::GetUpdates {
         Self::GetUpdates::new(self.clone(), payloads::GetUpdates::new())
     }
 
-    type SetWebhook = MultipartRequest<payloads::SetWebhook>;
+    type SetWebhook = MultipartRequest<payloads::SetWebhook, Tr>;
 
     fn set_webhook(&self, url: Url) -> Self::SetWebhook {
         Self::SetWebhook::new(self.clone(), payloads::SetWebhook::new(url))
     }
 
-    type DeleteWebhook = JsonRequest<payloads::DeleteWebhook>;
+    type DeleteWebhook = JsonRequest<payloads::DeleteWebhook, Tr>;
 
     fn delete_webhook(&self) -> Self::DeleteWebhook {
         Self::DeleteWebhook::new(self.clone(), payloads::DeleteWebhook::new())
     }
 
-    type GetWebhookInfo = JsonRequest<payloads::GetWebhookInfo>;
+    type GetWebhookInfo = JsonRequest<payloads::GetWebhookInfo, Tr>;
 
     fn get_webhook_info(&self) -> Self::GetWebhookInfo {
         Self::GetWebhookInfo::new(self.clone(), payloads::GetWebhookInfo::new())
     }
 
-    type GetMe = JsonRequest<payloads::GetMe>;
+    type GetMe = JsonRequest<payloads::GetMe, Tr>;
 
     fn get_me(&self) -> Self::GetMe {
         Self::GetMe::new(self.clone(), payloads::GetMe::new())
     }
 
-    type SendMessage = JsonRequest<payloads::SendMessage>;
+    type SendMessage = JsonRequest<payloads::SendMessage, Tr>;
 
     fn send_message<C, T>(&self, chat_id: C, text: T) -> Self::SendMessage
     where
@@ -55,7 +60,7 @@ impl Requester for Bot {
         Self::SendMessage::new(self.clone(), payloads::SendMessage::new(chat_id, text))
     }
 
-    type ForwardMessage = JsonRequest<payloads::ForwardMessage>;
+    type ForwardMessage = JsonRequest<payloads::ForwardMessage, Tr>;
 
     fn forward_message<C, F>(
         &self,
@@ -73,7 +78,7 @@ impl Requester for Bot {
         )
     }
 
-    type ForwardMessages = JsonRequest<payloads::ForwardMessages>;
+    type ForwardMessages = JsonRequest<payloads::ForwardMessages, Tr>;
     fn forward_messages<C, F, M>(
         &self,
         chat_id: C,
@@ -91,7 +96,7 @@ impl Requester for Bot {
         )
     }
 
-    type SendPhoto = MultipartRequest<payloads::SendPhoto>;
+    type SendPhoto = MultipartRequest<payloads::SendPhoto, Tr>;
 
     fn send_photo<C>(&self, chat_id: C, photo: InputFile) -> Self::SendPhoto
     where
@@ -100,7 +105,7 @@ impl Requester for Bot {
         Self::SendPhoto::new(self.clone(), payloads::SendPhoto::new(chat_id, photo))
     }
 
-    type SendAudio = MultipartRequest<payloads::SendAudio>;
+    type SendAudio = MultipartRequest<payloads::SendAudio, Tr>;
 
     fn send_audio<C>(&self, chat_id: C, audio: InputFile) -> Self::SendAudio
     where
@@ -109,7 +114,7 @@ impl Requester for Bot {
         Self::SendAudio::new(self.clone(), payloads::SendAudio::new(chat_id, audio))
     }
 
-    type SendDocument = MultipartRequest<payloads::SendDocument>;
+    type SendDocument = MultipartRequest<payloads::SendDocument, Tr>;
 
     fn send_document<C>(&self, chat_id: C, document: InputFile) -> Self::SendDocument
     where
@@ -118,7 +123,7 @@ impl Requester for Bot {
         Self::SendDocument::new(self.clone(), payloads::SendDocument::new(chat_id, document))
     }
 
-    type SendVideo = MultipartRequest<payloads::SendVideo>;
+    type SendVideo = MultipartRequest<payloads::SendVideo, Tr>;
 
     fn send_video<C>(&self, chat_id: C, video: InputFile) -> Self::SendVideo
     where
@@ -127,7 +132,7 @@ impl Requester for Bot {
         Self::SendVideo::new(self.clone(), payloads::SendVideo::new(chat_id, video))
     }
 
-    type SendAnimation = MultipartRequest<payloads::SendAnimation>;
+    type SendAnimation = MultipartRequest<payloads::SendAnimation, Tr>;
 
     fn send_animation<C>(&self, chat_id: C, animation: InputFile) -> Self::SendAnimation
     where
@@ -136,7 +141,7 @@ impl Requester for Bot {
         Self::SendAnimation::new(self.clone(), payloads::SendAnimation::new(chat_id, animation))
     }
 
-    type SendVoice = MultipartRequest<payloads::SendVoice>;
+    type SendVoice = MultipartRequest<payloads::SendVoice, Tr>;
 
     fn send_voice<C>(&self, chat_id: C, voice: InputFile) -> Self::SendVoice
     where
@@ -145,7 +150,7 @@ impl Requester for Bot {
         Self::SendVoice::new(self.clone(), payloads::SendVoice::new(chat_id, voice))
     }
 
-    type SendVideoNote = MultipartRequest<payloads::SendVideoNote>;
+    type SendVideoNote = MultipartRequest<payloads::SendVideoNote, Tr>;
 
     fn send_video_note<C>(&self, chat_id: C, video_note: InputFile) -> Self::SendVideoNote
     where
@@ -154,7 +159,20 @@ impl Requester for Bot {
         Self::SendVideoNote::new(self.clone(), payloads::SendVideoNote::new(chat_id, video_note))
     }
 
-    type SendMediaGroup = MultipartRequest<payloads::SendMediaGroup>;
+    type SendPaidMedia = MultipartRequest<payloads::SendPaidMedia, Tr>;
+
+    fn send_paid_media<C, M>(&self, chat_id: C, star_count: u32, media: M) -> Self::SendPaidMedia
+    where
+        C: Into<Recipient>,
+        M: IntoIterator<Item = InputPaidMedia>,
+    {
+        Self::SendPaidMedia::new(
+            self.clone(),
+            payloads::SendPaidMedia::new(chat_id, star_count, media),
+        )
+    }
+
+    type SendMediaGroup = MultipartRequest<payloads::SendMediaGroup, Tr>;
 
     fn send_media_group<C, M>(&self, chat_id: C, media: M) -> Self::SendMediaGroup
     where
@@ -164,7 +182,7 @@ impl Requester for Bot {
         Self::SendMediaGroup::new(self.clone(), payloads::SendMediaGroup::new(chat_id, media))
     }
 
-    type SendLocation = JsonRequest<payloads::SendLocation>;
+    type SendLocation = JsonRequest<payloads::SendLocation, Tr>;
 
     fn send_location<C>(&self, chat_id: C, latitude: f64, longitude: f64) -> Self::SendLocation
     where
@@ -176,7 +194,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageLiveLocation = JsonRequest<payloads::EditMessageLiveLocation>;
+    type EditMessageLiveLocation = JsonRequest<payloads::EditMessageLiveLocation, Tr>;
 
     fn edit_message_live_location<C>(
         &self,
@@ -194,7 +212,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageLiveLocationInline = JsonRequest<payloads::EditMessageLiveLocationInline>;
+    type EditMessageLiveLocationInline = JsonRequest<payloads::EditMessageLiveLocationInline, Tr>;
 
     fn edit_message_live_location_inline<I>(
         &self,
@@ -211,7 +229,7 @@ impl Requester for Bot {
         )
     }
 
-    type StopMessageLiveLocation = JsonRequest<payloads::StopMessageLiveLocation>;
+    type StopMessageLiveLocation = JsonRequest<payloads::StopMessageLiveLocation, Tr>;
 
     fn stop_message_live_location<C>(
         &self,
@@ -227,7 +245,7 @@ impl Requester for Bot {
         )
     }
 
-    type StopMessageLiveLocationInline = JsonRequest<payloads::StopMessageLiveLocationInline>;
+    type StopMessageLiveLocationInline = JsonRequest<payloads::StopMessageLiveLocationInline, Tr>;
 
     fn stop_message_live_location_inline<I>(
         &self,
@@ -242,7 +260,7 @@ impl Requester for Bot {
         )
     }
 
-    type SendVenue = JsonRequest<payloads::SendVenue>;
+    type SendVenue = JsonRequest<payloads::SendVenue, Tr>;
 
     fn send_venue<C, T, A>(
         &self,
@@ -263,7 +281,7 @@ impl Requester for Bot {
         )
     }
 
-    type SendContact = JsonRequest<payloads::SendContact>;
+    type SendContact = JsonRequest<payloads::SendContact, Tr>;
 
     fn send_contact<C, P, F>(&self, chat_id: C, phone_number: P, first_name: F) -> Self::SendContact
     where
@@ -277,7 +295,7 @@ impl Requester for Bot {
         )
     }
 
-    type SendPoll = JsonRequest<payloads::SendPoll>;
+    type SendPoll = JsonRequest<payloads::SendPoll, Tr>;
 
     fn send_poll<C, Q, O>(&self, chat_id: C, question: Q, options: O) -> Self::SendPoll
     where
@@ -288,7 +306,24 @@ impl Requester for Bot {
         Self::SendPoll::new(self.clone(), payloads::SendPoll::new(chat_id, question, options))
     }
 
-    type SendDice = JsonRequest<payloads::SendDice>;
+    type SendChecklist = JsonRequest<payloads::SendChecklist, Tr>;
+
+    fn send_checklist<C>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: C,
+        checklist: InputChecklist,
+    ) -> Self::SendChecklist
+    where
+        C: Into<ChatId>,
+    {
+        Self::SendChecklist::new(
+            self.clone(),
+            payloads::SendChecklist::new(business_connection_id, chat_id, checklist),
+        )
+    }
+
+    type SendDice = JsonRequest<payloads::SendDice, Tr>;
 
     fn send_dice<C>(&self, chat_id: C) -> Self::SendDice
     where
@@ -297,7 +332,7 @@ impl Requester for Bot {
         Self::SendDice::new(self.clone(), payloads::SendDice::new(chat_id))
     }
 
-    type SendChatAction = JsonRequest<payloads::SendChatAction>;
+    type SendChatAction = JsonRequest<payloads::SendChatAction, Tr>;
 
     fn send_chat_action<C>(
         &self,
@@ -310,7 +345,7 @@ impl Requester for Bot {
         Self::SendChatAction::new(self.clone(), payloads::SendChatAction::new(chat_id, action))
     }
 
-    type SetMessageReaction = JsonRequest<payloads::SetMessageReaction>;
+    type SetMessageReaction = JsonRequest<payloads::SetMessageReaction, Tr>;
 
     fn set_message_reaction<C>(&self, chat_id: C, message_id: MessageId) -> Self::SetMessageReaction
     where
@@ -322,13 +357,19 @@ impl Requester for Bot {
         )
     }
 
-    type GetUserProfilePhotos = JsonRequest<payloads::GetUserProfilePhotos>;
+    type GetUserProfilePhotos = JsonRequest<payloads::GetUserProfilePhotos, Tr>;
 
     fn get_user_profile_photos(&self, user_id: UserId) -> Self::GetUserProfilePhotos {
         Self::GetUserProfilePhotos::new(self.clone(), payloads::GetUserProfilePhotos::new(user_id))
     }
 
-    type GetFile = JsonRequest<payloads::GetFile>;
+    type SetUserEmojiStatus = JsonRequest<payloads::SetUserEmojiStatus, Tr>;
+
+    fn set_user_emoji_status(&self, user_id: UserId) -> Self::SetUserEmojiStatus {
+        Self::SetUserEmojiStatus::new(self.clone(), payloads::SetUserEmojiStatus::new(user_id))
+    }
+
+    type GetFile = JsonRequest<payloads::GetFile, Tr>;
 
     fn get_file<F>(&self, file_id: F) -> Self::GetFile
     where
@@ -337,7 +378,7 @@ impl Requester for Bot {
         Self::GetFile::new(self.clone(), payloads::GetFile::new(file_id))
     }
 
-    type KickChatMember = JsonRequest<payloads::KickChatMember>;
+    type KickChatMember = JsonRequest<payloads::KickChatMember, Tr>;
 
     fn kick_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::KickChatMember
     where
@@ -346,7 +387,7 @@ impl Requester for Bot {
         Self::KickChatMember::new(self.clone(), payloads::KickChatMember::new(chat_id, user_id))
     }
 
-    type BanChatMember = JsonRequest<payloads::BanChatMember>;
+    type BanChatMember = JsonRequest<payloads::BanChatMember, Tr>;
 
     fn ban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::BanChatMember
     where
@@ -355,7 +396,7 @@ impl Requester for Bot {
         Self::BanChatMember::new(self.clone(), payloads::BanChatMember::new(chat_id, user_id))
     }
 
-    type UnbanChatMember = JsonRequest<payloads::UnbanChatMember>;
+    type UnbanChatMember = JsonRequest<payloads::UnbanChatMember, Tr>;
 
     fn unban_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::UnbanChatMember
     where
@@ -364,7 +405,7 @@ impl Requester for Bot {
         Self::UnbanChatMember::new(self.clone(), payloads::UnbanChatMember::new(chat_id, user_id))
     }
 
-    type RestrictChatMember = JsonRequest<payloads::RestrictChatMember>;
+    type RestrictChatMember = JsonRequest<payloads::RestrictChatMember, Tr>;
 
     fn restrict_chat_member<C>(
         &self,
@@ -381,7 +422,7 @@ impl Requester for Bot {
         )
     }
 
-    type PromoteChatMember = JsonRequest<payloads::PromoteChatMember>;
+    type PromoteChatMember = JsonRequest<payloads::PromoteChatMember, Tr>;
 
     fn promote_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::PromoteChatMember
     where
@@ -393,7 +434,8 @@ impl Requester for Bot {
         )
     }
 
-    type SetChatAdministratorCustomTitle = JsonRequest<payloads::SetChatAdministratorCustomTitle>;
+    type SetChatAdministratorCustomTitle =
+        JsonRequest<payloads::SetChatAdministratorCustomTitle, Tr>;
 
     fn set_chat_administrator_custom_title<Ch, Cu>(
         &self,
@@ -411,7 +453,7 @@ impl Requester for Bot {
         )
     }
 
-    type BanChatSenderChat = JsonRequest<payloads::BanChatSenderChat>;
+    type BanChatSenderChat = JsonRequest<payloads::BanChatSenderChat, Tr>;
 
     fn ban_chat_sender_chat<C, S>(&self, chat_id: C, sender_chat_id: S) -> Self::BanChatSenderChat
     where
@@ -424,7 +466,7 @@ impl Requester for Bot {
         )
     }
 
-    type UnbanChatSenderChat = JsonRequest<payloads::UnbanChatSenderChat>;
+    type UnbanChatSenderChat = JsonRequest<payloads::UnbanChatSenderChat, Tr>;
 
     fn unban_chat_sender_chat<C, S>(
         &self,
@@ -441,7 +483,7 @@ impl Requester for Bot {
         )
     }
 
-    type SetChatPermissions = JsonRequest<payloads::SetChatPermissions>;
+    type SetChatPermissions = JsonRequest<payloads::SetChatPermissions, Tr>;
 
     fn set_chat_permissions<C>(
         &self,
@@ -457,7 +499,7 @@ impl Requester for Bot {
         )
     }
 
-    type ExportChatInviteLink = JsonRequest<payloads::ExportChatInviteLink>;
+    type ExportChatInviteLink = JsonRequest<payloads::ExportChatInviteLink, Tr>;
 
     fn export_chat_invite_link<C>(&self, chat_id: C) -> Self::ExportChatInviteLink
     where
@@ -466,7 +508,7 @@ impl Requester for Bot {
         Self::ExportChatInviteLink::new(self.clone(), payloads::ExportChatInviteLink::new(chat_id))
     }
 
-    type CreateChatInviteLink = JsonRequest<payloads::CreateChatInviteLink>;
+    type CreateChatInviteLink = JsonRequest<payloads::CreateChatInviteLink, Tr>;
 
     fn create_chat_invite_link<C>(&self, chat_id: C) -> Self::CreateChatInviteLink
     where
@@ -475,7 +517,7 @@ impl Requester for Bot {
         Self::CreateChatInviteLink::new(self.clone(), payloads::CreateChatInviteLink::new(chat_id))
     }
 
-    type EditChatInviteLink = JsonRequest<payloads::EditChatInviteLink>;
+    type EditChatInviteLink = JsonRequest<payloads::EditChatInviteLink, Tr>;
 
     fn edit_chat_invite_link<C, I>(&self, chat_id: C, invite_link: I) -> Self::EditChatInviteLink
     where
@@ -488,7 +530,46 @@ impl Requester for Bot {
         )
     }
 
-    type RevokeChatInviteLink = JsonRequest<payloads::RevokeChatInviteLink>;
+    type CreateChatSubscriptionInviteLink =
+        JsonRequest<payloads::CreateChatSubscriptionInviteLink, Tr>;
+
+    fn create_chat_subscription_invite_link<C>(
+        &self,
+        chat_id: C,
+        subscription_period: Seconds,
+        subscription_price: u32,
+    ) -> Self::CreateChatSubscriptionInviteLink
+    where
+        C: Into<Recipient>,
+    {
+        Self::CreateChatSubscriptionInviteLink::new(
+            self.clone(),
+            payloads::CreateChatSubscriptionInviteLink::new(
+                chat_id,
+                subscription_period,
+                subscription_price,
+            ),
+        )
+    }
+
+    type EditChatSubscriptionInviteLink = JsonRequest<payloads::EditChatSubscriptionInviteLink, Tr>;
+
+    fn edit_chat_subscription_invite_link<C, I>(
+        &self,
+        chat_id: C,
+        invite_link: I,
+    ) -> Self::EditChatSubscriptionInviteLink
+    where
+        C: Into<Recipient>,
+        I: Into<String>,
+    {
+        Self::EditChatSubscriptionInviteLink::new(
+            self.clone(),
+            payloads::EditChatSubscriptionInviteLink::new(chat_id, invite_link),
+        )
+    }
+
+    type RevokeChatInviteLink = JsonRequest<payloads::RevokeChatInviteLink, Tr>;
 
     fn revoke_chat_invite_link<C, I>(
         &self,
@@ -505,7 +586,7 @@ impl Requester for Bot {
         )
     }
 
-    type ApproveChatJoinRequest = JsonRequest<payloads::ApproveChatJoinRequest>;
+    type ApproveChatJoinRequest = JsonRequest<payloads::ApproveChatJoinRequest, Tr>;
 
     fn approve_chat_join_request<C>(
         &self,
@@ -521,7 +602,7 @@ impl Requester for Bot {
         )
     }
 
-    type DeclineChatJoinRequest = JsonRequest<payloads::DeclineChatJoinRequest>;
+    type DeclineChatJoinRequest = JsonRequest<payloads::DeclineChatJoinRequest, Tr>;
 
     fn decline_chat_join_request<C>(
         &self,
@@ -537,7 +618,7 @@ impl Requester for Bot {
         )
     }
 
-    type SetChatPhoto = MultipartRequest<payloads::SetChatPhoto>;
+    type SetChatPhoto = MultipartRequest<payloads::SetChatPhoto, Tr>;
 
     fn set_chat_photo<C>(&self, chat_id: C, photo: InputFile) -> Self::SetChatPhoto
     where
@@ -546,7 +627,7 @@ impl Requester for Bot {
         Self::SetChatPhoto::new(self.clone(), payloads::SetChatPhoto::new(chat_id, photo))
     }
 
-    type DeleteChatPhoto = JsonRequest<payloads::DeleteChatPhoto>;
+    type DeleteChatPhoto = JsonRequest<payloads::DeleteChatPhoto, Tr>;
 
     fn delete_chat_photo<C>(&self, chat_id: C) -> Self::DeleteChatPhoto
     where
@@ -555,7 +636,7 @@ impl Requester for Bot {
         Self::DeleteChatPhoto::new(self.clone(), payloads::DeleteChatPhoto::new(chat_id))
     }
 
-    type SetChatTitle = JsonRequest<payloads::SetChatTitle>;
+    type SetChatTitle = JsonRequest<payloads::SetChatTitle, Tr>;
 
     fn set_chat_title<C, T>(&self, chat_id: C, title: T) -> Self::SetChatTitle
     where
@@ -565,7 +646,7 @@ impl Requester for Bot {
         Self::SetChatTitle::new(self.clone(), payloads::SetChatTitle::new(chat_id, title))
     }
 
-    type SetChatDescription = JsonRequest<payloads::SetChatDescription>;
+    type SetChatDescription = JsonRequest<payloads::SetChatDescription, Tr>;
 
     fn set_chat_description<C>(&self, chat_id: C) -> Self::SetChatDescription
     where
@@ -574,7 +655,7 @@ impl Requester for Bot {
         Self::SetChatDescription::new(self.clone(), payloads::SetChatDescription::new(chat_id))
     }
 
-    type PinChatMessage = JsonRequest<payloads::PinChatMessage>;
+    type PinChatMessage = JsonRequest<payloads::PinChatMessage, Tr>;
 
     fn pin_chat_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::PinChatMessage
     where
@@ -583,7 +664,7 @@ impl Requester for Bot {
         Self::PinChatMessage::new(self.clone(), payloads::PinChatMessage::new(chat_id, message_id))
     }
 
-    type UnpinChatMessage = JsonRequest<payloads::UnpinChatMessage>;
+    type UnpinChatMessage = JsonRequest<payloads::UnpinChatMessage, Tr>;
 
     fn unpin_chat_message<C>(&self, chat_id: C) -> Self::UnpinChatMessage
     where
@@ -592,7 +673,7 @@ impl Requester for Bot {
         Self::UnpinChatMessage::new(self.clone(), payloads::UnpinChatMessage::new(chat_id))
     }
 
-    type LeaveChat = JsonRequest<payloads::LeaveChat>;
+    type LeaveChat = JsonRequest<payloads::LeaveChat, Tr>;
 
     fn leave_chat<C>(&self, chat_id: C) -> Self::LeaveChat
     where
@@ -601,7 +682,7 @@ impl Requester for Bot {
         Self::LeaveChat::new(self.clone(), payloads::LeaveChat::new(chat_id))
     }
 
-    type GetChat = JsonRequest<payloads::GetChat>;
+    type GetChat = JsonRequest<payloads::GetChat, Tr>;
 
     fn get_chat<C>(&self, chat_id: C) -> Self::GetChat
     where
@@ -610,7 +691,7 @@ impl Requester for Bot {
         Self::GetChat::new(self.clone(), payloads::GetChat::new(chat_id))
     }
 
-    type GetChatAdministrators = JsonRequest<payloads::GetChatAdministrators>;
+    type GetChatAdministrators = JsonRequest<payloads::GetChatAdministrators, Tr>;
 
     fn get_chat_administrators<C>(&self, chat_id: C) -> Self::GetChatAdministrators
     where
@@ -622,7 +703,7 @@ impl Requester for Bot {
         )
     }
 
-    type GetChatMembersCount = JsonRequest<payloads::GetChatMembersCount>;
+    type GetChatMembersCount = JsonRequest<payloads::GetChatMembersCount, Tr>;
 
     fn get_chat_members_count<C>(&self, chat_id: C) -> Self::GetChatMembersCount
     where
@@ -631,7 +712,7 @@ impl Requester for Bot {
         Self::GetChatMembersCount::new(self.clone(), payloads::GetChatMembersCount::new(chat_id))
     }
 
-    type GetChatMemberCount = JsonRequest<payloads::GetChatMemberCount>;
+    type GetChatMemberCount = JsonRequest<payloads::GetChatMemberCount, Tr>;
 
     fn get_chat_member_count<C>(&self, chat_id: C) -> Self::GetChatMemberCount
     where
@@ -640,7 +721,7 @@ impl Requester for Bot {
         Self::GetChatMemberCount::new(self.clone(), payloads::GetChatMemberCount::new(chat_id))
     }
 
-    type GetChatMember = JsonRequest<payloads::GetChatMember>;
+    type GetChatMember = JsonRequest<payloads::GetChatMember, Tr>;
 
     fn get_chat_member<C>(&self, chat_id: C, user_id: UserId) -> Self::GetChatMember
     where
@@ -649,7 +730,7 @@ impl Requester for Bot {
         Self::GetChatMember::new(self.clone(), payloads::GetChatMember::new(chat_id, user_id))
     }
 
-    type SetChatStickerSet = JsonRequest<payloads::SetChatStickerSet>;
+    type SetChatStickerSet = JsonRequest<payloads::SetChatStickerSet, Tr>;
 
     fn set_chat_sticker_set<C, S>(&self, chat_id: C, sticker_set_name: S) -> Self::SetChatStickerSet
     where
@@ -662,7 +743,7 @@ impl Requester for Bot {
         )
     }
 
-    type DeleteChatStickerSet = JsonRequest<payloads::DeleteChatStickerSet>;
+    type DeleteChatStickerSet = JsonRequest<payloads::DeleteChatStickerSet, Tr>;
 
     fn delete_chat_sticker_set<C>(&self, chat_id: C) -> Self::DeleteChatStickerSet
     where
@@ -671,7 +752,7 @@ impl Requester for Bot {
         Self::DeleteChatStickerSet::new(self.clone(), payloads::DeleteChatStickerSet::new(chat_id))
     }
 
-    type GetForumTopicIconStickers = JsonRequest<payloads::GetForumTopicIconStickers>;
+    type GetForumTopicIconStickers = JsonRequest<payloads::GetForumTopicIconStickers, Tr>;
 
     fn get_forum_topic_icon_stickers(&self) -> Self::GetForumTopicIconStickers {
         Self::GetForumTopicIconStickers::new(
@@ -680,7 +761,7 @@ impl Requester for Bot {
         )
     }
 
-    type CreateForumTopic = JsonRequest<payloads::CreateForumTopic>;
+    type CreateForumTopic = JsonRequest<payloads::CreateForumTopic, Tr>;
 
     fn create_forum_topic<C, N, I>(
         &self,
@@ -700,7 +781,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditForumTopic = JsonRequest<payloads::EditForumTopic>;
+    type EditForumTopic = JsonRequest<payloads::EditForumTopic, Tr>;
 
     fn edit_forum_topic<C>(&self, chat_id: C, message_thread_id: ThreadId) -> Self::EditForumTopic
     where
@@ -712,7 +793,7 @@ impl Requester for Bot {
         )
     }
 
-    type CloseForumTopic = JsonRequest<payloads::CloseForumTopic>;
+    type CloseForumTopic = JsonRequest<payloads::CloseForumTopic, Tr>;
 
     fn close_forum_topic<C>(&self, chat_id: C, message_thread_id: ThreadId) -> Self::CloseForumTopic
     where
@@ -724,7 +805,7 @@ impl Requester for Bot {
         )
     }
 
-    type ReopenForumTopic = JsonRequest<payloads::ReopenForumTopic>;
+    type ReopenForumTopic = JsonRequest<payloads::ReopenForumTopic, Tr>;
 
     fn reopen_forum_topic<C>(
         &self,
@@ -740,7 +821,7 @@ impl Requester for Bot {
         )
     }
 
-    type DeleteForumTopic = JsonRequest<payloads::DeleteForumTopic>;
+    type DeleteForumTopic = JsonRequest<payloads::DeleteForumTopic, Tr>;
 
     fn delete_forum_topic<C>(
         &self,
@@ -756,7 +837,7 @@ impl Requester for Bot {
         )
     }
 
-    type UnpinAllForumTopicMessages = JsonRequest<payloads::UnpinAllForumTopicMessages>;
+    type UnpinAllForumTopicMessages = JsonRequest<payloads::UnpinAllForumTopicMessages, Tr>;
 
     fn unpin_all_forum_topic_messages<C>(
         &self,
@@ -772,7 +853,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditGeneralForumTopic = JsonRequest<payloads::EditGeneralForumTopic>;
+    type EditGeneralForumTopic = JsonRequest<payloads::EditGeneralForumTopic, Tr>;
 
     fn edit_general_forum_topic<C, N>(&self, chat_id: C, name: N) -> Self::EditGeneralForumTopic
     where
@@ -785,7 +866,7 @@ impl Requester for Bot {
         )
     }
 
-    type CloseGeneralForumTopic = JsonRequest<payloads::CloseGeneralForumTopic>;
+    type CloseGeneralForumTopic = JsonRequest<payloads::CloseGeneralForumTopic, Tr>;
 
     fn close_general_forum_topic<C>(&self, chat_id: C) -> Self::CloseGeneralForumTopic
     where
@@ -797,7 +878,7 @@ impl Requester for Bot {
         )
     }
 
-    type ReopenGeneralForumTopic = JsonRequest<payloads::ReopenGeneralForumTopic>;
+    type ReopenGeneralForumTopic = JsonRequest<payloads::ReopenGeneralForumTopic, Tr>;
 
     fn reopen_general_forum_topic<C>(&self, chat_id: C) -> Self::ReopenGeneralForumTopic
     where
@@ -809,7 +890,7 @@ impl Requester for Bot {
         )
     }
 
-    type HideGeneralForumTopic = JsonRequest<payloads::HideGeneralForumTopic>;
+    type HideGeneralForumTopic = JsonRequest<payloads::HideGeneralForumTopic, Tr>;
 
     fn hide_general_forum_topic<C>(&self, chat_id: C) -> Self::HideGeneralForumTopic
     where
@@ -821,7 +902,7 @@ impl Requester for Bot {
         )
     }
 
-    type UnhideGeneralForumTopic = JsonRequest<payloads::UnhideGeneralForumTopic>;
+    type UnhideGeneralForumTopic = JsonRequest<payloads::UnhideGeneralForumTopic, Tr>;
 
     fn unhide_general_forum_topic<C>(&self, chat_id: C) -> Self::UnhideGeneralForumTopic
     where
@@ -834,7 +915,7 @@ impl Requester for Bot {
     }
 
     type UnpinAllGeneralForumTopicMessages =
-        JsonRequest<payloads::UnpinAllGeneralForumTopicMessages>;
+        JsonRequest<payloads::UnpinAllGeneralForumTopicMessages, Tr>;
 
     fn unpin_all_general_forum_topic_messages<C>(
         &self,
@@ -849,7 +930,7 @@ impl Requester for Bot {
         )
     }
 
-    type AnswerCallbackQuery = JsonRequest<payloads::AnswerCallbackQuery>;
+    type AnswerCallbackQuery = JsonRequest<payloads::AnswerCallbackQuery, Tr>;
 
     fn answer_callback_query<C>(&self, callback_query_id: C) -> Self::AnswerCallbackQuery
     where
@@ -861,7 +942,7 @@ impl Requester for Bot {
         )
     }
 
-    type GetUserChatBoosts = JsonRequest<payloads::GetUserChatBoosts>;
+    type GetUserChatBoosts = JsonRequest<payloads::GetUserChatBoosts, Tr>;
 
     fn get_user_chat_boosts<C>(&self, chat_id: C, user_id: UserId) -> Self::GetUserChatBoosts
     where
@@ -873,7 +954,7 @@ impl Requester for Bot {
         )
     }
 
-    type SetMyCommands = JsonRequest<payloads::SetMyCommands>;
+    type SetMyCommands = JsonRequest<payloads::SetMyCommands, Tr>;
 
     fn set_my_commands<C>(&self, commands: C) -> Self::SetMyCommands
     where
@@ -882,7 +963,7 @@ impl Requester for Bot {
         Self::SetMyCommands::new(self.clone(), payloads::SetMyCommands::new(commands))
     }
 
-    type GetBusinessConnection = JsonRequest<payloads::GetBusinessConnection>;
+    type GetBusinessConnection = JsonRequest<payloads::GetBusinessConnection, Tr>;
 
     fn get_business_connection(
         &self,
@@ -894,60 +975,61 @@ impl Requester for Bot {
         )
     }
 
-    type GetMyCommands = JsonRequest<payloads::GetMyCommands>;
+    type GetMyCommands = JsonRequest<payloads::GetMyCommands, Tr>;
 
     fn get_my_commands(&self) -> Self::GetMyCommands {
         Self::GetMyCommands::new(self.clone(), payloads::GetMyCommands::new())
     }
 
-    type SetMyName = JsonRequest<payloads::SetMyName>;
+    type SetMyName = JsonRequest<payloads::SetMyName, Tr>;
 
     fn set_my_name(&self) -> Self::SetMyName {
         Self::SetMyName::new(self.clone(), payloads::SetMyName::new())
     }
 
-    type GetMyName = JsonRequest<payloads::GetMyName>;
+    type GetMyName = JsonRequest<payloads::GetMyName, Tr>;
 
     fn get_my_name(&self) -> Self::GetMyName {
         Self::GetMyName::new(self.clone(), payloads::GetMyName::new())
     }
 
-    type SetMyDescription = JsonRequest<payloads::SetMyDescription>;
+    type SetMyDescription = JsonRequest<payloads::SetMyDescription, Tr>;
 
     fn set_my_description(&self) -> Self::SetMyDescription {
         Self::SetMyDescription::new(self.clone(), payloads::SetMyDescription::new())
     }
 
-    type GetMyDescription = JsonRequest<payloads::GetMyDescription>;
+    type GetMyDescription = JsonRequest<payloads::GetMyDescription, Tr>;
 
     fn get_my_description(&self) -> Self::GetMyDescription {
         Self::GetMyDescription::new(self.clone(), payloads::GetMyDescription::new())
     }
 
-    type SetMyShortDescription = JsonRequest<payloads::SetMyShortDescription>;
+    type SetMyShortDescription = JsonRequest<payloads::SetMyShortDescription, Tr>;
 
     fn set_my_short_description(&self) -> Self::SetMyShortDescription {
         Self::SetMyShortDescription::new(self.clone(), payloads::SetMyShortDescription::new())
     }
 
-    type GetMyShortDescription = JsonRequest<payloads::GetMyShortDescription>;
+    type GetMyShortDescription = JsonRequest<payloads::GetMyShortDescription, Tr>;
     fn get_my_short_description(&self) -> Self::GetMyShortDescription {
         Self::GetMyShortDescription::new(self.clone(), payloads::GetMyShortDescription::new())
     }
 
-    type SetChatMenuButton = JsonRequest<payloads::SetChatMenuButton>;
+    type SetChatMenuButton = JsonRequest<payloads::SetChatMenuButton, Tr>;
 
     fn set_chat_menu_button(&self) -> Self::SetChatMenuButton {
         Self::SetChatMenuButton::new(self.clone(), payloads::SetChatMenuButton::new())
     }
 
-    type GetChatMenuButton = JsonRequest<payloads::GetChatMenuButton>;
+    type GetChatMenuButton = JsonRequest<payloads::GetChatMenuButton, Tr>;
 
     fn get_chat_menu_button(&self) -> Self::GetChatMenuButton {
         Self::GetChatMenuButton::new(self.clone(), payloads::GetChatMenuButton::new())
     }
 
-    type SetMyDefaultAdministratorRights = JsonRequest<payloads::SetMyDefaultAdministratorRights>;
+    type SetMyDefaultAdministratorRights =
+        JsonRequest<payloads::SetMyDefaultAdministratorRights, Tr>;
 
     fn set_my_default_administrator_rights(&self) -> Self::SetMyDefaultAdministratorRights {
         Self::SetMyDefaultAdministratorRights::new(
@@ -956,7 +1038,8 @@ impl Requester for Bot {
         )
     }
 
-    type GetMyDefaultAdministratorRights = JsonRequest<payloads::GetMyDefaultAdministratorRights>;
+    type GetMyDefaultAdministratorRights =
+        JsonRequest<payloads::GetMyDefaultAdministratorRights, Tr>;
 
     fn get_my_default_administrator_rights(&self) -> Self::GetMyDefaultAdministratorRights {
         Self::GetMyDefaultAdministratorRights::new(
@@ -965,13 +1048,13 @@ impl Requester for Bot {
         )
     }
 
-    type DeleteMyCommands = JsonRequest<payloads::DeleteMyCommands>;
+    type DeleteMyCommands = JsonRequest<payloads::DeleteMyCommands, Tr>;
 
     fn delete_my_commands(&self) -> Self::DeleteMyCommands {
         Self::DeleteMyCommands::new(self.clone(), payloads::DeleteMyCommands::new())
     }
 
-    type AnswerInlineQuery = JsonRequest<payloads::AnswerInlineQuery>;
+    type AnswerInlineQuery = JsonRequest<payloads::AnswerInlineQuery, Tr>;
 
     fn answer_inline_query<I, R>(&self, inline_query_id: I, results: R) -> Self::AnswerInlineQuery
     where
@@ -984,7 +1067,7 @@ impl Requester for Bot {
         )
     }
 
-    type AnswerWebAppQuery = JsonRequest<payloads::AnswerWebAppQuery>;
+    type AnswerWebAppQuery = JsonRequest<payloads::AnswerWebAppQuery, Tr>;
 
     fn answer_web_app_query<W>(
         &self,
@@ -1000,7 +1083,20 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageText = JsonRequest<payloads::EditMessageText>;
+    type SavePreparedInlineMessage = JsonRequest<payloads::SavePreparedInlineMessage, Tr>;
+
+    fn save_prepared_inline_message(
+        &self,
+        user_id: UserId,
+        result: InlineQueryResult,
+    ) -> Self::SavePreparedInlineMessage {
+        Self::SavePreparedInlineMessage::new(
+            self.clone(),
+            payloads::SavePreparedInlineMessage::new(user_id, result),
+        )
+    }
+
+    type EditMessageText = JsonRequest<payloads::EditMessageText, Tr>;
 
     fn edit_message_text<C, T>(
         &self,
@@ -1018,7 +1114,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageTextInline = JsonRequest<payloads::EditMessageTextInline>;
+    type EditMessageTextInline = JsonRequest<payloads::EditMessageTextInline, Tr>;
 
     fn edit_message_text_inline<I, T>(
         &self,
@@ -1035,7 +1131,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageCaption = JsonRequest<payloads::EditMessageCaption>;
+    type EditMessageCaption = JsonRequest<payloads::EditMessageCaption, Tr>;
 
     fn edit_message_caption<C>(&self, chat_id: C, message_id: MessageId) -> Self::EditMessageCaption
     where
@@ -1047,7 +1143,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageCaptionInline = JsonRequest<payloads::EditMessageCaptionInline>;
+    type EditMessageCaptionInline = JsonRequest<payloads::EditMessageCaptionInline, Tr>;
 
     fn edit_message_caption_inline<I>(&self, inline_message_id: I) -> Self::EditMessageCaptionInline
     where
@@ -1059,7 +1155,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageMedia = MultipartRequest<payloads::EditMessageMedia>;
+    type EditMessageMedia = MultipartRequest<payloads::EditMessageMedia, Tr>;
 
     fn edit_message_media<C>(
         &self,
@@ -1076,7 +1172,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageMediaInline = MultipartRequest<payloads::EditMessageMediaInline>;
+    type EditMessageMediaInline = MultipartRequest<payloads::EditMessageMediaInline, Tr>;
 
     fn edit_message_media_inline<I>(
         &self,
@@ -1092,7 +1188,30 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageReplyMarkup = JsonRequest<payloads::EditMessageReplyMarkup>;
+    type EditMessageChecklist = JsonRequest<payloads::EditMessageChecklist, Tr>;
+
+    fn edit_message_checklist<C>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: C,
+        message_id: MessageId,
+        checklist: InputChecklist,
+    ) -> Self::EditMessageChecklist
+    where
+        C: Into<ChatId>,
+    {
+        Self::EditMessageChecklist::new(
+            self.clone(),
+            payloads::EditMessageChecklist::new(
+                business_connection_id,
+                chat_id,
+                message_id,
+                checklist,
+            ),
+        )
+    }
+
+    type EditMessageReplyMarkup = JsonRequest<payloads::EditMessageReplyMarkup, Tr>;
 
     fn edit_message_reply_markup<C>(
         &self,
@@ -1108,7 +1227,7 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageReplyMarkupInline = JsonRequest<payloads::EditMessageReplyMarkupInline>;
+    type EditMessageReplyMarkupInline = JsonRequest<payloads::EditMessageReplyMarkupInline, Tr>;
 
     fn edit_message_reply_markup_inline<I>(
         &self,
@@ -1123,7 +1242,7 @@ impl Requester for Bot {
         )
     }
 
-    type StopPoll = JsonRequest<payloads::StopPoll>;
+    type StopPoll = JsonRequest<payloads::StopPoll, Tr>;
 
     fn stop_poll<C>(&self, chat_id: C, message_id: MessageId) -> Self::StopPoll
     where
@@ -1132,7 +1251,39 @@ impl Requester for Bot {
         Self::StopPoll::new(self.clone(), payloads::StopPoll::new(chat_id, message_id))
     }
 
-    type DeleteMessage = JsonRequest<payloads::DeleteMessage>;
+    type ApproveSuggestedPost = JsonRequest<payloads::ApproveSuggestedPost, Tr>;
+
+    fn approve_suggested_post<C>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+    ) -> Self::ApproveSuggestedPost
+    where
+        C: Into<ChatId>,
+    {
+        Self::ApproveSuggestedPost::new(
+            self.clone(),
+            payloads::ApproveSuggestedPost::new(chat_id, message_id),
+        )
+    }
+
+    type DeclineSuggestedPost = JsonRequest<payloads::DeclineSuggestedPost, Tr>;
+
+    fn decline_suggested_post<C>(
+        &self,
+        chat_id: C,
+        message_id: MessageId,
+    ) -> Self::DeclineSuggestedPost
+    where
+        C: Into<ChatId>,
+    {
+        Self::DeclineSuggestedPost::new(
+            self.clone(),
+            payloads::DeclineSuggestedPost::new(chat_id, message_id),
+        )
+    }
+
+    type DeleteMessage = JsonRequest<payloads::DeleteMessage, Tr>;
 
     fn delete_message<C>(&self, chat_id: C, message_id: MessageId) -> Self::DeleteMessage
     where
@@ -1141,7 +1292,7 @@ impl Requester for Bot {
         Self::DeleteMessage::new(self.clone(), payloads::DeleteMessage::new(chat_id, message_id))
     }
 
-    type DeleteMessages = JsonRequest<payloads::DeleteMessages>;
+    type DeleteMessages = JsonRequest<payloads::DeleteMessages, Tr>;
     fn delete_messages<C, M>(&self, chat_id: C, message_ids: M) -> Self::DeleteMessages
     where
         C: Into<Recipient>,
@@ -1150,7 +1301,314 @@ impl Requester for Bot {
         Self::DeleteMessages::new(self.clone(), payloads::DeleteMessages::new(chat_id, message_ids))
     }
 
-    type SendSticker = MultipartRequest<payloads::SendSticker>;
+    type GetAvailableGifts = JsonRequest<payloads::GetAvailableGifts, Tr>;
+
+    fn get_available_gifts(&self) -> Self::GetAvailableGifts {
+        Self::GetAvailableGifts::new(self.clone(), payloads::GetAvailableGifts::new())
+    }
+
+    type SendGift = JsonRequest<payloads::SendGift, Tr>;
+
+    fn send_gift<G>(&self, user_id: UserId, gift_id: G) -> Self::SendGift
+    where
+        G: Into<String>,
+    {
+        Self::SendGift::new(self.clone(), payloads::SendGift::new(user_id, gift_id))
+    }
+
+    type SendGiftChat = JsonRequest<payloads::SendGiftChat, Tr>;
+
+    fn send_gift_chat<C, G>(&self, chat_id: C, gift_id: G) -> Self::SendGiftChat
+    where
+        C: Into<Recipient>,
+        G: Into<String>,
+    {
+        Self::SendGiftChat::new(self.clone(), payloads::SendGiftChat::new(chat_id, gift_id))
+    }
+
+    type VerifyUser = JsonRequest<payloads::VerifyUser, Tr>;
+
+    fn verify_user(&self, user_id: UserId) -> Self::VerifyUser {
+        Self::VerifyUser::new(self.clone(), payloads::VerifyUser::new(user_id))
+    }
+
+    type VerifyChat = JsonRequest<payloads::VerifyChat, Tr>;
+
+    fn verify_chat<C>(&self, chat_id: C) -> Self::VerifyChat
+    where
+        C: Into<Recipient>,
+    {
+        Self::VerifyChat::new(self.clone(), payloads::VerifyChat::new(chat_id))
+    }
+
+    type RemoveUserVerification = JsonRequest<payloads::RemoveUserVerification, Tr>;
+
+    fn remove_user_verification(&self, user_id: UserId) -> Self::RemoveUserVerification {
+        Self::RemoveUserVerification::new(
+            self.clone(),
+            payloads::RemoveUserVerification::new(user_id),
+        )
+    }
+
+    type RemoveChatVerification = JsonRequest<payloads::RemoveChatVerification, Tr>;
+
+    fn remove_chat_verification<C>(&self, chat_id: C) -> Self::RemoveChatVerification
+    where
+        C: Into<Recipient>,
+    {
+        Self::RemoveChatVerification::new(
+            self.clone(),
+            payloads::RemoveChatVerification::new(chat_id),
+        )
+    }
+
+    type ReadBusinessMessage = JsonRequest<payloads::ReadBusinessMessage, Tr>;
+
+    fn read_business_message<C>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: C,
+        message_id: MessageId,
+    ) -> Self::ReadBusinessMessage
+    where
+        C: Into<ChatId>,
+    {
+        Self::ReadBusinessMessage::new(
+            self.clone(),
+            payloads::ReadBusinessMessage::new(business_connection_id, chat_id, message_id),
+        )
+    }
+
+    type DeleteBusinessMessages = JsonRequest<payloads::DeleteBusinessMessages, Tr>;
+
+    fn delete_business_messages<M>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        message_ids: M,
+    ) -> Self::DeleteBusinessMessages
+    where
+        M: IntoIterator<Item = MessageId>,
+    {
+        Self::DeleteBusinessMessages::new(
+            self.clone(),
+            payloads::DeleteBusinessMessages::new(business_connection_id, message_ids),
+        )
+    }
+
+    type SetBusinessAccountName = JsonRequest<payloads::SetBusinessAccountName, Tr>;
+
+    fn set_business_account_name<F>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        first_name: F,
+    ) -> Self::SetBusinessAccountName
+    where
+        F: Into<String>,
+    {
+        Self::SetBusinessAccountName::new(
+            self.clone(),
+            payloads::SetBusinessAccountName::new(business_connection_id, first_name),
+        )
+    }
+
+    type SetBusinessAccountUsername = JsonRequest<payloads::SetBusinessAccountUsername, Tr>;
+
+    fn set_business_account_username(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::SetBusinessAccountUsername {
+        Self::SetBusinessAccountUsername::new(
+            self.clone(),
+            payloads::SetBusinessAccountUsername::new(business_connection_id),
+        )
+    }
+
+    type SetBusinessAccountBio = JsonRequest<payloads::SetBusinessAccountBio, Tr>;
+
+    fn set_business_account_bio(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::SetBusinessAccountBio {
+        Self::SetBusinessAccountBio::new(
+            self.clone(),
+            payloads::SetBusinessAccountBio::new(business_connection_id),
+        )
+    }
+
+    type SetBusinessAccountProfilePhoto =
+        MultipartRequest<payloads::SetBusinessAccountProfilePhoto, Tr>;
+
+    fn set_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        photo: InputProfilePhoto,
+    ) -> Self::SetBusinessAccountProfilePhoto {
+        Self::SetBusinessAccountProfilePhoto::new(
+            self.clone(),
+            payloads::SetBusinessAccountProfilePhoto::new(business_connection_id, photo),
+        )
+    }
+
+    type RemoveBusinessAccountProfilePhoto =
+        JsonRequest<payloads::RemoveBusinessAccountProfilePhoto, Tr>;
+
+    fn remove_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::RemoveBusinessAccountProfilePhoto {
+        Self::RemoveBusinessAccountProfilePhoto::new(
+            self.clone(),
+            payloads::RemoveBusinessAccountProfilePhoto::new(business_connection_id),
+        )
+    }
+
+    type SetBusinessAccountGiftSettings = JsonRequest<payloads::SetBusinessAccountGiftSettings, Tr>;
+
+    fn set_business_account_gift_settings(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        show_gift_button: bool,
+        accepted_gift_types: AcceptedGiftTypes,
+    ) -> Self::SetBusinessAccountGiftSettings {
+        Self::SetBusinessAccountGiftSettings::new(
+            self.clone(),
+            payloads::SetBusinessAccountGiftSettings::new(
+                business_connection_id,
+                show_gift_button,
+                accepted_gift_types,
+            ),
+        )
+    }
+
+    type GetBusinessAccountStarBalance = JsonRequest<payloads::GetBusinessAccountStarBalance, Tr>;
+
+    fn get_business_account_star_balance(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::GetBusinessAccountStarBalance {
+        Self::GetBusinessAccountStarBalance::new(
+            self.clone(),
+            payloads::GetBusinessAccountStarBalance::new(business_connection_id),
+        )
+    }
+
+    type TransferBusinessAccountStars = JsonRequest<payloads::TransferBusinessAccountStars, Tr>;
+
+    fn transfer_business_account_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        star_count: u32,
+    ) -> Self::TransferBusinessAccountStars {
+        Self::TransferBusinessAccountStars::new(
+            self.clone(),
+            payloads::TransferBusinessAccountStars::new(business_connection_id, star_count),
+        )
+    }
+
+    type GetBusinessAccountGifts = JsonRequest<payloads::GetBusinessAccountGifts, Tr>;
+
+    fn get_business_account_gifts(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::GetBusinessAccountGifts {
+        Self::GetBusinessAccountGifts::new(
+            self.clone(),
+            payloads::GetBusinessAccountGifts::new(business_connection_id),
+        )
+    }
+
+    type ConvertGiftToStars = JsonRequest<payloads::ConvertGiftToStars, Tr>;
+
+    fn convert_gift_to_stars<O>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+    ) -> Self::ConvertGiftToStars
+    where
+        O: Into<String>,
+    {
+        Self::ConvertGiftToStars::new(
+            self.clone(),
+            payloads::ConvertGiftToStars::new(business_connection_id, owned_gift_id),
+        )
+    }
+
+    type UpgradeGift = JsonRequest<payloads::UpgradeGift, Tr>;
+
+    fn upgrade_gift<O>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+    ) -> Self::UpgradeGift
+    where
+        O: Into<String>,
+    {
+        Self::UpgradeGift::new(
+            self.clone(),
+            payloads::UpgradeGift::new(business_connection_id, owned_gift_id),
+        )
+    }
+
+    type TransferGift = JsonRequest<payloads::TransferGift, Tr>;
+
+    fn transfer_gift<O, N>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+        new_owner_chat_id: N,
+    ) -> Self::TransferGift
+    where
+        O: Into<String>,
+        N: Into<ChatId>,
+    {
+        Self::TransferGift::new(
+            self.clone(),
+            payloads::TransferGift::new(business_connection_id, owned_gift_id, new_owner_chat_id),
+        )
+    }
+
+    type PostStory = MultipartRequest<payloads::PostStory, Tr>;
+
+    fn post_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        content: InputStoryContent,
+        active_period: Seconds,
+    ) -> Self::PostStory {
+        Self::PostStory::new(
+            self.clone(),
+            payloads::PostStory::new(business_connection_id, content, active_period),
+        )
+    }
+
+    type EditStory = MultipartRequest<payloads::EditStory, Tr>;
+
+    fn edit_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+        content: InputStoryContent,
+    ) -> Self::EditStory {
+        Self::EditStory::new(
+            self.clone(),
+            payloads::EditStory::new(business_connection_id, story_id, content),
+        )
+    }
+
+    type DeleteStory = JsonRequest<payloads::DeleteStory, Tr>;
+
+    fn delete_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+    ) -> Self::DeleteStory {
+        Self::DeleteStory::new(
+            self.clone(),
+            payloads::DeleteStory::new(business_connection_id, story_id),
+        )
+    }
+
+    type SendSticker = MultipartRequest<payloads::SendSticker, Tr>;
 
     fn send_sticker<C>(&self, chat_id: C, sticker: InputFile) -> Self::SendSticker
     where
@@ -1159,7 +1617,7 @@ impl Requester for Bot {
         Self::SendSticker::new(self.clone(), payloads::SendSticker::new(chat_id, sticker))
     }
 
-    type GetStickerSet = JsonRequest<payloads::GetStickerSet>;
+    type GetStickerSet = JsonRequest<payloads::GetStickerSet, Tr>;
 
     fn get_sticker_set<N>(&self, name: N) -> Self::GetStickerSet
     where
@@ -1168,7 +1626,7 @@ impl Requester for Bot {
         Self::GetStickerSet::new(self.clone(), payloads::GetStickerSet::new(name))
     }
 
-    type GetCustomEmojiStickers = JsonRequest<payloads::GetCustomEmojiStickers>;
+    type GetCustomEmojiStickers = JsonRequest<payloads::GetCustomEmojiStickers, Tr>;
 
     fn get_custom_emoji_stickers<C>(&self, custom_emoji_ids: C) -> Self::GetCustomEmojiStickers
     where
@@ -1180,7 +1638,7 @@ impl Requester for Bot {
         )
     }
 
-    type UploadStickerFile = MultipartRequest<payloads::UploadStickerFile>;
+    type UploadStickerFile = MultipartRequest<payloads::UploadStickerFile, Tr>;
 
     fn upload_sticker_file(
         &self,
@@ -1194,7 +1652,7 @@ impl Requester for Bot {
         )
     }
 
-    type CreateNewStickerSet = MultipartRequest<payloads::CreateNewStickerSet>;
+    type CreateNewStickerSet = MultipartRequest<payloads::CreateNewStickerSet, Tr>;
 
     fn create_new_sticker_set<N, T, S>(
         &self,
@@ -1214,7 +1672,7 @@ impl Requester for Bot {
         )
     }
 
-    type AddStickerToSet = MultipartRequest<payloads::AddStickerToSet>;
+    type AddStickerToSet = MultipartRequest<payloads::AddStickerToSet, Tr>;
 
     fn add_sticker_to_set<N>(
         &self,
@@ -1231,7 +1689,7 @@ impl Requester for Bot {
         )
     }
 
-    type SetStickerPositionInSet = JsonRequest<payloads::SetStickerPositionInSet>;
+    type SetStickerPositionInSet = JsonRequest<payloads::SetStickerPositionInSet, Tr>;
 
     fn set_sticker_position_in_set<S>(
         &self,
@@ -1247,7 +1705,7 @@ impl Requester for Bot {
         )
     }
 
-    type DeleteStickerFromSet = JsonRequest<payloads::DeleteStickerFromSet>;
+    type DeleteStickerFromSet = JsonRequest<payloads::DeleteStickerFromSet, Tr>;
 
     fn delete_sticker_from_set<S>(&self, sticker: S) -> Self::DeleteStickerFromSet
     where
@@ -1256,7 +1714,7 @@ impl Requester for Bot {
         Self::DeleteStickerFromSet::new(self.clone(), payloads::DeleteStickerFromSet::new(sticker))
     }
 
-    type ReplaceStickerInSet = JsonRequest<payloads::ReplaceStickerInSet>;
+    type ReplaceStickerInSet = JsonRequest<payloads::ReplaceStickerInSet, Tr>;
 
     fn replace_sticker_in_set<N, O>(
         &self,
@@ -1280,7 +1738,7 @@ impl Requester for Bot {
         )
     }
 
-    type SetStickerSetThumbnail = MultipartRequest<payloads::SetStickerSetThumbnail>;
+    type SetStickerSetThumbnail = MultipartRequest<payloads::SetStickerSetThumbnail, Tr>;
 
     fn set_sticker_set_thumbnail<N>(
         &self,
@@ -1298,7 +1756,7 @@ impl Requester for Bot {
     }
 
     type SetCustomEmojiStickerSetThumbnail =
-        JsonRequest<payloads::SetCustomEmojiStickerSetThumbnail>;
+        JsonRequest<payloads::SetCustomEmojiStickerSetThumbnail, Tr>;
 
     fn set_custom_emoji_sticker_set_thumbnail<N>(
         &self,
@@ -1313,7 +1771,7 @@ impl Requester for Bot {
         )
     }
 
-    type SetStickerSetTitle = JsonRequest<payloads::SetStickerSetTitle>;
+    type SetStickerSetTitle = JsonRequest<payloads::SetStickerSetTitle, Tr>;
 
     fn set_sticker_set_title<N, T>(&self, name: N, title: T) -> Self::SetStickerSetTitle
     where
@@ -1323,7 +1781,7 @@ impl Requester for Bot {
         Self::SetStickerSetTitle::new(self.clone(), payloads::SetStickerSetTitle::new(name, title))
     }
 
-    type DeleteStickerSet = JsonRequest<payloads::DeleteStickerSet>;
+    type DeleteStickerSet = JsonRequest<payloads::DeleteStickerSet, Tr>;
 
     fn delete_sticker_set<N>(&self, name: N) -> Self::DeleteStickerSet
     where
@@ -1332,7 +1790,7 @@ impl Requester for Bot {
         Self::DeleteStickerSet::new(self.clone(), payloads::DeleteStickerSet::new(name))
     }
 
-    type SetStickerEmojiList = JsonRequest<payloads::SetStickerEmojiList>;
+    type SetStickerEmojiList = JsonRequest<payloads::SetStickerEmojiList, Tr>;
 
     fn set_sticker_emoji_list<S, E>(&self, sticker: S, emoji_list: E) -> Self::SetStickerEmojiList
     where
@@ -1345,7 +1803,7 @@ impl Requester for Bot {
         )
     }
 
-    type SetStickerKeywords = JsonRequest<payloads::SetStickerKeywords>;
+    type SetStickerKeywords = JsonRequest<payloads::SetStickerKeywords, Tr>;
 
     fn set_sticker_keywords<S>(&self, sticker: S) -> Self::SetStickerKeywords
     where
@@ -1354,7 +1812,7 @@ impl Requester for Bot {
         Self::SetStickerKeywords::new(self.clone(), payloads::SetStickerKeywords::new(sticker))
     }
 
-    type SetStickerMaskPosition = JsonRequest<payloads::SetStickerMaskPosition>;
+    type SetStickerMaskPosition = JsonRequest<payloads::SetStickerMaskPosition, Tr>;
 
     fn set_sticker_mask_position<S>(&self, sticker: S) -> Self::SetStickerMaskPosition
     where
@@ -1366,7 +1824,7 @@ impl Requester for Bot {
         )
     }
 
-    type SendInvoice = JsonRequest<payloads::SendInvoice>;
+    type SendInvoice = JsonRequest<payloads::SendInvoice, Tr>;
 
     fn send_invoice<Ch, T, D, Pa, P, C, Pri>(
         &self,
@@ -1401,7 +1859,7 @@ impl Requester for Bot {
         )
     }
 
-    type CreateInvoiceLink = JsonRequest<payloads::CreateInvoiceLink>;
+    type CreateInvoiceLink = JsonRequest<payloads::CreateInvoiceLink, Tr>;
 
     fn create_invoice_link<T, D, Pa, P, C, Pri>(
         &self,
@@ -1433,7 +1891,7 @@ impl Requester for Bot {
         )
     }
 
-    type AnswerShippingQuery = JsonRequest<payloads::AnswerShippingQuery>;
+    type AnswerShippingQuery = JsonRequest<payloads::AnswerShippingQuery, Tr>;
 
     fn answer_shipping_query<S>(&self, shipping_query_id: S, ok: bool) -> Self::AnswerShippingQuery
     where
@@ -1445,7 +1903,7 @@ impl Requester for Bot {
         )
     }
 
-    type AnswerPreCheckoutQuery = JsonRequest<payloads::AnswerPreCheckoutQuery>;
+    type AnswerPreCheckoutQuery = JsonRequest<payloads::AnswerPreCheckoutQuery, Tr>;
 
     fn answer_pre_checkout_query<P>(
         &self,
@@ -1461,13 +1919,13 @@ impl Requester for Bot {
         )
     }
 
-    type GetStarTransactions = JsonRequest<payloads::GetStarTransactions>;
+    type GetStarTransactions = JsonRequest<payloads::GetStarTransactions, Tr>;
 
     fn get_star_transactions(&self) -> Self::GetStarTransactions {
         Self::GetStarTransactions::new(self.clone(), payloads::GetStarTransactions::new())
     }
 
-    type RefundStarPayment = JsonRequest<payloads::RefundStarPayment>;
+    type RefundStarPayment = JsonRequest<payloads::RefundStarPayment, Tr>;
 
     fn refund_star_payment<C>(
         &self,
@@ -1483,7 +1941,28 @@ impl Requester for Bot {
         )
     }
 
-    type SetPassportDataErrors = JsonRequest<payloads::SetPassportDataErrors>;
+    type EditUserStarSubscription = JsonRequest<payloads::EditUserStarSubscription, Tr>;
+
+    fn edit_user_star_subscription<C>(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: C,
+        is_canceled: bool,
+    ) -> Self::EditUserStarSubscription
+    where
+        C: Into<String>,
+    {
+        Self::EditUserStarSubscription::new(
+            self.clone(),
+            payloads::EditUserStarSubscription::new(
+                user_id,
+                telegram_payment_charge_id,
+                is_canceled,
+            ),
+        )
+    }
+
+    type SetPassportDataErrors = JsonRequest<payloads::SetPassportDataErrors, Tr>;
 
     fn set_passport_data_errors<E>(&self, user_id: UserId, errors: E) -> Self::SetPassportDataErrors
     where
@@ -1495,7 +1974,7 @@ impl Requester for Bot {
         )
     }
 
-    type SendGame = JsonRequest<payloads::SendGame>;
+    type SendGame = JsonRequest<payloads::SendGame, Tr>;
 
     fn send_game<C, G>(&self, chat_id: C, game_short_name: G) -> Self::SendGame
     where
@@ -1505,7 +1984,7 @@ impl Requester for Bot {
         Self::SendGame::new(self.clone(), payloads::SendGame::new(chat_id, game_short_name))
     }
 
-    type SetGameScore = JsonRequest<payloads::SetGameScore>;
+    type SetGameScore = JsonRequest<payloads::SetGameScore, Tr>;
 
     fn set_game_score(
         &self,
@@ -1520,7 +1999,7 @@ impl Requester for Bot {
         )
     }
 
-    type SetGameScoreInline = JsonRequest<payloads::SetGameScoreInline>;
+    type SetGameScoreInline = JsonRequest<payloads::SetGameScoreInline, Tr>;
 
     fn set_game_score_inline<I>(
         &self,
@@ -1537,7 +2016,7 @@ impl Requester for Bot {
         )
     }
 
-    type GetGameHighScores = JsonRequest<payloads::GetGameHighScores>;
+    type GetGameHighScores = JsonRequest<payloads::GetGameHighScores, Tr>;
 
     fn get_game_high_scores<T>(&self, user_id: UserId, target: T) -> Self::GetGameHighScores
     where
@@ -1549,19 +2028,19 @@ impl Requester for Bot {
         )
     }
 
-    type LogOut = JsonRequest<payloads::LogOut>;
+    type LogOut = JsonRequest<payloads::LogOut, Tr>;
 
     fn log_out(&self) -> Self::LogOut {
         Self::LogOut::new(self.clone(), payloads::LogOut::new())
     }
 
-    type Close = JsonRequest<payloads::Close>;
+    type Close = JsonRequest<payloads::Close, Tr>;
 
     fn close(&self) -> Self::Close {
         Self::Close::new(self.clone(), payloads::Close::new())
     }
 
-    type CopyMessage = JsonRequest<payloads::CopyMessage>;
+    type CopyMessage = JsonRequest<payloads::CopyMessage, Tr>;
 
     fn copy_message<C, F>(
         &self,
@@ -1579,7 +2058,7 @@ impl Requester for Bot {
         )
     }
 
-    type CopyMessages = JsonRequest<payloads::CopyMessages>;
+    type CopyMessages = JsonRequest<payloads::CopyMessages, Tr>;
     fn copy_messages<C, F, M>(
         &self,
         chat_id: C,
@@ -1597,7 +2076,7 @@ impl Requester for Bot {
         )
     }
 
-    type UnpinAllChatMessages = JsonRequest<payloads::UnpinAllChatMessages>;
+    type UnpinAllChatMessages = JsonRequest<payloads::UnpinAllChatMessages, Tr>;
 
     fn unpin_all_chat_messages<C>(&self, chat_id: C) -> Self::UnpinAllChatMessages
     where
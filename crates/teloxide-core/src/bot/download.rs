@@ -1,14 +1,24 @@
-use bytes::Bytes;
-use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
-use tokio::io::AsyncWrite;
+use std::io::{self, SeekFrom};
+
+use bytes::{Bytes, BytesMut};
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream},
+    FutureExt, StreamExt, TryStreamExt,
+};
+use tokio::io::{AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+use tokio_util::codec::{BytesCodec, FramedRead};
 
 use crate::{
-    bot::Bot,
-    net::{self, Download},
+    bot::{ApiMode, Bot},
+    net::{self, Download, Transport},
     DownloadError,
 };
 
-impl Download for Bot {
+impl<C> Download for Bot<C>
+where
+    C: Transport,
+{
     type Err<'dst> = DownloadError;
 
     // I would like to unbox this, but my coworkers will kill me if they'll see yet
@@ -20,28 +30,210 @@ impl Download for Bot {
         path: &str,
         destination: &'dst mut (dyn AsyncWrite + Unpin + Send),
     ) -> Self::Fut<'dst> {
-        net::download_file(
-            &self.client,
-            reqwest::Url::clone(&*self.api_url),
-            &self.token,
-            path,
-            destination,
-        )
-        .boxed()
+        match self.api_mode {
+            ApiMode::Cloud => {
+                let mut stream = self.download_file_stream(path);
+
+                async move {
+                    while let Some(chunk) = stream.next().await {
+                        destination.write_all(&chunk?).await?;
+                    }
+
+                    Ok(())
+                }
+                .boxed()
+            }
+            ApiMode::Local => {
+                let path = path.to_owned();
+
+                async move {
+                    let mut file = tokio::fs::File::open(path).await?;
+                    tokio::io::copy(&mut file, destination).await?;
+
+                    Ok(())
+                }
+                .boxed()
+            }
+        }
     }
 
-    type StreamErr = reqwest::Error;
+    type StreamErr = DownloadError;
 
     type Stream = BoxStream<'static, Result<Bytes, Self::StreamErr>>;
 
     fn download_file_stream(&self, path: &str) -> Self::Stream {
-        net::download_file_stream(
-            &self.client,
-            reqwest::Url::clone(&*self.api_url),
-            &self.token,
-            path,
-        )
-        .map(|res| res.map_err(crate::errors::hide_token))
-        .boxed()
+        self.download_file_stream_from(path, 0)
+    }
+
+    fn download_file_stream_from(&self, path: &str, offset: u64) -> Self::Stream {
+        match self.api_mode {
+            ApiMode::Cloud => self.client.download(
+                net::file_url(url::Url::clone(&*self.api_url), &self.token, path, self.api_env),
+                offset,
+            ),
+            ApiMode::Local => stream::once(open_at(path.to_owned(), offset))
+                .map_ok(|file| FramedRead::new(file, BytesCodec::new()).map_ok(BytesMut::freeze))
+                .try_flatten()
+                .map_err(DownloadError::from)
+                .boxed(),
+        }
+    }
+}
+
+async fn open_at(path: String, offset: u64) -> io::Result<tokio::fs::File> {
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(SeekFrom::Start(offset)).await?;
+
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Cursor},
+        time::Duration,
+    };
+
+    use bytes::Bytes;
+    use futures::{future::BoxFuture, stream::BoxStream, StreamExt, TryStreamExt};
+    use url::Url;
+
+    use crate::{
+        net::{multipart::Form, Download, DownloadProgress, ResumableDownload, Transport},
+        types::{File, FileMeta},
+        Bot, DownloadError, RequestError,
+    };
+
+    const DATA: &[u8] = b"0123456789";
+
+    /// A transport which sends at most 4 bytes of a file before failing.
+    #[derive(Clone)]
+    struct Flaky;
+
+    impl Transport for Flaky {
+        fn request_json(
+            &self,
+            _: Url,
+            _: Vec<u8>,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            Box::pin(async { Err(RequestError::Io(io::ErrorKind::Unsupported.into())) })
+        }
+
+        fn request_multipart(
+            &self,
+            _: Url,
+            _: Form,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            Box::pin(async { Err(RequestError::Io(io::ErrorKind::Unsupported.into())) })
+        }
+
+        fn download(
+            &self,
+            _: Url,
+            offset: u64,
+        ) -> BoxStream<'static, Result<Bytes, DownloadError>> {
+            let rest = &DATA[(offset as usize).min(DATA.len())..];
+            let chunk = Ok(Bytes::from_static(&rest[..rest.len().min(4)]));
+            let result = match rest.len() <= 4 {
+                true => vec![chunk],
+                false => vec![chunk, Err(io::Error::from(io::ErrorKind::ConnectionReset).into())],
+            };
+
+            futures::stream::iter(result).boxed()
+        }
+    }
+
+    fn file(unique_id: &str, size: u32) -> File {
+        let meta = FileMeta { id: String::from("id"), unique_id: unique_id.to_owned(), size };
+        File { meta, path: String::from("documents/file_0") }
+    }
+
+    #[tokio::test]
+    async fn download_resumable() {
+        let bot = Bot::with_transport("TOKEN", Flaky);
+        let file = file("unique", DATA.len() as u32);
+
+        let mut progress = Vec::new();
+        let mut dst = Cursor::new(b"01".to_vec());
+        let size = ResumableDownload::new(&file)
+            .expect_unique_id("unique")
+            .on_progress(|p: DownloadProgress| progress.push(p.downloaded))
+            .run(&bot, &mut dst)
+            .await
+            .unwrap();
+
+        assert_eq!(size, 10);
+        assert_eq!(dst.into_inner(), DATA);
+        assert_eq!(progress, [2, 6, 10]);
+    }
+
+    #[tokio::test]
+    async fn download_resumable_counts_only_failed_attempts() {
+        let bot = Bot::with_transport("TOKEN", Flaky);
+
+        // Every attempt downloads a part of the file, so none of them are counted
+        let mut dst = Cursor::new(Vec::new());
+        let size = ResumableDownload::new(&file("unique", DATA.len() as u32))
+            .max_attempts(1)
+            .run(&bot, &mut dst)
+            .await
+            .unwrap();
+
+        assert_eq!(size, 10);
+        assert_eq!(dst.into_inner(), DATA);
+
+        // The last attempt doesn't download anything
+        let err = ResumableDownload::new(&file("unique", 12))
+            .max_attempts(1)
+            .run(&bot, &mut Cursor::new(Vec::new()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DownloadError::UnexpectedSize { expected: 12, found: 10 }));
+    }
+
+    #[tokio::test]
+    async fn download_resumable_verifies() {
+        let bot = Bot::with_transport("TOKEN", Flaky);
+
+        let err = ResumableDownload::new(&file("unique", 10))
+            .expect_unique_id("other")
+            .run(&bot, &mut Cursor::new(Vec::new()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DownloadError::UnexpectedFile { .. }));
+
+        let err = ResumableDownload::new(&file("unique", 10))
+            .expect_meta(&file("unique", 11).meta)
+            .run(&bot, &mut Cursor::new(Vec::new()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DownloadError::UnexpectedSize { expected: 11, found: 10 }));
+
+        // The file is larger than `Flaky` can send
+        let err = ResumableDownload::new(&file("unique", 12))
+            .run(&bot, &mut Cursor::new(Vec::new()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DownloadError::UnexpectedSize { expected: 12, found: 10 }));
+    }
+
+    #[tokio::test]
+    async fn download_local() {
+        const PATH: &str = "../../media/teloxide-core-logo.png";
+
+        let bot = Bot::new("TOKEN").with_local_server("http://localhost:8081".parse().unwrap());
+        let expected = tokio::fs::read(PATH).await.unwrap();
+
+        let mut dst = Vec::new();
+        bot.download_file(PATH, &mut dst).await.unwrap();
+        assert_eq!(dst, expected);
+
+        let chunks: Vec<_> = bot.download_file_stream(PATH).try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), expected);
+
+        let chunks: Vec<_> = bot.download_file_stream_from(PATH, 100).try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), expected[100..]);
     }
 }
@@ -282,6 +282,8 @@ pub(crate) fn patch_ty(mut schema: Schema) -> Schema {
             ("ban_chat_member", "until_date"),
             ("kick_chat_member", "until_date"),
             ("restrict_chat_member", "until_date"),
+            ("set_user_emoji_status", "emoji_status_expiration_date"),
+            ("approve_suggested_post", "send_date"),
         ],
     );
     patch_types(
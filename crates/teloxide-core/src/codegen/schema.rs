@@ -21,6 +21,8 @@ pub fn get() -> Schema {
 pub struct Schema {
     pub api_version: ApiVersion,
     pub methods: Vec<Method>,
+    #[serde(default)]
+    pub types: Vec<TypeDef>,
     pub tg_categories: HashMap<String, String>,
 }
 
@@ -46,7 +48,7 @@ pub struct Method {
     pub sibling: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Doc {
     pub md: String,
@@ -62,6 +64,133 @@ pub struct Param {
     pub descr: Doc,
 }
 
+/// Definition of a Telegram type.
+///
+/// Types with the same `file` are generated into the same module
+/// (`src/types/{file}.rs`), in the order they are defined in the schema.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeDef {
+    pub name: String,
+    pub file: String,
+    #[serde(default)]
+    pub doc: Doc,
+    #[serde(default)]
+    pub tg_doc: Option<String>,
+    /// Derives, apart from `Serialize` and `Deserialize` (see `serde`).
+    pub derive: Vec<String>,
+    /// Which of `Serialize` and `Deserialize` are derived.
+    #[serde(default)]
+    pub serde: SerdeDerive,
+    /// Additional attributes of the type, e.g. `#[allow(...)]`s or container
+    /// `#[serde(...)]` attributes.
+    #[serde(default)]
+    pub attrs: Vec<String>,
+    /// A (non-doc) comment, placed at the start of the type body.
+    #[serde(default)]
+    pub comment: Option<String>,
+    pub kind: TypeKind,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum SerdeDerive {
+    #[default]
+    Both,
+    /// For types which are only sent to Telegram and may contain data that
+    /// can't be deserialized (e.g. `InputFile`s).
+    SerializeOnly,
+    /// For types which are only received from Telegram.
+    DeserializeOnly,
+    /// For types with hand-written `Serialize` and `Deserialize` impls.
+    Manual,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum TypeKind {
+    /// A struct with named fields.
+    ///
+    /// `Option(True)` fields are generated as `bool`s which are `false` when
+    /// absent, `Option(ArrayOf(_))` fields are generated as (possibly empty)
+    /// `Vec`s.
+    Object { fields: Vec<Field> },
+    /// A sum type, discriminated by the `tag` field of the object.
+    Union {
+        tag: String,
+        #[serde(default = "snake_case")]
+        rename_all: Option<String>,
+        variants: Vec<Variant>,
+    },
+    /// An enumeration of string values (or an externally tagged sum type, if
+    /// variants have data).
+    Enum {
+        #[serde(default = "snake_case")]
+        rename_all: Option<String>,
+        variants: Vec<Variant>,
+    },
+    /// A sum type, without a discriminator (variants are tried in order).
+    Untagged { variants: Vec<Variant> },
+    /// A tuple struct with a single field.
+    Newtype {
+        ty: Type,
+        #[serde(default)]
+        descr: Doc,
+        /// Don't make the field public (e.g. if its value is validated by
+        /// constructors).
+        #[serde(default)]
+        private: bool,
+    },
+    /// A unit struct.
+    Unit,
+}
+
+fn snake_case() -> Option<String> {
+    Some(String::from("snake_case"))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Field {
+    pub name: String,
+    pub ty: Type,
+    #[serde(default)]
+    pub descr: Doc,
+    /// Name of the field in the json, if it's different from `name`.
+    #[serde(default)]
+    pub rename: Option<String>,
+    /// Additional attributes of the field, e.g. `#[serde(flatten)]`.
+    #[serde(default)]
+    pub attrs: Vec<String>,
+    /// A (non-doc) comment, placed before the field.
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Variant {
+    pub name: String,
+    /// Type of the variant data, for tuple-like variants.
+    #[serde(default)]
+    pub ty: Option<Type>,
+    /// Fields of the variant, for struct-like variants.
+    #[serde(default)]
+    pub fields: Option<Vec<Field>>,
+    #[serde(default)]
+    pub descr: Option<Doc>,
+    /// Value of the discriminator, if it's different from the one given by
+    /// `rename_all`.
+    #[serde(default)]
+    pub rename: Option<String>,
+    /// Additional attributes of the variant.
+    #[serde(default)]
+    pub attrs: Vec<String>,
+    /// A (non-doc) comment, placed before the variant.
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
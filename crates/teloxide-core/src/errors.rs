@@ -24,9 +24,17 @@ pub enum RequestError {
     RetryAfter(Seconds),
 
     /// Network error while sending a request to Telegram.
+    ///
+    /// The error is reported by the [`Transport`], e.g. it's a
+    /// [`reqwest::Error`] for [`reqwest::Client`] (which can be accessed with
+    /// [`downcast_ref`]).
+    ///
+    /// [`Transport`]: crate::net::Transport
+    /// [`reqwest::Error`]: https://docs.rs/reqwest/latest/reqwest/struct.Error.html
+    /// [`reqwest::Client`]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
+    /// [`downcast_ref`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.downcast_ref-1
     #[error("A network error: {0}")]
-    // NOTE: this variant must not be created by anything except the explicit From impl
-    Network(#[source] reqwest::Error),
+    Network(#[source] Box<dyn std::error::Error + Send + Sync>),
 
     /// Error while parsing a response from Telegram.
     ///
@@ -49,15 +57,45 @@ pub enum RequestError {
 
 /// An error caused by downloading a file.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum DownloadError {
     /// A network error while downloading a file from Telegram.
+    ///
+    /// See [`RequestError::Network`] for more.
     #[error("A network error: {0}")]
-    // NOTE: this variant must not be created by anything except the explicit From impl
-    Network(#[source] reqwest::Error),
+    Network(#[source] Box<dyn std::error::Error + Send + Sync>),
 
-    /// An I/O error while writing a file to destination.
+    /// An I/O error while writing a file to destination (or reading a local
+    /// file, when using [`ApiMode::Local`]).
+    ///
+    /// [`ApiMode::Local`]: crate::ApiMode::Local
     #[error("An I/O error: {0}")]
     Io(#[from] std::io::Error),
+
+    /// The file doesn't have the expected `file_unique_id` (see
+    /// [`ResumableDownload::expect_unique_id`]).
+    ///
+    /// [`ResumableDownload::expect_unique_id`]: crate::net::ResumableDownload::expect_unique_id
+    #[error("Unexpected file: expected `{expected}` file unique id, found `{found}`")]
+    UnexpectedFile {
+        /// The expected `file_unique_id`.
+        expected: String,
+        /// `file_unique_id` of the file.
+        found: String,
+    },
+
+    /// The file doesn't have the expected size (see
+    /// [`ResumableDownload::expect_size`]), or the download ended before the
+    /// whole file was downloaded.
+    ///
+    /// [`ResumableDownload::expect_size`]: crate::net::ResumableDownload::expect_size
+    #[error("Unexpected file size: expected {expected} bytes, found {found} bytes")]
+    UnexpectedSize {
+        /// The expected size in bytes.
+        expected: u64,
+        /// Size of the file (or of its downloaded part) in bytes.
+        found: u64,
+    },
 }
 
 pub trait AsResponseParameters {
@@ -109,6 +147,18 @@ macro_rules! impl_api_error {
             )*
         }
 
+        impl $ident {
+            /// Returns the name of the error variant, e.g. `"BotBlocked"`.
+            #[must_use]
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(
+                    Self::$var_name $( (impl_api_error!(@wildcard $var_inner)) )? => stringify!($var_name),
+                    )*
+                }
+            }
+        }
+
         const _: () = {
             struct Visitor;
 
@@ -138,6 +188,9 @@ macro_rules! impl_api_error {
             }
         };
     };
+    (@wildcard $var_inner:ty) => {
+        _
+    };
     (@de $value:ident, $variant:ident, $val:literal) => {
         if $value == $val {
             return Ok(Self::Value::$variant)
@@ -752,24 +805,30 @@ impl From<DownloadError> for RequestError {
         match download_err {
             DownloadError::Network(err) => RequestError::Network(err),
             DownloadError::Io(err) => RequestError::Io(err),
+            err @ (DownloadError::UnexpectedFile { .. } | DownloadError::UnexpectedSize { .. }) => {
+                RequestError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+            }
         }
     }
 }
 
+#[cfg(feature = "reqwest")]
 impl From<reqwest::Error> for DownloadError {
     fn from(error: reqwest::Error) -> Self {
-        DownloadError::Network(hide_token(error))
+        DownloadError::Network(Box::new(hide_token(error)))
     }
 }
 
+#[cfg(feature = "reqwest")]
 impl From<reqwest::Error> for RequestError {
     fn from(error: reqwest::Error) -> Self {
-        RequestError::Network(hide_token(error))
+        RequestError::Network(Box::new(hide_token(error)))
     }
 }
 
 /// Replaces token in the url in the error with `token:redacted` string.
-pub(crate) fn hide_token(mut error: reqwest::Error) -> reqwest::Error {
+#[cfg(feature = "reqwest")]
+fn hide_token(mut error: reqwest::Error) -> reqwest::Error {
     let url = match error.url_mut() {
         Some(url) => url,
         None => return error,
@@ -1,7 +1,8 @@
 //! Core part of the [`teloxide`] library.
 //!
 //! This library provides tools for making requests to the [Telegram Bot API]
-//! (Currently, version `7.5` is supported) with ease. The library is fully
+//! (Currently, version `9.2` is supported, see the changelog for the
+//! missing parts) with ease. The library is fully
 //! asynchronous and built using [`tokio`].
 //!
 //!```toml
@@ -36,13 +37,20 @@
 //!
 //! ## Cargo features
 //!
+//! - `reqwest` — use [`reqwest::Client`] as the default [`Transport`] of
+//!   [`Bot`] (**enabled by default**)
 //! - `native-tls` = use [`native-tls`] tls implementation (**enabled by
 //!   default**)
 //! - `rustls` — use [`rustls`] tls implementation
 //! - `trace_adaptor` — enables [`Trace`] bot adaptor
+//! - `tracing` — enables [`Tracing`] bot adaptor
 //! - `erased` — enables [`ErasedRequester`] bot adaptor
 //! - `throttle` — enables [`Throttle`] bot adaptor
 //! - `cache_me` — enables [`CacheMe`] bot adaptor
+//! - `cache` — enables [`Cache`] bot adaptor
+//! - `retry` — enables [`Retry`] bot adaptor
+//! - `metrics` — enables [`Metrics`] bot adaptor
+//! - `cassette` — enables [`Recorder`] bot adaptor and [`Replayer`]
 //! - `full` — enables all features except `nightly` and tls-related
 //! - `nightly` — enables nightly-only features, currently:
 //!   - Removes some future boxing using `#![feature(type_alias_impl_trait)]`
@@ -50,9 +58,17 @@
 //!
 //! [`AutoSend`]: adaptors::AutoSend
 //! [`Trace`]: adaptors::Trace
+//! [`Tracing`]: adaptors::Tracing
 //! [`ErasedRequester`]: adaptors::ErasedRequester
 //! [`Throttle`]: adaptors::Throttle
 //! [`CacheMe`]: adaptors::CacheMe
+//! [`Cache`]: adaptors::Cache
+//! [`Retry`]: adaptors::Retry
+//! [`Metrics`]: adaptors::Metrics
+//! [`Recorder`]: adaptors::Recorder
+//! [`Replayer`]: adaptors::Replayer
+//! [`reqwest::Client`]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
+//! [`Transport`]: net::Transport
 //! [`native-tls`]: https://docs.rs/native-tls
 //! [`rustls`]: https://docs.rs/rustls
 
@@ -112,7 +128,7 @@
 mod local_macros;
 
 pub use self::{
-    bot::Bot,
+    bot::{ApiEnvironment, ApiMode, Bot},
     errors::{ApiError, DownloadError, RequestError},
 };
 
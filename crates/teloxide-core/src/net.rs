@@ -2,20 +2,105 @@
 
 use std::time::Duration;
 
-pub use self::download::{download_file, download_file_stream, Download};
+use crate::ApiEnvironment;
+
+pub use self::{
+    download::{Download, DownloadProgress, ResumableDownload},
+    multipart::UploadProgress,
+    transport::Transport,
+};
+
+#[cfg(feature = "reqwest")]
+pub use self::reqwest_client::{download_file, download_file_stream};
 
 pub(crate) use self::{
     request::{request_json, request_multipart},
     telegram_response::TelegramResponse,
 };
 
+pub mod multipart;
+
 mod download;
 mod request;
+#[cfg(feature = "reqwest")]
+mod reqwest_client;
 mod telegram_response;
+mod transport;
 
 /// The default Telegram API URL.
 pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
 
+/// The default [`Transport`] of [`Bot`], [`reqwest::Client`].
+///
+/// [`Bot`]: crate::Bot
+/// [`reqwest::Client`]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
+#[cfg(feature = "reqwest")]
+pub type DefaultTransport = reqwest::Client;
+
+/// The default [`Transport`] of [`Bot`] when the `reqwest` feature is
+/// disabled.
+///
+/// There is no default transport without the `reqwest` feature, so this type
+/// can't be constructed and the transport has to be specified explicitly, e.g.
+/// `Bot<MyTransport>`.
+///
+/// [`Bot`]: crate::Bot
+#[cfg(not(feature = "reqwest"))]
+#[derive(Debug, Clone)]
+pub enum DefaultTransport {}
+
+/// The default timeout of requests, used by [`default_reqwest_settings`].
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(17);
+
+/// The minimal upload speed (in bytes per second) assumed by
+/// [`default_timeout`].
+pub const MIN_UPLOAD_SPEED: u64 = 128 * 1024;
+
+/// The size of files assumed by [`default_timeout`] when it's not known in
+/// advance (e.g. for [`InputFile::read`]), which is the maximum size of files
+/// that can be uploaded to the cloud Bot API server.
+///
+/// With [`ApiMode::Local`], [`UNKNOWN_UPLOAD_SIZE_LOCAL`] is assumed instead.
+///
+/// [`InputFile::read`]: crate::types::InputFile::read
+/// [`ApiMode::Local`]: crate::ApiMode::Local
+pub const UNKNOWN_UPLOAD_SIZE: u64 = 50 * 1024 * 1024;
+
+/// The size of files assumed by [`default_timeout`] when it's not known in
+/// advance and the bot uses [`ApiMode::Local`], which is the maximum size of
+/// files that can be uploaded to a local Bot API server.
+///
+/// [`ApiMode::Local`]: crate::ApiMode::Local
+pub const UNKNOWN_UPLOAD_SIZE_LOCAL: u64 = 2000 * 1024 * 1024;
+
+/// Returns the timeout [`Bot`] uses for a request, which wasn't given an
+/// explicit one (see [`Request::set_timeout`]).
+///
+/// - `timeout_hint` is the additional time the request is expected to take (see
+///   [`Payload::timeout_hint`]), e.g. the long polling timeout of
+///   [`GetUpdates`].
+/// - `upload_size` is the size of files uploaded with the request, in bytes.
+///
+/// If the request is expected to take longer than usual, returns
+/// [`DEFAULT_TIMEOUT`] plus `timeout_hint` plus the time it takes to upload
+/// `upload_size` bytes at [`MIN_UPLOAD_SPEED`]. Otherwise returns `None`,
+/// meaning that the default timeout of the HTTP client should be used.
+///
+/// [`Bot`]: crate::Bot
+/// [`Request::set_timeout`]: crate::requests::Request::set_timeout
+/// [`Payload::timeout_hint`]: crate::requests::Payload::timeout_hint
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+#[must_use]
+pub fn default_timeout(timeout_hint: Option<Duration>, upload_size: u64) -> Option<Duration> {
+    if timeout_hint.is_none() && upload_size == 0 {
+        return None;
+    }
+
+    let upload = Duration::from_secs(upload_size.div_ceil(MIN_UPLOAD_SPEED));
+
+    Some(DEFAULT_TIMEOUT + timeout_hint.unwrap_or_default() + upload)
+}
+
 /// Constructs a network client from the `TELOXIDE_PROXY` environmental
 /// variable.
 ///
@@ -34,6 +119,7 @@ pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
 /// ## Panics
 ///
 /// If `TELOXIDE_PROXY` exists, but isn't correct url.
+#[cfg(feature = "reqwest")]
 #[must_use]
 pub fn client_from_env() -> reqwest::Client {
     use reqwest::Proxy;
@@ -57,37 +143,46 @@ pub fn client_from_env() -> reqwest::Client {
 ///
 /// The current settings are:
 ///  - A connection timeout of 5 seconds.
-///  - A timeout of 17 seconds.
+///  - A timeout of 17 seconds ([`DEFAULT_TIMEOUT`]).
 ///  - `tcp_nodelay` is on.
 ///
 /// ## Notes
 ///
 /// 1. The settings may change in the future.
-/// 2. If you are using the polling mechanism to get updates, the timeout
-///    configured in the client should be bigger than the polling timeout.
+/// 2. [`Bot`] extends the timeout for requests which are expected to take
+///    longer (long polling, file uploads), see [`default_timeout`].
 /// 3. If you alter the current settings listed above, your bot will not be
 ///    guaranteed to work over long time durations.
 ///
 /// [issue 223]: https://github.com/teloxide/teloxide/issues/223
+/// [`Bot`]: crate::Bot
+#[cfg(feature = "reqwest")]
 pub fn default_reqwest_settings() -> reqwest::ClientBuilder {
     reqwest::Client::builder()
         .connect_timeout(Duration::from_secs(5))
-        .timeout(Duration::from_secs(17))
+        .timeout(DEFAULT_TIMEOUT)
         .tcp_nodelay(true)
 }
 
 /// Creates URL for making HTTPS requests. See the [Telegram documentation].
 ///
 /// [Telegram documentation]: https://core.telegram.org/bots/api#making-requests
-fn method_url(base: reqwest::Url, token: &str, method_name: &str) -> reqwest::Url {
-    base.join(&format!("/bot{token}/{method_name}")).expect("failed to format url")
+fn method_url(base: url::Url, token: &str, method_name: &str, env: ApiEnvironment) -> url::Url {
+    let env = env.path_segment();
+    base.join(&format!("/bot{token}{env}/{method_name}")).expect("failed to format url")
 }
 
 /// Creates URL for downloading a file. See the [Telegram documentation].
 ///
 /// [Telegram documentation]: https://core.telegram.org/bots/api#file
-fn file_url(base: reqwest::Url, token: &str, file_path: &str) -> reqwest::Url {
-    base.join(&format!("file/bot{token}/{file_path}")).expect("failed to format url")
+pub(crate) fn file_url(
+    base: url::Url,
+    token: &str,
+    file_path: &str,
+    env: ApiEnvironment,
+) -> url::Url {
+    let env = env.path_segment();
+    base.join(&format!("file/bot{token}{env}/{file_path}")).expect("failed to format url")
 }
 
 #[cfg(test)]
@@ -97,9 +192,10 @@ mod tests {
     #[test]
     fn method_url_test() {
         let url = method_url(
-            reqwest::Url::parse(TELEGRAM_API_URL).unwrap(),
+            url::Url::parse(TELEGRAM_API_URL).unwrap(),
             "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
             "methodName",
+            ApiEnvironment::Production,
         );
 
         assert_eq!(
@@ -108,12 +204,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn method_url_test_env() {
+        let url = method_url(
+            url::Url::parse(TELEGRAM_API_URL).unwrap(),
+            "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
+            "methodName",
+            ApiEnvironment::Test,
+        );
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.telegram.org/bot535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao/test/methodName"
+        );
+    }
+
+    #[test]
+    fn default_timeout_test() {
+        assert_eq!(default_timeout(None, 0), None);
+        assert_eq!(
+            default_timeout(Some(Duration::from_secs(10)), 0),
+            Some(DEFAULT_TIMEOUT + Duration::from_secs(10))
+        );
+        assert_eq!(
+            default_timeout(None, MIN_UPLOAD_SPEED * 3 + 1),
+            Some(DEFAULT_TIMEOUT + Duration::from_secs(4))
+        );
+    }
+
     #[test]
     fn file_url_test() {
         let url = file_url(
-            reqwest::Url::parse(TELEGRAM_API_URL).unwrap(),
+            url::Url::parse(TELEGRAM_API_URL).unwrap(),
             "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
             "AgADAgADyqoxG2g8aEsu_KjjVsGF4-zetw8ABAEAAwIAA20AA_8QAwABFgQ",
+            ApiEnvironment::Production,
         );
 
         assert_eq!(
@@ -121,4 +246,19 @@ mod tests {
             "https://api.telegram.org/file/bot535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao/AgADAgADyqoxG2g8aEsu_KjjVsGF4-zetw8ABAEAAwIAA20AA_8QAwABFgQ"
         );
     }
+
+    #[test]
+    fn file_url_test_env() {
+        let url = file_url(
+            url::Url::parse(TELEGRAM_API_URL).unwrap(),
+            "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
+            "documents/file_0.txt",
+            ApiEnvironment::Test,
+        );
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.telegram.org/file/bot535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao/test/documents/file_0.txt"
+        );
+    }
 }
@@ -1,15 +1,13 @@
-use std::future::Future;
+use std::{future::Future, io::SeekFrom, pin::pin};
 
 use bytes::Bytes;
-use futures::{
-    future::{ready, Either},
-    stream::{once, unfold},
-    FutureExt, Stream, StreamExt,
-};
-use reqwest::{Client, Response, Url};
-use tokio::io::{AsyncWrite, AsyncWriteExt};
+use futures::{Stream, StreamExt};
+use tokio::io::{AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
 
-use crate::{errors::DownloadError, net::file_url};
+use crate::{
+    errors::DownloadError,
+    types::{File, FileMeta},
+};
 
 /// A trait for downloading files from Telegram.
 pub trait Download {
@@ -79,55 +77,238 @@ pub trait Download {
     /// [`tokio::fs::File`]: tokio::fs::File
     /// [`download_file`]: Self::download_file
     fn download_file_stream(&self, path: &str) -> Self::Stream;
+
+    /// Download a file from Telegram as [`Stream`], starting at the `offset`
+    /// byte.
+    ///
+    /// This allows continuing interrupted downloads. See [`ResumableDownload`]
+    /// for a higher level API, which also verifies the file and reports
+    /// progress.
+    fn download_file_stream_from(&self, path: &str, offset: u64) -> Self::Stream;
 }
 
-/// Download a file from Telegram into `dst`.
-///
-/// Note: if you don't need to use a different (from you're bot) client and
-/// don't need to get *all* performance (and you don't, c'mon it's very io-bound
-/// job), then it's recommended to use [`Download::download_file`].
-pub fn download_file<'o, D>(
-    client: &Client,
-    api_url: Url,
-    token: &str,
-    path: &str,
-    dst: &'o mut D,
-) -> impl Future<Output = Result<(), DownloadError>> + 'o
-where
-    D: ?Sized + AsyncWrite + Unpin,
-{
-    client.get(file_url(api_url, token, path)).send().then(move |r| async move {
-        let mut res = r?.error_for_status()?;
-
-        while let Some(chunk) = res.chunk().await? {
-            dst.write_all(&chunk).await?;
-        }
+/// Progress of a [`ResumableDownload`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// Number of bytes in the destination, including bytes written before
+    /// the download was resumed.
+    pub downloaded: u64,
 
-        Ok(())
-    })
+    /// Size of the file, if it's known.
+    pub total: Option<u64>,
 }
 
-/// Download a file from Telegram as [`Stream`].
+/// A download of a [`File`], which can be resumed and verified.
+///
+/// Unlike [`Download::download_file`], this:
+/// - continues a partially written destination, requesting only the missing
+///   bytes,
+/// - retries the download from where it stopped, if it was interrupted (e.g. by
+///   a network error),
+/// - checks that the file is the expected one and has the expected size,
+/// - reports progress of the download.
 ///
-/// Note: if you don't need to use a different (from you're bot) client and
-/// don't need to get *all* performance (and you don't, c'mon it's very io-bound
-/// job), then it's recommended to use [`Download::download_file_stream`].
-pub fn download_file_stream(
-    client: &Client,
-    api_url: Url,
-    token: &str,
-    path: &str,
-) -> impl Stream<Item = reqwest::Result<Bytes>> + 'static {
-    client.get(file_url(api_url, token, path)).send().into_stream().flat_map(|res| {
-        match res.and_then(Response::error_for_status) {
-            Ok(res) => Either::Left(unfold(res, |mut res| async {
-                match res.chunk().await {
-                    Err(err) => Some((Err(err), res)),
-                    Ok(Some(c)) => Some((Ok(c), res)),
-                    Ok(None) => None,
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide_core::{
+///     net::ResumableDownload,
+///     requests::{Request, Requester},
+///     types::Document,
+///     Bot,
+/// };
+/// use tokio::fs::OpenOptions;
+///
+/// # async fn run(document: Document) -> Result<(), Box<dyn std::error::Error>> {
+/// let bot = Bot::new("TOKEN");
+///
+/// let file = bot.get_file(&document.file.id).await?;
+///
+/// // Doesn't truncate the file, so it's continued if it was already
+/// // partially downloaded
+/// let mut dst = OpenOptions::new().create(true).write(true).open("/tmp/doc").await?;
+/// ResumableDownload::new(&file)
+///     .expect_meta(&document.file)
+///     .max_attempts(5)
+///     .on_progress(|p| log::info!("downloaded {}/{:?} bytes", p.downloaded, p.total))
+///     .run(&bot, &mut dst)
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[must_use = "`ResumableDownload` does nothing unless `run` is called"]
+pub struct ResumableDownload<'a> {
+    file: &'a File,
+    unique_id: Option<String>,
+    size: Option<u64>,
+    max_attempts: u32,
+    on_progress: Option<Box<dyn FnMut(DownloadProgress) + Send + 'a>>,
+}
+
+impl<'a> ResumableDownload<'a> {
+    /// Creates a download of the `file`, which can be obtained from
+    /// [`GetFile`].
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    pub fn new(file: &'a File) -> Self {
+        Self { file, unique_id: None, size: None, max_attempts: 3, on_progress: None }
+    }
+
+    /// Checks that the file has the given `file_unique_id`, before
+    /// downloading it.
+    pub fn expect_unique_id(self, unique_id: impl Into<String>) -> Self {
+        Self { unique_id: Some(unique_id.into()), ..self }
+    }
+
+    /// Checks that the file has the given size (in bytes), both before and
+    /// after downloading it.
+    pub fn expect_size(self, size: u64) -> Self {
+        Self { size: Some(size), ..self }
+    }
+
+    /// Checks that the file has the `file_unique_id` and size of `meta`
+    /// (e.g. of the [`Document`] which is being downloaded).
+    ///
+    /// [`Document`]: crate::types::Document
+    pub fn expect_meta(self, meta: &FileMeta) -> Self {
+        let this = self.expect_unique_id(meta.unique_id.clone());
+
+        match known_size(meta) {
+            Some(size) => this.expect_size(size),
+            None => this,
+        }
+    }
+
+    /// Sets the maximum number of consecutive attempts, which don't download
+    /// anything. Defaults to 3.
+    ///
+    /// Attempts which download a part of the file are not counted, so large
+    /// files can be downloaded over an unreliable connection.
+    ///
+    /// ## Panics
+    ///
+    /// If `max_attempts` is `0`.
+    pub fn max_attempts(self, max_attempts: u32) -> Self {
+        assert_ne!(max_attempts, 0, "`max_attempts` must be at least 1");
+
+        Self { max_attempts, ..self }
+    }
+
+    /// Sets a callback, which is called when the download starts and after
+    /// each chunk of the file is written into the destination.
+    pub fn on_progress<F>(self, on_progress: F) -> Self
+    where
+        F: FnMut(DownloadProgress) + Send + 'a,
+    {
+        Self { on_progress: Some(Box::new(on_progress)), ..self }
+    }
+
+    /// Downloads the file into `destination` using `downloader` (e.g. a
+    /// [`Bot`]).
+    ///
+    /// The download starts at the end of `destination`, so if it already
+    /// contains a part of the file, only the rest of the file is downloaded.
+    /// Returns the size of the file in bytes.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DownloadError::UnexpectedFile`], if the file doesn't have the
+    ///   expected `file_unique_id`,
+    /// - [`DownloadError::UnexpectedSize`], if the file doesn't have the
+    ///   expected size, or if `destination` is larger than the file,
+    /// - errors of the `downloader`, if the download failed `max_attempts`
+    ///   times in a row,
+    /// - [`DownloadError::Io`], if writing to `destination` failed.
+    ///
+    /// [`Bot`]: crate::Bot
+    pub async fn run<D, W>(
+        mut self,
+        downloader: &D,
+        destination: &mut W,
+    ) -> Result<u64, DownloadError>
+    where
+        D: ?Sized + Download<StreamErr = DownloadError> + Sync,
+        W: ?Sized + AsyncWrite + AsyncSeek + Unpin + Send,
+    {
+        let file = self.file;
+
+        if let Some(expected) = self.unique_id.take() {
+            if expected != file.unique_id {
+                return Err(DownloadError::UnexpectedFile {
+                    expected,
+                    found: file.unique_id.clone(),
+                });
+            }
+        }
+
+        let total = match (self.size, known_size(file)) {
+            (Some(expected), Some(found)) if expected != found => {
+                return Err(DownloadError::UnexpectedSize { expected, found });
+            }
+            (expected, found) => found.or(expected),
+        };
+
+        let mut downloaded = destination.seek(SeekFrom::End(0)).await?;
+        self.report(DownloadProgress { downloaded, total });
+
+        let mut attempts = 0;
+        loop {
+            match total {
+                Some(total) if downloaded == total => break,
+                Some(total) if downloaded > total => {
+                    return Err(DownloadError::UnexpectedSize {
+                        expected: total,
+                        found: downloaded,
+                    });
                 }
-            })),
-            Err(err) => Either::Right(once(ready(Err(err)))),
+                _ => {}
+            }
+
+            let start = downloaded;
+            let mut stream = pin!(downloader.download_file_stream_from(&file.path, start));
+            let res = loop {
+                match stream.next().await {
+                    Some(Ok(chunk)) => {
+                        destination.write_all(&chunk).await?;
+                        downloaded += chunk.len() as u64;
+                        self.report(DownloadProgress { downloaded, total });
+                    }
+                    Some(Err(err)) => break Err(err),
+                    None => break Ok(()),
+                }
+            };
+
+            let err = match (res, total) {
+                (Err(err), _) => err,
+                (Ok(()), Some(total)) if downloaded < total => {
+                    DownloadError::UnexpectedSize { expected: total, found: downloaded }
+                }
+                (Ok(()), _) => break,
+            };
+
+            attempts = if downloaded == start { attempts + 1 } else { 0 };
+            if attempts >= self.max_attempts {
+                return Err(err);
+            }
+
+            log::warn!(
+                "Download of `{}` was interrupted, resuming at {downloaded}: {err}",
+                file.path
+            );
         }
-    })
+
+        destination.flush().await?;
+
+        Ok(downloaded)
+    }
+
+    fn report(&mut self, progress: DownloadProgress) {
+        if let Some(on_progress) = &mut self.on_progress {
+            on_progress(progress);
+        }
+    }
+}
+
+/// Returns the size of a file, unless it's unknown.
+fn known_size(meta: &FileMeta) -> Option<u64> {
+    (meta.size != crate::types::file_size_fallback()).then(|| u64::from(meta.size))
 }
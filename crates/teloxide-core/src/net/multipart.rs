@@ -0,0 +1,303 @@
+//! Transport-independent representation of `multipart/form-data` bodies.
+
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::Poll,
+};
+
+use bytes::Bytes;
+use futures::{
+    ready,
+    stream::{self, BoxStream},
+    Stream, StreamExt, TryStreamExt,
+};
+
+use crate::ApiMode;
+
+/// A boxed error, used in [`PartBody::Stream`].
+pub type BoxError = Box<dyn Error + Send + Sync>;
+
+/// Progress of an upload, see [`MultipartRequest::on_progress`] and
+/// [`InputFile::on_progress`].
+///
+/// A chunk of a file is counted as sent once the HTTP client asks for the
+/// next one (or for the end of the file), i.e. once the client has written it
+/// out, though it may still be buffered by the client or the OS.
+///
+/// [`MultipartRequest::on_progress`]: crate::requests::MultipartRequest::on_progress
+/// [`InputFile::on_progress`]: crate::types::InputFile::on_progress
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Number of bytes, which were sent.
+    pub sent: u64,
+
+    /// Size of the upload, if it's known.
+    pub total: Option<u64>,
+}
+
+/// An observer of [`UploadProgress`], see [`Form::observe`].
+pub(crate) type ProgressObserver = Arc<dyn Fn(UploadProgress) + Send + Sync>;
+
+/// A `multipart/form-data` body of a request.
+///
+/// Built by [`Bot`] for requests with files and passed to
+/// [`Transport::request_multipart`].
+///
+/// [`Bot`]: crate::Bot
+/// [`Transport::request_multipart`]: crate::net::Transport::request_multipart
+#[derive(Debug, Default)]
+pub struct Form {
+    /// Named parts of the form, in order.
+    pub parts: Vec<(Cow<'static, str>, Part)>,
+}
+
+/// A single field of a [`Form`].
+#[derive(Debug)]
+pub struct Part {
+    /// Name of the file, set only for file parts.
+    pub file_name: Option<Cow<'static, str>>,
+
+    /// Contents of the part.
+    pub body: PartBody,
+
+    /// Size of the contents in bytes, if it's known in advance.
+    pub size: Option<u64>,
+}
+
+/// Contents of a [`Part`].
+pub enum PartBody {
+    /// A text value, e.g. `chat_id` or a JSON-serialized `reply_markup`.
+    Text(String),
+
+    /// Binary data, which is already in memory.
+    Bytes(Bytes),
+
+    /// Binary data, which is read lazily (e.g. from a file).
+    Stream(BoxStream<'static, Result<Bytes, BoxError>>),
+}
+
+impl Form {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn part(mut self, name: impl Into<Cow<'static, str>>, part: Part) -> Self {
+        self.parts.push((name.into(), part));
+        self
+    }
+
+    /// Returns the total size of files in the form, assuming the maximum size
+    /// of uploads in `api_mode` ([`UNKNOWN_UPLOAD_SIZE`] or
+    /// [`UNKNOWN_UPLOAD_SIZE_LOCAL`]) for files of unknown size.
+    ///
+    /// [`UNKNOWN_UPLOAD_SIZE`]: crate::net::UNKNOWN_UPLOAD_SIZE
+    /// [`UNKNOWN_UPLOAD_SIZE_LOCAL`]: crate::net::UNKNOWN_UPLOAD_SIZE_LOCAL
+    pub(crate) fn upload_size(&self, api_mode: ApiMode) -> u64 {
+        let unknown = match api_mode {
+            ApiMode::Cloud => crate::net::UNKNOWN_UPLOAD_SIZE,
+            ApiMode::Local => crate::net::UNKNOWN_UPLOAD_SIZE_LOCAL,
+        };
+
+        self.files().map(|part| part.size.unwrap_or(unknown)).sum()
+    }
+
+    /// Calls `observer` each time a chunk of a file is sent, with the progress
+    /// of all files in the form.
+    ///
+    /// The total size is known only if sizes of all files are known.
+    pub(crate) fn observe(self, observer: ProgressObserver) -> Self {
+        let total = self.files().map(|part| part.size).sum();
+        let sent = Arc::new(AtomicU64::new(0));
+
+        let parts = self
+            .parts
+            .into_iter()
+            .map(|(name, part)| {
+                if part.file_name.is_none() {
+                    return (name, part);
+                }
+
+                let sent = Arc::clone(&sent);
+                let observer = Arc::clone(&observer);
+                let part = part.on_sent(move |len| {
+                    let sent = sent.fetch_add(len, Ordering::Relaxed) + len;
+                    observer(UploadProgress { sent, total });
+                });
+
+                (name, part)
+            })
+            .collect();
+
+        Self { parts }
+    }
+
+    fn files(&self) -> impl Iterator<Item = &Part> {
+        self.parts.iter().map(|(_, part)| part).filter(|part| part.file_name.is_some())
+    }
+}
+
+impl Part {
+    pub(crate) fn text(text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self { size: Some(text.len() as u64), ..Self::new(PartBody::Text(text)) }
+    }
+
+    pub(crate) fn bytes(bytes: impl Into<Bytes>) -> Self {
+        let bytes = bytes.into();
+        Self { size: Some(bytes.len() as u64), ..Self::new(PartBody::Bytes(bytes)) }
+    }
+
+    pub(crate) fn stream<S, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        Self::new(PartBody::Stream(stream.map_err(Into::into).boxed()))
+    }
+
+    pub(crate) fn file_name(self, file_name: impl Into<Cow<'static, str>>) -> Self {
+        Self { file_name: Some(file_name.into()), ..self }
+    }
+
+    pub(crate) fn size(self, size: Option<u64>) -> Self {
+        Self { size, ..self }
+    }
+
+    /// Calls `observer` each time a chunk of the body is sent.
+    pub(crate) fn observe<F>(self, observer: F) -> Self
+    where
+        F: Fn(UploadProgress) + Send + 'static,
+    {
+        let total = self.size;
+        let mut sent = 0;
+
+        self.on_sent(move |len| {
+            sent += len;
+            observer(UploadProgress { sent, total });
+        })
+    }
+
+    /// Calls `on_sent` with the length of each chunk of the body, once the
+    /// chunk is sent, i.e. when the next chunk (or the end of the body) is
+    /// requested.
+    fn on_sent<F>(self, mut on_sent: F) -> Self
+    where
+        F: FnMut(u64) + Send + 'static,
+    {
+        /// Size of chunks in-memory bodies are split into, so their progress
+        /// can be observed as well.
+        const CHUNK: usize = 64 * 1024;
+
+        let mut chunks = match self.body {
+            PartBody::Text(text) => return Self { body: PartBody::Text(text), ..self },
+            PartBody::Bytes(bytes) => {
+                let len = bytes.len();
+                let chunks =
+                    (0..len).step_by(CHUNK).map(move |i| bytes.slice(i..len.min(i + CHUNK)));
+
+                stream::iter(chunks).map(Ok).boxed()
+            }
+            PartBody::Stream(stream) => stream,
+        };
+
+        // Length of the last chunk, which wasn't reported yet
+        let mut pending = 0;
+        let body = stream::poll_fn(move |cx| {
+            if pending != 0 {
+                on_sent(std::mem::take(&mut pending));
+            }
+
+            let chunk = ready!(chunks.poll_next_unpin(cx));
+            if let Some(Ok(chunk)) = &chunk {
+                pending = chunk.len() as u64;
+            }
+
+            Poll::Ready(chunk)
+        });
+
+        Self { body: PartBody::Stream(body.boxed()), ..self }
+    }
+
+    fn new(body: PartBody) -> Self {
+        Self { file_name: None, body, size: None }
+    }
+}
+
+impl fmt::Debug for PartBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Stream(_) => f.debug_struct("Stream").finish_non_exhaustive(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::{Form, Part, PartBody, UploadProgress};
+    use crate::{
+        net::{UNKNOWN_UPLOAD_SIZE, UNKNOWN_UPLOAD_SIZE_LOCAL},
+        ApiMode,
+    };
+
+    #[test]
+    fn upload_size() {
+        let form = Form::new()
+            .part("chat_id", Part::text("42"))
+            .part(
+                "a",
+                Part::stream(futures::stream::empty::<Result<_, std::io::Error>>()).file_name("a"),
+            )
+            .part("b", Part::bytes(vec![0; 10]).file_name("b"));
+
+        assert_eq!(form.upload_size(ApiMode::Cloud), UNKNOWN_UPLOAD_SIZE + 10);
+        assert_eq!(form.upload_size(ApiMode::Local), UNKNOWN_UPLOAD_SIZE_LOCAL + 10);
+    }
+
+    #[tokio::test]
+    async fn observe_form() {
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let form = Form::new()
+            .part("chat_id", Part::text("42"))
+            .part("a", Part::bytes(vec![0; 100_000]).file_name("a"))
+            .part("b", Part::bytes(vec![0; 10]).file_name("b"))
+            .observe(Arc::new({
+                let progress = Arc::clone(&progress);
+                move |p| progress.lock().unwrap().push(p)
+            }));
+
+        let mut parts = form.parts.into_iter().map(|(_, part)| part.body);
+        assert!(matches!(parts.next(), Some(PartBody::Text(_))));
+
+        let total = Some(100_010);
+        for body in parts {
+            let PartBody::Stream(mut body) = body else { panic!("body is not observed") };
+
+            // A chunk is reported only once the next one is requested
+            body.next().await.unwrap().unwrap();
+            let reported = progress.lock().unwrap().len();
+            let _: Vec<_> = body.try_collect().await.unwrap();
+            assert!(progress.lock().unwrap().len() > reported);
+        }
+
+        assert_eq!(
+            *progress.lock().unwrap(),
+            [
+                UploadProgress { sent: 65_536, total },
+                UploadProgress { sent: 100_000, total },
+                UploadProgress { sent: 100_010, total }
+            ]
+        );
+    }
+}
@@ -1,65 +1,54 @@
 use std::{any::TypeId, time::Duration};
 
-use reqwest::{
-    header::{HeaderValue, CONTENT_TYPE},
-    Client, Response,
-};
 use serde::de::DeserializeOwned;
 
-use crate::{net::TelegramResponse, requests::ResponseResult, RequestError};
-
-const DELAY_ON_SERVER_ERROR: Duration = Duration::from_secs(10);
+use crate::{
+    net::{multipart::Form, TelegramResponse, Transport},
+    requests::ResponseResult,
+    ApiEnvironment, RequestError,
+};
 
-pub async fn request_multipart<T>(
-    client: &Client,
+pub async fn request_multipart<T, C>(
+    transport: &C,
     token: &str,
-    api_url: reqwest::Url,
+    api_url: url::Url,
     method_name: &str,
-    params: reqwest::multipart::Form,
-    _timeout_hint: Option<Duration>,
+    env: ApiEnvironment,
+    params: Form,
+    timeout: Option<Duration>,
 ) -> ResponseResult<T>
 where
     T: DeserializeOwned + 'static,
+    C: Transport,
 {
-    // Workaround for [#460]
-    //
-    // Telegram has some methods that return either `Message` or `True` depending on
-    // the used arguments we model this as `...` and `..._inline` pairs of methods.
-    //
-    // Currently inline versions have wrong Payload::NAME (ie with the "Inline"
-    // suffix). This removes the suffix allowing to call the right telegram method.
-    // Note that currently there are no normal telegram methods ending in "Inline",
-    // so this is fine.
-    //
-    // [#460]: https://github.com/teloxide/teloxide/issues/460
-    let method_name = method_name.trim_end_matches("Inline");
-
-    let request = client
-        .post(crate::net::method_url(api_url, token, method_name))
-        .multipart(params)
-        .build()?;
+    let url = method_url(api_url, token, method_name, env);
+    let text = transport.request_multipart(url, params, timeout).await?;
 
-    // FIXME: uncomment this, when reqwest starts setting default timeout early
-    // if let Some(timeout) = timeout_hint {
-    //     *request.timeout_mut().get_or_insert(Duration::ZERO) += timeout;
-    // }
-
-    let response = client.execute(request).await?;
-
-    process_response(response).await
+    deserialize_response(text, false)
 }
 
-pub async fn request_json<T>(
-    client: &Client,
+#[allow(clippy::too_many_arguments)]
+pub async fn request_json<T, C>(
+    transport: &C,
     token: &str,
-    api_url: reqwest::Url,
+    api_url: url::Url,
     method_name: &str,
+    env: ApiEnvironment,
     params: Vec<u8>,
-    _timeout_hint: Option<Duration>,
+    timeout: Option<Duration>,
+    capture_unknown_fields: bool,
 ) -> ResponseResult<T>
 where
     T: DeserializeOwned + 'static,
+    C: Transport,
 {
+    let url = method_url(api_url, token, method_name, env);
+    let text = transport.request_json(url, params, timeout).await?;
+
+    deserialize_response(text, capture_unknown_fields)
+}
+
+fn method_url(api_url: url::Url, token: &str, method_name: &str, env: ApiEnvironment) -> url::Url {
     // Workaround for [#460]
     //
     // Telegram has some methods that return either `Message` or `True` depending on
@@ -73,81 +62,37 @@ where
     // [#460]: https://github.com/teloxide/teloxide/issues/460
     let method_name = method_name.trim_end_matches("Inline");
 
-    let request = client
-        .post(crate::net::method_url(api_url, token, method_name))
-        .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
-        .body(params)
-        .build()?;
-
-    // FIXME: uncomment this, when reqwest starts setting default timeout early
-    // if let Some(timeout) = timeout_hint {
-    //     *request.timeout_mut().get_or_insert(Duration::ZERO) += timeout;
-    // }
-
-    let response = client.execute(request).await?;
-
-    process_response(response).await
-}
-
-async fn process_response<T>(response: Response) -> ResponseResult<T>
-where
-    T: DeserializeOwned + 'static,
-{
-    if response.status().is_server_error() {
-        tokio::time::sleep(DELAY_ON_SERVER_ERROR).await;
-    }
-
-    let text = response.text().await?;
-
-    deserialize_response(text)
+    crate::net::method_url(api_url, token, method_name, env)
 }
 
-fn deserialize_response<T>(text: String) -> Result<T, RequestError>
+/// Deserializes a response of the Bot API.
+///
+/// If `capture_unknown_fields` is `true` and the response contains updates,
+/// [`Update::extra`] of each of them is filled with all the data `teloxide`
+/// doesn't know about.
+///
+/// [`Update::extra`]: crate::types::Update::extra
+fn deserialize_response<T>(text: String, capture_unknown_fields: bool) -> Result<T, RequestError>
 where
     T: DeserializeOwned + 'static,
 {
     serde_json::from_str::<TelegramResponse<T>>(&text)
         .map(|mut response| {
-            use crate::types::{Update, UpdateKind};
+            use crate::types::Update;
             use std::{any::Any, iter::zip};
 
-            // HACK: Fill-in error information into `UpdateKind::Error`.
-            //
-            //       Why? Well, we need `Update` deserialization to be reliable,
-            //       even if Telegram breaks something in their Bot API, we want
-            //       1. Deserialization to """succeed"""
-            //       2. Get the `update.id`
-            //
-            //       Both of these points are required for `get_updates(...) -> Vec<Update>`
-            //       to behave well after Telegram introduces updates that we can't parse.
-            //       (1.) makes it so only some of the updates in a butch need to be skipped
-            //       (otherwise serde'll stop on the first error). (2.) allows us to issue
-            //       the next `get_updates` call with the right offset, even if the last
-            //       update in the batch didn't deserialize well.
-            //
-            //       serde's interface doesn't allows us to implement `Deserialize` in such
-            //       a way, that we could keep the data we couldn't parse, so our
-            //       `Deserialize` impl for `UpdateKind` just returns
-            //       `UpdateKind::Error(/* some empty-ish value */)`. Here, through some
-            //       terrible hacks and downcasting, we fill-in the data we couldn't parse
-            //       so that our users can make actionable bug reports.
-            //
-            //       We specifically handle `Vec<Update>` here, because that's the return
-            //       type of the only method that returns updates.
-            if TypeId::of::<T>() == TypeId::of::<Vec<Update>>() {
+            // We specifically handle `Vec<Update>` here, because that's the return
+            // type of the only method that returns updates.
+            if capture_unknown_fields && TypeId::of::<T>() == TypeId::of::<Vec<Update>>() {
                 if let TelegramResponse::Ok { response, .. } = &mut response {
                     if let Some(updates) =
                         (response as &mut T as &mut dyn Any).downcast_mut::<Vec<Update>>()
                     {
-                        if updates.iter().any(|u| matches!(u.kind, UpdateKind::Error(_))) {
-                            let re_parsed = serde_json::from_str(&text);
+                        let re_parsed = serde_json::from_str(&text);
 
-                            if let Ok(TelegramResponse::Ok { response: values, .. }) = re_parsed {
-                                for (update, value) in zip::<_, Vec<_>>(updates, values) {
-                                    if let UpdateKind::Error(dest) = &mut update.kind {
-                                        *dest = value;
-                                    }
-                                }
+                        if let Ok(TelegramResponse::Ok { response: values, .. }) = re_parsed {
+                            for (update, value) in zip::<_, Vec<_>>(updates, values) {
+                                update.fill_unknown_fields(&value);
                             }
                         }
                     }
@@ -174,7 +119,7 @@ mod tests {
     fn smoke_ok() {
         let json = r#"{"ok":true,"result":true}"#.to_owned();
 
-        let res = deserialize_response::<True>(json);
+        let res = deserialize_response::<True>(json, false);
         assert_matches!(res, Ok(True));
     }
 
@@ -183,7 +128,7 @@ mod tests {
         let json =
             r#"{"ok":false,"description":"Forbidden: bot was blocked by the user"}"#.to_owned();
 
-        let res = deserialize_response::<True>(json);
+        let res = deserialize_response::<True>(json, false);
         assert_matches!(res, Err(RequestError::Api(ApiError::BotBlocked)));
     }
 
@@ -191,7 +136,7 @@ mod tests {
     fn migrate() {
         let json = r#"{"ok":false,"description":"this string is ignored","parameters":{"migrate_to_chat_id":123456}}"#.to_owned();
 
-        let res = deserialize_response::<True>(json);
+        let res = deserialize_response::<True>(json, false);
         assert_matches!(res, Err(RequestError::MigrateToChatId(ChatId(123456))));
     }
 
@@ -199,7 +144,7 @@ mod tests {
     fn retry_after() {
         let json = r#"{"ok":false,"description":"this string is ignored","parameters":{"retry_after":123456}}"#.to_owned();
 
-        let res = deserialize_response::<True>(json);
+        let res = deserialize_response::<True>(json, false);
         assert_matches!(res, Err(RequestError::RetryAfter(duration)) if duration == Seconds::from_seconds(123456));
     }
 
@@ -220,8 +165,8 @@ mod tests {
         }"#
         .to_owned();
 
-        let res = deserialize_response::<Vec<Update>>(json).unwrap();
-        assert_matches!(res, [Update { id: UpdateId(0), kind: UpdateKind::PollAnswer(_) }]);
+        let res = deserialize_response::<Vec<Update>>(json, false).unwrap();
+        assert_matches!(res, [Update { id: UpdateId(0), kind: UpdateKind::PollAnswer(_), .. }]);
     }
 
     /// Check that `get_updates` can work with malformed updates.
@@ -258,10 +203,10 @@ mod tests {
         }"#
         .to_owned();
 
-        let res = deserialize_response::<Vec<Update>>(json).unwrap();
+        let res = deserialize_response::<Vec<Update>>(json, false).unwrap();
         assert_matches!(
             res,
-            [Update { id: UpdateId(0), kind: UpdateKind::PollAnswer(_) }, Update { id: UpdateId(1), kind: UpdateKind::Error(v) } if v.is_object(), Update { id: UpdateId(2), kind: UpdateKind::PollAnswer(_) }, Update { id: UpdateId(3), kind: UpdateKind::Error(v) } if v.is_object()]
+            [Update { id: UpdateId(0), kind: UpdateKind::PollAnswer(_), .. }, Update { id: UpdateId(1), kind: UpdateKind::Error(v), .. } if v.is_object(), Update { id: UpdateId(2), kind: UpdateKind::PollAnswer(_), .. }, Update { id: UpdateId(3), kind: UpdateKind::Error(v), .. } if v["message"]["some fields are missing"] == true]
         );
     }
 }
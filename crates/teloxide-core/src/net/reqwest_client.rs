@@ -0,0 +1,191 @@
+//! [`Transport`] implementation for [`reqwest::Client`].
+
+use std::{future::Future, time::Duration};
+
+use bytes::Bytes;
+use futures::{
+    future::{ready, BoxFuture, Either},
+    stream::{once, unfold, BoxStream},
+    FutureExt, Stream, StreamExt, TryStreamExt,
+};
+use reqwest::{
+    header::{HeaderValue, CONTENT_TYPE, RANGE},
+    Body, Client, RequestBuilder, Response, StatusCode,
+};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use url::Url;
+
+use crate::{
+    net::{
+        file_url,
+        multipart::{Form, PartBody},
+        Transport,
+    },
+    ApiEnvironment, DownloadError, RequestError,
+};
+
+impl Transport for Client {
+    fn request_json(
+        &self,
+        url: Url,
+        body: Vec<u8>,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'static, Result<String, RequestError>> {
+        let request = self
+            .post(url)
+            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+            .body(body);
+
+        execute(self.clone(), with_timeout(request, timeout)).boxed()
+    }
+
+    fn request_multipart(
+        &self,
+        url: Url,
+        form: Form,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'static, Result<String, RequestError>> {
+        let request = self.post(url).multipart(into_reqwest_form(form));
+
+        execute(self.clone(), with_timeout(request, timeout)).boxed()
+    }
+
+    fn download(&self, url: Url, offset: u64) -> BoxStream<'static, Result<Bytes, DownloadError>> {
+        download_stream(self, url, offset).map_err(DownloadError::from).boxed()
+    }
+}
+
+fn with_timeout(request: RequestBuilder, timeout: Option<Duration>) -> RequestBuilder {
+    match timeout {
+        Some(timeout) => request.timeout(timeout),
+        None => request,
+    }
+}
+
+async fn execute(client: Client, request: RequestBuilder) -> Result<String, RequestError> {
+    let response = client.execute(request.build()?).await?;
+
+    Ok(response.text().await?)
+}
+
+fn into_reqwest_form(form: Form) -> reqwest::multipart::Form {
+    use reqwest::multipart::Part;
+
+    form.parts.into_iter().fold(reqwest::multipart::Form::new(), |form, (name, part)| {
+        let reqwest_part = match part.body {
+            PartBody::Text(text) => Part::text(text),
+            PartBody::Bytes(bytes) => Part::stream(bytes),
+            PartBody::Stream(stream) => Part::stream(Body::wrap_stream(stream)),
+        };
+
+        match part.file_name {
+            Some(file_name) => form.part(name, reqwest_part.file_name(file_name)),
+            None => form.part(name, reqwest_part),
+        }
+    })
+}
+
+/// Download a file from Telegram into `dst`.
+///
+/// The file is always downloaded from the production environment, see
+/// [`ApiEnvironment`].
+///
+/// Note: if you don't need to use a different (from you're bot) client and
+/// don't need to get *all* performance (and you don't, c'mon it's very io-bound
+/// job), then it's recommended to use [`Download::download_file`].
+///
+/// [`Download::download_file`]: crate::net::Download::download_file
+pub fn download_file<'o, D>(
+    client: &Client,
+    api_url: Url,
+    token: &str,
+    path: &str,
+    dst: &'o mut D,
+) -> impl Future<Output = Result<(), DownloadError>> + 'o
+where
+    D: ?Sized + AsyncWrite + Unpin,
+{
+    client.get(file_url(api_url, token, path, ApiEnvironment::Production)).send().then(
+        move |r| async move {
+            let mut res = r?.error_for_status()?;
+
+            while let Some(chunk) = res.chunk().await? {
+                dst.write_all(&chunk).await?;
+            }
+
+            Ok(())
+        },
+    )
+}
+
+/// Download a file from Telegram as [`Stream`].
+///
+/// The file is always downloaded from the production environment, see
+/// [`ApiEnvironment`].
+///
+/// Note: if you don't need to use a different (from you're bot) client and
+/// don't need to get *all* performance (and you don't, c'mon it's very io-bound
+/// job), then it's recommended to use [`Download::download_file_stream`].
+///
+/// [`Download::download_file_stream`]: crate::net::Download::download_file_stream
+pub fn download_file_stream(
+    client: &Client,
+    api_url: Url,
+    token: &str,
+    path: &str,
+) -> impl Stream<Item = reqwest::Result<Bytes>> + 'static {
+    download_stream(client, file_url(api_url, token, path, ApiEnvironment::Production), 0)
+}
+
+/// Downloads contents of `url`, starting at the `offset` byte, as [`Stream`].
+fn download_stream(
+    client: &Client,
+    url: Url,
+    offset: u64,
+) -> impl Stream<Item = reqwest::Result<Bytes>> + 'static {
+    let mut request = client.get(url);
+    if offset != 0 {
+        request = request.header(RANGE, format!("bytes={offset}-"));
+    }
+
+    request.send().into_stream().flat_map(move |res| {
+        match res.and_then(Response::error_for_status) {
+            Ok(res) => {
+                // The server may ignore the `Range` header and send the whole file
+                let skip = if res.status() == StatusCode::PARTIAL_CONTENT { 0 } else { offset };
+
+                let chunks = unfold(res, |mut res| async {
+                    match res.chunk().await {
+                        Err(err) => Some((Err(err), res)),
+                        Ok(Some(c)) => Some((Ok(c), res)),
+                        Ok(None) => None,
+                    }
+                });
+
+                Either::Left(skip_bytes(chunks, skip))
+            }
+            Err(err) => Either::Right(once(ready(Err(err)))),
+        }
+    })
+}
+
+/// Skips the first `n` bytes of a stream of chunks.
+fn skip_bytes<E>(
+    stream: impl Stream<Item = Result<Bytes, E>>,
+    mut n: u64,
+) -> impl Stream<Item = Result<Bytes, E>> {
+    stream.filter_map(move |chunk| {
+        let chunk = match chunk {
+            Ok(chunk) if n != 0 => {
+                let skipped = n.min(chunk.len() as u64);
+                n -= skipped;
+
+                let chunk = chunk.slice(skipped as usize..);
+                (!chunk.is_empty()).then_some(Ok(chunk))
+            }
+            chunk => Some(chunk),
+        };
+
+        ready(chunk)
+    })
+}
@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::{future::BoxFuture, stream::BoxStream};
+use url::Url;
+
+use crate::{net::multipart::Form, DownloadError, RequestError};
+
+/// An HTTP client used by [`Bot`] to make requests and download files.
+///
+/// [`Bot`] is generic over its transport, which allows replacing the default
+/// one ([`reqwest::Client`], available with the `reqwest` feature) with e.g. a
+/// client based on a different HTTP library, a client with custom connection
+/// pooling or an in-process fake for tests, without re-implementing
+/// [`Requester`].
+///
+/// Transports only move bytes, building URLs, serializing requests and
+/// parsing responses is done by [`Bot`].
+///
+/// ## Errors
+///
+/// Transports report their errors as [`RequestError::Network`] and
+/// [`DownloadError::Network`], boxing the error of the underlying HTTP client.
+/// Note that URLs contain the bot's token, so errors shouldn't include them.
+///
+/// The [`Retry`] adaptor can only tell what went wrong for [`reqwest::Error`]s
+/// and [`std::io::Error`]s, so other transports should either report
+/// [`std::io::Error`]s with a matching [`std::io::ErrorKind`] (e.g.
+/// [`ConnectionRefused`] if the request never left the client or
+/// [`TimedOut`] if the response didn't arrive in time), or wrap their errors
+/// into them (see [`std::io::Error::new`]).
+///
+/// [`Bot`]: crate::Bot
+/// [`Requester`]: crate::requests::Requester
+/// [`reqwest::Client`]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
+/// [`reqwest::Error`]: https://docs.rs/reqwest/latest/reqwest/struct.Error.html
+/// [`Retry`]: crate::adaptors::Retry
+/// [`ConnectionRefused`]: std::io::ErrorKind::ConnectionRefused
+/// [`TimedOut`]: std::io::ErrorKind::TimedOut
+pub trait Transport: Clone + Send + Sync + 'static {
+    /// Sends a `POST` request to `url` with a JSON `body` and returns the
+    /// response body.
+    ///
+    /// Note that responses are returned regardless of their status code, since
+    /// Telegram reports errors in the response body.
+    ///
+    /// `timeout` is the timeout of the whole request, which overrides the
+    /// default timeout of the transport. `None` means that the default timeout
+    /// should be used.
+    fn request_json(
+        &self,
+        url: Url,
+        body: Vec<u8>,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'static, Result<String, RequestError>>;
+
+    /// Sends a `POST` request to `url` with a `multipart/form-data` body and
+    /// returns the response body.
+    ///
+    /// See [`request_json`] for more.
+    ///
+    /// [`request_json`]: Transport::request_json
+    fn request_multipart(
+        &self,
+        url: Url,
+        form: Form,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'static, Result<String, RequestError>>;
+
+    /// Sends a `GET` request to `url` and returns the response body, starting
+    /// at the `offset` byte, as a stream of chunks.
+    ///
+    /// A non-zero `offset` is usually requested with the `Range` header, if
+    /// the server ignores it, the first `offset` bytes of the response must be
+    /// skipped.
+    ///
+    /// Unlike other methods, non-successful responses must be reported as
+    /// errors.
+    fn download(&self, url: Url, offset: u64) -> BoxStream<'static, Result<Bytes, DownloadError>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use bytes::Bytes;
+    use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+    use url::Url;
+
+    use crate::{
+        net::{
+            multipart::{Form, PartBody},
+            Transport,
+        },
+        prelude::Requester,
+        types::{ChatId, InputFile, MessageId},
+        Bot, DownloadError, RequestError,
+    };
+
+    /// Path of the request and its (stringified) body.
+    type Recorded = (String, Vec<String>);
+
+    /// A transport which records requests instead of sending them.
+    #[derive(Clone, Default)]
+    struct Fake {
+        requests: Arc<Mutex<Vec<Recorded>>>,
+    }
+
+    impl Transport for Fake {
+        fn request_json(
+            &self,
+            url: Url,
+            body: Vec<u8>,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            let body = String::from_utf8(body).unwrap();
+            self.requests.lock().unwrap().push((url.path().to_owned(), vec![body]));
+
+            async { Ok(String::from(r#"{"ok":true,"result":true}"#)) }.boxed()
+        }
+
+        fn request_multipart(
+            &self,
+            url: Url,
+            form: Form,
+            _: Option<Duration>,
+        ) -> BoxFuture<'static, Result<String, RequestError>> {
+            let parts = form
+                .parts
+                .into_iter()
+                .map(|(name, part)| match part.body {
+                    PartBody::Text(text) => format!("{name}={text}"),
+                    PartBody::Bytes(_) | PartBody::Stream(_) => format!("{name}: file"),
+                })
+                .collect();
+            self.requests.lock().unwrap().push((url.path().to_owned(), parts));
+
+            async { Ok(String::from(r#"{"ok":true,"result":true}"#)) }.boxed()
+        }
+
+        fn download(&self, _: Url, _: u64) -> BoxStream<'static, Result<Bytes, DownloadError>> {
+            futures::stream::empty().boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_transport() {
+        let transport = Fake::default();
+        let bot = Bot::with_transport("TOKEN", transport.clone());
+
+        bot.delete_message(ChatId(1), MessageId(2)).await.unwrap();
+        bot.set_chat_photo(ChatId(1), InputFile::memory(&b"photo"[..]).file_name("photo.png"))
+            .await
+            .unwrap();
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests[0].0, "/botTOKEN/DeleteMessage");
+        assert_eq!(requests[0].1, [r#"{"chat_id":1,"message_id":2}"#]);
+
+        assert_eq!(requests[1].0, "/botTOKEN/SetChatPhoto");
+        assert_eq!(requests[1].1[0], "chat_id=1");
+        assert!(requests[1].1[1].starts_with("photo=attach://"));
+        assert!(requests[1].1[2].ends_with(": file"));
+    }
+}
@@ -62,6 +62,7 @@ fn codegen_payloads() {
             || matches!(
                 &*method.names.1,
                 "SendMediaGroup"
+                    | "SendPaidMedia"
                     | "EditMessageMedia"
                     | "EditMessageMediaInline"
                     | "CreateNewStickerSet"
@@ -261,6 +262,9 @@ fn multipart_input_file_fields(m: &Method) -> Option<Vec<&str>> {
 }
 
 fn ty_is_multiparty(ty: &Type) -> bool {
-    matches!(ty, Type::RawTy(x) if x == "InputFile" || x == "InputSticker")
+    matches!(ty, Type::RawTy(x) if x == "InputFile"
+            || x == "InputSticker"
+            || x == "InputProfilePhoto"
+            || x == "InputStoryContent")
         || matches!(ty, Type::Option(inner) if ty_is_multiparty(inner))
 }
@@ -3,7 +3,8 @@
 use serde::Serialize;
 
 use crate::types::{
-    MessageEntity, MessageId, ParseMode, Recipient, ReplyMarkup, ReplyParameters, ThreadId,
+    MessageEntity, MessageId, ParseMode, Recipient, ReplyMarkup, ReplyParameters,
+    SuggestedPostParameters, ThreadId,
 };
 
 impl_payload! {
@@ -24,6 +25,8 @@ impl_payload! {
         optional {
             /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
             pub message_thread_id: ThreadId,
+            /// Identifier of the direct messages topic to which the message will be sent; required if the message is sent to a direct messages chat
+            pub direct_messages_topic_id: i64,
             /// New caption for media, 0-1024 characters after entities parsing. If not specified, the original caption is kept
             pub caption: String [into],
             /// Mode for parsing entities in the photo caption. See [formatting options] for more details.
@@ -40,6 +43,12 @@ impl_payload! {
             pub disable_notification: bool,
             /// Protects the contents of sent messages from forwarding and saving
             pub protect_content: bool,
+            /// Pass _True_ to allow up to 1000 messages per second, ignoring [broadcasting limits] for a fee of 0.1 Telegram Stars per message. The relevant Stars will be withdrawn from the bot's balance
+            ///
+            /// [broadcasting limits]: https://core.telegram.org/bots/faq#how-can-i-message-all-of-my-bot-39s-subscribers-at-once
+            pub allow_paid_broadcast: bool,
+            /// A JSON-serialized object containing the parameters of the suggested post to send; for direct messages chats only. If the message is sent as a reply to another suggested post, then that suggested post is automatically declined.
+            pub suggested_post_parameters: SuggestedPostParameters,
             /// Description of the message to reply to
             pub reply_parameters: ReplyParameters,
             /// Additional interface options. A JSON-serialized object for an [inline keyboard], [custom reply keyboard], instructions to remove a reply keyboard or to force a reply from the user. Not supported for messages sent on behalf of a business account.
@@ -0,0 +1,24 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{ChatInviteLink, Recipient};
+
+impl_payload! {
+    /// Use this method to edit a subscription invite link created by the bot. The bot must have the _can\_invite\_users_ administrator rights. Returns the edited invite link as a [`ChatInviteLink`] object.
+    ///
+    /// [`ChatInviteLink`]: crate::types::ChatInviteLink
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub EditChatSubscriptionInviteLink (EditChatSubscriptionInviteLinkSetters) => ChatInviteLink {
+        required {
+            /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+            pub chat_id: Recipient [into],
+            /// The invite link to edit
+            pub invite_link: String [into],
+        }
+        optional {
+            /// Invite link name; 0-32 characters
+            pub name: String [into],
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{
+    BusinessConnectionId, ChatId, InlineKeyboardMarkup, InputChecklist, Message, MessageId,
+};
+
+impl_payload! {
+    /// Use this method to edit a checklist on behalf of a connected business account. On success, the edited [`Message`] is returned.
+    ///
+    /// [`Message`]: crate::types::Message
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub EditMessageChecklist (EditMessageChecklistSetters) => Message {
+        required {
+            /// Unique identifier of the business connection on behalf of which the message will be sent
+            pub business_connection_id: BusinessConnectionId,
+            /// Unique identifier for the target chat
+            pub chat_id: ChatId [into],
+            /// Unique identifier for the target message
+            #[serde(flatten)]
+            pub message_id: MessageId,
+            /// A JSON-serialized object for the new checklist
+            pub checklist: InputChecklist,
+        }
+        optional {
+            /// A JSON-serialized object for the new [inline keyboard] for the message
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            pub reply_markup: InlineKeyboardMarkup,
+        }
+    }
+}
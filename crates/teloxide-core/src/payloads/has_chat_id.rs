@@ -0,0 +1,242 @@
+use crate::{
+    payloads::*,
+    requests::Payload,
+    types::{ChatId, Recipient},
+};
+
+/// Payloads, which may have the `chat_id` parameter.
+///
+/// This trait is implemented for all payloads, allowing to get the chat,
+/// which a request is addressed to, without knowing its type (e.g. in bot
+/// adaptors).
+pub trait HasChatId: Payload {
+    /// Returns the `chat_id` parameter of the payload, if it has one.
+    fn recipient(&self) -> Option<Recipient>;
+}
+
+/// Implements [`HasChatId`] for payloads, getting the `chat_id` parameter
+/// with the given closures.
+macro_rules! impl_has_chat_id {
+    ($($Payload:ident => |$this:pat_param| $recipient:expr),* $(,)?) => {
+        $(
+            impl HasChatId for $Payload {
+                fn recipient(&self) -> Option<Recipient> {
+                    let $this = self;
+                    $recipient
+                }
+            }
+        )*
+    };
+}
+
+// START BLOCK has_chat_id_impls
+// Generated by `codegen_has_chat_id_impls`, do not edit by hand.
+
+impl_has_chat_id! {
+    GetUpdates => |_| None,
+    SetWebhook => |_| None,
+    DeleteWebhook => |_| None,
+    GetWebhookInfo => |_| None,
+    GetMe => |_| None,
+    LogOut => |_| None,
+    Close => |_| None,
+    SendMessage => |this| Some(this.chat_id.clone()),
+    ForwardMessage => |this| Some(this.chat_id.clone()),
+    ForwardMessages => |this| Some(this.chat_id.clone()),
+    CopyMessage => |this| Some(this.chat_id.clone()),
+    CopyMessages => |this| Some(this.chat_id.clone()),
+    SendPhoto => |this| Some(this.chat_id.clone()),
+    SendAudio => |this| Some(this.chat_id.clone()),
+    SendDocument => |this| Some(this.chat_id.clone()),
+    SendVideo => |this| Some(this.chat_id.clone()),
+    SendAnimation => |this| Some(this.chat_id.clone()),
+    SendVoice => |this| Some(this.chat_id.clone()),
+    SendVideoNote => |this| Some(this.chat_id.clone()),
+    SendPaidMedia => |this| Some(this.chat_id.clone()),
+    SendMediaGroup => |this| Some(this.chat_id.clone()),
+    SendLocation => |this| Some(this.chat_id.clone()),
+    EditMessageLiveLocation => |this| Some(this.chat_id.clone()),
+    EditMessageLiveLocationInline => |_| None,
+    StopMessageLiveLocation => |this| Some(this.chat_id.clone()),
+    StopMessageLiveLocationInline => |_| None,
+    SendVenue => |this| Some(this.chat_id.clone()),
+    SendContact => |this| Some(this.chat_id.clone()),
+    SendPoll => |this| Some(this.chat_id.clone()),
+    SendChecklist => |this| Some(this.chat_id.into()),
+    SendDice => |this| Some(this.chat_id.clone()),
+    SendChatAction => |this| Some(this.chat_id.clone()),
+    SetMessageReaction => |this| Some(this.chat_id.clone()),
+    GetUserProfilePhotos => |_| None,
+    SetUserEmojiStatus => |_| None,
+    GetFile => |_| None,
+    BanChatMember => |this| Some(this.chat_id.clone()),
+    KickChatMember => |this| Some(this.chat_id.clone()),
+    UnbanChatMember => |this| Some(this.chat_id.clone()),
+    RestrictChatMember => |this| Some(this.chat_id.clone()),
+    PromoteChatMember => |this| Some(this.chat_id.clone()),
+    SetChatAdministratorCustomTitle => |this| Some(this.chat_id.clone()),
+    BanChatSenderChat => |this| Some(this.chat_id.clone()),
+    UnbanChatSenderChat => |this| Some(this.chat_id.clone()),
+    SetChatPermissions => |this| Some(this.chat_id.clone()),
+    ExportChatInviteLink => |this| Some(this.chat_id.clone()),
+    CreateChatInviteLink => |this| Some(this.chat_id.clone()),
+    EditChatInviteLink => |this| Some(this.chat_id.clone()),
+    CreateChatSubscriptionInviteLink => |this| Some(this.chat_id.clone()),
+    EditChatSubscriptionInviteLink => |this| Some(this.chat_id.clone()),
+    RevokeChatInviteLink => |this| Some(this.chat_id.clone()),
+    ApproveChatJoinRequest => |this| Some(this.chat_id.clone()),
+    DeclineChatJoinRequest => |this| Some(this.chat_id.clone()),
+    SetChatPhoto => |this| Some(this.chat_id.clone()),
+    DeleteChatPhoto => |this| Some(this.chat_id.clone()),
+    SetChatTitle => |this| Some(this.chat_id.clone()),
+    SetChatDescription => |this| Some(this.chat_id.clone()),
+    PinChatMessage => |this| Some(this.chat_id.clone()),
+    UnpinChatMessage => |this| Some(this.chat_id.clone()),
+    UnpinAllChatMessages => |this| Some(this.chat_id.clone()),
+    LeaveChat => |this| Some(this.chat_id.clone()),
+    GetChat => |this| Some(this.chat_id.clone()),
+    GetChatAdministrators => |this| Some(this.chat_id.clone()),
+    GetChatMemberCount => |this| Some(this.chat_id.clone()),
+    GetChatMembersCount => |this| Some(this.chat_id.clone()),
+    GetChatMember => |this| Some(this.chat_id.clone()),
+    SetChatStickerSet => |this| Some(this.chat_id.clone()),
+    DeleteChatStickerSet => |this| Some(this.chat_id.clone()),
+    GetForumTopicIconStickers => |_| None,
+    CreateForumTopic => |this| Some(this.chat_id.clone()),
+    EditForumTopic => |this| Some(this.chat_id.clone()),
+    CloseForumTopic => |this| Some(this.chat_id.clone()),
+    ReopenForumTopic => |this| Some(this.chat_id.clone()),
+    DeleteForumTopic => |this| Some(this.chat_id.clone()),
+    UnpinAllForumTopicMessages => |this| Some(this.chat_id.clone()),
+    EditGeneralForumTopic => |this| Some(this.chat_id.clone()),
+    CloseGeneralForumTopic => |this| Some(this.chat_id.clone()),
+    ReopenGeneralForumTopic => |this| Some(this.chat_id.clone()),
+    HideGeneralForumTopic => |this| Some(this.chat_id.clone()),
+    UnhideGeneralForumTopic => |this| Some(this.chat_id.clone()),
+    UnpinAllGeneralForumTopicMessages => |this| Some(this.chat_id.clone()),
+    AnswerCallbackQuery => |_| None,
+    GetUserChatBoosts => |this| Some(this.chat_id.clone()),
+    SetMyCommands => |_| None,
+    GetBusinessConnection => |_| None,
+    GetMyCommands => |_| None,
+    SetMyName => |_| None,
+    GetMyName => |_| None,
+    SetMyDescription => |_| None,
+    GetMyDescription => |_| None,
+    SetMyShortDescription => |_| None,
+    GetMyShortDescription => |_| None,
+    SetChatMenuButton => |this| this.chat_id.map(Into::into),
+    GetChatMenuButton => |this| this.chat_id.map(Into::into),
+    SetMyDefaultAdministratorRights => |_| None,
+    GetMyDefaultAdministratorRights => |_| None,
+    DeleteMyCommands => |_| None,
+    AnswerInlineQuery => |_| None,
+    AnswerWebAppQuery => |_| None,
+    SavePreparedInlineMessage => |_| None,
+    EditMessageText => |this| Some(this.chat_id.clone()),
+    EditMessageTextInline => |_| None,
+    EditMessageCaption => |this| Some(this.chat_id.clone()),
+    EditMessageCaptionInline => |_| None,
+    EditMessageMedia => |this| Some(this.chat_id.clone()),
+    EditMessageMediaInline => |_| None,
+    EditMessageChecklist => |this| Some(this.chat_id.into()),
+    EditMessageReplyMarkup => |this| Some(this.chat_id.clone()),
+    EditMessageReplyMarkupInline => |_| None,
+    StopPoll => |this| Some(this.chat_id.clone()),
+    ApproveSuggestedPost => |this| Some(this.chat_id.into()),
+    DeclineSuggestedPost => |this| Some(this.chat_id.into()),
+    DeleteMessage => |this| Some(this.chat_id.clone()),
+    DeleteMessages => |this| Some(this.chat_id.clone()),
+    GetAvailableGifts => |_| None,
+    SendGift => |_| None,
+    SendGiftChat => |this| Some(this.chat_id.clone()),
+    VerifyUser => |_| None,
+    VerifyChat => |this| Some(this.chat_id.clone()),
+    RemoveUserVerification => |_| None,
+    RemoveChatVerification => |this| Some(this.chat_id.clone()),
+    ReadBusinessMessage => |this| Some(this.chat_id.into()),
+    DeleteBusinessMessages => |_| None,
+    SetBusinessAccountName => |_| None,
+    SetBusinessAccountUsername => |_| None,
+    SetBusinessAccountBio => |_| None,
+    SetBusinessAccountProfilePhoto => |_| None,
+    RemoveBusinessAccountProfilePhoto => |_| None,
+    SetBusinessAccountGiftSettings => |_| None,
+    GetBusinessAccountStarBalance => |_| None,
+    TransferBusinessAccountStars => |_| None,
+    GetBusinessAccountGifts => |_| None,
+    ConvertGiftToStars => |_| None,
+    UpgradeGift => |_| None,
+    TransferGift => |_| None,
+    PostStory => |_| None,
+    EditStory => |_| None,
+    DeleteStory => |_| None,
+    SendSticker => |this| Some(this.chat_id.clone()),
+    GetStickerSet => |_| None,
+    GetCustomEmojiStickers => |_| None,
+    UploadStickerFile => |_| None,
+    CreateNewStickerSet => |_| None,
+    AddStickerToSet => |_| None,
+    SetStickerPositionInSet => |_| None,
+    DeleteStickerFromSet => |_| None,
+    ReplaceStickerInSet => |_| None,
+    SetStickerSetThumbnail => |_| None,
+    SetCustomEmojiStickerSetThumbnail => |_| None,
+    SetStickerSetTitle => |_| None,
+    DeleteStickerSet => |_| None,
+    SetStickerEmojiList => |_| None,
+    SetStickerKeywords => |_| None,
+    SetStickerMaskPosition => |_| None,
+    SendInvoice => |this| Some(this.chat_id.clone()),
+    CreateInvoiceLink => |_| None,
+    AnswerShippingQuery => |_| None,
+    AnswerPreCheckoutQuery => |_| None,
+    GetStarTransactions => |_| None,
+    RefundStarPayment => |_| None,
+    EditUserStarSubscription => |_| None,
+    SetPassportDataErrors => |_| None,
+    SendGame => |this| Some(this.chat_id.into()),
+    SetGameScore => |this| Some(ChatId(this.chat_id.into()).into()),
+    SetGameScoreInline => |_| None,
+    GetGameHighScores => |_| None,
+}
+// END BLOCK has_chat_id_impls
+
+/// Generates implementations of [`HasChatId`] above.
+#[test]
+fn codegen_has_chat_id_impls() {
+    use crate::codegen::{
+        add_hidden_preamble, ensure_file_contents, project_root, reformat, replace_block,
+        schema::{self, Type},
+    };
+
+    let path = project_root().join("src/payloads/has_chat_id.rs");
+    let schema = schema::get();
+    let mut block = String::from("impl_has_chat_id! {\n");
+
+    for method in &schema.methods {
+        let param = method.params.iter().find(|p| p.name == "chat_id");
+        let (this, recipient) = match param.map(|p| &p.ty) {
+            None => ("_", "None"),
+            Some(Type::RawTy(raw)) if raw == "Recipient" => ("this", "Some(this.chat_id.clone())"),
+            Some(Type::RawTy(raw)) if raw == "ChatId" => ("this", "Some(this.chat_id.into())"),
+            Some(Type::Option(inner)) if matches!(&**inner, Type::RawTy(raw) if raw == "ChatId") => {
+                ("this", "this.chat_id.map(Into::into)")
+            }
+            Some(Type::u32) => ("this", "Some(ChatId(this.chat_id.into()).into())"),
+            Some(ty) => panic!("unsupported type of `chat_id` in {}: {ty:?}", method.names.1),
+        };
+
+        block.push_str(&format!("    {} => |{this}| {recipient},\n", method.names.1));
+    }
+
+    block.push_str("}\n");
+
+    let contents = reformat(replace_block(
+        &path,
+        "has_chat_id_impls",
+        &add_hidden_preamble("codegen_has_chat_id_impls", block),
+    ));
+
+    ensure_file_contents(&path, &contents);
+}
@@ -0,0 +1,22 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{True, UserId};
+
+impl_payload! {
+    /// Verifies a user [on behalf of the organization] which is represented by the bot. Returns _True_ on success.
+    ///
+    /// [on behalf of the organization]: https://telegram.org/verify#third-party-verification
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub VerifyUser (VerifyUserSetters) => True {
+        required {
+            /// Unique identifier of the target user
+            pub user_id: UserId,
+        }
+        optional {
+            /// Custom description for the verification; 0-70 characters. Must be empty if the organization isn't allowed to provide a custom verification description.
+            pub custom_description: String [into],
+        }
+    }
+}
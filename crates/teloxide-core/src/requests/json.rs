@@ -1,9 +1,10 @@
-use std::future::IntoFuture;
+use std::{future::IntoFuture, time::Duration};
 
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     bot::Bot,
+    net::{DefaultTransport, Transport},
     requests::{HasPayload, Payload, Request, ResponseResult},
     RequestError,
 };
@@ -13,18 +14,19 @@ use crate::{
 /// [JSON]: https://core.telegram.org/bots/api#making-requests
 #[must_use = "Requests are lazy and do nothing unless sent"]
 #[derive(Clone)]
-pub struct JsonRequest<P> {
-    bot: Bot,
+pub struct JsonRequest<P, C = DefaultTransport> {
+    bot: Bot<C>,
     payload: P,
+    timeout: Option<Duration>,
 }
 
-impl<P> JsonRequest<P> {
-    pub const fn new(bot: Bot, payload: P) -> Self {
-        Self { bot, payload }
+impl<P, C> JsonRequest<P, C> {
+    pub const fn new(bot: Bot<C>, payload: P) -> Self {
+        Self { bot, payload, timeout: None }
     }
 }
 
-impl<P> Request for JsonRequest<P>
+impl<P, C> Request for JsonRequest<P, C>
 where
     // FIXME(waffle):
     //   this is required on stable because of
@@ -37,10 +39,11 @@ where
     P: 'static,
     P: Payload + Serialize,
     P::Output: DeserializeOwned,
+    C: Transport,
 {
     type Err = RequestError;
-    type Send = Send<P>;
-    type SendRef = SendRef<P>;
+    type Send = Send<P, C>;
+    type SendRef = SendRef<P, C>;
 
     fn send(self) -> Self::Send {
         Send::new(self)
@@ -49,10 +52,15 @@ where
     fn send_ref(&self) -> Self::SendRef {
         SendRef::new(self)
     }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
 }
 
-impl<P> IntoFuture for JsonRequest<P>
+impl<P, C> IntoFuture for JsonRequest<P, C>
 where
+    C: Transport,
     P: 'static,
     P: Payload + Serialize,
     P::Output: DeserializeOwned,
@@ -65,7 +73,7 @@ where
     }
 }
 
-impl<P> HasPayload for JsonRequest<P>
+impl<P, C> HasPayload for JsonRequest<P, C>
 where
     P: Payload,
 {
@@ -80,7 +88,7 @@ where
     }
 }
 
-impl<P: Payload + Serialize> core::ops::Deref for JsonRequest<P> {
+impl<P: Payload + Serialize, C> core::ops::Deref for JsonRequest<P, C> {
     type Target = P;
 
     fn deref(&self) -> &Self::Target {
@@ -88,29 +96,31 @@ impl<P: Payload + Serialize> core::ops::Deref for JsonRequest<P> {
     }
 }
 
-impl<P: Payload + Serialize> core::ops::DerefMut for JsonRequest<P> {
+impl<P: Payload + Serialize, C> core::ops::DerefMut for JsonRequest<P, C> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.payload_mut()
     }
 }
 
 req_future! {
-    def: |it: JsonRequest<U>| {
-        it.bot.execute_json(&it.payload)
+    def: |it: JsonRequest<U, T>| {
+        it.bot.execute_json(&it.payload, it.timeout)
     }
-    pub Send<U> (inner0) -> ResponseResult<U::Output>
+    pub Send<U, T> (inner0) -> ResponseResult<U::Output>
     where
+        T: Transport,
         U: 'static,
         U: Payload + Serialize,
         U::Output: DeserializeOwned,
 }
 
 req_future! {
-    def: |it: &JsonRequest<U>| {
-        it.bot.execute_json(&it.payload)
+    def: |it: &JsonRequest<U, T>| {
+        it.bot.execute_json(&it.payload, it.timeout)
     }
-    pub SendRef<U> (inner1) -> ResponseResult<U::Output>
+    pub SendRef<U, T> (inner1) -> ResponseResult<U::Output>
     where
+        T: Transport,
         U: 'static,
         U: Payload + Serialize,
         U::Output: DeserializeOwned,
@@ -1,9 +1,13 @@
-use std::future::IntoFuture;
+use std::{future::IntoFuture, sync::Arc, time::Duration};
 
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     bot::Bot,
+    net::{
+        multipart::{ProgressObserver, UploadProgress},
+        DefaultTransport, Transport,
+    },
     requests::{HasPayload, MultipartPayload, Payload, Request, ResponseResult},
     RequestError,
 };
@@ -14,19 +18,60 @@ use crate::{
 /// [multipart/form-data]: https://core.telegram.org/bots/api#making-requests
 #[must_use = "Requests are lazy and do nothing unless sent"]
 #[derive(Clone)]
-pub struct MultipartRequest<P> {
-    bot: Bot,
+pub struct MultipartRequest<P, C = DefaultTransport> {
+    bot: Bot<C>,
     payload: P,
+    timeout: Option<Duration>,
+    on_progress: Option<ProgressObserver>,
 }
 
-impl<P> MultipartRequest<P> {
-    pub const fn new(bot: Bot, payload: P) -> Self {
-        Self { bot, payload }
+impl<P, C> MultipartRequest<P, C> {
+    pub const fn new(bot: Bot<C>, payload: P) -> Self {
+        Self { bot, payload, timeout: None, on_progress: None }
+    }
+
+    /// Sets a progress observer, which is called as files of the request are
+    /// being uploaded, with the progress of all of them.
+    ///
+    /// [`UploadProgress::total`] is the total size of all files, if sizes of
+    /// all of them are known. Files, which aren't uploaded (file ids, URLs
+    /// and, with a local Bot API server, files from the file system), are not
+    /// counted. If the request is sent multiple times, the progress starts
+    /// over.
+    ///
+    /// To observe the upload of a single file, see [`InputFile::on_progress`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{
+    ///     prelude::*,
+    ///     types::{ChatId, InputFile, InputMedia, InputMediaPhoto},
+    /// };
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    /// let media = ["1.jpg", "2.jpg"]
+    ///     .map(|path| InputMedia::Photo(InputMediaPhoto::new(InputFile::file(path))));
+    ///
+    /// bot.send_media_group(ChatId(42), media)
+    ///     .on_progress(|p| println!("sent {} of {:?} bytes", p.sent, p.total))
+    ///     .await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`InputFile::on_progress`]: crate::types::InputFile::on_progress
+    pub fn on_progress<F>(self, on_progress: F) -> Self
+    where
+        F: Fn(UploadProgress) + core::marker::Send + Sync + 'static,
+    {
+        Self { on_progress: Some(Arc::new(on_progress)), ..self }
     }
 }
 
-impl<P> Request for MultipartRequest<P>
+impl<P, C> Request for MultipartRequest<P, C>
 where
+    C: Transport,
     // FIXME(waffle):
     //   this is required on stable because of
     //   https://github.com/rust-lang/rust/issues/76882
@@ -40,8 +85,8 @@ where
     P::Output: DeserializeOwned,
 {
     type Err = RequestError;
-    type Send = Send<P>;
-    type SendRef = SendRef<P>;
+    type Send = Send<P, C>;
+    type SendRef = SendRef<P, C>;
 
     fn send(self) -> Self::Send {
         Send::new(self)
@@ -50,10 +95,15 @@ where
     fn send_ref(&self) -> Self::SendRef {
         SendRef::new(self)
     }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
 }
 
-impl<P> IntoFuture for MultipartRequest<P>
+impl<P, C> IntoFuture for MultipartRequest<P, C>
 where
+    C: Transport,
     P: 'static,
     P: Payload + MultipartPayload + Serialize,
     P::Output: DeserializeOwned,
@@ -66,7 +116,7 @@ where
     }
 }
 
-impl<P> HasPayload for MultipartRequest<P>
+impl<P, C> HasPayload for MultipartRequest<P, C>
 where
     P: Payload,
 {
@@ -81,7 +131,7 @@ where
     }
 }
 
-impl<P> core::ops::Deref for MultipartRequest<P>
+impl<P, C> core::ops::Deref for MultipartRequest<P, C>
 where
     P: 'static,
     P: Payload + MultipartPayload,
@@ -94,7 +144,7 @@ where
     }
 }
 
-impl<P> core::ops::DerefMut for MultipartRequest<P>
+impl<P, C> core::ops::DerefMut for MultipartRequest<P, C>
 where
     P: 'static,
     P: Payload + MultipartPayload,
@@ -106,22 +156,24 @@ where
 }
 
 req_future! {
-    def: |it: MultipartRequest<U>| {
-        it.bot.execute_multipart(&mut {it.payload})
+    def: |it: MultipartRequest<U, T>| {
+        it.bot.execute_multipart(&mut {it.payload}, it.timeout, it.on_progress)
     }
-    pub Send<U> (inner0) -> ResponseResult<U::Output>
+    pub Send<U, T> (inner0) -> ResponseResult<U::Output>
     where
+        T: Transport,
         U: 'static,
         U: Payload + MultipartPayload + Serialize,
         U::Output: DeserializeOwned,
 }
 
 req_future! {
-    def: |it: &MultipartRequest<U>| {
-        it.bot.execute_multipart_ref(&it.payload)
+    def: |it: &MultipartRequest<U, T>| {
+        it.bot.execute_multipart_ref(&it.payload, it.timeout, it.on_progress.clone())
     }
-    pub SendRef<U> (inner1) -> ResponseResult<U::Output>
+    pub SendRef<U, T> (inner1) -> ResponseResult<U::Output>
     where
+        T: Transport,
         U: 'static,
         U: Payload + MultipartPayload + Serialize,
         U::Output: DeserializeOwned,
@@ -1,4 +1,7 @@
-use std::future::{Future, IntoFuture};
+use std::{
+    future::{Future, IntoFuture},
+    time::Duration,
+};
 
 // use either::Either;
 // use futures::future;
@@ -95,6 +98,61 @@ where
     #[must_use = "Futures are lazy and do nothing unless polled or awaited"]
     fn send_ref(&self) -> Self::SendRef;
 
+    /// Sets the timeout of this request.
+    ///
+    /// The timeout covers the whole request, from connecting to the server to
+    /// reading the response, and overrides the default timeout of the HTTP
+    /// client. When the timeout elapses, sending fails with an error (for
+    /// [`Bot`] it's a [`RequestError::Network`], e.g. a [`reqwest::Error`] for
+    /// which [`is_timeout`] is `true`).
+    ///
+    /// If the timeout is not set, [`Bot`] uses a default one, which is
+    /// extended for requests which are expected to take more time (long
+    /// polling [`GetUpdates`] and file uploads). See
+    /// [`net::default_timeout`] for more.
+    ///
+    /// Note that requests can also be cancelled at any time, by dropping the
+    /// future returned by [`send`] or [`send_ref`].
+    ///
+    /// ## Implementation notes
+    ///
+    /// Request wrappers should forward the timeout to the underlying request.
+    ///
+    /// [`Bot`]: crate::Bot
+    /// [`RequestError::Network`]: crate::RequestError::Network
+    /// [`reqwest::Error`]: https://docs.rs/reqwest/latest/reqwest/struct.Error.html
+    /// [`is_timeout`]: https://docs.rs/reqwest/latest/reqwest/struct.Error.html#method.is_timeout
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    /// [`net::default_timeout`]: crate::net::default_timeout
+    /// [`send`]: Request::send
+    /// [`send_ref`]: Request::send_ref
+    fn set_timeout(&mut self, timeout: Duration);
+
+    /// Sets the timeout of this request, see [`set_timeout`] for more.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use std::time::Duration;
+    ///
+    /// use teloxide_core::{prelude::*, requests::Request, types::ChatId};
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// bot.send_message(ChatId(0), "Hi!").with_timeout(Duration::from_secs(5)).await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`set_timeout`]: Request::set_timeout
+    #[must_use = "Requests are lazy and do nothing unless sent"]
+    fn with_timeout(mut self, timeout: Duration) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_timeout(timeout);
+        self
+    }
+
     #[cfg(feature = "erased")]
     fn erase<'a>(self) -> crate::adaptors::erased::ErasedRequest<'a, Self::Payload, Self::Err>
     where
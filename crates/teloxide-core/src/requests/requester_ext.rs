@@ -1,5 +1,8 @@
 use crate::{adaptors::DefaultParseMode, requests::Requester, types::ParseMode};
 
+#[cfg(feature = "cache")]
+use crate::adaptors::cache::{self, Cache};
+
 #[cfg(feature = "cache_me")]
 use crate::adaptors::CacheMe;
 
@@ -9,9 +12,21 @@ use crate::adaptors::ErasedRequester;
 #[cfg(feature = "trace_adaptor")]
 use crate::adaptors::trace::{Settings, Trace};
 
+#[cfg(feature = "tracing")]
+use crate::adaptors::Tracing;
+
 #[cfg(feature = "throttle")]
 use crate::adaptors::throttle::{Limits, Throttle};
 
+#[cfg(feature = "retry")]
+use crate::adaptors::retry::{self, Retry};
+
+#[cfg(feature = "metrics")]
+use crate::adaptors::Metrics;
+
+#[cfg(feature = "cassette")]
+use crate::adaptors::Recorder;
+
 /// Extensions methods for [`Requester`].
 pub trait RequesterExt: Requester {
     /// Add `get_me` caching ability, see [`CacheMe`] for more.
@@ -24,6 +39,16 @@ pub trait RequesterExt: Requester {
         CacheMe::new(self)
     }
 
+    /// Add caching of chat metadata requests, see [`Cache`] for more.
+    #[cfg(feature = "cache")]
+    #[must_use]
+    fn cache(self, settings: cache::Settings) -> Cache<Self>
+    where
+        Self: Sized,
+    {
+        Cache::new(self, settings)
+    }
+
     /// Erase requester type.
     #[cfg(feature = "erased")]
     #[must_use]
@@ -45,6 +70,16 @@ pub trait RequesterExt: Requester {
         Trace::new(self, settings)
     }
 
+    /// Create `tracing` spans for requests, see [`Tracing`] for more.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    fn tracing(self) -> Tracing<Self>
+    where
+        Self: Sized,
+    {
+        Tracing::new(self)
+    }
+
     /// Add throttling ability, see [`Throttle`] for more.
     ///
     /// Note: this spawns the worker, just as [`Throttle::new_spawn`].
@@ -59,6 +94,38 @@ pub trait RequesterExt: Requester {
         Throttle::new_spawn(self, limits)
     }
 
+    /// Add retrying ability, see [`Retry`] for more.
+    #[cfg(feature = "retry")]
+    #[must_use]
+    fn retry(self, settings: retry::Settings) -> Retry<Self>
+    where
+        Self: Sized,
+    {
+        Retry::new(self, settings)
+    }
+
+    /// Collect metrics of requests, see [`Metrics`] for more.
+    #[cfg(feature = "metrics")]
+    #[must_use]
+    fn metrics(self) -> Metrics<Self>
+    where
+        Self: Sized,
+    {
+        Metrics::new(self)
+    }
+
+    /// Record requests and their responses to a cassette, see [`Recorder`]
+    /// for more.
+    #[cfg(feature = "cassette")]
+    #[must_use]
+    fn record<W>(self, writer: W) -> Recorder<Self>
+    where
+        Self: Sized,
+        W: std::io::Write + Send + 'static,
+    {
+        Recorder::new(self, writer)
+    }
+
     /// Specifies default [`ParseMode`], which will be used during all calls to:
     ///
     ///  - [`send_message`]
@@ -1,7 +1,7 @@
 //! Module for serializing into `multipart/form-data`
-//! ([`reqwest::multipart::Form`])
+//! ([`net::multipart::Form`])
 //!
-//! [`reqwest::multipart::Form`]: reqwest::multipart::Form
+//! [`net::multipart::Form`]: crate::net::multipart::Form
 //!
 //! ## How it works
 //!
@@ -15,81 +15,134 @@ mod serializers;
 
 use std::future::Future;
 
-use reqwest::multipart::Form;
+use crate::net::multipart::{Form, PartBody};
 use serde::Serialize;
 
-use crate::requests::MultipartPayload;
+use crate::{requests::MultipartPayload, types::InputFile};
 use error::Error;
 use serializers::MultipartSerializer;
 
 /// Serializes given value into [`Form`] **taking all input files out**.
 ///
-/// [`Form`]:  reqwest::multipart::Form
-pub(crate) fn to_form<T>(val: &mut T) -> Result<impl Future<Output = Form>, Error>
+/// If `local_files` is `true`, files from the file system are referenced by
+/// `file://` URIs instead of being attached (this is only supported by local
+/// Bot API servers).
+///
+/// [`Form`]: crate::net::multipart::Form
+pub(crate) fn to_form<T>(
+    val: &mut T,
+    local_files: bool,
+) -> Result<impl Future<Output = Form>, Error>
 where
     T: Serialize + MultipartPayload,
 {
-    let mut form = val.serialize(MultipartSerializer::new())?;
+    let form = val.serialize(MultipartSerializer::new())?;
 
     let mut vec = Vec::with_capacity(1);
-    val.move_files(&mut |f| vec.push(f));
-    let iter = vec.into_iter();
-
-    let fut = async move {
-        for file in iter {
-            if file.needs_attach() {
-                let id = file.id().to_owned();
-                if let Some(part) = file.into_part() {
-                    form = form.part(id, part.await);
-                }
-            }
+    val.move_files(&mut |f| {
+        if f.needs_attach() {
+            vec.push(f)
         }
+    });
 
-        form
-    };
-
-    Ok(fut)
+    Ok(attach(form, vec, local_files))
 }
 
 /// Serializes given value into [`Form`].
 ///
-/// [`Form`]:  reqwest::multipart::Form
-pub(crate) fn to_form_ref<T: ?Sized>(val: &T) -> Result<impl Future<Output = Form>, Error>
+/// See [`to_form`] for the meaning of `local_files`.
+///
+/// [`Form`]: crate::net::multipart::Form
+pub(crate) fn to_form_ref<T: ?Sized>(
+    val: &T,
+    local_files: bool,
+) -> Result<impl Future<Output = Form>, Error>
 where
     T: Serialize + MultipartPayload,
 {
-    let mut form = val.serialize(MultipartSerializer::new())?;
+    let form = val.serialize(MultipartSerializer::new())?;
+
     let mut vec = Vec::with_capacity(1);
-    val.copy_files(&mut |f| vec.push(f));
+    val.copy_files(&mut |f| {
+        if f.needs_attach() {
+            vec.push(f)
+        }
+    });
 
-    let iter = vec.into_iter();
+    Ok(attach(form, vec, local_files))
+}
 
-    let fut = async move {
-        for file in iter {
-            if file.needs_attach() {
-                let id = file.id().to_owned();
-                if let Some(part) = file.into_part() {
-                    form = form.part(id, part.await);
-                }
+/// Adds `files` to the `form`.
+///
+/// If `local_files` is `true`, files from the file system are referenced by
+/// their `file://` URIs instead of being attached.
+fn attach(
+    mut form: Form,
+    mut files: Vec<InputFile>,
+    local_files: bool,
+) -> impl Future<Output = Form> {
+    if local_files {
+        files.retain(|file| match file.file_uri() {
+            Some(uri) => {
+                reference(&mut form, file.id(), &uri);
+                false
             }
+            None => true,
+        });
+    }
+
+    attach_parts(form, files)
+}
+
+/// Replaces `attach://{id}` references in text parts of the `form` with `uri`.
+///
+/// References are either whole parts (for fields of the payload) or JSON
+/// strings inside of parts (for files nested into other types, e.g.
+/// `InputMedia`).
+fn reference(form: &mut Form, id: &str, uri: &str) {
+    let attach = format!("attach://{id}");
+    let json_attach = format!("\"{attach}\"");
+    let json_uri = serde_json::to_string(uri).expect("strings are always serializable");
+
+    for (_, part) in &mut form.parts {
+        if let PartBody::Text(text) = &mut part.body {
+            if *text == attach {
+                *text = uri.to_owned();
+            } else if text.contains(&json_attach) {
+                *text = text.replace(&json_attach, &json_uri);
+            } else {
+                continue;
+            }
+
+            part.size = Some(text.len() as u64);
         }
+    }
+}
 
-        form
-    };
+/// Adds parts for all `files` to the `form`.
+async fn attach_parts(mut form: Form, files: Vec<InputFile>) -> Form {
+    for file in files {
+        let id = file.id().to_owned();
+        if let Some(part) = file.into_part() {
+            form = form.part(id, part.await);
+        }
+    }
 
-    Ok(fut)
+    form
 }
 
 #[cfg(test)]
 mod tests {
     use tokio::fs::File;
 
-    use super::to_form_ref;
+    use super::{to_form, to_form_ref};
     use crate::{
+        net::multipart::{Form, PartBody},
         payloads::{self, setters::*},
         types::{
             ChatId, InputFile, InputMedia, InputMediaAnimation, InputMediaAudio,
-            InputMediaDocument, InputMediaPhoto, InputMediaVideo, InputSticker, MessageEntity,
+            InputMediaDocument, InputMediaPhoto, InputMediaVideo, InputPaidMedia,
+            InputPaidMediaPhoto, InputPaidMediaVideo, InputSticker, MessageEntity,
             MessageEntityKind, ParseMode, StickerFormat, UserId,
         },
     };
@@ -101,6 +154,7 @@ mod tests {
             &payloads::SendPhoto::new(ChatId(0), InputFile::file_id("0")).caption_entities([
                 MessageEntity { kind: MessageEntityKind::Url, offset: 0, length: 0 },
             ]),
+            false,
         )
         .unwrap()
         .await;
@@ -110,56 +164,90 @@ mod tests {
     async fn test_send_media_group() {
         const CAPTION: &str = "caption";
 
-        to_form_ref(&payloads::SendMediaGroup::new(
-            ChatId(0),
-            [
-                InputMedia::Photo(
-                    InputMediaPhoto::new(InputFile::file("../../media/teloxide-core-logo.png"))
-                        .caption(CAPTION)
-                        .parse_mode(ParseMode::MarkdownV2)
-                        .caption_entities(entities()),
-                ),
-                InputMedia::Video(
-                    InputMediaVideo::new(InputFile::file_id("17")).supports_streaming(true),
-                ),
-                InputMedia::Animation(
-                    InputMediaAnimation::new(InputFile::read(
-                        File::open("../../media/example.gif").await.unwrap(),
-                    ))
-                    .thumbnail(InputFile::read(
-                        File::open("../../media/teloxide-core-logo.png").await.unwrap(),
-                    ))
-                    .duration(17),
-                ),
-                InputMedia::Audio(
-                    InputMediaAudio::new(InputFile::url("https://example.com".parse().unwrap()))
+        to_form_ref(
+            &payloads::SendMediaGroup::new(
+                ChatId(0),
+                [
+                    InputMedia::Photo(
+                        InputMediaPhoto::new(InputFile::file("../../media/teloxide-core-logo.png"))
+                            .caption(CAPTION)
+                            .parse_mode(ParseMode::MarkdownV2)
+                            .caption_entities(entities()),
+                    ),
+                    InputMedia::Video(
+                        InputMediaVideo::new(InputFile::file_id("17")).supports_streaming(true),
+                    ),
+                    InputMedia::Animation(
+                        InputMediaAnimation::new(InputFile::read(
+                            File::open("../../media/example.gif").await.unwrap(),
+                        ))
+                        .thumbnail(InputFile::read(
+                            File::open("../../media/teloxide-core-logo.png").await.unwrap(),
+                        ))
+                        .duration(17),
+                    ),
+                    InputMedia::Audio(
+                        InputMediaAudio::new(InputFile::url(
+                            "https://example.com".parse().unwrap(),
+                        ))
                         .performer("a"),
-                ),
-                InputMedia::Document(InputMediaDocument::new(InputFile::memory(
-                    &b"Hello world!"[..],
-                ))),
-            ],
-        ))
+                    ),
+                    InputMedia::Document(InputMediaDocument::new(InputFile::memory(
+                        &b"Hello world!"[..],
+                    ))),
+                ],
+            ),
+            false,
+        )
+        .unwrap()
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_send_paid_media() {
+        to_form_ref(
+            &payloads::SendPaidMedia::new(
+                ChatId(0),
+                17,
+                [
+                    InputPaidMedia::Photo(InputPaidMediaPhoto::new(InputFile::file(
+                        "../../media/teloxide-core-logo.png",
+                    ))),
+                    InputPaidMedia::Video(
+                        InputPaidMediaVideo::new(InputFile::read(
+                            File::open("../../media/example.gif").await.unwrap(),
+                        ))
+                        .thumbnail(InputFile::memory(&b"thumbnail"[..]))
+                        .supports_streaming(true),
+                    ),
+                ],
+            )
+            .caption("caption"),
+            false,
+        )
         .unwrap()
         .await;
     }
 
     #[tokio::test]
     async fn test_add_sticker_to_set() {
-        to_form_ref(&payloads::AddStickerToSet::new(
-            UserId(0),
-            "name",
-            InputSticker {
-                sticker: InputFile::file(
-                    "../../media/
+        to_form_ref(
+            &payloads::AddStickerToSet::new(
+                UserId(0),
+                "name",
+                InputSticker {
+                    sticker: InputFile::file(
+                        "../../media/
                 teloxide-core-logo.png",
-                ),
-                emoji_list: vec!["✈️⚙️".to_owned()],
-                keywords: vec![],
-                mask_position: None,
-                format: StickerFormat::Static,
-            },
-        ))
+                    ),
+                    emoji_list: vec!["✈️⚙️".to_owned()],
+                    keywords: vec![],
+                    mask_position: None,
+                    format: StickerFormat::Static,
+                },
+            ),
+            false,
+        )
         .unwrap()
         .await;
     }
@@ -175,11 +263,52 @@ mod tests {
             .thumbnail(InputFile::read(
                 File::open("../../media/teloxide-core-logo.png").await.unwrap(),
             )),
+            false,
         )
         .unwrap()
         .await;
     }
 
+    #[tokio::test]
+    async fn local_files() {
+        const PATH: &str = "../../media/teloxide-core-logo.png";
+
+        let payload = payloads::SendMediaGroup::new(
+            ChatId(0),
+            [
+                InputMedia::Photo(InputMediaPhoto::new(InputFile::file(PATH))),
+                InputMedia::Document(InputMediaDocument::new(InputFile::memory(&b"data"[..]))),
+            ],
+        );
+
+        let cloud = to_form_ref(&payload, false).unwrap().await;
+        assert_eq!(text(&cloud, "media").matches("\"attach://").count(), 2);
+
+        // Only the file from the file system is referenced by its URI
+        let local = to_form_ref(&payload, true).unwrap().await;
+        let media = text(&local, "media");
+        assert_eq!(media.matches("\"attach://").count(), 1);
+        assert_eq!(media.matches("\"file://").count(), 1);
+        assert!(media.contains("/media/teloxide-core-logo.png\""));
+        assert_eq!(local.parts.len(), cloud.parts.len() - 1);
+
+        let mut payload = payloads::SendPhoto::new(ChatId(0), InputFile::file(PATH));
+        let local = to_form(&mut payload, true).unwrap().await;
+        let photo = text(&local, "photo");
+        assert!(photo.starts_with("file://") && photo.ends_with("/media/teloxide-core-logo.png"));
+        assert_eq!(local.parts.len(), 2);
+    }
+
+    fn text<'a>(form: &'a Form, name: &str) -> &'a str {
+        form.parts
+            .iter()
+            .find_map(|(n, part)| match &part.body {
+                PartBody::Text(text) if n == name => Some(text.as_str()),
+                _ => None,
+            })
+            .unwrap()
+    }
+
     fn entities() -> impl Iterator<Item = MessageEntity> {
         <_>::into_iter([
             MessageEntity::new(MessageEntityKind::Url, 0, 0),
@@ -1,6 +1,7 @@
-use crate::serde_multipart::error::Error;
-
-use reqwest::multipart::{Form, Part};
+use crate::{
+    net::multipart::{Form, Part},
+    serde_multipart::error::Error,
+};
 use serde::{
     ser::{Impossible, SerializeMap, SerializeSeq, SerializeStruct},
     Serialize, Serializer,
@@ -1,5 +1,6 @@
 //! Telegram API types.
 
+pub use accepted_gift_types::*;
 pub use allowed_update::*;
 pub use animation::*;
 pub use audio::*;
@@ -11,6 +12,7 @@ pub use bot_command_scope::*;
 pub use bot_description::*;
 pub use bot_name::*;
 pub use bot_short_description::*;
+pub use business_bot_rights::*;
 pub use business_connection::*;
 pub use business_connection_id::*;
 pub use business_intro::*;
@@ -39,10 +41,14 @@ pub use chat_permissions::*;
 pub use chat_photo::*;
 pub use chat_shared::*;
 pub use chat_type::*;
+pub use checklist::*;
+pub use checklist_tasks_added::*;
+pub use checklist_tasks_done::*;
 pub use chosen_inline_result::*;
 pub use contact::*;
 pub use dice::*;
 pub use dice_emoji::*;
+pub use direct_messages_topic::*;
 pub use document::*;
 pub use encrypted_credentials::*;
 pub use encrypted_passport_element::*;
@@ -58,6 +64,8 @@ pub use game::*;
 pub use game_high_score::*;
 pub use general_forum_topic_hidden::*;
 pub use general_forum_topic_unhidden::*;
+pub use gift::*;
+pub use gift_info::*;
 pub use giveaway::*;
 pub use giveaway_completed::*;
 pub use giveaway_created::*;
@@ -88,11 +96,15 @@ pub use inline_query_result_venue::*;
 pub use inline_query_result_video::*;
 pub use inline_query_result_voice::*;
 pub use inline_query_results_button::*;
+pub use input_checklist::*;
 pub use input_file::*;
 pub use input_media::*;
 pub use input_message_content::*;
+pub use input_paid_media::*;
 pub use input_poll_option::*;
+pub use input_profile_photo::*;
 pub use input_sticker::*;
+pub use input_story_content::*;
 pub use invoice::*;
 pub use keyboard_button::*;
 pub use keyboard_button_poll_type::*;
@@ -102,6 +114,7 @@ pub use label_price::*;
 pub use link_preview_options::*;
 pub use live_period::*;
 pub use location::*;
+pub use location_address::*;
 pub use login_url::*;
 pub use mask_position::*;
 pub use maybe_anonymous_user::*;
@@ -111,11 +124,16 @@ pub use menu_button::*;
 pub use message::*;
 pub use message_auto_delete_timer_changed::*;
 pub use message_entity::*;
+pub use message_entity_ref::*;
 pub use message_id::*;
 pub use message_origin::*;
 pub use message_reaction_count_updated::*;
 pub use message_reaction_updated::*;
 pub use order_info::*;
+pub use owned_gift::*;
+pub use paid_media::*;
+pub use paid_media_info::*;
+pub use paid_media_purchased::*;
 pub use parse_mode::*;
 pub use passport_data::*;
 pub use passport_element_error::*;
@@ -126,6 +144,7 @@ pub use poll::*;
 pub use poll_answer::*;
 pub use poll_type::*;
 pub use pre_checkout_query::*;
+pub use prepared_inline_message::*;
 pub use proximity_alert_triggered::*;
 pub use reaction_type::*;
 pub use reply_keyboard_markup::*;
@@ -141,17 +160,29 @@ pub use shared_user::*;
 pub use shipping_address::*;
 pub use shipping_option::*;
 pub use shipping_query::*;
+pub use star_amount::*;
 pub use star_transaction::*;
 pub use sticker::*;
 pub use sticker_set::*;
 pub use story::*;
+pub use story_area::*;
 pub use story_id::*;
 pub use successful_payment::*;
+pub use suggested_post_approval_failed::*;
+pub use suggested_post_approved::*;
+pub use suggested_post_declined::*;
+pub use suggested_post_info::*;
+pub use suggested_post_paid::*;
+pub use suggested_post_parameters::*;
+pub use suggested_post_price::*;
+pub use suggested_post_refunded::*;
 pub use switch_inline_query_chosen_chat::*;
 pub use target_message::*;
 pub use text_quote::*;
 pub use thread_id::*;
 pub use transaction_partner::*;
+pub use unique_gift::*;
+pub use unique_gift_info::*;
 pub use unit_false::*;
 pub use unit_true::*;
 pub use update::*;
@@ -172,6 +203,7 @@ pub use web_app_info::*;
 pub use webhook_info::*;
 pub use write_access_allowed::*;
 
+mod accepted_gift_types;
 mod allowed_update;
 mod animation;
 mod audio;
@@ -183,6 +215,7 @@ mod bot_command_scope;
 mod bot_description;
 mod bot_name;
 mod bot_short_description;
+mod business_bot_rights;
 mod business_connection;
 mod business_connection_id;
 mod business_intro;
@@ -210,10 +243,14 @@ mod chat_permissions;
 mod chat_photo;
 mod chat_shared;
 mod chat_type;
+mod checklist;
+mod checklist_tasks_added;
+mod checklist_tasks_done;
 mod chosen_inline_result;
 mod contact;
 mod dice;
 mod dice_emoji;
+mod direct_messages_topic;
 mod document;
 mod external_reply_info;
 mod file;
@@ -227,6 +264,8 @@ mod game;
 mod game_high_score;
 mod general_forum_topic_hidden;
 mod general_forum_topic_unhidden;
+mod gift;
+mod gift_info;
 mod giveaway;
 mod giveaway_completed;
 mod giveaway_created;
@@ -235,11 +274,15 @@ mod inaccessible_message;
 mod inline_keyboard_button;
 mod inline_keyboard_markup;
 mod inline_query_results_button;
+mod input_checklist;
 mod input_file;
 mod input_media;
 mod input_message_content;
+mod input_paid_media;
 mod input_poll_option;
+mod input_profile_photo;
 mod input_sticker;
+mod input_story_content;
 mod invoice;
 mod keyboard_button;
 mod keyboard_button_poll_type;
@@ -249,6 +292,7 @@ mod label_price;
 mod link_preview_options;
 mod live_period;
 mod location;
+mod location_address;
 mod login_url;
 mod mask_position;
 mod maybe_anonymous_user;
@@ -258,11 +302,16 @@ mod menu_button;
 mod message;
 mod message_auto_delete_timer_changed;
 mod message_entity;
+mod message_entity_ref;
 mod message_id;
 mod message_origin;
 mod message_reaction_count_updated;
 mod message_reaction_updated;
 mod order_info;
+mod owned_gift;
+mod paid_media;
+mod paid_media_info;
+mod paid_media_purchased;
 mod parse_mode;
 mod percentage;
 mod photo_size;
@@ -270,6 +319,7 @@ mod poll;
 mod poll_answer;
 mod poll_type;
 mod pre_checkout_query;
+mod prepared_inline_message;
 mod proximity_alert_triggered;
 mod reaction_type;
 mod reply_keyboard_markup;
@@ -285,16 +335,28 @@ mod shared_user;
 mod shipping_address;
 mod shipping_option;
 mod shipping_query;
+mod star_amount;
 mod star_transaction;
 mod sticker;
 mod sticker_set;
 mod story;
+mod story_area;
 mod successful_payment;
+mod suggested_post_approval_failed;
+mod suggested_post_approved;
+mod suggested_post_declined;
+mod suggested_post_info;
+mod suggested_post_paid;
+mod suggested_post_parameters;
+mod suggested_post_price;
+mod suggested_post_refunded;
 mod switch_inline_query_chosen_chat;
 mod target_message;
 mod text_quote;
 mod thread_id;
 mod transaction_partner;
+mod unique_gift;
+mod unique_gift_info;
 mod unit_false;
 mod unit_true;
 mod update;
@@ -353,22 +415,32 @@ mod non_telegram_types {
     pub(super) mod until_date;
 }
 
+mod bare_chat_id;
 mod chat_id;
 mod recipient;
 mod seconds;
 mod user_id;
 
+pub(crate) use bare_chat_id::*;
 pub use chat_id::*;
 pub use recipient::*;
 pub use seconds::*;
 pub use user_id::*;
 
+#[cfg(test)]
+mod codegen;
+
 use serde_with::with_prefix;
 
 // Deserialization prefix for giveaway_message_id field used in GiveawayWinners
 // and ChatBoostSourceGiveaway
 with_prefix!(prefix_giveaway_message_id "giveaway_");
 
+/// The size of a file, if Telegram didn't send it.
+pub(crate) const fn file_size_fallback() -> u32 {
+    u32::MAX
+}
+
 /// Converts an `i64` timestamp to a `choro::DateTime`, producing serde error
 /// for invalid timestamps
 pub(crate) fn serde_timestamp<E: serde::de::Error>(
@@ -447,8 +519,8 @@ pub(crate) mod serde_date_from_unix_timestamp {
 }
 
 pub(crate) mod option_url_from_string {
-    use reqwest::Url;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use url::Url;
 
     pub(crate) fn serialize<S>(this: &Option<Url>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -464,7 +536,7 @@ pub(crate) mod option_url_from_string {
     where
         D: Deserializer<'de>,
     {
-        Ok(reqwest::Url::deserialize(deserializer).ok())
+        Ok(url::Url::deserialize(deserializer).ok())
     }
 
     #[test]
@@ -1,6 +1,10 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AllowedUpdate {
     Message,
@@ -18,6 +22,7 @@ pub enum AllowedUpdate {
     CallbackQuery,
     ShippingQuery,
     PreCheckoutQuery,
+    PurchasedPaidMedia,
     Poll,
     PollAnswer,
     MyChatMember,
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 
@@ -8,7 +10,9 @@ use crate::types::{FileMeta, PhotoSize, Seconds};
 ///
 /// [The official docs](https://core.telegram.org/bots/api#animation).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct Animation {
     /// Metadata of the animation file.
     #[serde(flatten)]
@@ -34,42 +38,4 @@ pub struct Animation {
     pub mime_type: Option<Mime>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let json = r#"{
-        "file_id":"id",
-        "file_unique_id":"",
-        "width":320,
-        "height":320,
-        "duration":59,
-        "thumbnail":{
-            "file_id":"id",
-            "file_unique_id":"",
-            "width":320,
-            "height":320,
-            "file_size":3452
-        },
-        "file_name":"some",
-        "mime_type":"video/gif",
-        "file_size":6500}"#;
-        let expected = Animation {
-            file: FileMeta { id: "id".to_string(), unique_id: "".to_string(), size: 6500 },
-            width: 320,
-            height: 320,
-            duration: Seconds::from_seconds(59),
-            thumbnail: Some(PhotoSize {
-                file: FileMeta { id: "id".to_owned(), unique_id: "".to_owned(), size: 3452 },
-                width: 320,
-                height: 320,
-            }),
-            file_name: Some("some".to_string()),
-            mime_type: Some("video/gif".parse().unwrap()),
-        };
-        let actual = serde_json::from_str::<Animation>(json).unwrap();
-        assert_eq!(actual, expected)
-    }
-}
+mod impls;
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::{Animation, FileMeta, PhotoSize, Seconds};
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+        "file_id":"id",
+        "file_unique_id":"",
+        "width":320,
+        "height":320,
+        "duration":59,
+        "thumbnail":{
+            "file_id":"id",
+            "file_unique_id":"",
+            "width":320,
+            "height":320,
+            "file_size":3452
+        },
+        "file_name":"some",
+        "mime_type":"video/gif",
+        "file_size":6500}"#;
+        let expected = Animation {
+            file: FileMeta { id: "id".to_string(), unique_id: "".to_string(), size: 6500 },
+            width: 320,
+            height: 320,
+            duration: Seconds::from_seconds(59),
+            thumbnail: Some(PhotoSize {
+                file: FileMeta { id: "id".to_owned(), unique_id: "".to_owned(), size: 3452 },
+                width: 320,
+                height: 320,
+            }),
+            file_name: Some("some".to_string()),
+            mime_type: Some("video/gif".parse().unwrap()),
+        };
+        let actual = serde_json::from_str::<Animation>(json).unwrap();
+        assert_eq!(actual, expected)
+    }
+}
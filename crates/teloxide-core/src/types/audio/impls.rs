@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::{Audio, FileMeta, PhotoSize, Seconds};
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "file_id":"id",
+            "file_unique_id":"",
+            "duration":60,
+            "performer":"Performer",
+            "title":"Title",
+            "mime_type":"application/zip",
+            "file_size":123456,
+            "thumbnail":{
+                "file_id":"id",
+                "file_unique_id":"",
+                "width":320,
+                "height":320,
+                "file_size":3452
+            }
+        }"#;
+        let expected = Audio {
+            file: FileMeta { id: "id".to_string(), unique_id: "".to_string(), size: 123_456 },
+            duration: Seconds::from_seconds(60),
+            performer: Some("Performer".to_string()),
+            title: Some("Title".to_string()),
+            mime_type: Some("application/zip".parse().unwrap()),
+            thumbnail: Some(PhotoSize {
+                file: FileMeta { id: "id".to_owned(), unique_id: "".to_owned(), size: 3452 },
+                width: 320,
+                height: 320,
+            }),
+            file_name: None,
+        };
+        let actual = serde_json::from_str::<Audio>(json).unwrap();
+        assert_eq!(actual, expected)
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::Rgb;
@@ -7,8 +9,8 @@ use crate::types::Rgb;
 #[derive(Clone, Debug)]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[derive(Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
 pub enum BackgroundFill {
     Solid(BackgroundFillSolid),
     Gradient(BackgroundFillGradient),
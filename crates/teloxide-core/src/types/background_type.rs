@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{BackgroundFill, Document, Percentage};
@@ -6,8 +8,8 @@ use crate::types::{BackgroundFill, Document, Percentage};
 #[derive(Clone, Debug)]
 #[derive(PartialEq, Eq, Hash)]
 #[derive(Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
 pub enum BackgroundType {
     Fill(BackgroundTypeFill),
     Wallpaper(BackgroundTypeWallpaper),
@@ -0,0 +1,58 @@
+use crate::types::{ChatId, UserId};
+
+/// Bare chat id as represented in MTProto API.
+///
+/// In MTProto API peer ids can have different types, for example `User(1)` and
+/// `Group(1)` are different chats. For bot API these peer ids are encoded in
+/// such a way that they can be stored in a simple integer (ie bot API chat ids
+/// have the type encoded in them). This type exposes the "bare" "peer id" of a
+/// chat.
+///
+/// `BareChatId` can be created by [`ChatId::to_bare`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum BareChatId {
+    User(UserId),
+    Group(u64),
+    /// Note: supergroups are considered channels.
+    Channel(u64),
+}
+
+impl ChatId {
+    /// Converts this id to "bare" MTProto peer id.
+    ///
+    /// See [`BareChatId`] for more.
+    pub(crate) fn to_bare(self) -> BareChatId {
+        use BareChatId::*;
+
+        match self.0 {
+            id @ MIN_MARKED_CHAT_ID..=MAX_MARKED_CHAT_ID => Group(-id as _),
+            id @ MIN_MARKED_CHANNEL_ID..=MAX_MARKED_CHANNEL_ID => {
+                Channel((MAX_MARKED_CHANNEL_ID - id) as _)
+            }
+            id @ MIN_USER_ID..=MAX_USER_ID => User(UserId(id as _)),
+            id => panic!("malformed chat id: {id}"),
+        }
+    }
+}
+
+impl BareChatId {
+    /// Converts bare chat id back to normal bot API [`ChatId`].
+    #[allow(unused)]
+    pub(crate) fn to_bot_api(self) -> ChatId {
+        use BareChatId::*;
+
+        match self {
+            User(UserId(id)) => ChatId(id as _),
+            Group(id) => ChatId(-(id as i64)),
+            Channel(id) => ChatId(MAX_MARKED_CHANNEL_ID - (id as i64)),
+        }
+    }
+}
+
+// https://github.com/mtcute/mtcute/blob/6933ecc3f82dd2e9100f52b0afec128af564713b/packages/core/src/utils/peer-utils.ts#L4
+const MIN_MARKED_CHANNEL_ID: i64 = -1997852516352;
+const MAX_MARKED_CHANNEL_ID: i64 = -1000000000000;
+const MIN_MARKED_CHAT_ID: i64 = MAX_MARKED_CHANNEL_ID + 1;
+const MAX_MARKED_CHAT_ID: i64 = MIN_USER_ID - 1;
+pub(crate) const MIN_USER_ID: i64 = 0;
+pub(crate) const MAX_USER_ID: i64 = (1 << 40) - 1;
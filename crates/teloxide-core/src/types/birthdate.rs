@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// Describes the birthdate of a user.
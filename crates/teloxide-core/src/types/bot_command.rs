@@ -1,10 +1,13 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// This object represents a bot command.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#botcommand).
-#[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct BotCommand {
     /// Text of the command, 1-32 characters.
     ///
@@ -15,28 +18,4 @@ pub struct BotCommand {
     pub description: String,
 }
 
-impl BotCommand {
-    pub fn new<S1, S2>(command: S1, description: S2) -> Self
-    where
-        S1: Into<String>,
-        S2: Into<String>,
-    {
-        Self { command: command.into(), description: description.into() }
-    }
-
-    pub fn command<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.command = val.into();
-        self
-    }
-
-    pub fn description<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.description = val.into();
-        self
-    }
-}
+mod impls;
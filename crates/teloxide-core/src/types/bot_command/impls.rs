@@ -0,0 +1,27 @@
+use crate::types::BotCommand;
+
+impl BotCommand {
+    pub fn new<S1, S2>(command: S1, description: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self { command: command.into(), description: description.into() }
+    }
+
+    pub fn command<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.command = val.into();
+        self
+    }
+
+    pub fn description<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.description = val.into();
+        self
+    }
+}
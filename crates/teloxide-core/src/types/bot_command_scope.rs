@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{Recipient, UserId};
@@ -41,27 +43,25 @@ use crate::types::{Recipient, UserId};
 /// [`Chat`]: BotCommandScope::Chat
 /// [`ChatAdministrators`]: BotCommandScope::ChatAdministrators
 /// [`ChatMember`]: BotCommandScope::ChatMember
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
 pub enum BotCommandScope {
     Default,
+
     AllPrivateChats,
+
     AllGroupChats,
+
     AllChatAdministrators,
+
     Chat { chat_id: Recipient },
+
     ChatAdministrators { chat_id: Recipient },
+
     ChatMember { chat_id: Recipient, user_id: UserId },
 }
 
-#[test]
-fn issue_486() {
-    use crate::types::ChatId;
-
-    serde_json::to_string(&BotCommandScope::Chat { chat_id: Recipient::Id(ChatId(0)) }).unwrap();
-
-    serde_json::to_string(&BotCommandScope::ChatAdministrators {
-        chat_id: Recipient::Id(ChatId(0)),
-    })
-    .unwrap();
-}
+mod impls;
@@ -0,0 +1,11 @@
+#[test]
+fn issue_486() {
+    use crate::types::{BotCommandScope, ChatId, Recipient};
+
+    serde_json::to_string(&BotCommandScope::Chat { chat_id: Recipient::Id(ChatId(0)) }).unwrap();
+
+    serde_json::to_string(&BotCommandScope::ChatAdministrators {
+        chat_id: Recipient::Id(ChatId(0)),
+    })
+    .unwrap();
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::BusinessConnection;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "id": "AAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            "user": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain",
+                "username": "shdwchn10",
+                "language_code": "en"
+            },
+            "user_chat_id": 1459074222,
+            "date": 1721592994,
+            "rights": {
+                "can_reply": true,
+                "can_read_messages": true,
+                "can_view_gifts_and_stars": true
+            },
+            "is_enabled": true
+        }"#;
+        let connection: BusinessConnection = serde_json::from_str(json).unwrap();
+        let rights = connection.rights.unwrap();
+
+        assert!(rights.can_reply);
+        assert!(rights.can_read_messages);
+        assert!(rights.can_view_gifts_and_stars);
+        assert!(!rights.can_transfer_stars);
+    }
+}
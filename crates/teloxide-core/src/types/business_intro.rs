@@ -1,8 +1,11 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::Sticker;
 
 /// An introduction for a Business.
+#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct BusinessIntro {
     /// Title text of the business intro
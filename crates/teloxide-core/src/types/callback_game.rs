@@ -1,6 +1,5 @@
-/// A placeholder, currently holds no information. Use [@BotFather](https://t.me/botfather) to set up your game.
-///
-/// [The official docs](https://core.telegram.org/bots/api#callbackgame).
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// A placeholder, currently holds no information.
@@ -8,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// Use [@Botfather] to set up your game.
 ///
 /// [@Botfather]:  https://t.me/botfather
-#[serde_with::skip_serializing_none]
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct CallbackGame {}
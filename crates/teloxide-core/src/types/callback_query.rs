@@ -1,6 +1,8 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
-use crate::types::{MaybeInaccessibleMessage, Message, User};
+use crate::types::{MaybeInaccessibleMessage, User};
 
 /// This object represents an incoming callback query from a callback button in
 /// an [inline keyboard].
@@ -50,67 +52,4 @@ pub struct CallbackQuery {
     pub game_short_name: Option<String>,
 }
 
-impl CallbackQuery {
-    /// Returns all users that are "contained" in this `CallbackQuery`
-    /// structure.
-    ///
-    /// This might be useful to track information about users.
-    /// Note that this function can return duplicate users.
-    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
-        use crate::util::flatten;
-        use std::iter::once;
-
-        once(&self.from).chain(flatten(self.regular_message().map(Message::mentioned_users)))
-    }
-
-    #[must_use]
-    pub fn regular_message(&self) -> Option<&Message> {
-        self.message
-            .as_ref()
-            // If we can access the message
-            .and_then(|maybe| maybe.regular_message())
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::types::UserId;
-
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let json = r#"{
-            "id":"id",
-            "from":{
-                "id":12345,
-                "is_bot":false,
-                "first_name":"firstName"
-            },
-            "inline_message_id":"i_m_id",
-            "chat_instance":"123456",
-            "data":"some_data",
-            "game_short_name":"game_name"
-        }"#;
-        let expected = CallbackQuery {
-            id: "id".to_string(),
-            from: User {
-                id: UserId(12345),
-                is_bot: false,
-                first_name: "firstName".to_string(),
-                last_name: None,
-                username: None,
-                language_code: None,
-                is_premium: false,
-                added_to_attachment_menu: false,
-            },
-            chat_instance: "123456".to_string(),
-            message: None,
-            inline_message_id: Some("i_m_id".to_string()),
-            data: Some("some_data".to_string()),
-            game_short_name: Some("game_name".to_string()),
-        };
-        let actual = serde_json::from_str::<CallbackQuery>(json).unwrap();
-        assert_eq!(actual, expected);
-    }
-}
+mod impls;
@@ -0,0 +1,210 @@
+use crate::types::{
+    Chat, ChatKind, ChatPrivate, ChatPublic, PublicChatChannel, PublicChatKind,
+    PublicChatSupergroup,
+};
+
+impl Chat {
+    #[must_use]
+    pub fn is_private(&self) -> bool {
+        matches!(self.kind, ChatKind::Private(_))
+    }
+
+    #[must_use]
+    pub fn is_group(&self) -> bool {
+        if let ChatKind::Public(chat_pub) = &self.kind {
+            matches!(*chat_pub, ChatPublic { kind: PublicChatKind::Group, .. })
+        } else {
+            false
+        }
+    }
+
+    #[must_use]
+    pub fn is_supergroup(&self) -> bool {
+        if let ChatKind::Public(chat_pub) = &self.kind {
+            matches!(*chat_pub, ChatPublic { kind: PublicChatKind::Supergroup(_), .. })
+        } else {
+            false
+        }
+    }
+
+    #[must_use]
+    pub fn is_channel(&self) -> bool {
+        if let ChatKind::Public(chat_pub) = &self.kind {
+            matches!(*chat_pub, ChatPublic { kind: PublicChatKind::Channel(_), .. })
+        } else {
+            false
+        }
+    }
+
+    #[must_use]
+    pub fn is_chat(&self) -> bool {
+        self.is_private() || self.is_group() || self.is_supergroup()
+    }
+
+    /// Returns `true` if this is the direct messages chat of a channel.
+    #[must_use]
+    pub fn is_direct_messages(&self) -> bool {
+        if let ChatKind::Public(chat_pub) = &self.kind {
+            matches!(
+                chat_pub.kind,
+                PublicChatKind::Supergroup(PublicChatSupergroup { is_direct_messages: true, .. })
+            )
+        } else {
+            false
+        }
+    }
+}
+
+/// Getters
+impl Chat {
+    /// A title, for supergroups, channels and group chats.
+    #[must_use]
+    pub fn title(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Public(this) => this.title.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// A username, for private chats, supergroups and channels if available.
+    #[must_use]
+    pub fn username(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Public(this) => match &this.kind {
+                PublicChatKind::Channel(PublicChatChannel { username, .. })
+                | PublicChatKind::Supergroup(PublicChatSupergroup { username, .. }) => {
+                    username.as_deref()
+                }
+                PublicChatKind::Group => None,
+            },
+            ChatKind::Private(this) => this.username.as_deref(),
+        }
+    }
+
+    /// A first name of the other party in a private chat.
+    #[must_use]
+    pub fn first_name(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Private(this) => this.first_name.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// A last name of the other party in a private chat.
+    #[must_use]
+    pub fn last_name(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Private(this) => this.last_name.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+pub(super) mod serde_helper {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    enum Type {
+        #[allow(non_camel_case_types)]
+        private,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub(crate) struct ChatPrivate {
+        /// A dummy field. Used to ensure that the `type` field is equal to
+        /// `private`.
+        r#type: Type,
+
+        username: Option<String>,
+        first_name: Option<String>,
+        last_name: Option<String>,
+    }
+
+    impl From<ChatPrivate> for super::ChatPrivate {
+        fn from(ChatPrivate { r#type: _, username, first_name, last_name }: ChatPrivate) -> Self {
+            Self { username, first_name, last_name }
+        }
+    }
+
+    impl From<super::ChatPrivate> for ChatPrivate {
+        fn from(
+            super::ChatPrivate { username, first_name, last_name }: super::ChatPrivate,
+        ) -> Self {
+            Self { r#type: Type::private, username, first_name, last_name }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+
+    use crate::types::*;
+
+    #[test]
+    fn channel_de() {
+        let expected = Chat {
+            id: ChatId(-1),
+            kind: ChatKind::Public(ChatPublic {
+                title: None,
+                kind: PublicChatKind::Channel(PublicChatChannel {
+                    username: Some("channel_name".into()),
+                }),
+            }),
+        };
+        let actual = from_str(
+            r#"{
+                "id": -1,
+                "type": "channel",
+                "username": "channel_name"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn private_chat_de() {
+        assert_eq!(
+            Chat {
+                id: ChatId(0),
+                kind: ChatKind::Private(ChatPrivate {
+                    username: Some("username".into()),
+                    first_name: Some("Anon".into()),
+                    last_name: None,
+                }),
+            },
+            from_str(
+                r#"{
+                    "id": 0,
+                    "type": "private",
+                    "username": "username",
+                    "first_name": "Anon"
+                }"#
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn private_roundtrip() {
+        let chat = Chat {
+            id: ChatId(0),
+            kind: ChatKind::Private(ChatPrivate {
+                username: Some("username".into()),
+                first_name: Some("Anon".into()),
+                last_name: None,
+            }),
+        };
+
+        let json = to_string(&chat).unwrap();
+        let chat2 = from_str::<Chat>(&json).unwrap();
+
+        assert_eq!(chat, chat2);
+    }
+
+    #[test]
+    fn private_chat_de_wrong_type_field() {
+        assert!(from_str::<Chat>(r#"{"id":0,"type":"WRONG"}"#).is_err());
+    }
+}
@@ -1,8 +1,12 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// Represents the rights of an administrator in a chat.
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct ChatAdministratorRights {
     /// `true`, if the user's presence in the chat is hidden
     pub is_anonymous: bool,
@@ -35,16 +39,15 @@ pub struct ChatAdministratorRights {
     /// `true`, if the user is allowed to invite new users to the chat
     pub can_invite_users: bool,
 
-    /// `true`, if the administrator can post in the channel; channels
-    /// only
+    /// `true`, if the administrator can post in the channel; channels only
     pub can_post_messages: Option<bool>,
 
-    /// `true`, if the administrator can edit messages of other users
-    /// and can pin messages; channels only
+    /// `true`, if the administrator can edit messages of other users and can
+    /// pin messages; channels only
     pub can_edit_messages: Option<bool>,
 
-    /// `true`, if the user is allowed to pin messages; groups and
-    /// supergroups only
+    /// `true`, if the user is allowed to pin messages; groups and supergroups
+    /// only
     pub can_pin_messages: Option<bool>,
 
     /// `true`, if the administrator can post stories to the chat
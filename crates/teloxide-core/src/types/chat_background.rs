@@ -1,9 +1,10 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::BackgroundType;
 
 /// This object represents a chat background.
-#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug)]
 #[derive(PartialEq, Eq, Hash)]
 #[derive(Serialize, Deserialize)]
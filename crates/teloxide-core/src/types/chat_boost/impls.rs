@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::ChatBoost;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "boost_id": "4506e1b7e866e33fcbde78fe1746ec3a",
+            "add_date": 1721399621,
+            "expiration_date": 1745088963,
+            "source": {
+                "source": "premium",
+                "user": {
+                    "id": 1459074222,
+                    "is_bot": false,
+                    "first_name": "shadowchain",
+                    "username": "shdwchn10",
+                    "language_code": "en",
+                    "is_premium": true
+                }
+            }
+        }
+        "#;
+        serde_json::from_str::<ChatBoost>(data).unwrap();
+    }
+}
@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::ChatBoostAdded;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "boost_count": 4
+        }
+        "#;
+        serde_json::from_str::<ChatBoostAdded>(data).unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::ChatBoostRemoved;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "chat": {
+                "id": -1002236736395,
+                "title": "Test",
+                "type": "channel"
+            },
+            "boost_id": "4506e1b7e866e33fcbde78fe1746ec3a",
+            "remove_date": 1745089963,
+            "source": {
+                "source": "premium",
+                "user": {
+                    "id": 1459074222,
+                    "is_bot": false,
+                    "first_name": "shadowchain",
+                    "username": "shdwchn10",
+                    "language_code": "en",
+                    "is_premium": true
+                }
+            }
+        }
+        "#;
+        serde_json::from_str::<ChatBoostRemoved>(data).unwrap();
+    }
+}
@@ -1,12 +1,13 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{MessageId, User};
 
 /// This object describes the source of a chat boost.
-#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
 #[serde(tag = "source")]
+#[serde(rename_all = "snake_case")]
 pub enum ChatBoostSource {
     Premium(ChatBoostSourcePremium),
     GiftCode(ChatBoostSourceGiftCode),
@@ -15,7 +16,6 @@ pub enum ChatBoostSource {
 
 /// The boost was obtained by subscribing to Telegram Premium or by gifting a
 /// Telegram Premium subscription to another user.
-#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChatBoostSourcePremium {
     /// User that boosted the chat.
@@ -25,7 +25,6 @@ pub struct ChatBoostSourcePremium {
 /// The boost was obtained by the creation of Telegram Premium gift codes to
 /// boost a chat. Each such code boosts the chat 4 times for the duration of the
 /// corresponding Telegram Premium subscription.
-#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChatBoostSourceGiftCode {
     /// User for which the gift code was created.
@@ -52,73 +51,4 @@ pub struct ChatBoostSourceGiveaway {
     pub is_unclaimed: bool,
 }
 
-impl ChatBoostSource {
-    #[must_use]
-    pub fn user(&self) -> Option<&User> {
-        Some(match &self {
-            Self::Premium(premium) => &premium.user,
-            Self::GiftCode(gift_code) => &gift_code.user,
-            Self::Giveaway(giveaway) => return giveaway.user.as_ref(),
-        })
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize_premium() {
-        let data = r#"
-        {
-            "source": "premium",
-            "user": {
-                "id": 1459074222,
-                "is_bot": false,
-                "first_name": "shadowchain",
-                "username": "shdwchn10",
-                "language_code": "en",
-                "is_premium": true
-            }
-        }
-        "#;
-        serde_json::from_str::<ChatBoostSource>(data).unwrap();
-    }
-
-    #[test]
-    fn deserialize_gift_code() {
-        let data = r#"
-        {
-            "source": "gift_code",
-            "user": {
-                "id": 1459074222,
-                "is_bot": false,
-                "first_name": "shadowchain",
-                "username": "shdwchn10",
-                "language_code": "en",
-                "is_premium": false
-            }
-        }
-        "#;
-        serde_json::from_str::<ChatBoostSource>(data).unwrap();
-    }
-
-    #[test]
-    fn deserialize_giveaway() {
-        let data = r#"
-        {
-            "source": "giveaway",
-            "giveaway_message_id": 420,
-            "user": {
-                "id": 1459074222,
-                "is_bot": false,
-                "first_name": "shadowchain",
-                "username": "shdwchn10",
-                "language_code": "en",
-                "is_premium": false
-            }
-        }
-        "#;
-        serde_json::from_str::<ChatBoostSource>(data).unwrap();
-    }
-}
+mod impls;
@@ -0,0 +1,72 @@
+use crate::types::{ChatBoostSource, User};
+
+impl ChatBoostSource {
+    #[must_use]
+    pub fn user(&self) -> Option<&User> {
+        Some(match &self {
+            Self::Premium(premium) => &premium.user,
+            Self::GiftCode(gift_code) => &gift_code.user,
+            Self::Giveaway(giveaway) => return giveaway.user.as_ref(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_premium() {
+        let data = r#"
+        {
+            "source": "premium",
+            "user": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain",
+                "username": "shdwchn10",
+                "language_code": "en",
+                "is_premium": true
+            }
+        }
+        "#;
+        serde_json::from_str::<ChatBoostSource>(data).unwrap();
+    }
+
+    #[test]
+    fn deserialize_gift_code() {
+        let data = r#"
+        {
+            "source": "gift_code",
+            "user": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain",
+                "username": "shdwchn10",
+                "language_code": "en",
+                "is_premium": false
+            }
+        }
+        "#;
+        serde_json::from_str::<ChatBoostSource>(data).unwrap();
+    }
+
+    #[test]
+    fn deserialize_giveaway() {
+        let data = r#"
+        {
+            "source": "giveaway",
+            "giveaway_message_id": 420,
+            "user": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain",
+                "username": "shdwchn10",
+                "language_code": "en",
+                "is_premium": false
+            }
+        }
+        "#;
+        serde_json::from_str::<ChatBoostSource>(data).unwrap();
+    }
+}
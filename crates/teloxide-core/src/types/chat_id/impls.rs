@@ -0,0 +1,106 @@
+use crate::types::{BareChatId, ChatId, UserId};
+
+impl ChatId {
+    /// Returns `true` if this is an id of a user.
+    #[must_use]
+    pub fn is_user(self) -> bool {
+        matches!(self.to_bare(), BareChatId::User(_))
+    }
+
+    /// Returns `true` if this is an id of a group.
+    ///
+    /// Note: supergroup is **not** considered a group.
+    #[must_use]
+    pub fn is_group(self) -> bool {
+        matches!(self.to_bare(), BareChatId::Group(_))
+    }
+
+    /// Returns `true` if this is an id of a channel.
+    #[must_use]
+    pub fn is_channel_or_supergroup(self) -> bool {
+        matches!(self.to_bare(), BareChatId::Channel(_))
+    }
+
+    /// Returns user id, if this is an id of a user.
+    #[must_use]
+    pub fn as_user(self) -> Option<UserId> {
+        match self.to_bare() {
+            BareChatId::User(u) => Some(u),
+            BareChatId::Group(_) | BareChatId::Channel(_) => None,
+        }
+    }
+}
+
+impl From<UserId> for ChatId {
+    fn from(UserId(id): UserId) -> Self {
+        Self(id as _)
+    }
+}
+
+impl PartialEq<UserId> for ChatId {
+    fn eq(&self, other: &UserId) -> bool {
+        self.is_user() && *self == ChatId::from(*other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::types::{BareChatId, ChatId, UserId};
+
+    /// Test that `ChatId` is serialized as the underlying integer
+    #[test]
+    fn deser() {
+        let chat_id = S { chat_id: ChatId(0xAA) };
+        let json = r#"{"chat_id":170}"#;
+
+        #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+        struct S {
+            chat_id: ChatId,
+        }
+
+        assert_eq!(serde_json::to_string(&chat_id).unwrap(), json);
+        assert_eq!(chat_id, serde_json::from_str(json).unwrap());
+    }
+
+    #[test]
+    fn chonky_user_id_to_bare() {
+        assert!(matches!(ChatId(5298363099).to_bare(), BareChatId::User(UserId(5298363099))));
+    }
+
+    #[test]
+    fn to_bare_to_bot_api_identity() {
+        fn assert_identity(x: u64) {
+            use BareChatId::*;
+
+            assert_eq!(User(UserId(x)), User(UserId(x)).to_bot_api().to_bare());
+            assert_eq!(Group(x), Group(x).to_bot_api().to_bare());
+            assert_eq!(Channel(x), Channel(x).to_bot_api().to_bare());
+        }
+
+        // Somewhat random numbers
+        let ids =
+            [1, 4, 17, 34, 51, 777000, 1000000, 617136926, 1666111087, 1 << 20, (1 << 35) | 123456];
+
+        // rust 2021 when :(
+        ids.iter().copied().for_each(assert_identity);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ChatId(1).to_string(), "1");
+    }
+
+    #[test]
+    fn user_id_eq() {
+        assert_eq!(ChatId(12), UserId(12));
+        assert_eq!(ChatId(4652762), UserId(4652762));
+        assert_ne!(ChatId(17), UserId(42));
+
+        // The user id is not well formed, so even though `-1 == max` is true,
+        // we don't want user id to match
+        assert_eq!(-1i64, u64::MAX as i64);
+        assert_ne!(ChatId(-1), UserId(u64::MAX));
+    }
+}
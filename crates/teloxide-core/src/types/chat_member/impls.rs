@@ -0,0 +1,601 @@
+use std::ops::Deref;
+
+use crate::types::{
+    Administrator, Banned, ChatMember, ChatMemberKind, ChatMemberStatus, Owner, Restricted,
+    UntilDate,
+};
+
+/// This allows calling [`ChatMemberKind`]'s methods directly on [`ChatMember`].
+///
+/// ```no_run
+/// use teloxide_core::types::ChatMember;
+///
+/// let member: ChatMember = todo!();
+///
+/// let _ = member.status();
+/// let _ = member.kind.status();
+/// ```
+impl Deref for ChatMember {
+    type Target = ChatMemberKind;
+
+    fn deref(&self) -> &Self::Target {
+        &self.kind
+    }
+}
+
+/// Simple methods for checking a user status.
+impl ChatMemberKind {
+    /// Returns chat member status.
+    #[must_use]
+    pub fn status(&self) -> ChatMemberStatus {
+        match self {
+            ChatMemberKind::Owner(_) => ChatMemberStatus::Owner,
+            ChatMemberKind::Administrator(_) => ChatMemberStatus::Administrator,
+            ChatMemberKind::Member => ChatMemberStatus::Member,
+            ChatMemberKind::Restricted(_) => ChatMemberStatus::Restricted,
+            ChatMemberKind::Left => ChatMemberStatus::Left,
+            ChatMemberKind::Banned(_) => ChatMemberStatus::Banned,
+        }
+    }
+
+    /// Returns `true` if the user is the [owner] of the given chat.
+    ///
+    /// [owner]: ChatMemberKind::Owner
+    #[must_use]
+    pub fn is_owner(&self) -> bool {
+        matches!(self, Self::Owner { .. })
+    }
+
+    /// Returns `true` if the user is an [administrator] of the given chat.
+    ///
+    /// [administrator]: ChatMemberKind::Administrator
+    ///
+    /// **Note**: this function **doesn't** return `true` if the user is the
+    /// owner of the given chat. See also: [`is_privileged`].
+    ///
+    /// [`is_privileged`]: ChatMemberKind::is_privileged
+    #[must_use]
+    pub fn is_administrator(&self) -> bool {
+        matches!(self, Self::Administrator { .. })
+    }
+
+    /// Returns `true` if the user is a common [member] of the given chat.
+    ///
+    /// ⚠️ Don't confuse this with [`is_present`]. This method merely checks
+    /// for [`ChatMemberKind::Member`] variant which is not enough to determine
+    /// if the user is joinned to the chat. Use [`is_present`] for that instead.
+    ///
+    /// [member]: ChatMemberKind::Member
+    /// [`is_present`]: ChatMemberKind::is_present
+    #[must_use]
+    pub fn is_member(&self) -> bool {
+        matches!(self, Self::Member { .. })
+    }
+
+    /// Returns `true` if the user is [restricted] in the given chat.
+    ///
+    /// [restricted]: ChatMemberKind::Restricted
+    #[must_use]
+    pub fn is_restricted(&self) -> bool {
+        matches!(self, Self::Restricted { .. })
+    }
+
+    /// Returns `true` if the user [left] the given chat.
+    ///
+    /// ⚠️ Don't confuse this with [`is_present`]. This method merely checks
+    /// for [`ChatMemberKind::Left`] variant which is not enough to determine
+    /// if the user is joinned to the chat. Use [`is_present`] for that instead.
+    ///
+    /// [left]: ChatMemberKind::Left
+    /// [`is_present`]: ChatMemberKind::is_present
+    #[must_use]
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left { .. })
+    }
+
+    /// Returns `true` if the user is [banned] in the given chat.
+    ///
+    /// [banned]: ChatMemberKind::Banned
+    #[must_use]
+    pub fn is_banned(&self) -> bool {
+        matches!(self, Self::Banned { .. })
+    }
+}
+
+/// Compound methods for checking a user status.
+impl ChatMemberKind {
+    /// Returns `true` if the user is privileged in the given chat. i.e. if the
+    /// user is either the [owner] or an [administrator] in the given chat.
+    ///
+    /// [owner]: ChatMemberKind::Owner
+    /// [administrator]: ChatMemberKind::Administrator
+    #[must_use]
+    pub fn is_privileged(&self) -> bool {
+        self.is_administrator() || self.is_owner()
+    }
+
+    /// Returns `true` if the user is currently present in the chat. i.e. if the
+    /// user **hasn't** [left] or been [banned]. It also returns `false` if the
+    /// user left the chat, but was [restricted].
+    ///
+    /// [left]: ChatMemberKind::Left
+    /// [banned]: ChatMemberKind::Banned
+    /// [restricted]: ChatMemberKind::Restricted
+    #[must_use]
+    pub fn is_present(&self) -> bool {
+        let is_restricted_non_member =
+            matches!(self, Self::Restricted(Restricted { is_member: false, .. }));
+
+        !(self.is_left() || self.is_banned() || is_restricted_non_member)
+    }
+}
+
+impl ChatMemberKind {
+    /// Getter for [`Administrator::custom_title`] and [`Owner::custom_title`]
+    /// fields.
+    #[must_use]
+    pub fn custom_title(&self) -> Option<&str> {
+        match &self {
+            Self::Administrator(Administrator { custom_title, .. })
+            | Self::Owner(Owner { custom_title, .. }) => custom_title.as_deref(),
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => None,
+        }
+    }
+
+    /// Returns `true` if the user's presence in the chat is hidden.
+    ///
+    /// I.e. returns `true` if the user is the owner of the chat or an
+    /// administrator in the chat and has the [`can_manage_chat`] privilege.
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_manage_chat`]: Administrator::can_manage_chat
+    #[must_use]
+    pub fn is_anonymous(&self) -> bool {
+        match self {
+            Self::Owner(Owner { is_anonymous, .. })
+            | Self::Administrator(Administrator { is_anonymous, .. }) => *is_anonymous,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Getter for [`Restricted::until_date`] and [`Banned::until_date`] fields.
+    #[must_use]
+    pub fn until_date(&self) -> Option<UntilDate> {
+        match &self {
+            Self::Owner(_) | Self::Administrator(_) | Self::Member | Self::Left => None,
+            Self::Restricted(Restricted { until_date, .. })
+            | Self::Banned(Banned { until_date, .. }) => Some(*until_date),
+        }
+    }
+}
+
+/// Methods for checking admin privileges.
+impl ChatMemberKind {
+    /// Returns `true` if the user is an administrator in the given chat and the
+    /// bot is allowed to edit administrator privileges of that user.
+    #[must_use]
+    pub fn can_be_edited(&self) -> bool {
+        match self {
+            Self::Administrator(Administrator { can_be_edited, .. }) => *can_be_edited,
+            // Owner can't ever be edited by any bot.
+            Self::Owner(_) | Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => {
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the user can access the chat event log, chat
+    /// statistics, message statistics in channels, see channel members, see
+    /// anonymous administrators in supergroups and ignore slow mode. Implied by
+    /// any other administrator privilege.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has [`can_manage_chat`]
+    ///   privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_manage_chat`]: Administrator::can_manage_chat
+    #[must_use]
+    pub fn can_manage_chat(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_manage_chat, .. }) => *can_manage_chat,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can post in the channel, channels only.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat (even if the chat is not a channel)
+    /// - is an administrator in the given chat and has [`can_post_messages`]
+    ///   privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_post_messages`]: Administrator::can_post_messages
+    #[must_use]
+    pub fn can_post_messages(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_post_messages, .. }) => *can_post_messages,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can edit messages of other users and can pin
+    /// messages, channels only.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat (even if the chat is not a channel)
+    /// - is an administrator in the given chat and has the
+    ///   [`can_edit_messages`] privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_edit_messages`]: Administrator::can_edit_messages
+    #[must_use]
+    pub fn can_edit_messages(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_edit_messages, .. }) => *can_edit_messages,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can delete messages of other users.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has the
+    ///   [`can_delete_messages`] privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_delete_messages`]: Administrator::can_delete_messages
+    #[must_use]
+    pub fn can_delete_messages(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_delete_messages, .. }) => *can_delete_messages,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the administrator can post stories to the chat.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat (even if the chat is not a channel)
+    /// - is an administrator in the given chat and has [`can_post_stories`]
+    ///   privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_post_stories`]: Administrator::can_post_stories
+    #[must_use]
+    pub fn can_post_stories(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_post_stories, .. }) => *can_post_stories,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the administrator can edit stories posted by other
+    /// users.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat (even if the chat is not a channel)
+    /// - is an administrator in the given chat and has the [`can_edit_stories`]
+    ///   privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_edit_stories`]: Administrator::can_edit_stories
+    #[must_use]
+    pub fn can_edit_stories(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_edit_stories, .. }) => *can_edit_stories,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the administrator can delete stories posted by other
+    /// users.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has the
+    ///   [`can_delete_stories`] privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_delete_stories`]: Administrator::can_delete_stories
+    #[must_use]
+    pub fn can_delete_stories(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_delete_stories, .. }) => *can_delete_stories,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can manage video chats.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has the
+    ///   [`can_manage_video_chats`] privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_manage_video_chats`]: Administrator::can_manage_video_chats
+    #[must_use]
+    pub fn can_manage_video_chats(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_manage_video_chats, .. }) => {
+                *can_manage_video_chats
+            }
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can restrict, ban or unban chat members.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has the
+    ///   [`can_restrict_members`] privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_restrict_members`]: Administrator::can_restrict_members
+    #[must_use]
+    pub fn can_restrict_members(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_restrict_members, .. }) => {
+                *can_restrict_members
+            }
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can add new administrators with a subset of
+    /// his own privileges or demote administrators that he has promoted,
+    /// directly or indirectly (promoted by administrators that were appointed
+    /// by the user).
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat (even if the chat is not a channel)
+    /// - is an administrator in the given chat and has the
+    ///   [`can_promote_members`] privilege.
+    ///
+    /// Returns `false` otherwise.
+    ///
+    /// [`can_promote_members`]: Administrator::can_promote_members
+    #[must_use]
+    pub fn can_promote_members(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_promote_members, .. }) => *can_promote_members,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+}
+
+/// Simple methods for checking a user status.
+impl ChatMemberStatus {
+    /// Returns `true` if the user is the [owner] of the given chat.
+    ///
+    /// [owner]: ChatMemberKind::Owner
+    #[must_use]
+    pub fn is_owner(&self) -> bool {
+        matches!(self, Self::Owner { .. })
+    }
+
+    /// Returns `true` if the user is an [administrator] of the given chat.
+    ///
+    /// [administrator]: ChatMemberKind::Administrator
+    ///
+    /// **Note**: this function **doesn't** return `true` if the user is the
+    /// owner of the given chat. See also: [`is_privileged`].
+    ///
+    /// [`is_privileged`]: ChatMemberKind::is_privileged
+    #[must_use]
+    pub fn is_administrator(&self) -> bool {
+        matches!(self, Self::Administrator { .. })
+    }
+
+    /// Returns `true` if the user is a common [member] of the given chat.
+    ///
+    /// ⚠️ Don't confuse this with [`is_present`]. This method merely checks
+    /// for [`ChatMemberStatus::Member`] variant which is not enough to
+    /// determine if the user is joinned to the chat. Use [`is_present`] for
+    /// that instead.
+    ///
+    /// [member]: ChatMemberKind::Member
+    /// [`is_present`]: ChatMemberKind::is_present
+    #[must_use]
+    pub fn is_member(&self) -> bool {
+        matches!(self, Self::Member { .. })
+    }
+
+    /// Returns `true` if the user is [restricted] in the given chat.
+    ///
+    /// [restricted]: ChatMemberKind::Restricted
+    #[must_use]
+    pub fn is_restricted(&self) -> bool {
+        matches!(self, Self::Restricted { .. })
+    }
+
+    /// Returns `true` if the user [left] the given chat.
+    ///
+    /// ⚠️ Don't confuse this with [`is_present`]. This method merely checks
+    /// for [`ChatMemberStatus::Left`] variant which is not enough to determine
+    /// if the user is joinned to the chat. Use [`is_present`] for that instead.
+    ///
+    /// [left]: ChatMemberKind::Left
+    /// [`is_present`]: ChatMemberKind::is_present
+    #[must_use]
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left { .. })
+    }
+
+    /// Returns `true` if the user is [banned] in the given chat.
+    ///
+    /// [banned]: ChatMemberKind::Banned
+    #[must_use]
+    pub fn is_banned(&self) -> bool {
+        matches!(self, Self::Banned { .. })
+    }
+}
+
+/// Compound methods for checking a user status.
+impl ChatMemberStatus {
+    /// Returns `true` if the user is privileged in the given chat. i.e. if the
+    /// user is either the [owner] or an [administrator] in the given chat.
+    ///
+    /// [owner]: ChatMemberKind::Owner
+    /// [administrator]: ChatMemberKind::Administrator
+    #[must_use]
+    pub fn is_privileged(&self) -> bool {
+        self.is_administrator() || self.is_owner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::UserId;
+
+    use super::*;
+    use crate::types::User;
+
+    #[test]
+    fn deserialize_administrator() {
+        let json = r#"{
+            "user":{
+                "id":1029940401,
+                "is_bot":false,
+                "first_name":"First",
+                "last_name":"Last",
+                "username":"fl",
+                "language_code":"en"
+            },
+            "status":"administrator",
+            "is_anonymous": false,
+            "can_be_edited": false,
+            "can_manage_chat": true,
+            "can_change_info": true,
+            "can_delete_messages": true,
+            "can_manage_video_chats": true,
+            "can_invite_users": true,
+            "can_restrict_members": true,
+            "can_pin_messages": true,
+            "can_promote_members": true
+        }"#;
+        let expected = ChatMember {
+            user: User {
+                id: UserId(1029940401),
+                is_bot: false,
+                first_name: "First".to_string(),
+                last_name: Some("Last".to_string()),
+                username: Some("fl".to_string()),
+                language_code: Some("en".to_string()),
+                is_premium: false,
+                added_to_attachment_menu: false,
+            },
+            kind: ChatMemberKind::Administrator(Administrator {
+                custom_title: None,
+                is_anonymous: false,
+                can_be_edited: false,
+                can_manage_chat: true,
+                can_change_info: true,
+                can_post_messages: false,
+                can_edit_messages: false,
+                can_delete_messages: true,
+                can_post_stories: false,
+                can_edit_stories: false,
+                can_delete_stories: false,
+                can_manage_video_chats: true,
+                can_invite_users: true,
+                can_restrict_members: true,
+                can_pin_messages: true,
+                can_promote_members: true,
+                can_manage_topics: false,
+            }),
+        };
+        let actual = serde_json::from_str::<ChatMember>(json).unwrap();
+        assert_eq!(actual, expected)
+    }
+
+    #[test]
+    fn deserialize_restricted() {
+        let json = r#"{
+            "user":{
+                "id":1029940401,
+                "is_bot":false,
+                "first_name":"First",
+                "last_name":"Last",
+                "username":"fl",
+                "language_code":"en"
+            },
+            "status":"restricted",
+            "is_member": true,
+            "can_send_messages": true,
+            "can_send_media_messages": true,
+            "can_send_audios": false,
+            "can_send_documents": false,
+            "can_send_photos": true,
+            "can_send_videos": true,
+            "can_send_video_notes": false,
+            "can_send_voice_notes": true,
+            "can_manage_topics": false,
+            "can_send_polls": true,
+            "can_send_other_messages": true,
+            "can_add_web_page_previews": true,
+            "can_change_info": true,
+            "can_invite_users": true,
+            "can_pin_messages": true,
+            "until_date": 1620000000
+        }"#;
+        let expected = ChatMember {
+            user: User {
+                id: UserId(1029940401),
+                is_bot: false,
+                first_name: "First".to_string(),
+                last_name: Some("Last".to_string()),
+                username: Some("fl".to_string()),
+                language_code: Some("en".to_string()),
+                is_premium: false,
+                added_to_attachment_menu: false,
+            },
+            kind: ChatMemberKind::Restricted(Restricted {
+                is_member: true,
+                can_send_messages: true,
+                can_send_audios: false,
+                can_send_documents: false,
+                can_send_photos: true,
+                can_send_videos: true,
+                can_send_video_notes: false,
+                can_send_voice_notes: true,
+                can_manage_topics: false,
+                can_send_polls: true,
+                can_send_other_messages: true,
+                can_add_web_page_previews: true,
+                can_change_info: true,
+                can_invite_users: true,
+                can_pin_messages: true,
+                until_date: UntilDate::Date(
+                    chrono::DateTime::from_timestamp(1620000000, 0).unwrap(),
+                ),
+            }),
+        };
+        let actual = serde_json::from_str::<ChatMember>(json).unwrap();
+        assert_eq!(actual, expected)
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -34,30 +36,9 @@ pub struct ChatMemberUpdated {
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub via_join_request: bool,
 
-    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     /// True, if the user joined the chat via a chat folder invite link
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub via_chat_folder_invite_link: bool,
 }
 
-impl ChatMemberUpdated {
-    /// Returns all users that are "contained" in this `ChatMemberUpdated`
-    /// structure.
-    ///
-    /// This might be useful to track information about users.
-    ///
-    /// Note that this function can return duplicate users.
-    ///
-    /// In earlier versions of the `teloixde-core`, this function
-    /// returned mentioned users in [`chat`] e.g. from pinned messages in
-    /// the chat. This functionality was lost with the TBA 7.3 update.
-    ///
-    /// [`chat`]: Self::chat
-    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
-        [
-            &self.from,
-            /* ignore `old_chat_member.user`, it should always be the same as the new one */
-            &self.new_chat_member.user,
-        ]
-        .into_iter()
-    }
-}
+mod impls;
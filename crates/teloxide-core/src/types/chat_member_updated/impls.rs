@@ -0,0 +1,24 @@
+use crate::types::{ChatMemberUpdated, User};
+
+impl ChatMemberUpdated {
+    /// Returns all users that are "contained" in this `ChatMemberUpdated`
+    /// structure.
+    ///
+    /// This might be useful to track information about users.
+    ///
+    /// Note that this function can return duplicate users.
+    ///
+    /// In earlier versions of the `teloixde-core`, this function
+    /// returned mentioned users in [`chat`] e.g. from pinned messages in
+    /// the chat. This functionality was lost with the TBA 7.3 update.
+    ///
+    /// [`chat`]: Self::chat
+    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
+        [
+            &self.from,
+            /* ignore `old_chat_member.user`, it should always be the same as the new one */
+            &self.new_chat_member.user,
+        ]
+        .into_iter()
+    }
+}
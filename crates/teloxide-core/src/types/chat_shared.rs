@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatId, PhotoSize, RequestId};
@@ -6,7 +8,10 @@ use crate::types::{ChatId, PhotoSize, RequestId};
 /// [`KeyboardButtonRequestChat`] button.
 ///
 /// [`KeyboardButtonRequestChat`]: crate::types::KeyboardButtonRequestChat
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct ChatShared {
     /// Identifier of the request.
     pub request_id: RequestId,
@@ -1,13 +1,21 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// Type of the chat, from which the inline query was sent.
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ChatType {
     /// Private chat with the inline query sender.
     Sender,
+
     Private,
+
     Group,
+
     Supergroup,
+
     Channel,
 }
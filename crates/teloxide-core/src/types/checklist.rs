@@ -0,0 +1,63 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{MessageEntity, User};
+
+/// Describes a checklist.
+///
+/// [The official docs](https://core.telegram.org/bots/api#checklist).
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct Checklist {
+    /// Title of the checklist.
+    pub title: String,
+
+    /// Special entities that appear in the checklist title.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub title_entities: Vec<MessageEntity>,
+
+    /// List of tasks in the checklist.
+    pub tasks: Vec<ChecklistTask>,
+
+    /// `true`, if users other than the creator of the list can add tasks to the
+    /// list.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub others_can_add_tasks: bool,
+
+    /// `true`, if users other than the creator of the list can mark tasks as
+    /// done or not done.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub others_can_mark_tasks_as_done: bool,
+}
+
+/// Describes a task in a checklist.
+///
+/// [The official docs](https://core.telegram.org/bots/api#checklisttask).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct ChecklistTask {
+    /// Unique identifier of the task.
+    pub id: u32,
+
+    /// Text of the task.
+    pub text: String,
+
+    /// Special entities that appear in the task text.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub text_entities: Vec<MessageEntity>,
+
+    /// User that completed the task; omitted if the task wasn't completed.
+    pub completed_by_user: Option<User>,
+
+    /// Point in time when the task was completed; omitted if the task wasn't
+    /// completed.
+    #[serde(default, with = "crate::types::serde_opt_date_from_unix_timestamp")]
+    pub completion_date: Option<DateTime<Utc>>,
+}
+
+mod impls;
@@ -0,0 +1,26 @@
+use crate::types::{Checklist, ChecklistTask, User};
+
+impl Checklist {
+    /// Returns all users that are "contained" in this `Checklist`
+    /// structure.
+    ///
+    /// This might be useful to track information about users.
+    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
+        use crate::util::mentioned_users_from_entities;
+
+        mentioned_users_from_entities(&self.title_entities)
+            .chain(self.tasks.iter().flat_map(ChecklistTask::mentioned_users))
+    }
+}
+
+impl ChecklistTask {
+    /// Returns all users that are "contained" in this `ChecklistTask`
+    /// structure.
+    ///
+    /// This might be useful to track information about users.
+    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
+        use crate::util::mentioned_users_from_entities;
+
+        mentioned_users_from_entities(&self.text_entities).chain(&self.completed_by_user)
+    }
+}
@@ -0,0 +1,20 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChecklistTask, Message};
+
+/// Describes a service message about tasks added to a checklist.
+///
+/// [The official docs](https://core.telegram.org/bots/api#checklisttasksadded).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChecklistTasksAdded {
+    /// Message containing the checklist to which the tasks were added. Note
+    /// that the [`Message`] object in this field will not contain the
+    /// `reply_to_message` field even if it itself is a reply.
+    pub checklist_message: Option<Box<Message>>,
+
+    /// List of tasks added to the checklist.
+    pub tasks: Vec<ChecklistTask>,
+}
@@ -0,0 +1,26 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Message;
+
+/// Describes a service message about checklist tasks marked as done or not
+/// done.
+///
+/// [The official docs](https://core.telegram.org/bots/api#checklisttasksdone).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChecklistTasksDone {
+    /// Message containing the checklist whose tasks were marked as done or not
+    /// done. Note that the [`Message`] object in this field will not contain
+    /// the `reply_to_message` field even if it itself is a reply.
+    pub checklist_message: Option<Box<Message>>,
+
+    /// Identifiers of the tasks that were marked as done.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub marked_as_done_task_ids: Vec<u32>,
+
+    /// Identifiers of the tasks that were marked as not done.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub marked_as_not_done_task_ids: Vec<u32>,
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{Location, User};
@@ -20,9 +22,9 @@ pub struct ChosenInlineResult {
     ///  A sender location, only for bots that require user location.
     pub location: Option<Location>,
 
-    /// An identifier of the sent inline message. Available only if
-    /// there is an [inline keyboard] attached to the message. Will be also
-    /// received in [callback queries] and can be used to [edit] the message.
+    /// An identifier of the sent inline message. Available only if there is an
+    /// [inline keyboard] attached to the message. Will be also received in
+    /// [callback queries] and can be used to [edit] the message.
     ///
     /// [inline keyboard]: https://core.telegram.org/bots/api#inlinekeyboardmarkup
     /// [callback queries]: https://core.telegram.org/bots/api#callbackquery
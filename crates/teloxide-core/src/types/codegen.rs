@@ -0,0 +1,374 @@
+#![allow(clippy::format_collect)]
+
+use std::collections::BTreeSet;
+
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+use crate::codegen::{
+    add_preamble, ensure_files_contents, project_root, reformat,
+    schema::{self, Doc, Field, SerdeDerive, Type, TypeDef, TypeKind, Variant},
+};
+
+/// Generates type definitions described in the schema.
+///
+/// Only the type definitions themselves (with derives and serde attributes)
+/// are generated. Hand-written `impl` blocks (and tests) for types in
+/// `src/types/{file}.rs` live in `src/types/{file}/impls.rs`, which is
+/// included by the generated module if it exists.
+///
+/// Types which are not in the schema (see the list at the top of
+/// `schema.ron`) are written by hand in their own modules.
+#[test]
+fn codegen_types() {
+    let base_path = project_root().join("src/types/");
+    let schema = schema::get();
+
+    let mut modules = IndexMap::<_, Vec<_>>::new();
+    for def in &schema.types {
+        modules.entry(&*def.file).or_default().push(def);
+    }
+
+    let mut files = Vec::new();
+
+    for (file, defs) in modules {
+        let path = base_path.join(format!("{file}.rs"));
+        let has_impls = base_path.join(file).join("impls.rs").exists();
+
+        let uses = uses(&defs);
+        let items = defs.iter().map(|def| render_type(def)).join("\n\n");
+        let impls = if has_impls { "\nmod impls;\n" } else { "" };
+
+        let contents = format!("{uses}\n\n{items}\n{impls}");
+
+        files.push((path, reformat(add_preamble("codegen_types", contents))));
+    }
+
+    ensure_files_contents(files.iter().map(|(p, c)| (&**p, &**c)))
+}
+
+/// Names, which are in scope without imports.
+const PRELUDE: &[&str] = &[
+    "Box", "Option", "Vec", "String", "str", "bool", "u8", "u16", "u32", "u64", "i32", "i64",
+    "f64", "usize",
+];
+
+fn uses(defs: &[&TypeDef]) -> String {
+    fn collect<'a>(ty: &'a Type, crate_uses: &mut BTreeSet<&'a str>, external: &mut Vec<String>) {
+        match ty {
+            Type::Option(inner) | Type::ArrayOf(inner) => collect(inner, crate_uses, external),
+            // `RawTy`s may be generic (e.g. `Box<Message>`) or contain paths (e.g.
+            // `mime::Mime`), which don't need to be imported
+            Type::RawTy(raw) => {
+                let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+                let mut rest = &**raw;
+
+                while let Some(start) = rest.find(is_ident) {
+                    let len = rest[start..].find(|c| !is_ident(c)).unwrap_or(rest.len() - start);
+                    let name = &rest[start..start + len];
+                    let qualified =
+                        rest[..start].ends_with("::") || rest[start + len..].starts_with("::");
+                    rest = &rest[start + len..];
+
+                    if qualified || PRELUDE.contains(&name) {
+                        continue;
+                    }
+
+                    match name {
+                        "DateTime" | "Utc" => {
+                            external.push(String::from("use chrono::{DateTime, Utc};"))
+                        }
+                        "Url" => external.push(String::from("use url::Url;")),
+                        "Mime" => external.push(String::from("use mime::Mime;")),
+                        "IpAddr" => external.push(String::from("use std::net::IpAddr;")),
+                        "Map" => external.push(String::from("use serde_json::Map;")),
+                        "Value" => external.push(String::from("use serde_json::Value;")),
+                        name if name.starts_with(|c: char| c.is_ascii_uppercase()) => {
+                            crate_uses.insert(name);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Type::True => {
+                crate_uses.insert("True");
+            }
+            Type::Url => external.push(String::from("use url::Url;")),
+            Type::DateTime => external.push(String::from("use chrono::{DateTime, Utc};")),
+            Type::u8
+            | Type::u16
+            | Type::u32
+            | Type::i32
+            | Type::u64
+            | Type::i64
+            | Type::f64
+            | Type::bool
+            | Type::String => {}
+        }
+    }
+
+    let mut crate_uses = BTreeSet::new();
+    let mut external_uses = Vec::new();
+
+    let serialize =
+        defs.iter().any(|def| matches!(def.serde, SerdeDerive::Both | SerdeDerive::SerializeOnly));
+    let deserialize = defs
+        .iter()
+        .any(|def| matches!(def.serde, SerdeDerive::Both | SerdeDerive::DeserializeOnly));
+    match (serialize, deserialize) {
+        (true, true) => external_uses.push(String::from("use serde::{Deserialize, Serialize};")),
+        (true, false) => external_uses.push(String::from("use serde::Serialize;")),
+        (false, true) => external_uses.push(String::from("use serde::Deserialize;")),
+        (false, false) => {}
+    }
+
+    for def in defs {
+        let tys: Vec<_> = match &def.kind {
+            TypeKind::Object { fields } => fields.iter().map(|f| &f.ty).collect(),
+            TypeKind::Union { variants, .. }
+            | TypeKind::Enum { variants, .. }
+            | TypeKind::Untagged { variants } => variants
+                .iter()
+                .flat_map(|v| v.ty.iter().chain(v.fields.iter().flatten().map(|f| &f.ty)))
+                .collect(),
+            TypeKind::Newtype { ty, .. } => vec![ty],
+            TypeKind::Unit => vec![],
+        };
+
+        tys.into_iter().for_each(|ty| match ty {
+            // Rendered as `bool`
+            Type::Option(inner) if **inner == Type::True => {}
+            ty => collect(ty, &mut crate_uses, &mut external_uses),
+        });
+    }
+
+    // Types defined in the same module don't need to be imported
+    defs.iter().for_each(|def| {
+        crate_uses.remove(&*def.name);
+    });
+
+    let external_uses = external_uses.into_iter().sorted().dedup().join("\n");
+
+    match crate_uses.len() {
+        0 => external_uses,
+        1 => format!("{external_uses}\n\nuse crate::types::{};", crate_uses.iter().next().unwrap()),
+        _ => format!("{external_uses}\n\nuse crate::types::{{{}}};", crate_uses.iter().join(", ")),
+    }
+}
+
+fn render_type(def: &TypeDef) -> String {
+    let mut lines = Vec::new();
+
+    lines.extend(render_doc(&def.doc, ""));
+    if let Some(link) = &def.tg_doc {
+        if !lines.is_empty() {
+            lines.push(String::from("///"));
+        }
+        lines.push(format!("/// [The official docs]({link})."));
+    }
+
+    let variants = match &def.kind {
+        TypeKind::Union { variants, .. }
+        | TypeKind::Enum { variants, .. }
+        | TypeKind::Untagged { variants } => &**variants,
+        _ => &[],
+    };
+    let nullable = match &def.kind {
+        TypeKind::Object { fields } => fields.iter().any(is_nullable),
+        _ => variants.iter().flat_map(|v| v.fields.iter().flatten()).any(is_nullable),
+    };
+    if nullable {
+        lines.push(String::from("#[serde_with::skip_serializing_none]"));
+    }
+
+    lines.extend(render_derive(&def.derive, &def.serde));
+
+    match &def.kind {
+        TypeKind::Union { tag, rename_all, .. } => {
+            lines.push(format!("#[serde(tag = \"{tag}\")]"));
+            lines.extend(rename_all.iter().map(|r| format!("#[serde(rename_all = \"{r}\")]")));
+        }
+        TypeKind::Enum { rename_all, .. } => {
+            lines.extend(rename_all.iter().map(|r| format!("#[serde(rename_all = \"{r}\")]")));
+        }
+        TypeKind::Untagged { .. } => lines.push(String::from("#[serde(untagged)]")),
+        TypeKind::Object { .. } | TypeKind::Newtype { .. } | TypeKind::Unit => {}
+    }
+
+    lines.extend(def.attrs.iter().cloned());
+
+    let comment = def.comment.as_deref().map(|c| render_comment(c, "    ")).unwrap_or_default();
+    let name = &def.name;
+
+    let body = match &def.kind {
+        TypeKind::Object { fields } => {
+            let fields = fields.iter().map(|f| render_field(f, "    ", "pub ")).join("\n\n");
+            format!("pub struct {name} {{\n{comment}{fields}\n}}")
+        }
+        TypeKind::Union { variants, .. }
+        | TypeKind::Enum { variants, .. }
+        | TypeKind::Untagged { variants } => {
+            let variants = render_variants(variants);
+            format!("pub enum {name} {{\n{comment}{variants}\n}}")
+        }
+        TypeKind::Newtype { ty, descr, private } => {
+            let descr = match &*descr.md {
+                "" => String::new(),
+                md => format!("/** {md} */ "),
+            };
+            let vis = if *private { "" } else { "pub " };
+            format!("pub struct {name}({descr}{vis}{ty});")
+        }
+        TypeKind::Unit => format!("pub struct {name};"),
+    };
+    lines.push(body);
+
+    lines.join("\n")
+}
+
+fn render_derive(derive: &[String], serde: &SerdeDerive) -> Vec<String> {
+    let serde = match serde {
+        SerdeDerive::Both => "Serialize, Deserialize",
+        SerdeDerive::SerializeOnly => "Serialize",
+        SerdeDerive::DeserializeOnly => "Deserialize",
+        SerdeDerive::Manual => "",
+    };
+
+    // Keep derives grouped the same way as in hand-written types
+    let (cmp, other): (Vec<_>, Vec<_>) = derive
+        .iter()
+        .partition(|d| matches!(&***d, "PartialEq" | "Eq" | "PartialOrd" | "Ord" | "Hash"));
+
+    if cmp.iter().all(|d| *d == "PartialEq") {
+        let all = derive.iter().map(|d| &**d).chain((!serde.is_empty()).then_some(serde));
+        return vec![format!("#[derive({})]", all.format(", "))];
+    }
+
+    let mut lines = Vec::new();
+    if !other.is_empty() {
+        lines.push(format!("#[derive({})]", other.iter().join(", ")));
+    }
+    if !cmp.is_empty() {
+        lines.push(format!("#[derive({})]", cmp.iter().join(", ")));
+    }
+    if !serde.is_empty() {
+        lines.push(format!("#[derive({serde})]"));
+    }
+
+    lines
+}
+
+fn render_field(field: &Field, indent: &str, vis: &str) -> String {
+    let mut lines = render_doc(&field.descr, indent);
+    if let Some(comment) = &field.comment {
+        lines.push(render_comment(comment, indent).trim_end().to_owned());
+    }
+    lines.extend(field.attrs.iter().map(|attr| format!("{indent}{attr}")));
+    lines.extend(field.rename.iter().map(|r| format!("{indent}#[serde(rename = \"{r}\")]")));
+
+    let attr = match &field.ty {
+        Type::Option(inner) => match &**inner {
+            Type::True => Some("#[serde(default, skip_serializing_if = \"std::ops::Not::not\")]"),
+            Type::ArrayOf(_) => Some("#[serde(default, skip_serializing_if = \"Vec::is_empty\")]"),
+            Type::DateTime => Some(
+                "#[serde(default, with = \"crate::types::serde_opt_date_from_unix_timestamp\")]",
+            ),
+            _ => None,
+        },
+        Type::DateTime => Some("#[serde(with = \"crate::types::serde_date_from_unix_timestamp\")]"),
+        _ => None,
+    };
+    lines.extend(attr.map(|attr| format!("{indent}{attr}")));
+
+    lines.push(format!("{indent}{vis}{}: {},", field.name, field_ty(&field.ty)));
+
+    lines.join("\n")
+}
+
+/// Returns the rust type of a field.
+fn field_ty(ty: &Type) -> String {
+    match ty {
+        Type::Option(inner) => match &**inner {
+            Type::True => String::from("bool"),
+            Type::ArrayOf(_) => inner.to_string(),
+            _ => ty.to_string(),
+        },
+        ty => ty.to_string(),
+    }
+}
+
+fn render_variants(variants: &[Variant]) -> String {
+    // Documented variants are separated by an empty line
+    let sep = if variants.iter().any(|v| v.descr.is_some() || v.fields.is_some()) {
+        "\n\n"
+    } else {
+        "\n"
+    };
+    variants.iter().map(render_variant).join(sep)
+}
+
+fn render_variant(variant: &Variant) -> String {
+    let mut lines = variant.descr.as_ref().map(|d| render_doc(d, "    ")).unwrap_or_default();
+    if let Some(comment) = &variant.comment {
+        lines.push(render_comment(comment, "    ").trim_end().to_owned());
+    }
+    lines.extend(variant.rename.iter().map(|r| format!("    #[serde(rename = \"{r}\")]")));
+    lines.extend(variant.attrs.iter().map(|attr| format!("    {attr}")));
+
+    let name = &variant.name;
+    lines.push(match (&variant.ty, &variant.fields) {
+        (Some(ty), _) => format!("    {name}({ty}),"),
+        (None, Some(fields)) if fields.is_empty() => format!("    {name} {{}},"),
+        (None, Some(fields)) => {
+            let fields = fields.iter().map(|f| render_field(f, "        ", "")).join("\n\n");
+            format!("    {name} {{\n{fields}\n    }},")
+        }
+        (None, None) => format!("    {name},"),
+    });
+
+    lines.join("\n")
+}
+
+fn render_doc(doc: &Doc, indent: &str) -> Vec<String> {
+    if doc.md.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines: Vec<_> = doc.md.split('\n').map(|line| doc_line(indent, line)).collect();
+    if !doc.md_links.is_empty() {
+        lines.push(format!("{indent}///"));
+        lines.extend(
+            doc.md_links.iter().map(|(name, link)| format!("{indent}/// [{name}]: {link}")),
+        );
+    }
+
+    lines
+}
+
+fn doc_line(indent: &str, line: &str) -> String {
+    if line.is_empty() {
+        format!("{indent}///")
+    } else {
+        format!("{indent}/// {line}")
+    }
+}
+
+fn render_comment(comment: &str, indent: &str) -> String {
+    comment
+        .split('\n')
+        .map(
+            |line| {
+                if line.is_empty() {
+                    format!("{indent}//\n")
+                } else {
+                    format!("{indent}// {line}\n")
+                }
+            },
+        )
+        .collect()
+}
+
+/// Returns `true` if the field is represented by an `Option<_>` in Rust.
+fn is_nullable(field: &Field) -> bool {
+    field_ty(&field.ty).starts_with("Option<")
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::UserId;
@@ -6,7 +8,9 @@ use crate::types::UserId;
 ///
 /// [The official docs](https://core.telegram.org/bots/api#contact).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct Contact {
     /// A contact's phone number.
     pub phone_number: String,
@@ -1,6 +1,10 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(Copy, Debug, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
 pub enum DiceEmoji {
     /// "🎲" emoji. Values from 1-6. Defaults to this variant.
     #[serde(rename = "🎲")]
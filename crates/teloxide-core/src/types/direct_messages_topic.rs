@@ -0,0 +1,21 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::User;
+
+/// Describes a topic of a direct messages chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#directmessagestopic).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct DirectMessagesTopic {
+    /// Unique identifier of the topic.
+    pub topic_id: i64,
+
+    /// Information about the user that created the topic. Currently, it is
+    /// always present.
+    pub user: Option<User>,
+}
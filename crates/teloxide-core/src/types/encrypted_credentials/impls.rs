@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::EncryptedCredentials;
+
+    #[test]
+    fn must_serialize_encrypted_credentials_to_json() {
+        // given
+        let expected_json = r#"
+        {
+            "data":"someData",
+            "hash":"1122",
+            "secret":"secret"
+        }"#
+        .replace('\n', "")
+        .replace(' ', "");
+        let encrypted_credentials = EncryptedCredentials {
+            data: "someData".to_string(),
+            hash: "1122".to_string(),
+            secret: "secret".to_string(),
+        };
+        // when
+        let actual_json = serde_json::to_string(&encrypted_credentials).unwrap();
+        //then
+        assert_eq!(actual_json, expected_json)
+    }
+}
@@ -1,27 +1,34 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
-    Animation, Audio, Chat, Contact, Dice, Document, Game, Giveaway, GiveawayWinners, Invoice,
-    LinkPreviewOptions, Location, MessageId, MessageOrigin, PhotoSize, Poll, Sticker, Story, Venue,
-    Video, VideoNote, Voice,
+    Animation, Audio, Chat, Checklist, Contact, Dice, Document, Game, Giveaway, GiveawayWinners,
+    Invoice, LinkPreviewOptions, Location, MessageId, MessageOrigin, PaidMediaInfo, PhotoSize,
+    Poll, Sticker, Story, Venue, Video, VideoNote, Voice,
 };
 
 /// This object contains information about a message that is being replied to,
 /// which may come from another chat or forum topic.
+#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExternalReplyInfo {
     /// Origin of the message replied to by the given message.
     pub origin: MessageOrigin,
+
     /// Chat the original message belongs to. Available only if the chat is a
     /// supergroup or a channel.
     pub chat: Option<Chat>,
+
     /// Unique message identifier inside the original chat. Available only if
     /// the original chat is a supergroup or a channel.
     #[serde(with = "crate::types::option_msg_id_as_int")]
     pub message_id: Option<MessageId>,
+
     /// Options used for link preview generation for the original message, if it
     /// is a text message.
     pub link_preview_options: Option<LinkPreviewOptions>,
+
     /// _true_, if the message media is covered by a spoiler animation.
     #[serde(default)]
     pub has_media_spoiler: bool,
@@ -43,12 +50,14 @@ pub enum ExternalReplyInfoKind {
     // See <https://github.com/teloxide/teloxide/issues/481>
     Animation(Animation),
     Audio(Audio),
+    Checklist(Checklist),
     Contact(Contact),
     Dice(Dice),
     Document(Document),
     Game(Game),
     Venue(Venue),
     Location(Location),
+    PaidMedia(PaidMediaInfo),
     Photo(Vec<PhotoSize>),
     Poll(Poll),
     Sticker(Sticker),
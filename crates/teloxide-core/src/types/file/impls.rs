@@ -0,0 +1,85 @@
+use std::ops::Deref;
+
+use crate::types::{File, FileMeta};
+
+/// Allows access to [`FileMeta`]'s fields for [`File`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use teloxide_core::types::File;
+/// #
+/// # let get_file = || File { meta: teloxide_core::types::FileMeta { id: String::new(), unique_id: String::new(), size: 0 }, path: String::new() };
+/// let file: File = get_file();
+///
+/// let file_id: &str = &file.id;
+/// let file_unique_id: &str = &file.unique_id;
+/// let file_size: u32 = file.size;
+/// #
+/// # let _ = (file_id, file_unique_id, file_size);
+/// ```
+impl Deref for File {
+    type Target = FileMeta;
+
+    fn deref(&self) -> &Self::Target {
+        &self.meta
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{File, FileMeta};
+
+    // As per <https://github.com/tdlib/telegram-bot-api/issues/192> file size is **not** optional,
+    // But <https://github.com/tdlib/telegram-bot-api/issues/294> suggests that it can be missing in case Telegram servers are going insane.
+    // To be safe, we use a placeholder value.
+    #[test]
+    fn no_file_size() {
+        let json =
+            r#"{"file_id":"FILE_ID","file_unique_id":"FILE_UNIQUE_ID","file_path":"FILE_PATH"}"#;
+        let file: File = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            file,
+            File {
+                meta: FileMeta {
+                    id: "FILE_ID".to_owned(),
+                    unique_id: "FILE_UNIQUE_ID".to_owned(),
+                    size: u32::MAX,
+                },
+                path: "FILE_PATH".to_owned(),
+            }
+        );
+    }
+
+    // In some places file metadata w/o path is returned. Make sure that we can
+    // deserialize it.
+    #[test]
+    fn no_file_path() {
+        let json = r#"{"file_id":"FILE_ID","file_unique_id":"FILE_UNIQUE_ID","file_size":42}"#;
+        let file: FileMeta = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            file,
+            FileMeta { id: "FILE_ID".to_owned(), unique_id: "FILE_UNIQUE_ID".to_owned(), size: 42 }
+        );
+    }
+
+    #[test]
+    fn full_file() {
+        let json = r#"{"file_id":"FILE_ID","file_unique_id":"FILE_UNIQUE_ID","file_size":42,"file_path":"FILE_PATH"}"#;
+        let file: File = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            file,
+            File {
+                meta: FileMeta {
+                    id: "FILE_ID".to_owned(),
+                    unique_id: "FILE_UNIQUE_ID".to_owned(),
+                    size: 42,
+                },
+                path: "FILE_PATH".to_owned(),
+            }
+        );
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::True;
@@ -13,7 +15,9 @@ use crate::types::True;
 ///
 /// [privacy mode]: https://core.telegram.org/bots#privacy-mode
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Default, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct ForceReply {
     /// Shows reply interface to the user, as if they manually selected the
     /// bot‘s message and tapped ’Reply'.
@@ -24,50 +28,13 @@ pub struct ForceReply {
     pub input_field_placeholder: Option<String>,
 
     /// Use this parameter if you want to force reply from specific users only.
-    /// Targets: 1) users that are `@mentioned` in the text of the
-    /// [`Message`] object; 2) if the bot's message is a reply
-    /// (has reply_to_message_id), sender of the original message.
+    /// Targets: 1) users that are `@mentioned` in the text of the [`Message`]
+    /// object; 2) if the bot's message is a reply (has reply_to_message_id),
+    /// sender of the original message.
     ///
     /// [`Message`]: crate::types::Message
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub selective: bool,
 }
 
-impl ForceReply {
-    #[must_use]
-    pub const fn new() -> Self {
-        Self { force_reply: True, input_field_placeholder: None, selective: false }
-    }
-
-    pub fn input_field_placeholder<T>(self, val: T) -> Self
-    where
-        T: Into<Option<String>>,
-    {
-        Self { input_field_placeholder: val.into(), ..self }
-    }
-
-    /// Sets [`selective`] to `true`.
-    ///
-    /// [`selective`]: ForceReply::selective
-    #[must_use]
-    pub fn selective(self) -> Self {
-        Self { selective: true, ..self }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let data = r#"
-        {
-            "force_reply": true,
-            "input_field_placeholder": "placeholder",
-            "selective": false
-        }
-        "#;
-        serde_json::from_str::<ForceReply>(data).unwrap();
-    }
-}
+mod impls;
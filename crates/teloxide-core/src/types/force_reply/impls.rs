@@ -0,0 +1,40 @@
+use crate::types::{ForceReply, True};
+
+impl ForceReply {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { force_reply: True, input_field_placeholder: None, selective: false }
+    }
+
+    pub fn input_field_placeholder<T>(self, val: T) -> Self
+    where
+        T: Into<Option<String>>,
+    {
+        Self { input_field_placeholder: val.into(), ..self }
+    }
+
+    /// Sets [`selective`] to `true`.
+    ///
+    /// [`selective`]: ForceReply::selective
+    #[must_use]
+    pub fn selective(self) -> Self {
+        Self { selective: true, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "force_reply": true,
+            "input_field_placeholder": "placeholder",
+            "selective": false
+        }
+        "#;
+        serde_json::from_str::<ForceReply>(data).unwrap();
+    }
+}
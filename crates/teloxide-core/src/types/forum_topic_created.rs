@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::Rgb;
@@ -7,7 +9,9 @@ use crate::types::Rgb;
 ///
 /// [The official docs](https://core.telegram.org/bots/api#forumtopiccreated).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct ForumTopicCreated {
     /// Name of the topic.
     pub name: String,
@@ -20,19 +24,4 @@ pub struct ForumTopicCreated {
     pub icon_custom_emoji_id: Option<String>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialization() {
-        let json =
-            r#"{"icon_color":9367192,"icon_custom_emoji_id":"5312536423851630001","name":"???"}"#;
-
-        let event = serde_json::from_str::<ForumTopicCreated>(json).unwrap();
-
-        assert_eq!(event.name, "???");
-        assert_eq!(event.icon_color, Rgb { r: 0x8E, g: 0xEE, b: 0x98 });
-        assert_eq!(event.icon_custom_emoji_id.as_deref(), Some("5312536423851630001"));
-    }
-}
+mod impls;
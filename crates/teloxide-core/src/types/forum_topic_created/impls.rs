@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::{ForumTopicCreated, Rgb};
+
+    #[test]
+    fn deserialization() {
+        let json =
+            r#"{"icon_color":9367192,"icon_custom_emoji_id":"5312536423851630001","name":"???"}"#;
+
+        let event = serde_json::from_str::<ForumTopicCreated>(json).unwrap();
+
+        assert_eq!(event.name, "???");
+        assert_eq!(event.icon_color, Rgb { r: 0x8E, g: 0xEE, b: 0x98 });
+        assert_eq!(event.icon_custom_emoji_id.as_deref(), Some("5312536423851630001"));
+    }
+}
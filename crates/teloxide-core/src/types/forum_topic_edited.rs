@@ -1,10 +1,14 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// This object represents a service message about an edited forum topic.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#forumtopicedited).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct ForumTopicEdited {
     /// New name of the topic, if it was edited
     pub name: Option<String>,
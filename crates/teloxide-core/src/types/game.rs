@@ -1,6 +1,8 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
-use crate::types::{Animation, MessageEntity, PhotoSize, User};
+use crate::types::{Animation, MessageEntity, PhotoSize};
 
 /// This object represents a game.
 ///
@@ -9,7 +11,9 @@ use crate::types::{Animation, MessageEntity, PhotoSize, User};
 ///
 /// [@Botfather]: https://t.me/botfather
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct Game {
     /// Title of the game.
     pub title: String,
@@ -21,9 +25,9 @@ pub struct Game {
     pub photo: Vec<PhotoSize>,
 
     /// Brief description of the game or high scores included in the game
-    /// message. Can be automatically edited to include current high scores
-    /// for the game when the bot calls [`SetGameScore`], or manually
-    /// edited using [`EditMessageText`]. 0-4096 characters.
+    /// message. Can be automatically edited to include current high scores for
+    /// the game when the bot calls [`SetGameScore`], or manually edited using
+    /// [`EditMessageText`]. 0-4096 characters.
     ///
     /// [`SetGameScore`]: crate::payloads::SetGameScore
     /// [`EditMessageText`]: crate::payloads::EditMessageText
@@ -40,16 +44,4 @@ pub struct Game {
     pub animation: Option<Animation>,
 }
 
-impl Game {
-    /// Returns all users that are "contained" in this `Game`
-    /// structure.
-    ///
-    /// This might be useful to track information about users.
-    ///
-    /// Note that this function can return duplicate users.
-    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
-        use crate::util::{flatten, mentioned_users_from_entities};
-
-        flatten(self.text_entities.as_deref().map(mentioned_users_from_entities))
-    }
-}
+mod impls;
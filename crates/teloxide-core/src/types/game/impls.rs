@@ -0,0 +1,15 @@
+use crate::types::{Game, User};
+
+impl Game {
+    /// Returns all users that are "contained" in this `Game`
+    /// structure.
+    ///
+    /// This might be useful to track information about users.
+    ///
+    /// Note that this function can return duplicate users.
+    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
+        use crate::util::{flatten, mentioned_users_from_entities};
+
+        flatten(self.text_entities.as_deref().map(mentioned_users_from_entities))
+    }
+}
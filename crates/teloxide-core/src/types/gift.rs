@@ -0,0 +1,42 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Sticker;
+
+/// This object represent a list of gifts.
+///
+/// [The official docs](https://core.telegram.org/bots/api#gifts).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Gifts {
+    /// The list of gifts.
+    pub gifts: Vec<Gift>,
+}
+
+/// This object represents a gift that can be sent by the bot.
+///
+/// [The official docs](https://core.telegram.org/bots/api#gift).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Gift {
+    /// Unique identifier of the gift.
+    pub id: String,
+
+    /// The sticker that represents the gift.
+    pub sticker: Sticker,
+
+    /// The number of Telegram Stars that must be paid to send the sticker.
+    pub star_count: u32,
+
+    /// The number of Telegram Stars that must be paid to upgrade the gift to a
+    /// unique one.
+    pub upgrade_star_count: Option<u32>,
+
+    /// The total number of the gifts of this type that can be sent; for limited
+    /// gifts only.
+    pub total_count: Option<u32>,
+
+    /// The number of remaining gifts of this type that can be sent; for limited
+    /// gifts only.
+    pub remaining_count: Option<u32>,
+}
@@ -0,0 +1,44 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Gift, MessageEntity};
+
+/// Describes a service message about a regular gift that was sent or received.
+///
+/// [The official docs](https://core.telegram.org/bots/api#giftinfo).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GiftInfo {
+    /// Information about the gift.
+    pub gift: Gift,
+
+    /// Unique identifier of the received gift for the bot; only present for
+    /// gifts received on behalf of business accounts.
+    pub owned_gift_id: Option<String>,
+
+    /// Number of Telegram Stars that can be claimed by the receiver by
+    /// converting the gift; omitted if conversion to Telegram Stars is
+    /// impossible.
+    pub convert_star_count: Option<u32>,
+
+    /// Number of Telegram Stars that were prepaid by the sender for the ability
+    /// to upgrade the gift.
+    pub prepaid_upgrade_star_count: Option<u32>,
+
+    /// `true`, if the gift can be upgraded to a unique gift.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub can_be_upgraded: bool,
+
+    /// Text of the message that was added to the gift.
+    pub text: Option<String>,
+
+    /// Special entities that appear in the text.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entities: Vec<MessageEntity>,
+
+    /// `true`, if the sender and gift text are shown only to the gift receiver;
+    /// otherwise, everyone will be able to see them.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_private: bool,
+}
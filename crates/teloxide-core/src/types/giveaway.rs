@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -32,11 +34,7 @@ pub struct Giveaway {
     /// Description of additional giveaway prize
     pub prize_description: Option<String>,
 
-    /// A list of two-letter [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) country codes indicating the
-    /// countries from which eligible users for the giveaway must come. If
-    /// empty, then all users can participate in the giveaway. Users with a
-    /// phone number that was bought on Fragment can always participate in
-    /// giveaways.
+    /// A list of two-letter [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) country codes indicating the countries from which eligible users for the giveaway must come. If empty, then all users can participate in the giveaway. Users with a phone number that was bought on Fragment can always participate in giveaways.
     pub country_codes: Option<Vec<CountryCode>>,
 
     /// The number of months the Telegram Premium subscription won from the
@@ -44,27 +42,4 @@ pub struct Giveaway {
     pub premium_subscription_month_count: Option<u8>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let data = r#"
-        {
-            "chats": [
-                {
-                    "id": -1002236736395,
-                    "title": "Test",
-                    "type": "channel"
-                }
-            ],
-            "winners_selection_date": 1721162701,
-            "winner_count": 1,
-            "has_public_winners": true,
-            "premium_subscription_month_count": 6
-        }
-        "#;
-        serde_json::from_str::<Giveaway>(data).unwrap();
-    }
-}
+mod impls;
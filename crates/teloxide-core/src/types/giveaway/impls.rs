@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::Giveaway;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "chats": [
+                {
+                    "id": -1002236736395,
+                    "title": "Test",
+                    "type": "channel"
+                }
+            ],
+            "winners_selection_date": 1721162701,
+            "winner_count": 1,
+            "has_public_winners": true,
+            "premium_subscription_month_count": 6
+        }
+        "#;
+        serde_json::from_str::<Giveaway>(data).unwrap();
+    }
+}
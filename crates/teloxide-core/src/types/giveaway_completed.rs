@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::Message;
@@ -17,45 +19,4 @@ pub struct GiveawayCompleted {
     pub giveaway_message: Option<Box<Message>>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let data = r#"
-        {
-            "winner_count": 0,
-            "unclaimed_prize_count": 1,
-            "giveaway_message": {
-                "message_id": 24,
-                "sender_chat": {
-                    "id": -1002236736395,
-                    "title": "Test",
-                    "type": "channel"
-                },
-                "chat": {
-                    "id": -1002236736395,
-                    "title": "Test",
-                    "type": "channel"
-                },
-                "date": 1721161230,
-                "giveaway": {
-                    "chats": [
-                        {
-                            "id": -1002236736395,
-                            "title": "Test",
-                            "type": "channel"
-                        }
-                    ],
-                    "winners_selection_date": 1721162701,
-                    "winner_count": 1,
-                    "has_public_winners": true,
-                    "premium_subscription_month_count": 6
-                }
-            }
-        }
-        "#;
-        serde_json::from_str::<GiveawayCompleted>(data).unwrap();
-    }
-}
+mod impls;
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::GiveawayCompleted;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "winner_count": 0,
+            "unclaimed_prize_count": 1,
+            "giveaway_message": {
+                "message_id": 24,
+                "sender_chat": {
+                    "id": -1002236736395,
+                    "title": "Test",
+                    "type": "channel"
+                },
+                "chat": {
+                    "id": -1002236736395,
+                    "title": "Test",
+                    "type": "channel"
+                },
+                "date": 1721161230,
+                "giveaway": {
+                    "chats": [
+                        {
+                            "id": -1002236736395,
+                            "title": "Test",
+                            "type": "channel"
+                        }
+                    ],
+                    "winners_selection_date": 1721162701,
+                    "winner_count": 1,
+                    "has_public_winners": true,
+                    "premium_subscription_month_count": 6
+                }
+            }
+        }
+        "#;
+        serde_json::from_str::<GiveawayCompleted>(data).unwrap();
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::GiveawayCreated;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"{}"#;
+        serde_json::from_str::<GiveawayCreated>(data).unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{Chat, MessageId};
@@ -8,6 +10,7 @@ use crate::types::{Chat, MessageId};
 pub struct InaccessibleMessage {
     /// Chat the message belonged to
     pub chat: Chat,
+
     /// Unique message identifier inside the chat
     #[serde(flatten)]
     pub message_id: MessageId,
@@ -0,0 +1,105 @@
+use crate::types::{
+    CallbackGame, InlineKeyboardButton, InlineKeyboardButtonKind, LoginUrl, True, WebAppInfo,
+};
+
+impl InlineKeyboardButton {
+    /// Creates a new `InlineKeyboardButton`.
+    pub fn new<S>(text: S, kind: InlineKeyboardButtonKind) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { text: text.into(), kind }
+    }
+
+    /// Constructor for `InlineKeyboardButton` with [`Url`] kind.
+    ///
+    /// [`Url`]: InlineKeyboardButtonKind::Url
+    pub fn url<T>(text: T, url: url::Url) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(text, InlineKeyboardButtonKind::Url(url))
+    }
+
+    /// Constructor for `InlineKeyboardButton` with [`LoginUrl`] kind.
+    ///
+    /// [`LoginUrl`]: InlineKeyboardButtonKind::LoginUrl
+    pub fn login<T>(text: T, url: LoginUrl) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(text, InlineKeyboardButtonKind::LoginUrl(url))
+    }
+
+    /// Constructor for `InlineKeyboardButton` with [`CallbackData`] kind.
+    ///
+    /// [`CallbackData`]: InlineKeyboardButtonKind::CallbackData
+    pub fn callback<T, C>(text: T, callback_data: C) -> Self
+    where
+        T: Into<String>,
+        C: Into<String>,
+    {
+        Self::new(text, InlineKeyboardButtonKind::CallbackData(callback_data.into()))
+    }
+
+    /// Constructor for `InlineKeyboardButton` with [`WebApp`] kind.
+    ///
+    /// [`WebApp`]: InlineKeyboardButtonKind::WebApp
+    pub fn web_app<T>(text: T, info: WebAppInfo) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(text, InlineKeyboardButtonKind::WebApp(info))
+    }
+
+    /// Constructor for `InlineKeyboardButton` with [`SwitchInlineQuery`] kind.
+    ///
+    /// [`SwitchInlineQuery`]: InlineKeyboardButtonKind::SwitchInlineQuery
+    pub fn switch_inline_query<T, Q>(text: T, switch_inline_query: Q) -> Self
+    where
+        T: Into<String>,
+        Q: Into<String>,
+    {
+        Self::new(text, InlineKeyboardButtonKind::SwitchInlineQuery(switch_inline_query.into()))
+    }
+
+    /// Constructor for `InlineKeyboardButton` with
+    /// [`SwitchInlineQueryCurrentChat`] kind.
+    ///
+    /// [`SwitchInlineQueryCurrentChat`]: InlineKeyboardButtonKind::SwitchInlineQueryCurrentChat
+    pub fn switch_inline_query_current_chat<T, Q>(
+        text: T,
+        switch_inline_query_current_chat: Q,
+    ) -> Self
+    where
+        T: Into<String>,
+        Q: Into<String>,
+    {
+        Self::new(
+            text,
+            InlineKeyboardButtonKind::SwitchInlineQueryCurrentChat(
+                switch_inline_query_current_chat.into(),
+            ),
+        )
+    }
+
+    /// Constructor for `InlineKeyboardButton` with [`CallbackGame`] kind.
+    ///
+    /// [`CallbackGame`]: InlineKeyboardButtonKind::CallbackGame
+    pub fn callback_game<T>(text: T, game: CallbackGame) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(text, InlineKeyboardButtonKind::CallbackGame(game))
+    }
+
+    /// Constructor for `InlineKeyboardButton` with [`Pay`] kind.
+    ///
+    /// [`Pay`]: InlineKeyboardButtonKind::Pay
+    pub fn pay<T>(text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(text, InlineKeyboardButtonKind::Pay(True))
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatType, Location, User};
@@ -29,7 +31,7 @@ pub struct InlineQuery {
     /// Type of the chat, from which the inline query was sent.
     ///
     /// The chat type should be always known for requests sent from official
-    /// clients and most third-party clients, unless the request was sent
-    /// from a secret chat.
+    /// clients and most third-party clients, unless the request was sent from a
+    /// secret chat.
     pub chat_type: Option<ChatType>,
 }
@@ -0,0 +1,88 @@
+use crate::types::{InlineKeyboardMarkup, InlineQueryResultArticle, InputMessageContent};
+
+impl InlineQueryResultArticle {
+    pub fn new<S1, S2>(id: S1, title: S2, input_message_content: InputMessageContent) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            input_message_content,
+            reply_markup: None,
+            url: None,
+            hide_url: None,
+            description: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+
+    pub fn id<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.id = val.into();
+        self
+    }
+
+    pub fn title<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.title = val.into();
+        self
+    }
+
+    #[must_use]
+    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
+        self.input_message_content = val;
+        self
+    }
+
+    #[must_use]
+    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn url(mut self, val: url::Url) -> Self {
+        self.url = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn hide_url(mut self, val: bool) -> Self {
+        self.hide_url = Some(val);
+        self
+    }
+
+    pub fn description<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.description = Some(val.into());
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_url(mut self, val: url::Url) -> Self {
+        self.thumbnail_url = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_width(mut self, val: u32) -> Self {
+        self.thumbnail_width = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_height(mut self, val: u32) -> Self {
+        self.thumbnail_height = Some(val);
+        self
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode, Seconds};
@@ -16,7 +18,7 @@ pub struct InlineQueryResultAudio {
     pub id: String,
 
     /// A valid URL for the audio file.
-    pub audio_url: reqwest::Url,
+    pub audio_url: url::Url,
 
     /// Title.
     pub title: String,
@@ -51,92 +53,4 @@ pub struct InlineQueryResultAudio {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-impl InlineQueryResultAudio {
-    pub fn new<S1, S2>(id: S1, audio_url: reqwest::Url, title: S2) -> Self
-    where
-        S1: Into<String>,
-        S2: Into<String>,
-    {
-        Self {
-            id: id.into(),
-            audio_url,
-            title: title.into(),
-            caption: None,
-            parse_mode: None,
-            caption_entities: None,
-            performer: None,
-            audio_duration: None,
-            reply_markup: None,
-            input_message_content: None,
-        }
-    }
-
-    pub fn id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.id = val.into();
-        self
-    }
-
-    #[must_use]
-    pub fn audio_url(mut self, val: reqwest::Url) -> Self {
-        self.audio_url = val;
-        self
-    }
-
-    pub fn title<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.title = val.into();
-        self
-    }
-
-    pub fn caption<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.caption = Some(val.into());
-        self
-    }
-
-    #[must_use]
-    pub fn parse_mode(mut self, val: ParseMode) -> Self {
-        self.parse_mode = Some(val);
-        self
-    }
-
-    pub fn caption_entities<C>(mut self, val: C) -> Self
-    where
-        C: IntoIterator<Item = MessageEntity>,
-    {
-        self.caption_entities = Some(val.into_iter().collect());
-        self
-    }
-
-    pub fn performer<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.performer = Some(val.into());
-        self
-    }
-
-    pub fn audio_duration(mut self, val: Seconds) -> Self {
-        self.audio_duration = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
-        self.reply_markup = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
-        self.input_message_content = Some(val);
-        self
-    }
-}
+mod impls;
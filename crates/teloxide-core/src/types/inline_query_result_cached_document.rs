@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
@@ -48,89 +50,4 @@ pub struct InlineQueryResultCachedDocument {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-impl InlineQueryResultCachedDocument {
-    pub fn new<S1, S2, S3>(id: S1, title: S2, document_file_id: S3) -> Self
-    where
-        S1: Into<String>,
-        S2: Into<String>,
-        S3: Into<String>,
-    {
-        Self {
-            id: id.into(),
-            title: title.into(),
-            document_file_id: document_file_id.into(),
-            description: None,
-            caption: None,
-            parse_mode: None,
-            caption_entities: None,
-            reply_markup: None,
-            input_message_content: None,
-        }
-    }
-
-    pub fn id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.id = val.into();
-        self
-    }
-
-    pub fn title<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.title = val.into();
-        self
-    }
-
-    pub fn document_file_id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.document_file_id = val.into();
-        self
-    }
-
-    pub fn description<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.description = Some(val.into());
-        self
-    }
-
-    pub fn caption<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.caption = Some(val.into());
-        self
-    }
-
-    #[must_use]
-    pub fn parse_mode(mut self, val: ParseMode) -> Self {
-        self.parse_mode = Some(val);
-        self
-    }
-
-    pub fn caption_entities<C>(mut self, val: C) -> Self
-    where
-        C: IntoIterator<Item = MessageEntity>,
-    {
-        self.caption_entities = Some(val.into_iter().collect());
-        self
-    }
-
-    #[must_use]
-    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
-        self.reply_markup = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
-        self.input_message_content = Some(val);
-        self
-    }
-}
+mod impls;
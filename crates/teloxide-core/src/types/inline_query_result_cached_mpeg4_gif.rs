@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
@@ -50,77 +52,4 @@ pub struct InlineQueryResultCachedMpeg4Gif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-impl InlineQueryResultCachedMpeg4Gif {
-    pub fn new<S1, S2>(id: S1, mpeg4_file_id: S2) -> Self
-    where
-        S1: Into<String>,
-        S2: Into<String>,
-    {
-        Self {
-            id: id.into(),
-            mpeg4_file_id: mpeg4_file_id.into(),
-            title: None,
-            caption: None,
-            parse_mode: None,
-            reply_markup: None,
-            caption_entities: None,
-            show_caption_above_media: false,
-            input_message_content: None,
-        }
-    }
-
-    pub fn id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.id = val.into();
-        self
-    }
-
-    pub fn title<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.title = Some(val.into());
-        self
-    }
-
-    pub fn caption<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.caption = Some(val.into());
-        self
-    }
-
-    #[must_use]
-    pub fn parse_mode(mut self, val: ParseMode) -> Self {
-        self.parse_mode = Some(val);
-        self
-    }
-
-    pub fn caption_entities<C>(mut self, val: C) -> Self
-    where
-        C: IntoIterator<Item = MessageEntity>,
-    {
-        self.caption_entities = Some(val.into_iter().collect());
-        self
-    }
-
-    pub fn show_caption_above_media(mut self, val: bool) -> Self {
-        self.show_caption_above_media = val;
-        self
-    }
-
-    #[must_use]
-    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
-        self.reply_markup = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
-        self.input_message_content = Some(val);
-        self
-    }
-}
+mod impls;
@@ -0,0 +1,93 @@
+use crate::types::{InlineKeyboardMarkup, InlineQueryResultContact, InputMessageContent};
+
+impl InlineQueryResultContact {
+    pub fn new<S1, S2, S3>(id: S1, phone_number: S2, first_name: S3) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+
+    pub fn id<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.id = val.into();
+        self
+    }
+
+    pub fn phone_number<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.phone_number = val.into();
+        self
+    }
+
+    pub fn first_name<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.first_name = val.into();
+        self
+    }
+
+    pub fn last_name<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.last_name = Some(val.into());
+        self
+    }
+
+    pub fn vcard<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.vcard = Some(val.into());
+        self
+    }
+
+    #[must_use]
+    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
+        self.input_message_content = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_url(mut self, val: url::Url) -> Self {
+        self.thumbnail_url = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_width(mut self, val: u32) -> Self {
+        self.thumbnail_width = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_height(mut self, val: u32) -> Self {
+        self.thumbnail_height = Some(val);
+        self
+    }
+}
@@ -0,0 +1,95 @@
+use mime::Mime;
+
+use crate::types::{
+    InlineKeyboardMarkup, InlineQueryResultDocument, InputMessageContent, MessageEntity, ParseMode,
+};
+
+impl InlineQueryResultDocument {
+    pub fn id<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.id = val.into();
+        self
+    }
+
+    pub fn title<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.title = val.into();
+        self
+    }
+
+    pub fn caption<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.caption = Some(val.into());
+        self
+    }
+
+    #[must_use]
+    pub fn parse_mode(mut self, val: ParseMode) -> Self {
+        self.parse_mode = Some(val);
+        self
+    }
+
+    pub fn caption_entities<C>(mut self, val: C) -> Self
+    where
+        C: IntoIterator<Item = MessageEntity>,
+    {
+        self.caption_entities = Some(val.into_iter().collect());
+        self
+    }
+
+    #[must_use]
+    pub fn document_url(mut self, val: url::Url) -> Self {
+        self.document_url = val;
+        self
+    }
+
+    #[must_use]
+    pub fn mime_type(mut self, val: Mime) -> Self {
+        self.mime_type = val;
+        self
+    }
+
+    pub fn description<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.description = Some(val.into());
+        self
+    }
+
+    #[must_use]
+    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
+        self.input_message_content = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_url(mut self, val: url::Url) -> Self {
+        self.thumbnail_url = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_width(mut self, val: u32) -> Self {
+        self.thumbnail_width = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_height(mut self, val: u32) -> Self {
+        self.thumbnail_height = Some(val);
+        self
+    }
+}
@@ -0,0 +1,33 @@
+use crate::types::{InlineKeyboardMarkup, InlineQueryResultGame};
+
+impl InlineQueryResultGame {
+    pub fn new<S1, S2>(id: S1, game_short_name: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self { id: id.into(), game_short_name: game_short_name.into(), reply_markup: None }
+    }
+
+    pub fn id<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.id = val.into();
+        self
+    }
+
+    pub fn game_short_name<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.game_short_name = val.into();
+        self
+    }
+
+    #[must_use]
+    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(val);
+        self
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode, Seconds};
@@ -16,7 +18,7 @@ pub struct InlineQueryResultGif {
     pub id: String,
 
     /// A valid URL for the GIF file. File size must not exceed 1MB.
-    pub gif_url: reqwest::Url,
+    pub gif_url: url::Url,
 
     /// Width of the GIF.
     pub gif_width: Option<u32>,
@@ -29,11 +31,11 @@ pub struct InlineQueryResultGif {
 
     /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the
     /// result
-    pub thumbnail_url: reqwest::Url,
+    pub thumbnail_url: url::Url,
 
+    /// MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or
+    /// “video/mp4”. Defaults to “image/jpeg”
     // FIXME: maybe make dedicated enum for the mime type?
-    /// MIME type of the thumbnail, must be one of “image/jpeg”,
-    /// “image/gif”, or “video/mp4”. Defaults to “image/jpeg”
     pub thumbnail_mime_type: Option<String>,
 
     /// Title for the result.
@@ -67,111 +69,4 @@ pub struct InlineQueryResultGif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-impl InlineQueryResultGif {
-    pub fn new<S>(id: S, gif_url: reqwest::Url, thumbnail_url: reqwest::Url) -> Self
-    where
-        S: Into<String>,
-    {
-        Self {
-            id: id.into(),
-            gif_url,
-            gif_width: None,
-            gif_height: None,
-            gif_duration: None,
-            thumbnail_url,
-            thumbnail_mime_type: None,
-            title: None,
-            caption: None,
-            parse_mode: None,
-            reply_markup: None,
-            input_message_content: None,
-            caption_entities: None,
-            show_caption_above_media: false,
-        }
-    }
-
-    pub fn id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.id = val.into();
-        self
-    }
-
-    #[must_use]
-    pub fn gif_url(mut self, val: reqwest::Url) -> Self {
-        self.gif_url = val;
-        self
-    }
-
-    #[must_use]
-    pub fn gif_width(mut self, val: u32) -> Self {
-        self.gif_width = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn gif_height(mut self, val: u32) -> Self {
-        self.gif_height = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn gif_duration(mut self, val: Seconds) -> Self {
-        self.gif_duration = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn thumbnail_url(mut self, val: reqwest::Url) -> Self {
-        self.thumbnail_url = val;
-        self
-    }
-
-    pub fn title<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.title = Some(val.into());
-        self
-    }
-
-    pub fn caption<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.caption = Some(val.into());
-        self
-    }
-
-    #[must_use]
-    pub fn parse_mode(mut self, val: ParseMode) -> Self {
-        self.parse_mode = Some(val);
-        self
-    }
-
-    pub fn caption_entities<C>(mut self, val: C) -> Self
-    where
-        C: IntoIterator<Item = MessageEntity>,
-    {
-        self.caption_entities = Some(val.into_iter().collect());
-        self
-    }
-
-    pub fn show_caption_above_media(mut self, val: bool) -> Self {
-        self.show_caption_above_media = val;
-        self
-    }
-
-    #[must_use]
-    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
-        self.reply_markup = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
-        self.input_message_content = Some(val);
-        self
-    }
-}
+mod impls;
@@ -0,0 +1,113 @@
+use crate::types::{
+    InlineKeyboardMarkup, InlineQueryResultGif, InputMessageContent, MessageEntity, ParseMode,
+    Seconds,
+};
+
+impl InlineQueryResultGif {
+    pub fn new<S>(id: S, gif_url: url::Url, thumbnail_url: url::Url) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            gif_url,
+            gif_width: None,
+            gif_height: None,
+            gif_duration: None,
+            thumbnail_url,
+            thumbnail_mime_type: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+        }
+    }
+
+    pub fn id<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.id = val.into();
+        self
+    }
+
+    #[must_use]
+    pub fn gif_url(mut self, val: url::Url) -> Self {
+        self.gif_url = val;
+        self
+    }
+
+    #[must_use]
+    pub fn gif_width(mut self, val: u32) -> Self {
+        self.gif_width = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn gif_height(mut self, val: u32) -> Self {
+        self.gif_height = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn gif_duration(mut self, val: Seconds) -> Self {
+        self.gif_duration = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_url(mut self, val: url::Url) -> Self {
+        self.thumbnail_url = val;
+        self
+    }
+
+    pub fn title<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.title = Some(val.into());
+        self
+    }
+
+    pub fn caption<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.caption = Some(val.into());
+        self
+    }
+
+    #[must_use]
+    pub fn parse_mode(mut self, val: ParseMode) -> Self {
+        self.parse_mode = Some(val);
+        self
+    }
+
+    pub fn caption_entities<C>(mut self, val: C) -> Self
+    where
+        C: IntoIterator<Item = MessageEntity>,
+    {
+        self.caption_entities = Some(val.into_iter().collect());
+        self
+    }
+
+    pub fn show_caption_above_media(mut self, val: bool) -> Self {
+        self.show_caption_above_media = val;
+        self
+    }
+
+    #[must_use]
+    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
+        self.input_message_content = Some(val);
+        self
+    }
+}
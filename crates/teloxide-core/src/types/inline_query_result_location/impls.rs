@@ -0,0 +1,109 @@
+use crate::types::{
+    InlineKeyboardMarkup, InlineQueryResultLocation, InputMessageContent, LivePeriod,
+};
+
+impl InlineQueryResultLocation {
+    pub fn new<S1, S2>(id: S1, title: S2, latitude: f64, longitude: f64) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            latitude,
+            longitude,
+            live_period: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+            horizontal_accuracy: None,
+            heading: None,
+            proximity_alert_radius: None,
+        }
+    }
+
+    pub fn id<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.id = val.into();
+        self
+    }
+
+    #[must_use]
+    pub fn latitude(mut self, val: f64) -> Self {
+        self.latitude = val;
+        self
+    }
+
+    #[must_use]
+    pub fn longitude(mut self, val: f64) -> Self {
+        self.longitude = val;
+        self
+    }
+
+    pub fn title<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.title = val.into();
+        self
+    }
+
+    #[must_use]
+    pub fn horizontal_accuracy<S>(mut self, val: f64) -> Self {
+        self.horizontal_accuracy = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn live_period(mut self, val: LivePeriod) -> Self {
+        self.live_period = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn heading(mut self, val: u16) -> Self {
+        self.heading = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn proximity_alert_radius(mut self, val: u32) -> Self {
+        self.proximity_alert_radius = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
+        self.input_message_content = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_url(mut self, val: url::Url) -> Self {
+        self.thumbnail_url = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_width(mut self, val: u32) -> Self {
+        self.thumbnail_width = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_height(mut self, val: u32) -> Self {
+        self.thumbnail_height = Some(val);
+        self
+    }
+}
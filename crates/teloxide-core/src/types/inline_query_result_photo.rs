@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
@@ -17,10 +19,10 @@ pub struct InlineQueryResultPhoto {
 
     /// A valid URL of the photo. Photo must be in **jpeg** format. Photo size
     /// must not exceed 5MB.
-    pub photo_url: reqwest::Url,
+    pub photo_url: url::Url,
 
     /// URL of the thumbnail for the photo.
-    pub thumbnail_url: reqwest::Url,
+    pub thumbnail_url: url::Url,
 
     /// Width of the photo.
     pub photo_width: Option<u32>,
@@ -62,112 +64,4 @@ pub struct InlineQueryResultPhoto {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-impl InlineQueryResultPhoto {
-    pub fn new<S>(id: S, photo_url: reqwest::Url, thumbnail_url: reqwest::Url) -> Self
-    where
-        S: Into<String>,
-    {
-        Self {
-            id: id.into(),
-            photo_url,
-            thumbnail_url,
-            photo_width: None,
-            photo_height: None,
-            title: None,
-            description: None,
-            caption: None,
-            parse_mode: None,
-            caption_entities: None,
-            show_caption_above_media: false,
-            reply_markup: None,
-            input_message_content: None,
-        }
-    }
-
-    pub fn id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.id = val.into();
-        self
-    }
-
-    #[must_use]
-    pub fn photo_url(mut self, val: reqwest::Url) -> Self {
-        self.photo_url = val;
-        self
-    }
-
-    #[must_use]
-    pub fn thumbnail_url<S>(mut self, val: reqwest::Url) -> Self {
-        self.thumbnail_url = val;
-        self
-    }
-
-    #[must_use]
-    pub fn photo_width(mut self, val: u32) -> Self {
-        self.photo_width = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn photo_height(mut self, val: u32) -> Self {
-        self.photo_height = Some(val);
-        self
-    }
-
-    pub fn title<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.title = Some(val.into());
-        self
-    }
-
-    pub fn description<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.description = Some(val.into());
-        self
-    }
-
-    pub fn caption<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.caption = Some(val.into());
-        self
-    }
-
-    #[must_use]
-    pub fn parse_mode(mut self, val: ParseMode) -> Self {
-        self.parse_mode = Some(val);
-        self
-    }
-
-    pub fn caption_entities<C>(mut self, val: C) -> Self
-    where
-        C: IntoIterator<Item = MessageEntity>,
-    {
-        self.caption_entities = Some(val.into_iter().collect());
-        self
-    }
-
-    pub fn show_caption_above_media(mut self, val: bool) -> Self {
-        self.show_caption_above_media = val;
-        self
-    }
-
-    #[must_use]
-    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
-        self.reply_markup = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
-        self.input_message_content = Some(val);
-        self
-    }
-}
+mod impls;
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, InputMessageContent};
@@ -52,7 +54,7 @@ pub struct InlineQueryResultVenue {
     pub input_message_content: Option<InputMessageContent>,
 
     /// Url of the thumbnail for the result.
-    pub thumbnail_url: Option<reqwest::Url>,
+    pub thumbnail_url: Option<url::Url>,
 
     /// Thumbnail width.
     pub thumbnail_width: Option<u32>,
@@ -61,126 +63,4 @@ pub struct InlineQueryResultVenue {
     pub thumbnail_height: Option<u32>,
 }
 
-impl InlineQueryResultVenue {
-    pub fn new<S1, S2, S3>(id: S1, latitude: f64, longitude: f64, title: S2, address: S3) -> Self
-    where
-        S1: Into<String>,
-        S2: Into<String>,
-        S3: Into<String>,
-    {
-        Self {
-            id: id.into(),
-            latitude,
-            longitude,
-            title: title.into(),
-            address: address.into(),
-            foursquare_id: None,
-            foursquare_type: None,
-            google_place_id: None,
-            google_place_type: None,
-            reply_markup: None,
-            input_message_content: None,
-            thumbnail_url: None,
-            thumbnail_width: None,
-            thumbnail_height: None,
-        }
-    }
-
-    pub fn id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.id = val.into();
-        self
-    }
-
-    #[must_use]
-    pub fn latitude(mut self, val: f64) -> Self {
-        self.latitude = val;
-        self
-    }
-
-    #[must_use]
-    pub fn longitude(mut self, val: f64) -> Self {
-        self.longitude = val;
-        self
-    }
-
-    pub fn title<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.title = val.into();
-        self
-    }
-
-    pub fn address<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.address = val.into();
-        self
-    }
-
-    pub fn foursquare_id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.foursquare_id = Some(val.into());
-        self
-    }
-
-    pub fn foursquare_type<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.foursquare_type = Some(val.into());
-        self
-    }
-
-    pub fn google_place_id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.google_place_id = Some(val.into());
-        self
-    }
-
-    pub fn google_place_type<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.google_place_type = Some(val.into());
-        self
-    }
-
-    #[must_use]
-    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
-        self.reply_markup = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
-        self.input_message_content = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn thumbnail_url(mut self, val: reqwest::Url) -> Self {
-        self.thumbnail_url = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn thumbnail_width(mut self, val: u32) -> Self {
-        self.thumbnail_width = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn thumbnail_height(mut self, val: u32) -> Self {
-        self.thumbnail_height = Some(val);
-        self
-    }
-}
+mod impls;
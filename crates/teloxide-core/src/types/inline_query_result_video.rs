@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 
@@ -18,14 +20,14 @@ pub struct InlineQueryResultVideo {
     pub id: String,
 
     /// A valid URL for the embedded video player or video file.
-    pub video_url: reqwest::Url,
+    pub video_url: url::Url,
 
     /// Mime type of the content of video url, `text/html` or `video/mp4`.
     #[serde(with = "crate::types::non_telegram_types::mime::deser")]
     pub mime_type: Mime,
 
     /// URL of the thumbnail (jpeg only) for the video.
-    pub thumbnail_url: reqwest::Url,
+    pub thumbnail_url: url::Url,
 
     /// Title for the result.
     pub title: String,
@@ -70,138 +72,8 @@ pub struct InlineQueryResultVideo {
     /// **required** if [`InlineQueryResultVideo`] is used to send an HTML-page
     /// as a result (e.g., a YouTube video).
     ///
-    /// [`InlineQueryResultVideo`]:
-    /// crate::types::InlineQueryResultVideo
+    /// [`InlineQueryResultVideo`]: crate::types::InlineQueryResultVideo
     pub input_message_content: Option<InputMessageContent>,
 }
 
-impl InlineQueryResultVideo {
-    pub fn new<S1, S2>(
-        id: S1,
-        video_url: reqwest::Url,
-        mime_type: Mime,
-        thumbnail_url: reqwest::Url,
-        title: S2,
-    ) -> Self
-    where
-        S1: Into<String>,
-        S2: Into<String>,
-    {
-        Self {
-            id: id.into(),
-            video_url,
-            mime_type,
-            thumbnail_url,
-            title: title.into(),
-            caption: None,
-            parse_mode: None,
-            caption_entities: None,
-            show_caption_above_media: false,
-            video_width: None,
-            video_height: None,
-            video_duration: None,
-            description: None,
-            reply_markup: None,
-            input_message_content: None,
-        }
-    }
-
-    pub fn id<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.id = val.into();
-        self
-    }
-
-    #[must_use]
-    pub fn video_url(mut self, val: reqwest::Url) -> Self {
-        self.video_url = val;
-        self
-    }
-
-    #[must_use]
-    pub fn mime_type(mut self, val: Mime) -> Self {
-        self.mime_type = val;
-        self
-    }
-
-    #[must_use]
-    pub fn thumbnail_url(mut self, val: reqwest::Url) -> Self {
-        self.thumbnail_url = val;
-        self
-    }
-
-    pub fn title<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.title = val.into();
-        self
-    }
-
-    pub fn caption<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.caption = Some(val.into());
-        self
-    }
-
-    #[must_use]
-    pub fn parse_mode(mut self, val: ParseMode) -> Self {
-        self.parse_mode = Some(val);
-        self
-    }
-
-    pub fn caption_entities<C>(mut self, val: C) -> Self
-    where
-        C: IntoIterator<Item = MessageEntity>,
-    {
-        self.caption_entities = Some(val.into_iter().collect());
-        self
-    }
-
-    pub fn show_caption_above_media(mut self, val: bool) -> Self {
-        self.show_caption_above_media = val;
-        self
-    }
-
-    #[must_use]
-    pub fn video_width(mut self, val: u32) -> Self {
-        self.video_width = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn video_height(mut self, val: u32) -> Self {
-        self.video_height = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn video_duration(mut self, val: Seconds) -> Self {
-        self.video_duration = Some(val);
-        self
-    }
-
-    pub fn description<S>(mut self, val: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.description = Some(val.into());
-        self
-    }
-
-    #[must_use]
-    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
-        self.reply_markup = Some(val);
-        self
-    }
-
-    #[must_use]
-    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
-        self.input_message_content = Some(val);
-        self
-    }
-}
+mod impls;
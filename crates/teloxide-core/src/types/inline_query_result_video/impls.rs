@@ -0,0 +1,137 @@
+use mime::Mime;
+
+use crate::types::{
+    InlineKeyboardMarkup, InlineQueryResultVideo, InputMessageContent, MessageEntity, ParseMode,
+    Seconds,
+};
+
+impl InlineQueryResultVideo {
+    pub fn new<S1, S2>(
+        id: S1,
+        video_url: url::Url,
+        mime_type: Mime,
+        thumbnail_url: url::Url,
+        title: S2,
+    ) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            video_url,
+            mime_type,
+            thumbnail_url,
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            video_width: None,
+            video_height: None,
+            video_duration: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn id<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.id = val.into();
+        self
+    }
+
+    #[must_use]
+    pub fn video_url(mut self, val: url::Url) -> Self {
+        self.video_url = val;
+        self
+    }
+
+    #[must_use]
+    pub fn mime_type(mut self, val: Mime) -> Self {
+        self.mime_type = val;
+        self
+    }
+
+    #[must_use]
+    pub fn thumbnail_url(mut self, val: url::Url) -> Self {
+        self.thumbnail_url = val;
+        self
+    }
+
+    pub fn title<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.title = val.into();
+        self
+    }
+
+    pub fn caption<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.caption = Some(val.into());
+        self
+    }
+
+    #[must_use]
+    pub fn parse_mode(mut self, val: ParseMode) -> Self {
+        self.parse_mode = Some(val);
+        self
+    }
+
+    pub fn caption_entities<C>(mut self, val: C) -> Self
+    where
+        C: IntoIterator<Item = MessageEntity>,
+    {
+        self.caption_entities = Some(val.into_iter().collect());
+        self
+    }
+
+    pub fn show_caption_above_media(mut self, val: bool) -> Self {
+        self.show_caption_above_media = val;
+        self
+    }
+
+    #[must_use]
+    pub fn video_width(mut self, val: u32) -> Self {
+        self.video_width = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn video_height(mut self, val: u32) -> Self {
+        self.video_height = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn video_duration(mut self, val: Seconds) -> Self {
+        self.video_duration = Some(val);
+        self
+    }
+
+    pub fn description<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.description = Some(val.into());
+        self
+    }
+
+    #[must_use]
+    pub fn reply_markup(mut self, val: InlineKeyboardMarkup) -> Self {
+        self.reply_markup = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub fn input_message_content(mut self, val: InputMessageContent) -> Self {
+        self.input_message_content = Some(val);
+        self
+    }
+}
@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::{InlineQueryResultsButton, InlineQueryResultsButtonKind};
+
+    #[test]
+    fn inline_query_results_button() {
+        let button = InlineQueryResultsButton {
+            text: "test".into(),
+            kind: InlineQueryResultsButtonKind::StartParameter("bot".into()),
+        };
+        let expected = r#"{"text":"test","start_parameter":"bot"}"#;
+        let actual = serde_json::to_string(&button).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,70 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{MessageEntity, ParseMode};
+
+/// Describes a checklist to create.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputchecklist).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize)]
+pub struct InputChecklist {
+    /// Title of the checklist; 1-255 characters after entities parsing.
+    pub title: String,
+
+    /// Mode for parsing entities in the title. See [formatting options] for
+    /// more details.
+    ///
+    /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the title, which can be
+    /// specified instead of `parse_mode`. Currently, only _bold_, _italic_,
+    /// _underline_, _strikethrough_, _spoiler_, and _custom\_emoji_ entities
+    /// are allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub title_entities: Vec<MessageEntity>,
+
+    /// List of 1-30 tasks in the checklist.
+    pub tasks: Vec<InputChecklistTask>,
+
+    /// Pass `true` if other users can add tasks to the checklist.
+    pub others_can_add_tasks: Option<bool>,
+
+    /// Pass `true` if other users can mark tasks as done or not done in the
+    /// checklist.
+    pub others_can_mark_tasks_as_done: Option<bool>,
+}
+
+/// Describes a task to add to a checklist.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputchecklisttask).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize)]
+pub struct InputChecklistTask {
+    /// Unique identifier of the task; must be positive and unique among all
+    /// task identifiers currently present in the checklist.
+    pub id: u32,
+
+    /// Text of the task; 1-100 characters after entities parsing.
+    pub text: String,
+
+    /// Mode for parsing entities in the text. See [formatting options] for more
+    /// details.
+    ///
+    /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the text, which can be specified
+    /// instead of `parse_mode`. Currently, only _bold_, _italic_, _underline_,
+    /// _strikethrough_, _spoiler_, and _custom\_emoji_ entities are allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub text_entities: Vec<MessageEntity>,
+}
+
+mod impls;
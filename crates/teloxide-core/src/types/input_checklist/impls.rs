@@ -0,0 +1,84 @@
+use crate::types::{InputChecklist, InputChecklistTask, MessageEntity, ParseMode};
+
+impl InputChecklist {
+    pub fn new<S, T>(title: S, tasks: T) -> Self
+    where
+        S: Into<String>,
+        T: IntoIterator<Item = InputChecklistTask>,
+    {
+        Self {
+            title: title.into(),
+            parse_mode: None,
+            title_entities: Vec::new(),
+            tasks: tasks.into_iter().collect(),
+            others_can_add_tasks: None,
+            others_can_mark_tasks_as_done: None,
+        }
+    }
+
+    pub fn parse_mode(mut self, val: ParseMode) -> Self {
+        self.parse_mode = Some(val);
+        self
+    }
+
+    pub fn title_entities<E>(mut self, val: E) -> Self
+    where
+        E: IntoIterator<Item = MessageEntity>,
+    {
+        self.title_entities = val.into_iter().collect();
+        self
+    }
+
+    pub fn others_can_add_tasks(mut self, val: bool) -> Self {
+        self.others_can_add_tasks = Some(val);
+        self
+    }
+
+    pub fn others_can_mark_tasks_as_done(mut self, val: bool) -> Self {
+        self.others_can_mark_tasks_as_done = Some(val);
+        self
+    }
+}
+
+impl InputChecklistTask {
+    pub fn new<S>(id: u32, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { id, text: text.into(), parse_mode: None, text_entities: Vec::new() }
+    }
+
+    pub fn parse_mode(mut self, val: ParseMode) -> Self {
+        self.parse_mode = Some(val);
+        self
+    }
+
+    pub fn text_entities<E>(mut self, val: E) -> Self
+    where
+        E: IntoIterator<Item = MessageEntity>,
+    {
+        self.text_entities = val.into_iter().collect();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let expected = r#"{"title":"Groceries","parse_mode":"HTML","tasks":[{"id":1,"text":"Milk"},{"id":2,"text":"<b>Bread</b>","parse_mode":"HTML"}],"others_can_add_tasks":true}"#;
+        let checklist = InputChecklist::new(
+            "Groceries",
+            [
+                InputChecklistTask::new(1, "Milk"),
+                InputChecklistTask::new(2, "<b>Bread</b>").parse_mode(ParseMode::Html),
+            ],
+        )
+        .parse_mode(ParseMode::Html)
+        .others_can_add_tasks(true);
+
+        assert_eq!(serde_json::to_string(&checklist).unwrap(), expected);
+    }
+}
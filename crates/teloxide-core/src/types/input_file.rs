@@ -1,11 +1,10 @@
 use bytes::{Bytes, BytesMut};
 use futures::{
     future::{ready, Either},
-    stream,
+    stream, FutureExt,
 };
 use once_cell::sync::OnceCell;
 use rc_box::ArcBox;
-use reqwest::{multipart::Part, Body};
 use serde::Serialize;
 use takecell::TakeCell;
 use tokio::{
@@ -15,11 +14,21 @@ use tokio::{
 use tokio_util::codec::{Decoder, FramedRead};
 
 use std::{
-    borrow::Cow, convert::Infallible, fmt, future::Future, io, iter, mem, path::PathBuf, pin::Pin,
-    sync::Arc, task,
+    borrow::Cow,
+    convert::Infallible,
+    fmt,
+    future::Future,
+    io, iter, mem,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    task,
 };
 
-use crate::types::InputSticker;
+use crate::{
+    net::multipart::{Part, UploadProgress},
+    types::{InputProfilePhoto, InputSticker, InputStoryContent},
+};
 
 /// This object represents the contents of a file to be uploaded.
 ///
@@ -29,8 +38,13 @@ pub struct InputFile {
     id: OnceCell<Arc<str>>,
     file_name: Option<Cow<'static, str>>,
     inner: InnerFile,
+    on_progress: Option<OnProgress>,
 }
 
+/// A progress observer, see [`InputFile::on_progress`].
+#[derive(Clone)]
+struct OnProgress(Arc<dyn Fn(UploadProgress) + Send + Sync>);
+
 #[derive(Clone)]
 enum InnerFile {
     Read(Read),
@@ -83,6 +97,11 @@ impl InputFile {
     }
 
     /// Creates an `InputFile` from a file path.
+    ///
+    /// When using a local Bot API server (see [`ApiMode::Local`]), the file is
+    /// not uploaded, instead its path is sent as a `file://` URI.
+    ///
+    /// [`ApiMode::Local`]: crate::ApiMode::Local
     pub fn file(path: impl Into<PathBuf>) -> Self {
         Self::new(File(path.into()))
     }
@@ -106,10 +125,69 @@ impl InputFile {
         Self::new(Read(Read::new(Arc::new(TakeCell::new(it)))))
     }
 
-    /// Shorthand for `Self { file_name: None, inner, id: default() }`
+    /// Sets a progress observer, which is called as the file is being
+    /// uploaded.
+    ///
+    /// The observer is called each time a chunk of the file is sent (see
+    /// [`UploadProgress`]), with the number of bytes sent so far and the size
+    /// of the file, if it's known (it's unknown for [`InputFile::read`]).
+    /// Files, which are not uploaded (e.g. [`InputFile::url`]), are not
+    /// observed.
+    ///
+    /// Note that the observer is called from the task, which sends the
+    /// request, so it should be cheap. If a request is sent multiple times
+    /// (e.g. retried), the progress starts over.
+    ///
+    /// To observe the progress of all files of a request (e.g. of
+    /// [`SendMediaGroup`]), see [`MultipartRequest::on_progress`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{
+    ///     prelude::*,
+    ///     types::{ChatId, InputFile},
+    /// };
+    /// use tokio::sync::watch;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let bot = Bot::new("TOKEN");
+    /// let chat_id = ChatId(42);
+    ///
+    /// let status = bot.send_message(chat_id, "uploading...").await?;
+    ///
+    /// let (tx, mut rx) = watch::channel(0);
+    /// let video = InputFile::file("video.mp4").on_progress(move |p| {
+    ///     if let Some(total) = p.total.filter(|&t| t != 0) {
+    ///         tx.send_replace(p.sent * 100 / total);
+    ///     }
+    /// });
+    ///
+    /// let progress_bot = bot.clone();
+    /// tokio::spawn(async move {
+    ///     while rx.changed().await.is_ok() {
+    ///         let text = format!("uploading {}%", *rx.borrow_and_update());
+    ///         let _ = progress_bot.edit_message_text(chat_id, status.id, text).await;
+    ///     }
+    /// });
+    ///
+    /// bot.send_video(chat_id, video).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`SendMediaGroup`]: crate::payloads::SendMediaGroup
+    /// [`MultipartRequest::on_progress`]: crate::requests::MultipartRequest::on_progress
+    pub fn on_progress<F>(self, on_progress: F) -> Self
+    where
+        F: Fn(UploadProgress) + Send + Sync + 'static,
+    {
+        Self { on_progress: Some(OnProgress(Arc::new(on_progress))), ..self }
+    }
+
+    /// Shorthand for `Self { file_name: None, inner, id: default(), .. }`
     /// (private because `InnerFile` is private implementation detail)
     fn new(inner: InnerFile) -> Self {
-        Self { file_name: None, inner, id: OnceCell::new() }
+        Self { file_name: None, inner, id: OnceCell::new(), on_progress: None }
     }
 
     /// Returns id of this file.
@@ -126,6 +204,15 @@ impl InputFile {
         !matches!(self.inner, Url(_) | FileId(_))
     }
 
+    /// Returns the `file://` URI of this file, if it's a file from the file
+    /// system (see [`InputFile::file`]).
+    pub(crate) fn file_uri(&self) -> Option<String> {
+        match &self.inner {
+            File(path) => Some(file_uri(path)),
+            _ => None,
+        }
+    }
+
     /// Takes this file out.
     ///
     /// **Note**: this replaces `self` with a dummy value, this function should
@@ -168,6 +255,15 @@ impl InputFile {
     }
 }
 
+/// Returns `file://` URI of the file at `path`.
+fn file_uri(path: &Path) -> String {
+    std::path::absolute(path)
+        .ok()
+        .and_then(|path| url::Url::from_file_path(path).ok())
+        .map(String::from)
+        .unwrap_or_else(|| format!("file://{}", path.display()))
+}
+
 impl fmt::Debug for InnerFile {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -181,6 +277,12 @@ impl fmt::Debug for InnerFile {
     }
 }
 
+impl fmt::Debug for OnProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnProgress").finish_non_exhaustive()
+    }
+}
+
 impl Serialize for InputFile {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -195,37 +297,40 @@ impl Serialize for InputFile {
 impl InputFile {
     pub(crate) fn into_part(mut self) -> Option<impl Future<Output = Part>> {
         let filename = self.take_or_guess_filename();
+        let on_progress = self.on_progress.take();
 
-        match self.inner {
+        let part = match self.inner {
             // Url and FileId are serialized just as strings, they don't need additional parts
             Url(_) | FileId(_) => None,
 
             File(path_to_file) => {
                 let fut = async {
-                    let body = match tokio::fs::File::open(path_to_file).await {
+                    let part = match tokio::fs::File::open(path_to_file).await {
                         Ok(file) => {
-                            let file = FramedRead::new(file, BytesDecoder);
-
-                            Body::wrap_stream(file)
-                        }
-                        Err(err) => {
-                            // explicit type needed for `Bytes: From<?T>` in `wrap_stream`
-                            let err = Err::<Bytes, _>(err);
-                            Body::wrap_stream(stream::iter([err]))
+                            let size = file.metadata().await.ok().map(|m| m.len());
+                            Part::stream(FramedRead::new(file, BytesDecoder)).size(size)
                         }
+                        Err(err) => Part::stream(stream::iter([Err::<Bytes, _>(err)])),
                     };
 
-                    Part::stream(body).file_name(filename)
+                    part.file_name(filename)
                 };
 
                 Some(Either::Left(fut))
             }
             Bytes(data) => {
-                let stream = Part::stream(data).file_name(filename);
-                Some(Either::Right(Either::Left(ready(stream))))
+                let part = Part::bytes(data).file_name(filename);
+                Some(Either::Right(Either::Left(ready(part))))
             }
             Read(read) => Some(Either::Right(Either::Right(read.into_part(filename)))),
-        }
+        };
+
+        part.map(|part| {
+            part.map(move |part| match on_progress {
+                Some(OnProgress(on_progress)) => part.observe(move |p| on_progress(p)),
+                None => part,
+            })
+        })
     }
 }
 
@@ -255,8 +360,7 @@ impl Read {
                 Ok(arc_box) => {
                     let fr = FramedRead::new(ExclusiveArcAsyncRead(arc_box), BytesDecoder);
 
-                    let body = Body::wrap_stream(fr);
-                    return Part::stream(body).file_name(filename);
+                    return Part::stream(fr).file_name(filename);
                 }
                 // move the arc back into `self`
                 Err(i) => self.inner = i,
@@ -265,12 +369,10 @@ impl Read {
 
         // Slow path: either wait until someone will read the whole `dyn AsyncRead` into
         // a buffer, or be the one who reads
-        let body = self.into_shared_body().await;
-
-        Part::stream(body).file_name(filename)
+        self.into_shared_part().await.file_name(filename)
     }
 
-    async fn into_shared_body(mut self) -> Body {
+    async fn into_shared_part(mut self) -> Part {
         match self.inner.take() {
             // Read `dyn AsyncRead` into a buffer
             Some(mut read_ref) => {
@@ -343,12 +445,12 @@ impl Read {
                     Err(_) => unreachable!(),
                 });
 
-                Body::wrap_stream(stream::iter(iter))
+                Part::stream(stream::iter(iter))
             }
 
             Err(err) => {
                 let err = Err::<Bytes, _>(Arc::clone(err));
-                Body::wrap_stream(stream::iter(iter::once(err)))
+                Part::stream(stream::iter(iter::once(err)))
             }
         }
     }
@@ -385,7 +487,7 @@ impl Decoder for BytesDecoder {
 
 /// An internal trait that is used in expansion of `impl_payload!` used to work
 /// with input-file-like things (`InputFile` itself, `Option<InputFile>`,
-/// `InputSticker`)
+/// `InputSticker`, `InputProfilePhoto`, `InputStoryContent`)
 pub(crate) trait InputFileLike {
     fn copy_into(&self, into: &mut dyn FnMut(InputFile));
 
@@ -425,3 +527,53 @@ impl InputFileLike for InputSticker {
         self.sticker.move_into(into)
     }
 }
+
+impl InputFileLike for InputProfilePhoto {
+    fn copy_into(&self, into: &mut dyn FnMut(InputFile)) {
+        self.file().copy_into(into)
+    }
+
+    fn move_into(&mut self, into: &mut dyn FnMut(InputFile)) {
+        self.file_mut().move_into(into)
+    }
+}
+
+impl InputFileLike for InputStoryContent {
+    fn copy_into(&self, into: &mut dyn FnMut(InputFile)) {
+        self.file().copy_into(into)
+    }
+
+    fn move_into(&mut self, into: &mut dyn FnMut(InputFile)) {
+        self.file_mut().move_into(into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::TryStreamExt;
+
+    use super::InputFile;
+    use crate::net::multipart::{PartBody, UploadProgress};
+
+    #[tokio::test]
+    async fn on_progress() {
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let file = InputFile::memory(vec![0; 100_000]).on_progress({
+            let progress = Arc::clone(&progress);
+            move |p| progress.lock().unwrap().push(p)
+        });
+
+        let part = file.into_part().unwrap().await;
+        let PartBody::Stream(body) = part.body else { panic!("body is not observed") };
+        let body: Vec<_> = body.try_collect().await.unwrap();
+        assert_eq!(body.concat().len(), 100_000);
+
+        let total = Some(100_000);
+        assert_eq!(
+            *progress.lock().unwrap(),
+            [UploadProgress { sent: 65_536, total }, UploadProgress { sent: 100_000, total }]
+        );
+    }
+}
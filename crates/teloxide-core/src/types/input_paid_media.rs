@@ -0,0 +1,56 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::InputFile;
+
+/// This object describes the paid media to be sent.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputpaidmedia).
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum InputPaidMedia {
+    Photo(InputPaidMediaPhoto),
+    Video(InputPaidMediaVideo),
+}
+
+/// The paid media to send is a photo.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputpaidmediaphoto).
+#[derive(Clone, Debug, Serialize)]
+pub struct InputPaidMediaPhoto {
+    /// File to send.
+    pub media: InputFile,
+}
+
+/// The paid media to send is a video.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputpaidmediavideo).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct InputPaidMediaVideo {
+    /// File to send.
+    pub media: InputFile,
+
+    /// Thumbnail of the file sent; can be ignored if thumbnail generation for
+    /// the file is supported server-side. The thumbnail should be in JPEG
+    /// format and less than 200 kB in size. A thumbnail‘s width and height
+    /// should not exceed 320. Ignored if the file is not uploaded using
+    /// multipart/form-data.
+    pub thumbnail: Option<InputFile>,
+
+    /// Video width.
+    pub width: Option<u16>,
+
+    /// Video height.
+    pub height: Option<u16>,
+
+    /// Video duration in seconds.
+    pub duration: Option<u16>,
+
+    /// Pass `true`, if the uploaded video is suitable for streaming.
+    pub supports_streaming: Option<bool>,
+}
+
+mod impls;
@@ -0,0 +1,120 @@
+use std::iter;
+
+use crate::types::{InputFile, InputPaidMedia, InputPaidMediaPhoto, InputPaidMediaVideo};
+
+impl InputPaidMediaPhoto {
+    pub const fn new(media: InputFile) -> Self {
+        Self { media }
+    }
+
+    pub fn media(mut self, val: InputFile) -> Self {
+        self.media = val;
+        self
+    }
+}
+
+impl InputPaidMediaVideo {
+    pub const fn new(media: InputFile) -> Self {
+        Self {
+            media,
+            thumbnail: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: None,
+        }
+    }
+
+    pub fn media(mut self, val: InputFile) -> Self {
+        self.media = val;
+        self
+    }
+
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
+        self
+    }
+
+    pub const fn width(mut self, val: u16) -> Self {
+        self.width = Some(val);
+        self
+    }
+
+    pub const fn height(mut self, val: u16) -> Self {
+        self.height = Some(val);
+        self
+    }
+
+    pub const fn duration(mut self, val: u16) -> Self {
+        self.duration = Some(val);
+        self
+    }
+
+    pub const fn supports_streaming(mut self, val: bool) -> Self {
+        self.supports_streaming = Some(val);
+        self
+    }
+}
+
+impl From<InputPaidMediaPhoto> for InputPaidMedia {
+    fn from(photo: InputPaidMediaPhoto) -> Self {
+        Self::Photo(photo)
+    }
+}
+
+impl From<InputPaidMediaVideo> for InputPaidMedia {
+    fn from(video: InputPaidMediaVideo) -> Self {
+        Self::Video(video)
+    }
+}
+
+impl InputPaidMedia {
+    /// Returns an iterator of all files in this input paid media
+    pub(crate) fn files(&self) -> impl Iterator<Item = &InputFile> {
+        use InputPaidMedia::*;
+
+        let (media, thumbnail) = match self {
+            Photo(InputPaidMediaPhoto { media }) => (media, None),
+            Video(InputPaidMediaVideo { media, thumbnail, .. }) => (media, thumbnail.as_ref()),
+        };
+
+        iter::once(media).chain(thumbnail)
+    }
+
+    /// Returns an iterator of all files in this input paid media
+    pub(crate) fn files_mut(&mut self) -> impl Iterator<Item = &mut InputFile> {
+        use InputPaidMedia::*;
+
+        let (media, thumbnail) = match self {
+            Photo(InputPaidMediaPhoto { media }) => (media, None),
+            Video(InputPaidMediaVideo { media, thumbnail, .. }) => (media, thumbnail.as_mut()),
+        };
+
+        iter::once(media).chain(thumbnail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn photo_serialize() {
+        let expected_json = r#"{"type":"photo","media":"123456"}"#;
+        let photo = InputPaidMedia::Photo(InputPaidMediaPhoto::new(InputFile::file_id("123456")));
+
+        let actual_json = serde_json::to_string(&photo).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+
+    #[test]
+    fn video_serialize() {
+        let expected_json = r#"{"type":"video","media":"123456","width":640}"#;
+        let video = InputPaidMedia::Video(
+            InputPaidMediaVideo::new(InputFile::file_id("123456")).width(640),
+        );
+
+        let actual_json = serde_json::to_string(&video).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+}
@@ -0,0 +1,43 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::InputFile;
+
+/// This object describes a profile photo to set.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputprofilephoto).
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum InputProfilePhoto {
+    Static(InputProfilePhotoStatic),
+    Animated(InputProfilePhotoAnimated),
+}
+
+/// A static profile photo in the .JPG format.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputprofilephotostatic).
+#[derive(Clone, Debug, Serialize)]
+pub struct InputProfilePhotoStatic {
+    /// The static profile photo. Profile photos can't be reused and can only be
+    /// uploaded as a new file.
+    pub photo: InputFile,
+}
+
+/// An animated profile photo in the MPEG4 format.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputprofilephotoanimated).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct InputProfilePhotoAnimated {
+    /// The animated profile photo. Profile photos can't be reused and can only
+    /// be uploaded as a new file.
+    pub animation: InputFile,
+
+    /// Timestamp in seconds of the frame that will be used as the static
+    /// profile photo. Defaults to 0.0.
+    pub main_frame_timestamp: Option<f64>,
+}
+
+mod impls;
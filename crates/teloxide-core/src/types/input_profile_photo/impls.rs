@@ -0,0 +1,87 @@
+use crate::types::{
+    InputFile, InputProfilePhoto, InputProfilePhotoAnimated, InputProfilePhotoStatic,
+};
+
+impl InputProfilePhotoStatic {
+    pub const fn new(photo: InputFile) -> Self {
+        Self { photo }
+    }
+
+    pub fn photo(mut self, val: InputFile) -> Self {
+        self.photo = val;
+        self
+    }
+}
+
+impl InputProfilePhotoAnimated {
+    pub const fn new(animation: InputFile) -> Self {
+        Self { animation, main_frame_timestamp: None }
+    }
+
+    pub fn animation(mut self, val: InputFile) -> Self {
+        self.animation = val;
+        self
+    }
+
+    pub fn main_frame_timestamp(mut self, val: f64) -> Self {
+        self.main_frame_timestamp = Some(val);
+        self
+    }
+}
+
+impl InputProfilePhoto {
+    /// Returns a reference to the file of this input profile photo.
+    pub(crate) fn file(&self) -> &InputFile {
+        match self {
+            Self::Static(InputProfilePhotoStatic { photo }) => photo,
+            Self::Animated(InputProfilePhotoAnimated { animation, .. }) => animation,
+        }
+    }
+
+    /// Returns a mutable reference to the file of this input profile photo.
+    pub(crate) fn file_mut(&mut self) -> &mut InputFile {
+        match self {
+            Self::Static(InputProfilePhotoStatic { photo }) => photo,
+            Self::Animated(InputProfilePhotoAnimated { animation, .. }) => animation,
+        }
+    }
+}
+
+impl From<InputProfilePhotoStatic> for InputProfilePhoto {
+    fn from(photo: InputProfilePhotoStatic) -> Self {
+        Self::Static(photo)
+    }
+}
+
+impl From<InputProfilePhotoAnimated> for InputProfilePhoto {
+    fn from(animation: InputProfilePhotoAnimated) -> Self {
+        Self::Animated(animation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_serialize() {
+        let expected_json = r#"{"type":"static","photo":"123456"}"#;
+        let photo =
+            InputProfilePhoto::Static(InputProfilePhotoStatic::new(InputFile::file_id("123456")));
+
+        let actual_json = serde_json::to_string(&photo).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+
+    #[test]
+    fn animated_serialize() {
+        let expected_json =
+            r#"{"type":"animated","animation":"123456","main_frame_timestamp":1.5}"#;
+        let photo = InputProfilePhoto::Animated(
+            InputProfilePhotoAnimated::new(InputFile::file_id("123456")).main_frame_timestamp(1.5),
+        );
+
+        let actual_json = serde_json::to_string(&photo).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+}
@@ -1,16 +1,16 @@
-use serde::Serialize;
+//! Generated by `codegen_types`, do not edit by hand.
 
-use crate::types::{InputFile, MaskPosition};
+use serde::Serialize;
 
-use super::StickerFormat;
+use crate::types::{InputFile, MaskPosition, StickerFormat};
 
 /// This object describes a sticker to be added to a sticker set.
 #[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, Serialize)]
 pub struct InputSticker {
     /// The added sticker. Pass a file_id as a String to send a file that
-    /// already exists on the Telegram servers, pass an HTTP URL as a String
-    /// for Telegram to get a file from the Internet, upload a new one using
+    /// already exists on the Telegram servers, pass an HTTP URL as a String for
+    /// Telegram to get a file from the Internet, upload a new one using
     /// multipart/form-data, or pass “attach://<file_attach_name>” to upload a
     /// new one using multipart/form-data under <file_attach_name> name.
     /// Animated and video stickers can't be uploaded via HTTP URL.
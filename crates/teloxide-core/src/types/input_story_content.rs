@@ -0,0 +1,52 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::InputFile;
+
+/// This object describes the content of a story to post.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputstorycontent).
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum InputStoryContent {
+    Photo(InputStoryContentPhoto),
+    Video(InputStoryContentVideo),
+}
+
+/// Describes a photo to post as a story.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputstorycontentphoto).
+#[derive(Clone, Debug, Serialize)]
+pub struct InputStoryContentPhoto {
+    /// The photo to post as a story. The photo must be of the size 1080x1920
+    /// and must not exceed 10 MB. The photo can't be reused and can only be
+    /// uploaded as a new file.
+    pub photo: InputFile,
+}
+
+/// Describes a video to post as a story.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputstorycontentvideo).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct InputStoryContentVideo {
+    /// The video to post as a story. The video must be of the size 720x1280,
+    /// streamable, encoded with H.265 codec, with key frames added each second
+    /// in the MPEG4 format, and must not exceed 30 MB. The video can't be
+    /// reused and can only be uploaded as a new file.
+    pub video: InputFile,
+
+    /// Precise duration of the video in seconds; 0-60.
+    pub duration: Option<f64>,
+
+    /// Timestamp in seconds of the frame that will be used as the static cover
+    /// for the story. Defaults to 0.0.
+    pub cover_frame_timestamp: Option<f64>,
+
+    /// Pass `true` if the video has no sound.
+    pub is_animation: Option<bool>,
+}
+
+mod impls;
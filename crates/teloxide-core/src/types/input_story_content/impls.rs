@@ -0,0 +1,94 @@
+use crate::types::{InputFile, InputStoryContent, InputStoryContentPhoto, InputStoryContentVideo};
+
+impl InputStoryContentPhoto {
+    pub const fn new(photo: InputFile) -> Self {
+        Self { photo }
+    }
+
+    pub fn photo(mut self, val: InputFile) -> Self {
+        self.photo = val;
+        self
+    }
+}
+
+impl InputStoryContentVideo {
+    pub const fn new(video: InputFile) -> Self {
+        Self { video, duration: None, cover_frame_timestamp: None, is_animation: None }
+    }
+
+    pub fn video(mut self, val: InputFile) -> Self {
+        self.video = val;
+        self
+    }
+
+    pub fn duration(mut self, val: f64) -> Self {
+        self.duration = Some(val);
+        self
+    }
+
+    pub fn cover_frame_timestamp(mut self, val: f64) -> Self {
+        self.cover_frame_timestamp = Some(val);
+        self
+    }
+
+    pub fn is_animation(mut self, val: bool) -> Self {
+        self.is_animation = Some(val);
+        self
+    }
+}
+
+impl InputStoryContent {
+    /// Returns a reference to the file of this input story content.
+    pub(crate) fn file(&self) -> &InputFile {
+        match self {
+            Self::Photo(InputStoryContentPhoto { photo }) => photo,
+            Self::Video(InputStoryContentVideo { video, .. }) => video,
+        }
+    }
+
+    /// Returns a mutable reference to the file of this input story content.
+    pub(crate) fn file_mut(&mut self) -> &mut InputFile {
+        match self {
+            Self::Photo(InputStoryContentPhoto { photo }) => photo,
+            Self::Video(InputStoryContentVideo { video, .. }) => video,
+        }
+    }
+}
+
+impl From<InputStoryContentPhoto> for InputStoryContent {
+    fn from(photo: InputStoryContentPhoto) -> Self {
+        Self::Photo(photo)
+    }
+}
+
+impl From<InputStoryContentVideo> for InputStoryContent {
+    fn from(video: InputStoryContentVideo) -> Self {
+        Self::Video(video)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn photo_serialize() {
+        let expected_json = r#"{"type":"photo","photo":"123456"}"#;
+        let content =
+            InputStoryContent::Photo(InputStoryContentPhoto::new(InputFile::file_id("123456")));
+
+        let actual_json = serde_json::to_string(&content).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+
+    #[test]
+    fn video_serialize() {
+        let expected_json = r#"{"type":"video","video":"123456","duration":12.5}"#;
+        let content = InputStoryContent::Video(
+            InputStoryContentVideo::new(InputFile::file_id("123456")).duration(12.5),
+        );
+
+        let actual_json = serde_json::to_string(&content).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+}
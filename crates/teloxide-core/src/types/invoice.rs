@@ -1,10 +1,13 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// This object contains basic information about an invoice.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#invoice).
-#[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct Invoice {
     /// Product name.
     pub title: String,
@@ -26,8 +29,8 @@ pub struct Invoice {
     /// Total price in the smallest units of the currency (integer, **not**
     /// float/double). For example, for a price of `US$ 1.45` pass `amount =
     /// 145`. See the exp parameter in [`currencies.json`], it shows the number
-    /// of digits past the decimal point for each currency (2 for the
-    /// majority of currencies).
+    /// of digits past the decimal point for each currency (2 for the majority
+    /// of currencies).
     ///
     /// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
     pub total_amount: u32,
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatAdministratorRights, RequestId};
@@ -9,7 +11,10 @@ use crate::types::{ChatAdministratorRights, RequestId};
 /// the chat if appropriate. [More about requesting chats »]
 ///
 /// [More about requesting chats »]: https://core.telegram.org/bots/features#chat-and-user-selection
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct KeyboardButtonRequestChat {
     /// identifier of the request, which will be received back in the
@@ -69,84 +74,4 @@ pub struct KeyboardButtonRequestChat {
     pub request_photo: bool,
 }
 
-impl KeyboardButtonRequestChat {
-    /// Creates a new [`KeyboardButtonRequestChat`].
-    pub fn new(request_id: RequestId, chat_is_channel: bool) -> Self {
-        Self {
-            request_id,
-            chat_is_channel,
-            chat_is_forum: None,
-            chat_has_username: None,
-            chat_is_created: None,
-            user_administrator_rights: None,
-            bot_administrator_rights: None,
-            bot_is_member: false,
-            request_title: false,
-            request_username: false,
-            request_photo: false,
-        }
-    }
-
-    /// Setter for `chat_is_forum` field.
-    #[must_use]
-    pub fn chat_is_forum(mut self, value: bool) -> Self {
-        self.chat_is_forum = Some(value);
-        self
-    }
-
-    /// Setter for `chat_has_username` field.
-    #[must_use]
-    pub fn chat_has_username(mut self, value: bool) -> Self {
-        self.chat_has_username = Some(value);
-        self
-    }
-
-    /// Setter for `chat_is_created` field.
-    #[must_use]
-    pub fn chat_is_created(mut self, value: bool) -> Self {
-        self.chat_is_created = Some(value);
-        self
-    }
-
-    /// Request a chat where the user has the specified administrator rights.
-    #[must_use]
-    pub fn user_administrator_rights(mut self, rights: ChatAdministratorRights) -> Self {
-        self.user_administrator_rights = Some(rights);
-        self
-    }
-
-    /// Request a chat where the bot has the specified administrator rights.
-    #[must_use]
-    pub fn bot_administrator_rights(mut self, rights: ChatAdministratorRights) -> Self {
-        self.bot_administrator_rights = Some(rights);
-        self
-    }
-
-    /// Setter for `bot_is_member` field.
-    #[must_use]
-    pub fn bot_is_member(mut self, value: bool) -> Self {
-        self.bot_is_member = value;
-        self
-    }
-
-    /// Setter for `request_title` field.
-    #[must_use]
-    pub fn request_title(mut self) -> Self {
-        self.request_title = true;
-        self
-    }
-
-    /// Setter for `request_username` field.
-    #[must_use]
-    pub fn request_username(mut self) -> Self {
-        self.request_username = true;
-        self
-    }
-
-    /// Setter for `request_photo` field.
-    #[must_use]
-    pub fn request_photo(mut self) -> Self {
-        self.request_photo = true;
-        self
-    }
-}
+mod impls;
@@ -0,0 +1,68 @@
+use crate::types::{KeyboardButtonRequestUsers, RequestId};
+
+impl KeyboardButtonRequestUsers {
+    /// Creates a new [`KeyboardButtonRequestUsers`].
+    pub fn new(request_id: RequestId) -> Self {
+        Self {
+            request_id,
+            user_is_bot: None,
+            user_is_premium: None,
+            max_quantity: 1,
+            request_name: false,
+            request_username: false,
+            request_photo: false,
+        }
+    }
+
+    /// Setter for `user_is_bot` field
+    #[must_use]
+    pub fn user_is_bot(mut self, value: bool) -> Self {
+        self.user_is_bot = Some(value);
+        self
+    }
+
+    /// Setter for `user_is_premium` field
+    #[must_use]
+    pub fn user_is_premium(mut self, value: bool) -> Self {
+        self.user_is_premium = Some(value);
+        self
+    }
+
+    /// Setter for `max_quantity` field, the value must be in the range 1..=10
+    #[must_use]
+    pub fn max_quantity(mut self, value: u8) -> Self {
+        assert!((1..=10).contains(&value));
+
+        self.max_quantity = value;
+        self
+    }
+
+    /// Setter for `request_name` field
+    #[must_use]
+    pub fn request_name(mut self) -> Self {
+        self.request_name = true;
+        self
+    }
+
+    /// Setter for `request_username` field
+    #[must_use]
+    pub fn request_username(mut self) -> Self {
+        self.request_username = true;
+        self
+    }
+
+    /// Setter for `request_photo` field
+    #[must_use]
+    pub fn request_photo(mut self) -> Self {
+        self.request_photo = true;
+        self
+    }
+}
+
+pub(super) fn one() -> u8 {
+    1
+}
+
+pub(super) fn is_one(value: &u8) -> bool {
+    *value == 1
+}
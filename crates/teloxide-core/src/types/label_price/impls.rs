@@ -0,0 +1,37 @@
+use crate::types::LabeledPrice;
+
+impl LabeledPrice {
+    pub fn new<S>(label: S, amount: u32) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { label: label.into(), amount }
+    }
+
+    pub fn label<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.label = val.into();
+        self
+    }
+
+    #[must_use]
+    pub fn amount(mut self, val: u32) -> Self {
+        self.amount = val;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let labeled_price = LabeledPrice { label: "Label".to_string(), amount: 60 };
+        let expected = r#"{"label":"Label","amount":60}"#;
+        let actual = serde_json::to_string(&labeled_price).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,8 +1,12 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// Describes the options used for link preview generation.
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Hash, Eq, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct LinkPreviewOptions {
     /// `true`, if the link preview is disabled
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
@@ -30,21 +34,4 @@ pub struct LinkPreviewOptions {
     pub show_above_text: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let data = r#"
-        {
-            "is_disabled": true,
-            "url": "https://kernel.org/",
-            "prefer_small_media": false,
-            "prefer_large_media": true,
-            "show_above_text": true
-        }
-        "#;
-        serde_json::from_str::<LinkPreviewOptions>(data).unwrap();
-    }
-}
+mod impls;
@@ -0,0 +1,18 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::LinkPreviewOptions;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "is_disabled": true,
+            "url": "https://kernel.org/",
+            "prefer_small_media": false,
+            "prefer_large_media": true,
+            "show_above_text": true
+        }
+        "#;
+        serde_json::from_str::<LinkPreviewOptions>(data).unwrap();
+    }
+}
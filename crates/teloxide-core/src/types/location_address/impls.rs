@@ -0,0 +1,34 @@
+use crate::types::LocationAddress;
+
+impl LocationAddress {
+    pub fn new<S>(country_code: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { country_code: country_code.into(), state: None, city: None, street: None }
+    }
+
+    pub fn state<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.state = Some(val.into());
+        self
+    }
+
+    pub fn city<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.city = Some(val.into());
+        self
+    }
+
+    pub fn street<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.street = Some(val.into());
+        self
+    }
+}
@@ -0,0 +1,31 @@
+use crate::types::LoginUrl;
+
+impl LoginUrl {
+    #[must_use]
+    pub fn url(mut self, val: url::Url) -> Self {
+        self.url = val;
+        self
+    }
+
+    pub fn forward_text<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.forward_text = Some(val.into());
+        self
+    }
+
+    pub fn bot_username<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.bot_username = Some(val.into());
+        self
+    }
+
+    #[must_use]
+    pub fn request_write_access(mut self, val: bool) -> Self {
+        self.request_write_access = Some(val);
+        self
+    }
+}
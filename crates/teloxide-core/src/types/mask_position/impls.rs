@@ -0,0 +1,32 @@
+use crate::types::{MaskPoint, MaskPosition};
+
+impl MaskPosition {
+    #[must_use]
+    pub const fn new(point: MaskPoint, x_shift: f64, y_shift: f64, scale: f64) -> Self {
+        Self { point, x_shift, y_shift, scale }
+    }
+
+    #[must_use]
+    pub const fn point(mut self, val: MaskPoint) -> Self {
+        self.point = val;
+        self
+    }
+
+    #[must_use]
+    pub const fn x_shift(mut self, val: f64) -> Self {
+        self.x_shift = val;
+        self
+    }
+
+    #[must_use]
+    pub const fn y_shift(mut self, val: f64) -> Self {
+        self.y_shift = val;
+        self
+    }
+
+    #[must_use]
+    pub const fn scale(mut self, val: f64) -> Self {
+        self.scale = val;
+        self
+    }
+}
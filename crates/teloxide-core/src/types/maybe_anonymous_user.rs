@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{Chat, User};
@@ -11,59 +13,4 @@ pub enum MaybeAnonymousUser {
     Chat(Chat),
 }
 
-impl MaybeAnonymousUser {
-    pub fn is_user(&self) -> bool {
-        self.user().is_some()
-    }
-
-    pub fn is_chat(&self) -> bool {
-        self.chat().is_some()
-    }
-
-    #[must_use]
-    pub fn chat(&self) -> Option<&Chat> {
-        match self {
-            Self::Chat(chat) => Some(chat),
-            _ => None,
-        }
-    }
-
-    #[must_use]
-    pub fn user(&self) -> Option<&User> {
-        match self {
-            Self::User(user) => Some(user),
-            _ => None,
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn user_de() {
-        let json = r#"{
-            "id": 42,
-            "is_bot": false,
-            "first_name": "blah"
-        }"#;
-
-        let user: MaybeAnonymousUser = serde_json::from_str(json).unwrap();
-
-        assert!(user.user().is_some());
-    }
-
-    #[test]
-    fn chat_de() {
-        let json = r#"{
-            "id": -1001160242915,
-            "title": "a",
-            "type": "group"
-        }"#;
-
-        let chat: MaybeAnonymousUser = serde_json::from_str(json).unwrap();
-
-        assert!(chat.chat().is_some());
-    }
-}
+mod impls;
@@ -0,0 +1,60 @@
+use std::ops::Deref;
+
+use crate::types::{Me, User};
+
+impl Me {
+    /// Returns the username of the bot.
+    #[must_use]
+    pub fn username(&self) -> &str {
+        self.user.username.as_deref().expect("Bots must have usernames")
+    }
+
+    /// Returns a username mention of this bot.
+    #[must_use]
+    pub fn mention(&self) -> String {
+        format!("@{}", self.username())
+    }
+
+    /// Returns an URL that links to this bot in the form of `t.me/<...>`.
+    #[must_use]
+    pub fn tme_url(&self) -> url::Url {
+        format!("https://t.me/{}", self.username()).parse().unwrap()
+    }
+}
+
+impl Deref for Me {
+    type Target = User;
+
+    fn deref(&self) -> &Self::Target {
+        &self.user
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{Me, User, UserId};
+
+    #[test]
+    fn convenience_methods_work() {
+        let me = Me {
+            user: User {
+                id: UserId(42),
+                is_bot: true,
+                first_name: "First".to_owned(),
+                last_name: None,
+                username: Some("SomethingSomethingBot".to_owned()),
+                language_code: None,
+                is_premium: false,
+                added_to_attachment_menu: false,
+            },
+            can_join_groups: false,
+            can_read_all_group_messages: false,
+            supports_inline_queries: false,
+            can_connect_to_business: false,
+        };
+
+        assert_eq!(me.username(), "SomethingSomethingBot");
+        assert_eq!(me.mention(), "@SomethingSomethingBot");
+        assert_eq!(me.tme_url(), "https://t.me/SomethingSomethingBot".parse().unwrap());
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::WebAppInfo;
@@ -7,9 +9,11 @@ use crate::types::WebAppInfo;
 /// If a menu button other than `MenuButton::Default` is set for a private chat,
 /// then it is applied in the chat. Otherwise the default menu button is
 /// applied. By default, the menu button opens the list of bot commands.
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
 pub enum MenuButton {
     /// Represents a menu button, which opens the bot's list of commands.
     Commands,
@@ -0,0 +1,256 @@
+use crate::types::{MessageEntity, MessageEntityKind, User, UserId};
+
+impl MessageEntity {
+    #[must_use]
+    pub const fn new(kind: MessageEntityKind, offset: usize, length: usize) -> Self {
+        Self { kind, offset, length }
+    }
+
+    /// Create a message entity representing a bold text.
+    #[must_use]
+    pub const fn bold(offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::Bold, offset, length }
+    }
+
+    /// Create a message entity representing an italic text.
+    #[must_use]
+    pub const fn italic(offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::Italic, offset, length }
+    }
+
+    /// Create a message entity representing an underline text.
+    #[must_use]
+    pub const fn underline(offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::Underline, offset, length }
+    }
+
+    /// Create a message entity representing a strikethrough text.
+    #[must_use]
+    pub const fn strikethrough(offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::Strikethrough, offset, length }
+    }
+
+    /// Create a message entity representing a spoiler text.
+    #[must_use]
+    pub const fn spoiler(offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::Spoiler, offset, length }
+    }
+
+    /// Create a message entity representing a monowidth text.
+    #[must_use]
+    pub const fn code(offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::Code, offset, length }
+    }
+
+    /// Create a message entity representing a monowidth block.
+    #[must_use]
+    pub const fn pre(language: Option<String>, offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::Pre { language }, offset, length }
+    }
+
+    /// Create a message entity representing a clickable text URL.
+    #[must_use]
+    pub const fn text_link(url: url::Url, offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::TextLink { url }, offset, length }
+    }
+
+    /// Create a message entity representing a text mention.
+    ///
+    /// # Note
+    ///
+    /// If you don't have a complete [`User`] value, please use
+    /// [`MessageEntity::text_mention_id`] instead.
+    #[must_use]
+    pub const fn text_mention(user: User, offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::TextMention { user }, offset, length }
+    }
+
+    /// Create a message entity representing a text link in the form of
+    /// `tg://user/?id=...` that mentions user with `user_id`.
+    #[must_use]
+    pub fn text_mention_id(user_id: UserId, offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::TextLink { url: user_id.url() }, offset, length }
+    }
+
+    /// Create a message entity representing a custom emoji.
+    #[must_use]
+    pub const fn custom_emoji(custom_emoji_id: String, offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::CustomEmoji { custom_emoji_id }, offset, length }
+    }
+
+    #[must_use]
+    pub fn kind(mut self, val: MessageEntityKind) -> Self {
+        self.kind = val;
+        self
+    }
+
+    #[must_use]
+    pub const fn offset(mut self, val: usize) -> Self {
+        self.offset = val;
+        self
+    }
+
+    #[must_use]
+    pub const fn length(mut self, val: usize) -> Self {
+        self.length = val;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MessageEntityRef;
+    use cool_asserts::assert_matches;
+    use MessageEntityKind::*;
+
+    #[test]
+    fn recursive_kind() {
+        use serde_json::from_str;
+
+        assert_eq!(
+            MessageEntity {
+                kind: MessageEntityKind::TextLink {
+                    url: url::Url::parse("https://example.com").unwrap(),
+                },
+                offset: 1,
+                length: 2,
+            },
+            from_str::<MessageEntity>(
+                r#"{"type":"text_link","url":"https://example.com","offset":1,"length":2}"#
+            )
+            .unwrap()
+        );
+    }
+
+    // https://github.com/teloxide/teloxide/issues/1062
+    #[test]
+    fn blockquote() {
+        use serde_json::from_str;
+
+        assert_eq!(
+            MessageEntity { kind: MessageEntityKind::Blockquote, offset: 32, length: 92 },
+            from_str::<MessageEntity>(r#"{"type": "blockquote", "offset": 32, "length": 92}"#)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn expandable_blockquote() {
+        use serde_json::from_str;
+
+        assert_eq!(
+            MessageEntity {
+                kind: MessageEntityKind::ExpandableBlockquote,
+                offset: 69,
+                length: 420
+            },
+            from_str::<MessageEntity>(
+                r#"{"type": "expandable_blockquote", "offset": 69, "length": 420}"#
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn pre() {
+        use serde_json::from_str;
+
+        assert_eq!(
+            MessageEntity {
+                kind: MessageEntityKind::Pre { language: Some("rust".to_string()) },
+                offset: 1,
+                length: 2,
+            },
+            from_str::<MessageEntity>(r#"{"type":"pre","offset":1,"length":2,"language":"rust"}"#)
+                .unwrap()
+        );
+    }
+
+    // https://github.com/teloxide/teloxide-core/pull/145
+    #[test]
+    fn pre_with_none_language() {
+        use serde_json::to_string;
+
+        assert_eq!(
+            to_string(&MessageEntity {
+                kind: MessageEntityKind::Pre { language: None },
+                offset: 1,
+                length: 2,
+            })
+            .unwrap()
+            .find("language"),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_быба() {
+        let parsed = MessageEntityRef::parse(
+            "быба",
+            &[
+                MessageEntity { kind: Strikethrough, offset: 0, length: 1 },
+                MessageEntity { kind: Bold, offset: 1, length: 1 },
+                MessageEntity { kind: Italic, offset: 2, length: 1 },
+                MessageEntity { kind: Code, offset: 3, length: 1 },
+            ],
+        );
+
+        assert_matches!(
+            parsed,
+            [entity if entity.text() == "б" && entity.kind() == &Strikethrough, entity if entity.text() == "ы" && entity.kind() == &Bold, entity if entity.text() == "б" && entity.kind() == &Italic, entity if entity.text() == "а" && entity.kind() == &Code]
+        );
+    }
+
+    #[test]
+    fn parse_symbol_24bit() {
+        let parsed = MessageEntityRef::parse(
+            "xx আ #tt",
+            &[MessageEntity { kind: Hashtag, offset: 5, length: 3 }],
+        );
+
+        assert_matches!(parsed, [entity if entity.text() == "#tt" && entity.kind() == &Hashtag]);
+    }
+
+    #[test]
+    fn parse_enclosed() {
+        let parsed = MessageEntityRef::parse(
+            "b i b",
+            // For some reason this is how telegram encodes <b>b <i>i<i/> b<b/>
+            &[
+                MessageEntity { kind: Bold, offset: 0, length: 2 },
+                MessageEntity { kind: Bold, offset: 2, length: 3 },
+                MessageEntity { kind: Italic, offset: 2, length: 1 },
+            ],
+        );
+
+        assert_matches!(
+            parsed,
+            [entity if entity.text() == "b " && entity.kind() == &Bold, entity if entity.text() == "i b" && entity.kind() == &Bold, entity if entity.text() == "i" && entity.kind() == &Italic]
+        );
+    }
+
+    #[test]
+    fn parse_nothing() {
+        let parsed = MessageEntityRef::parse("a", &[]);
+        assert_eq!(parsed, []);
+    }
+
+    #[test]
+    fn parse_empty() {
+        // It should be impossible for this to be returned from telegram, but just to be
+        // sure
+        let parsed = MessageEntityRef::parse(
+            "",
+            &[
+                MessageEntity { kind: Bold, offset: 0, length: 0 },
+                MessageEntity { kind: Italic, offset: 0, length: 0 },
+            ],
+        );
+
+        assert_matches!(
+            parsed,
+            [entity if entity.text() == "" && entity.kind() == &Bold, entity if entity.text() == "" && entity.kind() == &Italic]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use std::{cmp, ops::Range};
+
+use crate::types::{MessageEntity, MessageEntityKind};
+
+/// A "parsed" [`MessageEntity`].
+///
+/// [`MessageEntity`] has offsets in UTF-**16** code units, but in Rust we
+/// mostly work with UTF-**8**. In order to use an entity we need to convert
+/// UTF-16 offsets to UTF-8 ones. This type represents a message entity with
+/// converted offsets and a reference to the text.
+///
+/// You can get [`MessageEntityRef`]s by calling [`parse_entities`] and
+/// [`parse_caption_entities`] methods of [`Message`] or by calling
+/// [`MessageEntityRef::parse`].
+///
+/// [`parse_entities`]: crate::types::Message::parse_entities
+/// [`parse_caption_entities`]: crate::types::Message::parse_caption_entities
+/// [`Message`]: crate::types::Message
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MessageEntityRef<'a> {
+    message: &'a str,
+    range: Range<usize>,
+    kind: &'a MessageEntityKind,
+}
+
+impl<'a> MessageEntityRef<'a> {
+    /// Returns kind of this entity.
+    #[must_use]
+    pub fn kind(&self) -> &'a MessageEntityKind {
+        self.kind
+    }
+
+    /// Returns the text that this entity is related to.
+    #[must_use]
+    pub fn text(&self) -> &'a str {
+        &self.message[self.range.clone()]
+    }
+
+    /// Returns range that this entity is related to.
+    ///
+    /// The range is in bytes for UTF-8 encoding i.e. you can use it with common
+    /// Rust strings.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the offset (in bytes, for UTF-8) to the start of this entity in
+    /// the original message.
+    #[must_use]
+    pub fn start(&self) -> usize {
+        self.range.start
+    }
+
+    /// Returns the offset (in bytes, for UTF-8) to the end of this entity in
+    /// the original message.
+    #[must_use]
+    pub fn end(&self) -> usize {
+        self.range.end
+    }
+
+    /// Returns the length of this entity in bytes for UTF-8 encoding.
+    #[allow(clippy::len_without_is_empty)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns the full text of the original message.
+    #[must_use]
+    pub fn message_text(&self) -> &'a str {
+        self.message
+    }
+
+    /// Parses telegram [`MessageEntity`]s converting offsets to UTF-8.
+    #[must_use]
+    pub fn parse(text: &'a str, entities: &'a [MessageEntity]) -> Vec<Self> {
+        // This creates entities with **wrong** offsets (UTF-16) that we later patch.
+        let mut entities: Vec<_> = entities
+            .iter()
+            .map(|e| Self { message: text, range: e.offset..e.offset + e.length, kind: &e.kind })
+            .collect();
+
+        // Convert offsets
+
+        // References to all offsets that need patching
+        let mut offsets: Vec<&mut usize> = entities
+            .iter_mut()
+            .flat_map(|Self { range: Range { start, end }, .. }| [start, end])
+            .collect();
+
+        // Sort in decreasing order, so the smallest elements are at the end and can be
+        // removed more easily
+        offsets.sort_unstable_by_key(|&&mut offset| cmp::Reverse(offset));
+
+        let _ = text
+            .chars()
+            .chain(['\0']) // this is needed to process offset pointing at the end of the string
+            .try_fold((0, 0), |(len_utf8, len_utf16), c| {
+                // Stop if there are no more offsets to patch
+                if offsets.is_empty() {
+                    return None;
+                }
+
+                // Patch all offsets that can be patched
+                while offsets.last().map(|&&mut offset| offset <= len_utf16).unwrap_or(false) {
+                    let offset = offsets.pop().unwrap();
+                    assert_eq!(*offset, len_utf16, "Invalid utf-16 offset");
+
+                    // Patch the offset to be UTF-8
+                    *offset = len_utf8;
+                }
+
+                // Update "running" length
+                Some((len_utf8 + c.len_utf8(), len_utf16 + c.len_utf16()))
+            });
+
+        entities
+    }
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::MessageOrigin;
+
+impl MessageOrigin {
+    pub fn date(&self) -> DateTime<Utc> {
+        *match self {
+            Self::User { date, .. } => date,
+            Self::HiddenUser { date, .. } => date,
+            Self::Chat { date, .. } => date,
+            Self::Channel { date, .. } => date,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -5,7 +7,6 @@ use crate::types::{Chat, MessageId, ReactionType};
 
 /// This object represents reaction changes on a message with anonymous
 /// reactions.
-#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageReactionCountUpdated {
     /// The chat containing the message
@@ -25,7 +26,6 @@ pub struct MessageReactionCountUpdated {
 
 /// Represents a reaction added to a message along with the number of times it
 /// was added.
-#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ReactionCount {
     /// Type of the reaction
@@ -35,39 +35,4 @@ pub struct ReactionCount {
     pub total_count: u64,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let data = r#"
-        {
-            "chat": {
-                "id": -1002236736395,
-                "title": "Test",
-                "type": "channel"
-            },
-            "message_id": 36,
-            "date": 1721306391,
-            "reactions": [
-                {
-                    "type": {
-                        "type": "emoji",
-                        "emoji": "🗿"
-                    },
-                    "total_count": 2
-                },
-                {
-                    "type": {
-                        "type": "emoji",
-                        "emoji": "🌭"
-                    },
-                    "total_count": 1
-                }
-            ]
-        }
-        "#;
-        serde_json::from_str::<MessageReactionCountUpdated>(data).unwrap();
-    }
-}
+mod impls;
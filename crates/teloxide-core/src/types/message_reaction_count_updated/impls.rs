@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::MessageReactionCountUpdated;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "chat": {
+                "id": -1002236736395,
+                "title": "Test",
+                "type": "channel"
+            },
+            "message_id": 36,
+            "date": 1721306391,
+            "reactions": [
+                {
+                    "type": {
+                        "type": "emoji",
+                        "emoji": "🗿"
+                    },
+                    "total_count": 2
+                },
+                {
+                    "type": {
+                        "type": "emoji",
+                        "emoji": "🌭"
+                    },
+                    "total_count": 1
+                }
+            ]
+        }
+        "#;
+        serde_json::from_str::<MessageReactionCountUpdated>(data).unwrap();
+    }
+}
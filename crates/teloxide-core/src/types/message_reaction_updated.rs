@@ -1,11 +1,12 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Serialize};
 
-use crate::types::{Chat, MaybeAnonymousUser, MessageId, ReactionType, User};
+use crate::types::{Chat, MaybeAnonymousUser, MessageId, ReactionType};
 
 /// This object represents a change of a reaction on a message performed by a
 /// user.
-#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageReactionUpdated {
     /// The chat containing the message the user reacted to
@@ -16,9 +17,13 @@ pub struct MessageReactionUpdated {
     pub message_id: MessageId,
 
     /// The [`MaybeAnonymousUser::User`] that changed the reaction, if the user
-    /// isn't anonymous or the [`MaybeAnonymousUser::Chat`] on behalf of
-    /// which the reaction was changed, if the user is anonymous
-    #[serde(deserialize_with = "deserialize_actor", flatten)]
+    /// isn't anonymous or the [`MaybeAnonymousUser::Chat`] on behalf of which
+    /// the reaction was changed, if the user is anonymous
+    #[serde(
+        deserialize_with = "impls::deserialize_actor",
+        serialize_with = "impls::serialize_actor",
+        flatten
+    )]
     pub actor: MaybeAnonymousUser,
 
     /// Date of the change in Unix time
@@ -32,96 +37,4 @@ pub struct MessageReactionUpdated {
     pub new_reaction: Vec<ReactionType>,
 }
 
-impl MessageReactionUpdated {
-    #[must_use]
-    pub fn chat(&self) -> Option<&Chat> {
-        self.actor.chat()
-    }
-
-    #[must_use]
-    pub fn user(&self) -> Option<&User> {
-        self.actor.user()
-    }
-}
-
-#[derive(Deserialize)]
-struct ActorDe {
-    /// The user that changed the reaction, if the user isn't anonymous
-    user: Option<User>,
-    /// The chat on behalf of which the reaction was changed, if the user is
-    /// anonymous
-    actor_chat: Option<Chat>,
-}
-
-fn deserialize_actor<'d, D: Deserializer<'d>>(d: D) -> Result<MaybeAnonymousUser, D::Error> {
-    let ActorDe { user, actor_chat } = ActorDe::deserialize(d)?;
-
-    Ok(actor_chat.map(MaybeAnonymousUser::Chat).or(user.map(MaybeAnonymousUser::User)).unwrap())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize_user() {
-        let data = r#"
-        {
-            "chat": {
-                "id": -1002184233434,
-                "title": "Test",
-                "type": "supergroup"
-            },
-            "message_id": 35,
-            "user": {
-                "id": 1459074222,
-                "is_bot": false,
-                "first_name": "shadowchain",
-                "username": "shdwchn10",
-                "language_code": "en",
-                "is_premium": true
-            },
-            "date": 1721306082,
-            "old_reaction": [],
-            "new_reaction": [
-                {
-                    "type": "emoji",
-                    "emoji": "🌭"
-                }
-            ]
-        }
-        "#;
-        let message_reaction_update = serde_json::from_str::<MessageReactionUpdated>(data).unwrap();
-
-        assert!(message_reaction_update.actor.is_user());
-    }
-
-    #[test]
-    fn deserialize_chat() {
-        let data = r#"{
-            "chat": {
-                "id": -1002199793788,
-                "title": "тест",
-                "type": "supergroup"
-            },
-            "message_id": 2,
-            "actor_chat": {
-                "id": -1002199793788,
-                "title": "тест",
-                "type": "supergroup"
-            },
-            "date": 1723798597,
-            "old_reaction": [
-                {
-                    "type": "emoji",
-                    "emoji": "❤"
-                }
-            ],
-            "new_reaction": []
-        }"#;
-
-        let message_reaction_update = serde_json::from_str::<MessageReactionUpdated>(data).unwrap();
-
-        assert!(message_reaction_update.actor.is_chat())
-    }
-}
+mod impls;
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::{Chat, MaybeAnonymousUser, MessageReactionUpdated, User};
+
+impl MessageReactionUpdated {
+    #[must_use]
+    pub fn chat(&self) -> Option<&Chat> {
+        self.actor.chat()
+    }
+
+    #[must_use]
+    pub fn user(&self) -> Option<&User> {
+        self.actor.user()
+    }
+}
+
+#[derive(Deserialize)]
+struct ActorDe {
+    /// The user that changed the reaction, if the user isn't anonymous
+    user: Option<User>,
+    /// The chat on behalf of which the reaction was changed, if the user is
+    /// anonymous
+    actor_chat: Option<Chat>,
+}
+
+pub(super) fn deserialize_actor<'d, D: Deserializer<'d>>(
+    d: D,
+) -> Result<MaybeAnonymousUser, D::Error> {
+    let ActorDe { user, actor_chat } = ActorDe::deserialize(d)?;
+
+    Ok(actor_chat.map(MaybeAnonymousUser::Chat).or(user.map(MaybeAnonymousUser::User)).unwrap())
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize)]
+struct ActorSe<'a> {
+    user: Option<&'a User>,
+    actor_chat: Option<&'a Chat>,
+}
+
+pub(super) fn serialize_actor<S: Serializer>(
+    actor: &MaybeAnonymousUser,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    ActorSe { user: actor.user(), actor_chat: actor.chat() }.serialize(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_user() {
+        let data = r#"
+        {
+            "chat": {
+                "id": -1002184233434,
+                "title": "Test",
+                "type": "supergroup"
+            },
+            "message_id": 35,
+            "user": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain",
+                "username": "shdwchn10",
+                "language_code": "en",
+                "is_premium": true
+            },
+            "date": 1721306082,
+            "old_reaction": [],
+            "new_reaction": [
+                {
+                    "type": "emoji",
+                    "emoji": "🌭"
+                }
+            ]
+        }
+        "#;
+        let message_reaction_update = serde_json::from_str::<MessageReactionUpdated>(data).unwrap();
+
+        assert!(message_reaction_update.actor.is_user());
+    }
+
+    #[test]
+    fn deserialize_chat() {
+        let data = r#"{
+            "chat": {
+                "id": -1002199793788,
+                "title": "тест",
+                "type": "supergroup"
+            },
+            "message_id": 2,
+            "actor_chat": {
+                "id": -1002199793788,
+                "title": "тест",
+                "type": "supergroup"
+            },
+            "date": 1723798597,
+            "old_reaction": [
+                {
+                    "type": "emoji",
+                    "emoji": "❤"
+                }
+            ],
+            "new_reaction": []
+        }"#;
+
+        let message_reaction_update = serde_json::from_str::<MessageReactionUpdated>(data).unwrap();
+
+        assert!(message_reaction_update.actor.is_chat())
+    }
+
+    #[test]
+    fn serialize_actor() {
+        let data = r#"{"chat":{"id":-1002199793788,"title":"тест","type":"supergroup"},"message_id":2,"actor_chat":{"id":-1002199793788,"title":"тест","type":"supergroup"},"date":1723798597,"old_reaction":[],"new_reaction":[]}"#;
+
+        let message_reaction_update = serde_json::from_str::<MessageReactionUpdated>(data).unwrap();
+
+        assert_eq!(serde_json::to_string(&message_reaction_update).unwrap(), data);
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::ShippingAddress;
@@ -6,7 +8,9 @@ use crate::types::ShippingAddress;
 ///
 /// [The official docs](https://core.telegram.org/bots/api#orderinfo).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Default)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct OrderInfo {
     /// User's name.
     pub name: Option<String>,
@@ -0,0 +1,124 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Gift, MessageEntity, UniqueGift, User};
+
+/// Contains the list of gifts received and owned by a user or a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#ownedgifts).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnedGifts {
+    /// The total number of gifts owned by the user or the chat.
+    pub total_count: u32,
+
+    /// The list of gifts.
+    pub gifts: Vec<OwnedGift>,
+
+    /// Offset for the next request. If empty, then there are no more results.
+    pub next_offset: Option<String>,
+}
+
+/// This object describes a gift received and owned by a user or a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#ownedgift).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum OwnedGift {
+    Regular(OwnedGiftRegular),
+    Unique(OwnedGiftUnique),
+}
+
+/// Describes a regular gift owned by a user or a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#ownedgiftregular).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnedGiftRegular {
+    /// Information about the regular gift.
+    pub gift: Gift,
+
+    /// Unique identifier of the gift for the bot; for gifts received on behalf
+    /// of business accounts only.
+    pub owned_gift_id: Option<String>,
+
+    /// Sender of the gift if it is a known user.
+    pub sender_user: Option<User>,
+
+    /// Date the gift was sent.
+    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
+    pub send_date: DateTime<Utc>,
+
+    /// Text of the message that was added to the gift.
+    pub text: Option<String>,
+
+    /// Special entities that appear in the text.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entities: Vec<MessageEntity>,
+
+    /// `true`, if the sender and gift text are shown only to the gift receiver;
+    /// otherwise, everyone will be able to see them.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_private: bool,
+
+    /// `true`, if the gift is displayed on the account's profile page; for
+    /// gifts received on behalf of business accounts only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_saved: bool,
+
+    /// `true`, if the gift can be upgraded to a unique gift; for gifts received
+    /// on behalf of business accounts only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub can_be_upgraded: bool,
+
+    /// `true`, if the gift was refunded and isn't available anymore.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub was_refunded: bool,
+
+    /// Number of Telegram Stars that can be claimed by the receiver instead of
+    /// the gift; omitted if the gift cannot be converted to Telegram Stars.
+    pub convert_star_count: Option<u32>,
+
+    /// Number of Telegram Stars that were paid by the sender for the ability to
+    /// upgrade the gift.
+    pub prepaid_upgrade_star_count: Option<u32>,
+}
+
+/// Describes a unique gift received and owned by a user or a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#ownedgiftunique).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnedGiftUnique {
+    /// Information about the unique gift.
+    pub gift: UniqueGift,
+
+    /// Unique identifier of the received gift for the bot; for gifts received
+    /// on behalf of business accounts only.
+    pub owned_gift_id: Option<String>,
+
+    /// Sender of the gift if it is a known user.
+    pub sender_user: Option<User>,
+
+    /// Date the gift was sent.
+    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
+    pub send_date: DateTime<Utc>,
+
+    /// `true`, if the gift is displayed on the account's profile page; for
+    /// gifts received on behalf of business accounts only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_saved: bool,
+
+    /// `true`, if the gift can be transferred to another owner; for gifts
+    /// received on behalf of business accounts only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub can_be_transferred: bool,
+
+    /// Number of Telegram Stars that must be paid to transfer the gift; omitted
+    /// if the bot cannot transfer the gift.
+    pub transfer_star_count: Option<u32>,
+}
@@ -0,0 +1,62 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{PhotoSize, Seconds, Video};
+
+/// This object describes paid media.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmedia).
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum PaidMedia {
+    Preview(PaidMediaPreview),
+    Photo(PaidMediaPhoto),
+    Video(PaidMediaVideo),
+}
+
+/// The paid media isn't available before the payment.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediapreview).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PaidMediaPreview {
+    /// Media width as defined by the sender.
+    pub width: Option<u32>,
+
+    /// Media height as defined by the sender.
+    pub height: Option<u32>,
+
+    /// Duration of the media in seconds as defined by the sender.
+    pub duration: Option<Seconds>,
+}
+
+/// The paid media is a photo.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediaphoto).
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PaidMediaPhoto {
+    /// The photo.
+    pub photo: Vec<PhotoSize>,
+}
+
+/// The paid media is a video.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediavideo).
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PaidMediaVideo {
+    /// The video.
+    pub video: Video,
+}
+
+mod impls;
@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::{PaidMedia, PaidMediaPreview};
+
+    #[test]
+    fn preview_de() {
+        let json = r#"{"type":"preview","width":1280,"height":720}"#;
+        let media: PaidMedia = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            media,
+            PaidMedia::Preview(PaidMediaPreview {
+                width: Some(1280),
+                height: Some(720),
+                duration: None
+            })
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::PaidMedia;
+
+/// Describes the paid media added to a message.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediainfo).
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PaidMediaInfo {
+    /// The number of Telegram Stars that must be paid to buy access to the
+    /// media.
+    pub star_count: u32,
+
+    /// Information about the paid media.
+    pub paid_media: Vec<PaidMedia>,
+}
@@ -1,13 +1,16 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
-use super::{EncryptedCredentials, EncryptedPassportElement};
+use crate::types::{EncryptedCredentials, EncryptedPassportElement};
 
 /// Contains information about Telegram Passport data shared with the bot by the
 /// user.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#passportdata).
-#[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct PassportData {
     /// Array with information about documents and other Telegram Passport
     /// elements that was shared with the bot.
@@ -0,0 +1,276 @@
+use crate::types::{
+    PassportElementError, PassportElementErrorDataField, PassportElementErrorDataFieldType,
+    PassportElementErrorFile, PassportElementErrorFileType, PassportElementErrorFiles,
+    PassportElementErrorFilesType, PassportElementErrorFrontSide,
+    PassportElementErrorFrontSideType, PassportElementErrorKind, PassportElementErrorReverseSide,
+    PassportElementErrorReverseSideType, PassportElementErrorSelfie,
+    PassportElementErrorSelfieType, PassportElementErrorTranslationFile,
+    PassportElementErrorTranslationFileType, PassportElementErrorTranslationFiles,
+    PassportElementErrorTranslationFilesType, PassportElementErrorUnspecified,
+    PassportElementErrorUnspecifiedType,
+};
+
+impl PassportElementError {
+    pub fn new<S>(message: S, kind: PassportElementErrorKind) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { message: message.into(), kind }
+    }
+
+    pub fn message<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.message = val.into();
+        self
+    }
+
+    #[must_use]
+    pub fn kind(mut self, val: PassportElementErrorKind) -> Self {
+        self.kind = val;
+        self
+    }
+}
+
+impl PassportElementErrorDataField {
+    pub fn new<S1, S2>(
+        r#type: PassportElementErrorDataFieldType,
+        field_name: S1,
+        data_hash: S2,
+    ) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self { r#type, field_name: field_name.into(), data_hash: data_hash.into() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorDataFieldType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn field_name<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.field_name = val.into();
+        self
+    }
+
+    pub fn data_hash<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.data_hash = val.into();
+        self
+    }
+}
+
+impl PassportElementErrorFrontSide {
+    pub fn new<S>(r#type: PassportElementErrorFrontSideType, file_hash: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { r#type, file_hash: file_hash.into() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorFrontSideType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn file_hash<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.file_hash = val.into();
+        self
+    }
+}
+
+impl PassportElementErrorReverseSide {
+    pub fn new<S>(r#type: PassportElementErrorReverseSideType, file_hash: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { r#type, file_hash: file_hash.into() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorReverseSideType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn file_hash<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.file_hash = val.into();
+        self
+    }
+}
+
+impl PassportElementErrorSelfie {
+    pub fn new<S>(r#type: PassportElementErrorSelfieType, file_hash: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { r#type, file_hash: file_hash.into() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorSelfieType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn file_hash<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.file_hash = val.into();
+        self
+    }
+}
+
+impl PassportElementErrorFile {
+    pub fn new<S>(r#type: PassportElementErrorFileType, file_hash: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { r#type, file_hash: file_hash.into() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorFileType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn file_hash<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.file_hash = val.into();
+        self
+    }
+}
+
+impl PassportElementErrorFiles {
+    pub fn new<S>(r#type: PassportElementErrorFilesType, file_hashes: S) -> Self
+    where
+        S: IntoIterator<Item = String>,
+    {
+        Self { r#type, file_hashes: file_hashes.into_iter().collect() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorFilesType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn file_hashes<S>(mut self, val: S) -> Self
+    where
+        S: IntoIterator<Item = String>,
+    {
+        self.file_hashes = val.into_iter().collect();
+        self
+    }
+}
+
+impl PassportElementErrorTranslationFile {
+    pub fn new<S>(r#type: PassportElementErrorTranslationFileType, file_hash: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { r#type, file_hash: file_hash.into() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorTranslationFileType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn file_hash<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.file_hash = val.into();
+        self
+    }
+}
+
+impl PassportElementErrorTranslationFiles {
+    pub fn new<S>(r#type: PassportElementErrorTranslationFilesType, file_hashes: S) -> Self
+    where
+        S: IntoIterator<Item = String>,
+    {
+        Self { r#type, file_hashes: file_hashes.into_iter().collect() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorTranslationFilesType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn file_hashes<S>(mut self, val: S) -> Self
+    where
+        S: IntoIterator<Item = String>,
+    {
+        self.file_hashes = val.into_iter().collect();
+        self
+    }
+}
+
+impl PassportElementErrorUnspecified {
+    pub fn new<S>(r#type: PassportElementErrorUnspecifiedType, file_hash: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { r#type, element_hash: file_hash.into() }
+    }
+
+    #[must_use]
+    pub fn r#type(mut self, val: PassportElementErrorUnspecifiedType) -> Self {
+        self.r#type = val;
+        self
+    }
+
+    pub fn element_hash<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.element_hash = val.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_data_field() {
+        let data = PassportElementError {
+            message: "This is an error message!".to_owned(),
+            kind: PassportElementErrorKind::DataField(PassportElementErrorDataField {
+                r#type: PassportElementErrorDataFieldType::InternalPassport,
+                field_name: "The field name".to_owned(),
+                data_hash: "This is a data hash".to_owned(),
+            }),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&data).unwrap(),
+            r#"{"message":"This is an error message!","source":"data","type":"internal_passport","field_name":"The field name","data_hash":"This is a data hash"}"#
+        );
+    }
+}
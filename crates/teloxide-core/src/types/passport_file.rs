@@ -1,5 +1,6 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use chrono::{DateTime, Utc};
-use derive_more::Deref;
 use serde::{Deserialize, Serialize};
 
 use crate::types::FileMeta;
@@ -10,8 +11,9 @@ use crate::types::FileMeta;
 /// don't exceed 10MB.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#passportfile).
-#[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Deref)]
+#[derive(Clone, Debug, derive_more::Deref)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct PassportFile {
     /// Metadata of the passport file.
     #[deref]
@@ -19,7 +21,7 @@ pub struct PassportFile {
     pub file: FileMeta,
 
     /// Time when the file was uploaded.
-    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
     #[serde(rename = "file_date")]
+    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
     pub date: DateTime<Utc>,
 }
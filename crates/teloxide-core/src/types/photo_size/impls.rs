@@ -0,0 +1,17 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::{FileMeta, PhotoSize};
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"file_id":"id","file_unique_id":"","width":320,"height":320,
+                             "file_size":3452}"#;
+        let expected = PhotoSize {
+            file: FileMeta { id: "id".to_owned(), unique_id: "".to_owned(), size: 3452 },
+            width: 320,
+            height: 320,
+        };
+        let actual = serde_json::from_str::<PhotoSize>(json).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,13 +1,17 @@
-use crate::types::{MessageEntity, PollType, Seconds, User};
+//! Generated by `codegen_types`, do not edit by hand.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::types::{MessageEntity, PollType, Seconds};
+
 /// This object contains information about a poll.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#poll).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct Poll {
     /// Unique poll identifier.
     pub id: String,
@@ -39,8 +43,8 @@ pub struct Poll {
     pub allows_multiple_answers: bool,
 
     /// 0-based identifier of the correct answer option. Available only for
-    /// polls in the quiz mode, which are closed, or was sent (not
-    /// forwarded) by the bot or to the private chat with the bot.
+    /// polls in the quiz mode, which are closed, or was sent (not forwarded) by
+    /// the bot or to the private chat with the bot.
     pub correct_option_id: Option<u8>,
 
     /// Text that is shown when a user chooses an incorrect answer or taps on
@@ -62,7 +66,10 @@ pub struct Poll {
 /// This object contains information about one answer option in a poll.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#polloption).
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct PollOption {
     /// Option text, 1-100 characters.
     pub text: String,
@@ -75,59 +82,4 @@ pub struct PollOption {
     pub voter_count: u32,
 }
 
-impl Poll {
-    /// Returns all users that are "contained" in this `Poll`
-    /// structure.
-    ///
-    /// This might be useful to track information about users.
-    ///
-    /// Note that this function can return duplicate users.
-    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
-        use crate::util::{flatten, mentioned_users_from_entities};
-
-        flatten(self.explanation_entities.as_deref().map(mentioned_users_from_entities))
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let data = r#"
-        {
-            "allows_multiple_answers": false,
-            "id": "5377643193141559299",
-            "is_anonymous": true,
-            "is_closed": false,
-            "options": [
-                {
-                    "text": "1",
-                    "voter_count": 1
-                },
-                {
-                    "text": "2",
-                    "voter_count": 0
-                },
-                {
-                    "text": "3",
-                    "voter_count": 0
-                },
-                {
-                    "text": "4",
-                    "voter_count": 0
-                },
-                {
-                    "text": "5",
-                    "voter_count": 0
-                }
-            ],
-            "question": "Rate me from 1 to 5.",
-            "total_voter_count": 1,
-            "type": "regular"
-        }
-        "#;
-        serde_json::from_str::<Poll>(data).unwrap();
-    }
-}
+mod impls;
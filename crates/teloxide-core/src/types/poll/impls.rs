@@ -0,0 +1,58 @@
+use crate::types::{Poll, User};
+
+impl Poll {
+    /// Returns all users that are "contained" in this `Poll`
+    /// structure.
+    ///
+    /// This might be useful to track information about users.
+    ///
+    /// Note that this function can return duplicate users.
+    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
+        use crate::util::{flatten, mentioned_users_from_entities};
+
+        flatten(self.explanation_entities.as_deref().map(mentioned_users_from_entities))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "allows_multiple_answers": false,
+            "id": "5377643193141559299",
+            "is_anonymous": true,
+            "is_closed": false,
+            "options": [
+                {
+                    "text": "1",
+                    "voter_count": 1
+                },
+                {
+                    "text": "2",
+                    "voter_count": 0
+                },
+                {
+                    "text": "3",
+                    "voter_count": 0
+                },
+                {
+                    "text": "4",
+                    "voter_count": 0
+                },
+                {
+                    "text": "5",
+                    "voter_count": 0
+                }
+            ],
+            "question": "Rate me from 1 to 5.",
+            "total_voter_count": 1,
+            "type": "regular"
+        }
+        "#;
+        serde_json::from_str::<Poll>(data).unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{OrderInfo, User};
@@ -6,7 +8,9 @@ use crate::types::{OrderInfo, User};
 ///
 /// [The official docs](https://core.telegram.org/bots/api#precheckoutquery).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct PreCheckoutQuery {
     /// Unique query identifier.
     pub id: String,
@@ -24,8 +28,8 @@ pub struct PreCheckoutQuery {
     /// Total price in the _smallest units_ of the currency (integer, **not**
     /// float/double). For example, for a price of `US$ 1.45` pass `amount =
     /// 145`. See the exp parameter in [`currencies.json`], it shows the number
-    /// of digits past the decimal point for each currency (2 for the
-    /// majority of currencies).
+    /// of digits past the decimal point for each currency (2 for the majority
+    /// of currencies).
     ///
     /// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
     pub total_amount: u32,
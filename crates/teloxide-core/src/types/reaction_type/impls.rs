@@ -0,0 +1,19 @@
+use crate::types::ReactionType;
+
+impl ReactionType {
+    #[must_use]
+    pub fn emoji(&self) -> Option<&String> {
+        match &self {
+            Self::Emoji { emoji } => Some(emoji),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn custom_emoji_id(&self) -> Option<&String> {
+        match &self {
+            Self::CustomEmoji { custom_emoji_id } => Some(custom_emoji_id),
+            _ => None,
+        }
+    }
+}
@@ -1,12 +1,13 @@
-use derive_more::{Display, From};
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
-use crate::types::{ChatId, UserId};
+use crate::types::ChatId;
 
 /// A unique identifier for the target chat or username of the target channel
 /// (in the format `@channelusername`).
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[derive(Debug, Display, From)]
+#[derive(Clone, Debug, derive_more::Display, derive_more::From)]
+#[derive(PartialEq, Eq, Hash)]
 #[derive(Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Recipient {
@@ -19,30 +20,4 @@ pub enum Recipient {
     ChannelUsername(String),
 }
 
-impl From<UserId> for Recipient {
-    fn from(id: UserId) -> Self {
-        Self::Id(id.into())
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn chat_id_id_serialization() {
-        let expected_json = String::from("123456");
-        let actual_json = serde_json::to_string(&Recipient::Id(ChatId(123_456))).unwrap();
-
-        assert_eq!(expected_json, actual_json)
-    }
-
-    #[test]
-    fn chat_id_channel_username_serialization() {
-        let expected_json = String::from(r#""@username""#);
-        let actual_json =
-            serde_json::to_string(&Recipient::ChannelUsername(String::from("@username"))).unwrap();
-
-        assert_eq!(expected_json, actual_json)
-    }
-}
+mod impls;
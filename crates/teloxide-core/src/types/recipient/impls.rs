@@ -0,0 +1,32 @@
+use derive_more::From;
+
+use crate::types::{Recipient, UserId};
+
+impl From<UserId> for Recipient {
+    fn from(id: UserId) -> Self {
+        Self::Id(id.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ChatId;
+
+    #[test]
+    fn chat_id_id_serialization() {
+        let expected_json = String::from("123456");
+        let actual_json = serde_json::to_string(&Recipient::Id(ChatId(123_456))).unwrap();
+
+        assert_eq!(expected_json, actual_json)
+    }
+
+    #[test]
+    fn chat_id_channel_username_serialization() {
+        let expected_json = String::from(r#""@username""#);
+        let actual_json =
+            serde_json::to_string(&Recipient::ChannelUsername(String::from("@username"))).unwrap();
+
+        assert_eq!(expected_json, actual_json)
+    }
+}
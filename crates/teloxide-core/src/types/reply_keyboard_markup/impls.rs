@@ -0,0 +1,112 @@
+use crate::types::KeyboardMarkup;
+// FIXME: rename module (s/reply_//)
+
+use crate::types::KeyboardButton;
+
+impl KeyboardMarkup {
+    // FIXME: Re-think the interface of building keyboard markups
+    pub fn new<K>(keyboard: K) -> Self
+    where
+        K: IntoIterator,
+        K::Item: IntoIterator<Item = KeyboardButton>,
+    {
+        Self {
+            keyboard: keyboard.into_iter().map(<_>::into_iter).map(<_>::collect).collect(),
+            is_persistent: false,
+            resize_keyboard: false,
+            one_time_keyboard: false,
+            input_field_placeholder: String::new(),
+            selective: false,
+        }
+    }
+
+    pub fn append_row<R>(mut self, buttons: R) -> Self
+    where
+        R: IntoIterator<Item = KeyboardButton>,
+    {
+        self.keyboard.push(buttons.into_iter().collect());
+        self
+    }
+
+    #[must_use]
+    pub fn append_to_row(mut self, index: usize, button: KeyboardButton) -> Self {
+        match self.keyboard.get_mut(index) {
+            Some(buttons) => buttons.push(button),
+            None => self.keyboard.push(vec![button]),
+        };
+        self
+    }
+
+    /// Sets [`is_persistent`] to `true`.
+    ///
+    /// [`is_persistent`]: KeyboardMarkup::is_persistent
+    pub fn persistent(self) -> Self {
+        Self { is_persistent: true, ..self }
+    }
+
+    /// Sets [`resize_keyboard`] to `true`.
+    ///
+    /// [`resize_keyboard`]: KeyboardMarkup::resize_keyboard
+    pub fn resize_keyboard(self) -> Self {
+        Self { resize_keyboard: true, ..self }
+    }
+
+    /// Sets [`one_time_keyboard`] to `true`.
+    ///
+    /// [`one_time_keyboard`]: KeyboardMarkup::one_time_keyboard
+    pub fn one_time_keyboard(self) -> Self {
+        Self { one_time_keyboard: true, ..self }
+    }
+
+    // FIXME: document
+    pub fn input_field_placeholder<T>(self, val: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self { input_field_placeholder: val.into(), ..self }
+    }
+
+    /// Sets [`selective`] to `true`.
+    ///
+    /// [`selective`]: KeyboardMarkup::selective
+    pub fn selective(self) -> Self {
+        Self { selective: true, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "keyboard": [[{"text": "a"}, {"text": "b"}], [{"text": "c"}, {"text": "d"}]],
+            "input_field_placeholder": "",
+            "is_persistent": true,
+            "one_time_keyboard": false,
+            "resize_keyboard": true,
+            "selective": false
+        }
+        "#;
+        assert!(serde_json::from_str::<KeyboardMarkup>(data).is_ok())
+    }
+    #[test]
+    fn serialize() {
+        let keyboard = vec![vec![
+            KeyboardButton::new("a"),
+            KeyboardButton::new("b"),
+            KeyboardButton::new("c"),
+            KeyboardButton::new("d"),
+        ]];
+        let keyboard_markup = KeyboardMarkup::new(keyboard)
+            .persistent()
+            .resize_keyboard()
+            .selective()
+            .one_time_keyboard();
+        let expected = r#"{"keyboard":[[{"text":"a"},{"text":"b"},{"text":"c"},{"text":"d"}]],"is_persistent":true,"resize_keyboard":true,"one_time_keyboard":true,"selective":true}"#;
+
+        assert!(serde_json::ser::to_string(&keyboard_markup).is_ok_and(|s| s.eq(expected)));
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::True;
@@ -12,21 +14,20 @@ use crate::types::True;
 /// [The official docs](https://core.telegram.org/bots/api#replykeyboardremove).
 ///
 /// [`KeyboardMarkup`]: crate::types::KeyboardMarkup
-#[serde_with::skip_serializing_none]
-#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Default)]
 #[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct KeyboardRemove {
-    /// Requests clients to remove the custom keyboard (user will not be able
-    /// to summon this keyboard; if you want to hide the keyboard from sight
-    /// but keep it accessible, use one_time_keyboard in
-    /// [`KeyboardMarkup`]).
+    /// Requests clients to remove the custom keyboard (user will not be able to
+    /// summon this keyboard; if you want to hide the keyboard from sight but
+    /// keep it accessible, use one_time_keyboard in [`KeyboardMarkup`]).
     ///
     /// [`KeyboardMarkup`]: crate::types::KeyboardMarkup
     pub remove_keyboard: True,
 
-    /// Use this parameter if you want to remove the keyboard for specific
-    /// users only. Targets: 1) users that are `@mentioned` in the `text` of
-    /// the [`Message`] object; 2) if the bot's message is a reply (has
+    /// Use this parameter if you want to remove the keyboard for specific users
+    /// only. Targets: 1) users that are `@mentioned` in the `text` of the
+    /// [`Message`] object; 2) if the bot's message is a reply (has
     /// `reply_to_message_id`), sender of the original message.
     ///
     /// Example: A user votes in a poll, bot returns confirmation message in
@@ -38,33 +39,4 @@ pub struct KeyboardRemove {
     pub selective: bool,
 }
 
-impl KeyboardRemove {
-    #[must_use]
-    pub const fn new() -> Self {
-        Self { remove_keyboard: True, selective: false }
-    }
-
-    /// Sets [`selective`] to `true`.
-    ///
-    /// [`selective`]: KeyboardRemove::selective
-    #[must_use]
-    pub const fn selective(self) -> Self {
-        Self { selective: true, ..self }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn deserialize() {
-        let data = r#"
-        {
-            "remove_keyboard": true,
-            "selective": false
-        }
-        "#;
-        serde_json::from_str::<KeyboardRemove>(data).unwrap();
-    }
-}
+mod impls;
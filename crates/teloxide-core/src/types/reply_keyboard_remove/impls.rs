@@ -0,0 +1,32 @@
+use crate::types::{KeyboardRemove, True};
+
+impl KeyboardRemove {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { remove_keyboard: True, selective: false }
+    }
+
+    /// Sets [`selective`] to `true`.
+    ///
+    /// [`selective`]: KeyboardRemove::selective
+    #[must_use]
+    pub const fn selective(self) -> Self {
+        Self { selective: true, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "remove_keyboard": true,
+            "selective": false
+        }
+        "#;
+        serde_json::from_str::<KeyboardRemove>(data).unwrap();
+    }
+}
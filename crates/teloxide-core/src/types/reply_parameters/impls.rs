@@ -0,0 +1,25 @@
+use crate::types::{MessageId, Recipient, ReplyParameters};
+
+impl ReplyParameters {
+    pub fn new(message_id: MessageId) -> Self {
+        Self { message_id, ..Self::default() }
+    }
+
+    /// Setter for the `chat_id` field
+    pub fn chat_id(mut self, chat_id: Recipient) -> Self {
+        self.chat_id = Some(chat_id);
+        self
+    }
+
+    /// Sets the `allow_sending_without_reply_field` to _true_
+    pub fn allow_sending_without_reply(mut self) -> Self {
+        self.allow_sending_without_reply = Some(true);
+        self
+    }
+
+    /// Setter for the `quote` field
+    pub fn quote(mut self, quote: String) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatId, Seconds};
@@ -5,7 +7,9 @@ use crate::types::{ChatId, Seconds};
 /// Contains information about why a request was unsuccessful.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#responseparameters).
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ResponseParameters {
     /// The group has been migrated to a supergroup with the specified
@@ -17,24 +21,4 @@ pub enum ResponseParameters {
     RetryAfter(Seconds),
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn migrate_to_chat_id_deserialization() {
-        let expected = ResponseParameters::MigrateToChatId(ChatId(123_456));
-        let actual: ResponseParameters =
-            serde_json::from_str(r#"{"migrate_to_chat_id":123456}"#).unwrap();
-
-        assert_eq!(expected, actual);
-    }
-
-    #[test]
-    fn retry_after_deserialization() {
-        let expected = ResponseParameters::RetryAfter(Seconds::from_seconds(123_456));
-        let actual: ResponseParameters = serde_json::from_str(r#"{"retry_after":123456}"#).unwrap();
-
-        assert_eq!(expected, actual);
-    }
-}
+mod impls;
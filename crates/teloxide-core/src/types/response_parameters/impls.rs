@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::{ChatId, ResponseParameters, Seconds};
+
+    #[test]
+    fn migrate_to_chat_id_deserialization() {
+        let expected = ResponseParameters::MigrateToChatId(ChatId(123_456));
+        let actual: ResponseParameters =
+            serde_json::from_str(r#"{"migrate_to_chat_id":123456}"#).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn retry_after_deserialization() {
+        let expected = ResponseParameters::RetryAfter(Seconds::from_seconds(123_456));
+        let actual: ResponseParameters = serde_json::from_str(r#"{"retry_after":123456}"#).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,13 +1,15 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use chrono::{DateTime, Utc};
-use reqwest::Url;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 /// This object describes the state of a revenue withdrawal operation.
 #[derive(Clone, Debug)]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[derive(Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
 pub enum RevenueWithdrawalState {
     Pending,
     Succeeded(RevenueWithdrawalStateSucceeded),
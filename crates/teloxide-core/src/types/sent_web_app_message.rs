@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 /// Contains information about an inline message sent by a [Web App] on behalf
@@ -5,7 +7,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// [Web App]: https://core.telegram.org/bots/webapps
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct SentWebAppMessage {
     /// Identifier of the sent inline message. Available only if there is an
     /// inline keyboard attached to the message.
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{PhotoSize, UserId};
@@ -6,7 +8,10 @@ use crate::types::{PhotoSize, UserId};
 /// using a [`KeyboardButtonRequestUsers`] button.
 ///
 /// [`KeyboardButtonRequestUsers`]: crate::types::KeyboardButtonRequestUsers
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct SharedUser {
     /// Identifier of the shared user
     pub user_id: UserId,
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{ShippingAddress, User};
@@ -5,8 +7,9 @@ use crate::types::{ShippingAddress, User};
 /// This object contains information about an incoming shipping query.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#shippingquery).
-#[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct ShippingQuery {
     /// Unique query identifier.
     pub id: String,
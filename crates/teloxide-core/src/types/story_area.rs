@@ -0,0 +1,135 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::types::{LocationAddress, ReactionType};
+
+/// Describes a clickable area on a story media.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyarea).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryArea {
+    /// Position of the area.
+    pub position: StoryAreaPosition,
+
+    /// Type of the area.
+    #[serde(rename = "type")]
+    pub kind: StoryAreaType,
+}
+
+/// Describes the position of a clickable area within a story.
+///
+/// All the values are given in percentages of the media width or height.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareaposition).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaPosition {
+    /// The abscissa of the area's center, as a percentage of the media width.
+    pub x_percentage: f64,
+
+    /// The ordinate of the area's center, as a percentage of the media height.
+    pub y_percentage: f64,
+
+    /// The width of the area's rectangle, as a percentage of the media width.
+    pub width_percentage: f64,
+
+    /// The height of the area's rectangle, as a percentage of the media height.
+    pub height_percentage: f64,
+
+    /// The clockwise rotation angle of the rectangle, in degrees; 0-360.
+    pub rotation_angle: f64,
+
+    /// The radius of the rectangle corner rounding, as a percentage of the
+    /// media width.
+    pub corner_radius_percentage: f64,
+}
+
+/// Describes the type of a clickable area on a story.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatype).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum StoryAreaType {
+    Location(StoryAreaTypeLocation),
+    SuggestedReaction(StoryAreaTypeSuggestedReaction),
+    Link(StoryAreaTypeLink),
+    Weather(StoryAreaTypeWeather),
+    UniqueGift(StoryAreaTypeUniqueGift),
+}
+
+/// Describes a story area pointing to a location. Currently, a story can have
+/// up to 10 location areas.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypelocation).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeLocation {
+    /// Location latitude in degrees.
+    pub latitude: f64,
+
+    /// Location longitude in degrees.
+    pub longitude: f64,
+
+    /// Address of the location.
+    pub address: Option<LocationAddress>,
+}
+
+/// Describes a story area pointing to a suggested reaction. Currently, a story
+/// can have up to 5 suggested reaction areas.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypesuggestedreaction).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeSuggestedReaction {
+    /// Type of the reaction.
+    pub reaction_type: ReactionType,
+
+    /// `true`, if the reaction area has a dark background.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_dark: bool,
+
+    /// `true`, if reaction area corner is flipped.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_flipped: bool,
+}
+
+/// Describes a story area pointing to an HTTP or tg:// link. Currently, a story
+/// can have up to 3 link areas.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypelink).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeLink {
+    /// HTTP or tg:// URL to be opened when the area is clicked.
+    pub url: Url,
+}
+
+/// Describes a story area containing weather information. Currently, a story
+/// can have up to 3 weather areas.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypeweather).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeWeather {
+    /// Temperature, in degree Celsius.
+    pub temperature: f64,
+
+    /// Emoji representing the weather.
+    pub emoji: String,
+
+    /// A color of the area background in the ARGB format.
+    pub background_color: u32,
+}
+
+/// Describes a story area pointing to a unique gift. Currently, a story can
+/// have at most 1 unique gift area.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypeuniquegift).
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct StoryAreaTypeUniqueGift {
+    /// Unique name of the gift.
+    pub name: String,
+}
+
+mod impls;
@@ -0,0 +1,31 @@
+use crate::types::{StoryArea, StoryAreaPosition, StoryAreaType};
+
+impl StoryArea {
+    pub const fn new(position: StoryAreaPosition, kind: StoryAreaType) -> Self {
+        Self { position, kind }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{StoryArea, StoryAreaPosition, StoryAreaType, StoryAreaTypeLink};
+
+    #[test]
+    fn serialize() {
+        let area = StoryArea::new(
+            StoryAreaPosition {
+                x_percentage: 50.0,
+                y_percentage: 50.0,
+                width_percentage: 20.0,
+                height_percentage: 10.0,
+                rotation_angle: 0.0,
+                corner_radius_percentage: 1.0,
+            },
+            StoryAreaType::Link(StoryAreaTypeLink { url: "https://example.com".parse().unwrap() }),
+        );
+        let expected_json = r#"{"position":{"x_percentage":50.0,"y_percentage":50.0,"width_percentage":20.0,"height_percentage":10.0,"rotation_angle":0.0,"corner_radius_percentage":1.0},"type":{"type":"link","url":"https://example.com/"}}"#;
+
+        let actual_json = serde_json::to_string(&area).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+}
@@ -1,3 +1,6 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::types::OrderInfo;
@@ -6,7 +9,9 @@ use crate::types::OrderInfo;
 ///
 /// [The official docs](https://core.telegram.org/bots/api#successfulpayment).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct SuccessfulPayment {
     /// Three-letter ISO 4217 currency code, see [more on currencies]. Pass
     /// `XTR` for payments in [Telegram Stars].
@@ -17,9 +22,9 @@ pub struct SuccessfulPayment {
 
     /// Total price in the smallest units of the currency (integer, not
     /// float/double). For example, for a price of `US$ 1.45` pass `amount =
-    /// 145`. See the exp parameter in [`currencies.json`], it shows the
-    /// number of digits past the decimal point for each currency (2 for
-    /// the majority of currencies).
+    /// 145`. See the exp parameter in [`currencies.json`], it shows the number
+    /// of digits past the decimal point for each currency (2 for the majority
+    /// of currencies).
     ///
     /// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
     pub total_amount: u32,
@@ -27,6 +32,18 @@ pub struct SuccessfulPayment {
     /// Bot specified invoice payload.
     pub invoice_payload: String,
 
+    /// Expiration date of the subscription, if this is a recurring payment.
+    #[serde(default, with = "crate::types::serde_opt_date_from_unix_timestamp")]
+    pub subscription_expiration_date: Option<DateTime<Utc>>,
+
+    /// `true`, if the payment is a recurring payment for a subscription.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_recurring: bool,
+
+    /// `true`, if the payment is the first payment for a subscription.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_first_recurring: bool,
+
     /// Identifier of the shipping option chosen by the user.
     pub shipping_option_id: Option<String>,
 
@@ -40,3 +57,5 @@ pub struct SuccessfulPayment {
     /// Provider payment identifier.
     pub provider_payment_charge_id: String,
 }
+
+mod impls;
@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod tests {
+    use crate::types::SuccessfulPayment;
+    use chrono::DateTime;
+
+    #[test]
+    fn subscription_de() {
+        let json = r#"{
+            "currency": "XTR",
+            "total_amount": 100,
+            "invoice_payload": "payload",
+            "subscription_expiration_date": 1729000000,
+            "is_recurring": true,
+            "telegram_payment_charge_id": "charge",
+            "provider_payment_charge_id": ""
+        }"#;
+        let payment: SuccessfulPayment = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            payment.subscription_expiration_date,
+            Some(DateTime::from_timestamp(1729000000, 0).unwrap())
+        );
+        assert!(payment.is_recurring);
+        assert!(!payment.is_first_recurring);
+    }
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::{SuggestedPostParameters, SuggestedPostPrice};
+
+impl SuggestedPostParameters {
+    pub const fn new() -> Self {
+        Self { price: None, send_date: None }
+    }
+
+    pub fn price(mut self, val: SuggestedPostPrice) -> Self {
+        self.price = Some(val);
+        self
+    }
+
+    pub fn send_date(mut self, val: DateTime<Utc>) -> Self {
+        self.send_date = Some(val);
+        self
+    }
+}
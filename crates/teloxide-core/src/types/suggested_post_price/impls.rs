@@ -0,0 +1,10 @@
+use crate::types::SuggestedPostPrice;
+
+impl SuggestedPostPrice {
+    pub fn new<S>(currency: S, amount: i64) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { currency: currency.into(), amount }
+    }
+}
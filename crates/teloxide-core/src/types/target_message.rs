@@ -1,23 +1,25 @@
-use crate::types::{MessageId, Recipient};
+//! Generated by `codegen_types`, do not edit by hand.
 
 use serde::{Deserialize, Serialize};
 
+use crate::types::{MessageId, Recipient};
+
 /// A message in chat or inline message.
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum TargetMessage {
     Common {
         chat_id: Recipient,
+
         #[serde(flatten)]
         message_id: MessageId,
     },
+
     Inline {
         inline_message_id: String,
     },
 }
 
-impl From<String> for TargetMessage {
-    fn from(inline_message_id: String) -> Self {
-        Self::Inline { inline_message_id }
-    }
-}
+mod impls;
@@ -0,0 +1,7 @@
+use crate::types::TargetMessage;
+
+impl From<String> for TargetMessage {
+    fn from(inline_message_id: String) -> Self {
+        Self::Inline { inline_message_id }
+    }
+}
@@ -1,7 +1,9 @@
-use crate::types::MessageId;
+//! Generated by `codegen_types`, do not edit by hand.
 
 use serde::{Deserialize, Serialize};
 
+use crate::types::MessageId;
+
 /// Reply thread identifier.
 ///
 /// A message that isn't a reply and other messages that reply to it directly or
@@ -38,43 +40,10 @@ use serde::{Deserialize, Serialize};
 /// of the topic).
 ///
 /// [`thread_id`]: crate::types::Message::thread_id
-#[derive(Clone, Copy, Debug, derive_more::Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(from = "ThreadIdRaw", into = "ThreadIdRaw")]
-pub struct ThreadId(/** Identifier of the root message in a reply thread. */ pub MessageId);
-
-// N.B. this is a hack to [de]serialize `ThreadId` as just a number
-//      we need this since `MessageId` is [de]serialized as `{"message_id":n}`.
-
+#[derive(Clone, Copy, Debug, derive_more::Display)]
+#[derive(PartialEq, Eq, Hash)]
 #[derive(Serialize, Deserialize)]
-struct ThreadIdRaw(i32);
-
-impl From<ThreadIdRaw> for ThreadId {
-    fn from(ThreadIdRaw(message_id): ThreadIdRaw) -> Self {
-        ThreadId(MessageId(message_id))
-    }
-}
-
-impl From<ThreadId> for ThreadIdRaw {
-    fn from(ThreadId(MessageId(message_id)): ThreadId) -> Self {
-        ThreadIdRaw(message_id)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::types::{MessageId, ThreadId};
-
-    #[test]
-    fn smoke_deser() {
-        let json = "123";
-        let mid: ThreadId = serde_json::from_str(json).unwrap();
-        assert_eq!(mid, ThreadId(MessageId(123)));
-    }
+#[serde(from = "impls::ThreadIdRaw", into = "impls::ThreadIdRaw")]
+pub struct ThreadId(/** Identifier of the root message in a reply thread. */ pub MessageId);
 
-    #[test]
-    fn smoke_ser() {
-        let mid: ThreadId = ThreadId(MessageId(123));
-        let json = serde_json::to_string(&mid).unwrap();
-        assert_eq!(json, "123");
-    }
-}
+mod impls;
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{MessageId, ThreadId};
+
+// N.B. this is a hack to [de]serialize `ThreadId` as just a number
+//      we need this since `MessageId` is [de]serialized as `{"message_id":n}`.
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct ThreadIdRaw(i32);
+
+impl From<ThreadIdRaw> for ThreadId {
+    fn from(ThreadIdRaw(message_id): ThreadIdRaw) -> Self {
+        ThreadId(MessageId(message_id))
+    }
+}
+
+impl From<ThreadId> for ThreadIdRaw {
+    fn from(ThreadId(MessageId(message_id)): ThreadId) -> Self {
+        ThreadIdRaw(message_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{MessageId, ThreadId};
+
+    #[test]
+    fn smoke_deser() {
+        let json = "123";
+        let mid: ThreadId = serde_json::from_str(json).unwrap();
+        assert_eq!(mid, ThreadId(MessageId(123)));
+    }
+
+    #[test]
+    fn smoke_ser() {
+        let mid: ThreadId = ThreadId(MessageId(123));
+        let json = serde_json::to_string(&mid).unwrap();
+        assert_eq!(json, "123");
+    }
+}
@@ -1,21 +1,25 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
-use crate::types::{RevenueWithdrawalState, User};
+use crate::types::{RevenueWithdrawalState, Seconds, User};
 
 /// This object describes the source of a transaction, or its recipient for
 /// outgoing transactions.
 #[derive(Clone, Debug)]
 #[derive(PartialEq, Eq, Hash)]
 #[derive(Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
 pub enum TransactionPartner {
     Fragment(TransactionPartnerFragment),
     User(TransactionPartnerUser),
+    TelegramAds,
     Other,
 }
 
 /// Describes a withdrawal transaction with Fragment.
+#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug)]
 #[derive(PartialEq, Eq, Hash)]
 #[derive(Serialize, Deserialize)]
@@ -25,10 +29,17 @@ pub struct TransactionPartnerFragment {
 }
 
 /// Describes a transaction with a user.
+#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug)]
 #[derive(PartialEq, Eq, Hash)]
 #[derive(Serialize, Deserialize)]
 pub struct TransactionPartnerUser {
     /// Information about the user.
     pub user: User,
+
+    /// Bot-specified invoice payload.
+    pub invoice_payload: Option<String>,
+
+    /// The duration of the paid subscription.
+    pub subscription_period: Option<Seconds>,
 }
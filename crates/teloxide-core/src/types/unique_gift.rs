@@ -0,0 +1,102 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Rgb, Sticker};
+
+/// This object describes a unique gift that was upgraded from a regular gift.
+///
+/// [The official docs](https://core.telegram.org/bots/api#uniquegift).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UniqueGift {
+    /// Human-readable name of the regular gift from which this unique gift was
+    /// upgraded.
+    pub base_name: String,
+
+    /// Unique name of the gift. This name can be used in
+    /// `https://t.me/nft/...` links and story areas.
+    pub name: String,
+
+    /// Unique number of the upgraded gift among gifts upgraded from the same
+    /// regular gift.
+    pub number: u32,
+
+    /// Model of the gift.
+    pub model: UniqueGiftModel,
+
+    /// Symbol of the gift.
+    pub symbol: UniqueGiftSymbol,
+
+    /// Backdrop of the gift.
+    pub backdrop: UniqueGiftBackdrop,
+}
+
+/// This object describes the model of a unique gift.
+///
+/// [The official docs](https://core.telegram.org/bots/api#uniquegiftmodel).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UniqueGiftModel {
+    /// Name of the model.
+    pub name: String,
+
+    /// The sticker that represents the unique gift.
+    pub sticker: Sticker,
+
+    /// The number of unique gifts that receive this model for every 1000 gifts
+    /// upgraded.
+    pub rarity_per_mille: u16,
+}
+
+/// This object describes the symbol shown on the pattern of a unique gift.
+///
+/// [The official docs](https://core.telegram.org/bots/api#uniquegiftsymbol).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UniqueGiftSymbol {
+    /// Name of the symbol.
+    pub name: String,
+
+    /// The sticker that represents the unique gift.
+    pub sticker: Sticker,
+
+    /// The number of unique gifts that receive this model for every 1000 gifts
+    /// upgraded.
+    pub rarity_per_mille: u16,
+}
+
+/// This object describes the colors of the backdrop of a unique gift.
+///
+/// [The official docs](https://core.telegram.org/bots/api#uniquegiftbackdropcolors).
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct UniqueGiftBackdropColors {
+    /// The color in the center of the backdrop.
+    pub center_color: Rgb,
+
+    /// The color on the edges of the backdrop.
+    pub edge_color: Rgb,
+
+    /// The color to be applied to the symbol.
+    pub symbol_color: Rgb,
+
+    /// The color for the text on the backdrop.
+    pub text_color: Rgb,
+}
+
+/// This object describes the backdrop of a unique gift.
+///
+/// [The official docs](https://core.telegram.org/bots/api#uniquegiftbackdrop).
+#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct UniqueGiftBackdrop {
+    /// Name of the backdrop.
+    pub name: String,
+
+    /// Colors of the backdrop.
+    pub colors: UniqueGiftBackdropColors,
+
+    /// The number of unique gifts that receive this backdrop for every 1000
+    /// gifts upgraded.
+    pub rarity_per_mille: u16,
+}
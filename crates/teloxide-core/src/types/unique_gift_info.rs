@@ -0,0 +1,39 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::UniqueGift;
+
+/// Describes a service message about a unique gift that was sent or received.
+///
+/// [The official docs](https://core.telegram.org/bots/api#uniquegiftinfo).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UniqueGiftInfo {
+    /// Information about the gift.
+    pub gift: UniqueGift,
+
+    /// Origin of the gift.
+    pub origin: UniqueGiftOrigin,
+
+    /// Unique identifier of the received gift for the bot; only present for
+    /// gifts received on behalf of business accounts.
+    pub owned_gift_id: Option<String>,
+
+    /// Number of Telegram Stars that must be paid to transfer the gift; omitted
+    /// if the bot cannot transfer the gift.
+    pub transfer_star_count: Option<u32>,
+}
+
+/// Origin of a [`UniqueGift`].
+#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UniqueGiftOrigin {
+    /// The gift was upgraded from a regular gift.
+    Upgrade,
+
+    /// The gift was transferred from another user or channel chat.
+    Transfer,
+}
@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::PhotoSize;
@@ -5,8 +7,9 @@ use crate::types::PhotoSize;
 /// This object represent a user's profile pictures.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#userprofilephotos).
-#[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct UserProfilePhotos {
     /// Total number of profile pictures the target user has.
     pub total_count: u32,
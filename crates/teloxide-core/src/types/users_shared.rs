@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{RequestId, SharedUser};
@@ -6,10 +8,13 @@ use crate::types::{RequestId, SharedUser};
 /// shared with the bot using a [KeyboardButtonRequestUsers] button.
 ///
 /// [KeyboardButtonRequestUsers]: crate::types::KeyboardButtonRequestUsers
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct UsersShared {
     /// Identifier of the request
     pub request_id: RequestId,
+
     /// Identifiers of the shared users
     pub users: Vec<SharedUser>,
 }
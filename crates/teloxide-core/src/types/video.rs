@@ -1,3 +1,5 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 
@@ -7,7 +9,9 @@ use crate::types::{FileMeta, PhotoSize, Seconds};
 ///
 /// [The official docs](https://core.telegram.org/bots/api#video).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct Video {
     /// Metadata of the video file.
     #[serde(flatten)]
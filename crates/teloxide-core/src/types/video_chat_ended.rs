@@ -1,11 +1,15 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::Seconds;
 
 /// This object represents a service message about a video chat ended in the
 /// chat.
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct VideoChatEnded {
     /// Video chat duration in seconds.
-    duration: Seconds,
+    pub duration: Seconds,
 }
@@ -1,10 +1,15 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::User;
 
 /// This object represents a service message about new members invited to a
 /// video chat.
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct VideoChatParticipantsInvited {
     /// New members that were invited to the video chat
     pub users: Option<Vec<User>>,
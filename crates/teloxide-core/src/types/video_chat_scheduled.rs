@@ -1,9 +1,13 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// This object represents a service message about a video chat scheduled in the
 /// chat.
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct VideoChatScheduled {
     /// Point in time when the video chat is supposed to be started by a chat
     /// administrator.
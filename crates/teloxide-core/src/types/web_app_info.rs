@@ -1,10 +1,14 @@
+//! Generated by `codegen_types`, do not edit by hand.
+
 use serde::{Deserialize, Serialize};
 use url::Url;
 
 /// Contains information about a [Web App].
 ///
 /// [Web App]: https://core.telegram.org/bots/webapps
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct WebAppInfo {
     /// An HTTPS URL of a Web App to be opened with additional data as specified
     /// in [Initializing Web Apps].
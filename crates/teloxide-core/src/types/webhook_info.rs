@@ -1,7 +1,8 @@
-use std::net::IpAddr;
+//! Generated by `codegen_types`, do not edit by hand.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 
 use crate::types::AllowedUpdate;
 
@@ -9,11 +10,13 @@ use crate::types::AllowedUpdate;
 ///
 /// [The official docs](https://core.telegram.org/bots/api#webhookinfo).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[derive(Eq, Hash, PartialEq)]
+#[derive(Serialize, Deserialize)]
 pub struct WebhookInfo {
     /// Webhook URL, `None` if webhook is not set up.
     #[serde(with = "crate::types::option_url_from_string")]
-    pub url: Option<reqwest::Url>,
+    pub url: Option<url::Url>,
 
     /// `true`, if a custom certificate was provided for webhook certificate
     /// checks.
@@ -25,8 +28,8 @@ pub struct WebhookInfo {
     /// Currently used webhook IP address.
     pub ip_address: Option<IpAddr>,
 
-    /// Time of the most recent error that happened when trying to
-    /// deliver an update via webhook.
+    /// Time of the most recent error that happened when trying to deliver an
+    /// update via webhook.
     #[serde(default, with = "crate::types::serde_opt_date_from_unix_timestamp")]
     pub last_error_date: Option<DateTime<Utc>>,
 
@@ -48,25 +51,4 @@ pub struct WebhookInfo {
     pub allowed_updates: Option<Vec<AllowedUpdate>>,
 }
 
-// Regression test for <https://github.com/teloxide/teloxide-core/pull/166>
-#[test]
-fn empty_url() {
-    let json = r#"{"url":"","has_custom_certificate":false,"pending_update_count":0,"allowed_updates":["message"]}"#;
-    let actual: WebhookInfo = serde_json::from_str(json).unwrap();
-    let expected = WebhookInfo {
-        url: None,
-        has_custom_certificate: false,
-        pending_update_count: 0,
-        ip_address: None,
-        last_error_date: None,
-        last_error_message: None,
-        last_synchronization_error_date: None,
-        max_connections: None,
-        allowed_updates: Some(vec![AllowedUpdate::Message]),
-    };
-
-    assert_eq!(actual, expected);
-
-    let json = r#"{"ok":true,"result":{"url":"","has_custom_certificate":false,"pending_update_count":0,"allowed_updates":["message"]}}"#;
-    serde_json::from_str::<crate::net::TelegramResponse<WebhookInfo>>(json).unwrap();
-}
+mod impls;
@@ -0,0 +1,24 @@
+// Regression test for <https://github.com/teloxide/teloxide-core/pull/166>
+#[test]
+fn empty_url() {
+    use crate::types::{AllowedUpdate, WebhookInfo};
+
+    let json = r#"{"url":"","has_custom_certificate":false,"pending_update_count":0,"allowed_updates":["message"]}"#;
+    let actual: WebhookInfo = serde_json::from_str(json).unwrap();
+    let expected = WebhookInfo {
+        url: None,
+        has_custom_certificate: false,
+        pending_update_count: 0,
+        ip_address: None,
+        last_error_date: None,
+        last_error_message: None,
+        last_synchronization_error_date: None,
+        max_connections: None,
+        allowed_updates: Some(vec![AllowedUpdate::Message]),
+    };
+
+    assert_eq!(actual, expected);
+
+    let json = r#"{"ok":true,"result":{"url":"","has_custom_certificate":false,"pending_update_count":0,"allowed_updates":["message"]}}"#;
+    serde_json::from_str::<crate::net::TelegramResponse<WebhookInfo>>(json).unwrap();
+}
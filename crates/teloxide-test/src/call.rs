@@ -0,0 +1,77 @@
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// A method call received by the [`FakeServer`].
+///
+/// [`FakeServer`]: crate::FakeServer
+#[derive(Clone, Debug)]
+pub struct Call {
+    /// Name of the method, as it was sent by the client (e.g. `SendMessage`).
+    ///
+    /// Note that method names are case-insensitive.
+    pub method: String,
+
+    /// Token of the bot, which made the call.
+    pub token: String,
+
+    /// Parameters of the call.
+    ///
+    /// For `multipart/form-data` requests, fields which contain valid JSON
+    /// (e.g. `reply_markup` or `chat_id`) are parsed, others are kept as
+    /// strings.
+    pub params: Map<String, Value>,
+
+    /// Files, uploaded with the call.
+    pub files: Vec<File>,
+}
+
+/// A file uploaded with a [`Call`].
+#[derive(Clone, Debug)]
+pub struct File {
+    /// Name of the `multipart/form-data` field.
+    pub field: String,
+
+    /// Name of the file, as it was sent by the client.
+    pub file_name: Option<String>,
+
+    /// Contents of the file.
+    pub data: Bytes,
+}
+
+impl Call {
+    /// Returns `true` if this is a call of the `method` (case-insensitive).
+    ///
+    /// The `Inline` suffix of the `method` is ignored, since [`Bot`] removes
+    /// it before sending a request (e.g. `EditMessageTextInline` is sent as
+    /// `editMessageText`), so names of payloads can be used as is.
+    ///
+    /// [`Bot`]: teloxide_core::Bot
+    #[must_use]
+    pub fn is(&self, method: &str) -> bool {
+        self.method.eq_ignore_ascii_case(method.trim_end_matches("Inline"))
+    }
+
+    /// Returns a parameter of the call, deserialized into `T`.
+    ///
+    /// Returns `None` if the parameter is missing or can't be deserialized
+    /// into `T`.
+    #[must_use]
+    pub fn param<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+        serde_json::from_value(self.params.get(name)?.clone()).ok()
+    }
+
+    /// Returns a file passed as the `name` parameter.
+    ///
+    /// Both files uploaded in a field with the same name and files referenced
+    /// with `attach://<field>` are returned.
+    #[must_use]
+    pub fn file(&self, name: &str) -> Option<&File> {
+        let field = match self.params.get(name).and_then(Value::as_str) {
+            Some(value) => value.strip_prefix("attach://").unwrap_or(name),
+            None => name,
+        };
+
+        self.files.iter().find(|f| f.field == field)
+    }
+}
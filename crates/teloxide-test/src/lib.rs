@@ -0,0 +1,104 @@
+//! Testing utilities for [`teloxide`] bots.
+//!
+//! This crate provides [`FakeServer`], a local HTTP server which speaks the
+//! [Telegram Bot API]. Point a [`Bot`] at it (see [`FakeServer::bot`] or
+//! [`Bot::set_api_url`]) and it will:
+//!
+//! - record every method call with its decoded parameters and files (see
+//!   [`FakeServer::calls`]),
+//! - reply with scripted responses and errors (see [`FakeServer::respond`]),
+//! - feed updates to polling and webhook update listeners (see
+//!   [`FakeServer::push_update`]),
+//!
+//! so a whole `Dispatcher` can run end-to-end without network access.
+//!
+//!```toml
+//! [dev-dependencies]
+//! teloxide-test = "0.1.0"
+//! ```
+//! _Compiler support: requires rustc 1.80+_.
+//!
+//! ```
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! use teloxide_core::{
+//!     payloads::SendMessage,
+//!     prelude::*,
+//!     types::{ChatId, Seconds},
+//!     RequestError,
+//! };
+//! use teloxide_test::{FakeServer, Response};
+//!
+//! let server = FakeServer::start().await;
+//! let bot = server.bot();
+//!
+//! // `SendMessage` has a built-in default response...
+//! bot.send_message(ChatId(42), "Hello!").await.unwrap();
+//!
+//! // ...which can be overridden
+//! server.respond::<SendMessage>(Response::retry_after(Seconds::from_seconds(5)));
+//! let res = bot.send_message(ChatId(42), "Hello again!").await;
+//! assert!(matches!(res, Err(RequestError::RetryAfter(_))));
+//!
+//! let calls = server.calls_of::<SendMessage>();
+//! assert_eq!(calls.len(), 2);
+//! assert_eq!(calls[0].param::<String>("text").unwrap(), "Hello!");
+//! # }
+//! ```
+//!
+//! ## Default responses
+//!
+//! Unless a response is scripted, the following methods are handled by the
+//! server itself:
+//!
+//! - `getMe` returns [`FakeServer::me`],
+//! - `getUpdates` returns updates added with [`FakeServer::push_update`],
+//!   supporting `offset`, `limit` and `timeout` (long polling),
+//! - `setWebhook`, `deleteWebhook` and `getWebhookInfo` manage the webhook,
+//!   updates are delivered to it instead of `getUpdates` while it's set,
+//! - `sendMessage` returns a message built from the `chat_id` and `text`
+//!   parameters.
+//!
+//! All other methods fail with a `404 Not Found` error, describing the method
+//! which has no response scripted.
+//!
+//! ## Running a `Dispatcher`
+//!
+//! `Dispatcher` blocks the thread it's running on, so tests which run it should
+//! use the multi-threaded runtime with at least two worker threads (i.e.
+//! `#[tokio::test(flavor = "multi_thread", worker_threads = 2)]`) and run it on
+//! a blocking thread, otherwise the server may not be able to respond:
+//!
+//! ```no_run
+//! # use teloxide::{dptree, prelude::*, update_listeners::polling_default, RequestError};
+//! # #[tokio::main]
+//! # async fn main() {
+//! # let bot = teloxide_test::FakeServer::start().await.bot();
+//! # let listener = polling_default(bot.clone()).await;
+//! # let mut dispatcher = Dispatcher::<_, RequestError, _>::builder(bot, dptree::entry()).build();
+//! let runtime = tokio::runtime::Handle::current();
+//! let dispatch = tokio::task::spawn_blocking(move || {
+//!     runtime.block_on(dispatcher.dispatch_with_listener(listener, LoggingErrorHandler::new()))
+//! });
+//! # dispatch.await.unwrap();
+//! # }
+//! ```
+//!
+//! [`teloxide`]: https://docs.rs/teloxide
+//! [Telegram Bot API]: https://core.telegram.org/bots/api
+//! [`Bot`]: teloxide_core::Bot
+//! [`Bot::set_api_url`]: teloxide_core::Bot::set_api_url
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+mod call;
+mod response;
+mod server;
+pub mod update;
+
+pub use self::{
+    call::{Call, File},
+    response::Response,
+    server::FakeServer,
+};
@@ -0,0 +1,100 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use teloxide_core::{
+    types::{ChatId, Seconds},
+    ApiError,
+};
+
+/// A scripted response of the [`FakeServer`].
+///
+/// [`FakeServer`]: crate::FakeServer
+#[derive(Clone, Debug)]
+pub struct Response {
+    pub(crate) status: u16,
+    pub(crate) body: Value,
+}
+
+impl Response {
+    /// A successful response with the given `result`.
+    ///
+    /// ## Panics
+    ///
+    /// If `result` can't be serialized into JSON.
+    #[must_use]
+    pub fn ok(result: impl Serialize) -> Self {
+        let result = serde_json::to_value(result).expect("failed to serialize the result");
+
+        Self { status: 200, body: json!({ "ok": true, "result": result }) }
+    }
+
+    /// An error with the given code and description.
+    ///
+    /// `error_code` is also used as the HTTP status code of the response.
+    #[must_use]
+    pub fn error(error_code: u16, description: impl Into<String>) -> Self {
+        Self::error_with(error_code, description.into(), None)
+    }
+
+    /// An error, which is parsed as the given [`ApiError`] by the client.
+    #[must_use]
+    pub fn api_error(error: ApiError) -> Self {
+        let description = match error {
+            // The only error, message of which differs from the description
+            ApiError::InvalidToken => String::from("Unauthorized"),
+            ApiError::Unknown(description) => description,
+            error => error.to_string(),
+        };
+
+        let error_code = match description.split(':').next() {
+            Some("Unauthorized") => 401,
+            Some("Forbidden") => 403,
+            Some("Not Found") => 404,
+            Some("Conflict") => 409,
+            Some("Request Entity Too Large") => 413,
+            _ => 400,
+        };
+
+        Self::error(error_code, description)
+    }
+
+    /// A flood control error, which is parsed as
+    /// [`RequestError::RetryAfter`] by the client.
+    ///
+    /// [`RequestError::RetryAfter`]: teloxide_core::RequestError::RetryAfter
+    #[must_use]
+    pub fn retry_after(seconds: Seconds) -> Self {
+        let seconds = seconds.seconds();
+
+        Self::error_with(
+            429,
+            format!("Too Many Requests: retry after {seconds}"),
+            Some(json!({ "retry_after": seconds })),
+        )
+    }
+
+    /// A group migration error, which is parsed as
+    /// [`RequestError::MigrateToChatId`] by the client.
+    ///
+    /// [`RequestError::MigrateToChatId`]: teloxide_core::RequestError::MigrateToChatId
+    #[must_use]
+    pub fn migrate_to_chat_id(chat_id: ChatId) -> Self {
+        Self::error_with(
+            400,
+            String::from("Bad Request: group chat was upgraded to a supergroup chat"),
+            Some(json!({ "migrate_to_chat_id": chat_id.0 })),
+        )
+    }
+
+    fn error_with(error_code: u16, description: String, parameters: Option<Value>) -> Self {
+        let mut body = json!({
+            "ok": false,
+            "error_code": error_code,
+            "description": description,
+        });
+        if let Some(parameters) = parameters {
+            body["parameters"] = parameters;
+        }
+
+        Self { status: error_code, body }
+    }
+}
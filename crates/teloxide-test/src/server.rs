@@ -0,0 +1,556 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    net::Ipv4Addr,
+    pin::pin,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Multipart, Path, Request, State},
+    http::{
+        header::{CONNECTION, CONTENT_RANGE, CONTENT_TYPE, RANGE},
+        HeaderMap, StatusCode,
+    },
+    response::IntoResponse,
+    routing::{any, get},
+    Router,
+};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use teloxide_core::{
+    requests::Payload,
+    types::{ChatId, Me, UpdateId},
+    Bot,
+};
+use tokio::{net::TcpListener, sync::Notify, task::JoinHandle, time::Instant};
+use url::Url;
+
+use crate::{update, Call, File, Response};
+
+/// A local HTTP server, which speaks the Telegram Bot API.
+///
+/// The server is started with [`FakeServer::start`] and is stopped when
+/// dropped. See the [crate-level docs] for an overview.
+///
+/// [crate-level docs]: crate
+pub struct FakeServer {
+    state: Arc<ServerState>,
+    url: Url,
+    tasks: [JoinHandle<()>; 2],
+}
+
+struct ServerState {
+    inner: Mutex<Inner>,
+    /// Notified when a call is recorded.
+    calls_changed: Notify,
+    /// Notified when updates or the webhook change.
+    updates_changed: Notify,
+    http: reqwest::Client,
+}
+
+struct Inner {
+    me: Me,
+    calls: Vec<Call>,
+    /// One-off responses, keyed by lowercase method name.
+    responses: HashMap<String, VecDeque<Response>>,
+    /// Responses used when there are no one-off ones, keyed by lowercase
+    /// method name.
+    default_responses: HashMap<String, Response>,
+    updates: VecDeque<Value>,
+    next_update_id: u32,
+    next_message_id: i32,
+    webhook: Option<Webhook>,
+    files: HashMap<String, Bytes>,
+}
+
+#[derive(Clone)]
+struct Webhook {
+    url: Url,
+    secret_token: Option<String>,
+}
+
+impl FakeServer {
+    /// Token of the bot returned by [`FakeServer::bot`].
+    ///
+    /// Note that the server accepts any token.
+    pub const TOKEN: &'static str = "1234567:TEST-TOKEN";
+
+    /// Starts the server on a random local port.
+    ///
+    /// ## Panics
+    ///
+    /// If the server can't bind to a local port.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .expect("failed to bind the fake Bot API server");
+        let addr = listener.local_addr().expect("failed to get the fake server address");
+
+        let state = Arc::new(ServerState {
+            inner: Mutex::new(Inner {
+                me: default_me(),
+                calls: Vec::new(),
+                responses: HashMap::new(),
+                default_responses: HashMap::new(),
+                updates: VecDeque::new(),
+                next_update_id: 1,
+                next_message_id: 1,
+                webhook: None,
+                files: HashMap::new(),
+            }),
+            calls_changed: Notify::new(),
+            updates_changed: Notify::new(),
+            http: reqwest::Client::new(),
+        });
+
+        let router = Router::new()
+            .route("/{bot}/{method}", any(handle_method))
+            .route("/file/{bot}/{*path}", get(handle_file))
+            .with_state(Arc::clone(&state));
+
+        let server = tokio::spawn(async move {
+            if let Err(err) = axum::serve(listener, router).await {
+                log::error!("Fake Bot API server failed: {err}");
+            }
+        });
+        let delivery = tokio::spawn(deliver_updates(Arc::clone(&state)));
+
+        let url = Url::parse(&format!("http://{addr}/")).unwrap();
+
+        Self { state, url, tasks: [server, delivery] }
+    }
+
+    /// Returns the URL of the server, to be used with [`Bot::set_api_url`].
+    ///
+    /// [`Bot::set_api_url`]: teloxide_core::Bot::set_api_url
+    #[must_use]
+    pub fn url(&self) -> Url {
+        self.url.clone()
+    }
+
+    /// Returns a bot which sends requests to this server.
+    pub fn bot(&self) -> Bot {
+        Bot::new(Self::TOKEN).set_api_url(self.url())
+    }
+
+    /// Returns the bot info returned by `getMe`.
+    #[must_use]
+    pub fn me(&self) -> Me {
+        self.state.lock().me.clone()
+    }
+
+    /// Sets the bot info returned by `getMe`.
+    pub fn set_me(&self, me: Me) {
+        self.state.lock().me = me;
+    }
+
+    /// Scripts a one-off response to the next call of `P`.
+    ///
+    /// One-off responses are used in the order they were added, before the
+    /// default ones (see [`FakeServer::respond_always`]).
+    pub fn respond<P: Payload>(&self, response: Response) {
+        self.state.lock().responses.entry(method_key::<P>()).or_default().push_back(response);
+    }
+
+    /// Scripts a response to all calls of `P`, which don't have a one-off
+    /// response.
+    ///
+    /// This overrides the built-in handling of the method, if any.
+    pub fn respond_always<P: Payload>(&self, response: Response) {
+        self.state.lock().default_responses.insert(method_key::<P>(), response);
+    }
+
+    /// Returns all calls received by the server, in order.
+    #[must_use]
+    pub fn calls(&self) -> Vec<Call> {
+        self.state.lock().calls.clone()
+    }
+
+    /// Returns calls of `P` received by the server, in order.
+    #[must_use]
+    pub fn calls_of<P: Payload>(&self) -> Vec<Call> {
+        self.state.lock().calls.iter().filter(|call| call.is(P::NAME)).cloned().collect()
+    }
+
+    /// Forgets all calls received so far.
+    pub fn clear_calls(&self) {
+        self.state.lock().calls.clear();
+    }
+
+    /// Waits until at least `n` calls of `P` are received and returns them.
+    ///
+    /// This never times out, consider wrapping it in [`tokio::time::timeout`].
+    pub async fn wait_for_calls<P: Payload>(&self, n: usize) -> Vec<Call> {
+        loop {
+            let mut notified = pin!(self.state.calls_changed.notified());
+            notified.as_mut().enable();
+
+            let calls = self.calls_of::<P>();
+            if calls.len() >= n {
+                return calls;
+            }
+
+            notified.await;
+        }
+    }
+
+    /// Adds an update to the queue and returns its id.
+    ///
+    /// `update_id` of the update is assigned by the server. Updates are
+    /// delivered to the webhook if it's set, and returned by `getUpdates`
+    /// otherwise.
+    ///
+    /// See the [`update`] module for helpers to build updates.
+    ///
+    /// ## Panics
+    ///
+    /// If `update` doesn't serialize into a JSON object.
+    pub fn push_update(&self, update: impl Serialize) -> UpdateId {
+        let mut update = serde_json::to_value(update).expect("failed to serialize the update");
+        let Value::Object(fields) = &mut update else {
+            panic!("an update must serialize into a JSON object");
+        };
+
+        let id = {
+            let mut inner = self.state.lock();
+            let id = inner.next_update_id;
+            inner.next_update_id += 1;
+
+            fields.insert(String::from("update_id"), id.into());
+            inner.updates.push_back(update);
+            id
+        };
+        self.state.updates_changed.notify_waiters();
+
+        UpdateId(id)
+    }
+
+    /// Returns the number of updates, which weren't yet confirmed by
+    /// `getUpdates` or delivered to the webhook.
+    #[must_use]
+    pub fn pending_updates(&self) -> usize {
+        self.state.lock().updates.len()
+    }
+
+    /// Returns the URL of the webhook, if it's set.
+    #[must_use]
+    pub fn webhook_url(&self) -> Option<Url> {
+        self.state.lock().webhook.as_ref().map(|w| w.url.clone())
+    }
+
+    /// Adds a file, which can be downloaded using `file_path` (e.g. with
+    /// `Download::download_file`).
+    ///
+    /// `Range: bytes=<start>-` requests are supported, so resumed downloads
+    /// can be tested as well.
+    pub fn add_file(&self, file_path: impl Into<String>, data: impl Into<Bytes>) {
+        self.state.lock().files.insert(file_path.into(), data.into());
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        self.tasks.iter().for_each(JoinHandle::abort);
+    }
+}
+
+impl ServerState {
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn record(&self, call: Call) {
+        self.lock().calls.push(call);
+        self.calls_changed.notify_waiters();
+    }
+
+    fn scripted_response(&self, method: &str) -> Option<Response> {
+        let mut inner = self.lock();
+        let method = method.to_ascii_lowercase();
+
+        inner
+            .responses
+            .get_mut(&method)
+            .and_then(VecDeque::pop_front)
+            .or_else(|| inner.default_responses.get(&method).cloned())
+    }
+}
+
+/// Returns the name of the method of `P`, as it's received by the server, in
+/// lowercase.
+///
+/// `Bot` removes the `Inline` suffix of payload names before sending requests,
+/// so e.g. `EditMessageTextInline` is received as `editMessageText`.
+fn method_key<P: Payload>() -> String {
+    P::NAME.trim_end_matches("Inline").to_ascii_lowercase()
+}
+
+async fn handle_method(
+    State(state): State<Arc<ServerState>>,
+    Path((bot, method)): Path<(String, String)>,
+    request: Request,
+) -> impl IntoResponse {
+    let response = match bot.strip_prefix("bot") {
+        Some(token) => match decode(request).await {
+            Ok((params, files)) => {
+                let call = Call { method, token: token.to_owned(), params, files };
+                state.record(call.clone());
+                respond(&state, &call).await
+            }
+            Err(description) => Response::error(400, description),
+        },
+        None => Response::error(404, "Not Found"),
+    };
+
+    let status = StatusCode::from_u16(response.status).unwrap_or(StatusCode::BAD_REQUEST);
+    // Pooled connections are tied to the runtime they were opened on, while
+    // `Dispatcher` runs on its own runtime, so connections are never reused
+    (status, [(CONTENT_TYPE, "application/json"), (CONNECTION, "close")], response.body.to_string())
+}
+
+async fn handle_file(
+    State(state): State<Arc<ServerState>>,
+    Path((_bot, path)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> axum::response::Response {
+    let Some(data) = state.lock().files.get(&path).cloned() else {
+        return (StatusCode::NOT_FOUND, "Not Found").into_response();
+    };
+
+    // Only `bytes=<start>-` ranges are supported, since that's what
+    // `teloxide_core` sends when resuming downloads
+    let start: Option<usize> = headers.get(RANGE).and_then(|range| {
+        range.to_str().ok()?.strip_prefix("bytes=")?.strip_suffix('-')?.parse().ok()
+    });
+
+    match start {
+        None => data.into_response(),
+        Some(start) if start < data.len() => {
+            let content_range = format!("bytes {start}-{}/{}", data.len() - 1, data.len());
+            (StatusCode::PARTIAL_CONTENT, [(CONTENT_RANGE, content_range)], data.slice(start..))
+                .into_response()
+        }
+        Some(_) => StatusCode::RANGE_NOT_SATISFIABLE.into_response(),
+    }
+}
+
+/// Decodes parameters and files of a call.
+async fn decode(request: Request) -> Result<(Map<String, Value>, Vec<File>), String> {
+    let is_multipart = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("multipart/form-data"));
+
+    if !is_multipart {
+        let body = Bytes::from_request(request, &()).await.map_err(|e| e.body_text())?;
+        if body.is_empty() {
+            return Ok(Default::default());
+        }
+
+        return match serde_json::from_slice(&body) {
+            Ok(Value::Object(params)) => Ok((params, Vec::new())),
+            _ => Err(String::from("Bad Request: can't parse JSON body")),
+        };
+    }
+
+    let mut multipart = Multipart::from_request(request, &()).await.map_err(|e| e.body_text())?;
+    let mut params = Map::new();
+    let mut files = Vec::new();
+
+    while let Some(field) = multipart.next_field().await.map_err(|e| e.body_text())? {
+        let name = field.name().unwrap_or_default().to_owned();
+
+        match field.file_name().map(ToOwned::to_owned) {
+            Some(file_name) => {
+                let data = field.bytes().await.map_err(|e| e.body_text())?;
+                files.push(File { field: name, file_name: Some(file_name), data });
+            }
+            None => {
+                let text = field.text().await.map_err(|e| e.body_text())?;
+                let value = serde_json::from_str(&text).unwrap_or(Value::String(text));
+                params.insert(name, value);
+            }
+        }
+    }
+
+    Ok((params, files))
+}
+
+async fn respond(state: &ServerState, call: &Call) -> Response {
+    if let Some(response) = state.scripted_response(&call.method) {
+        return response;
+    }
+
+    match &*call.method.to_ascii_lowercase() {
+        "getme" => Response::ok(&state.lock().me),
+        "getupdates" => get_updates(state, call).await,
+        "setwebhook" => set_webhook(state, call),
+        "deletewebhook" => delete_webhook(state, call),
+        "getwebhookinfo" => get_webhook_info(state),
+        "sendmessage" => send_message(state, call),
+        _ => Response::error(404, format!("Not Found: no response scripted for `{}`", call.method)),
+    }
+}
+
+async fn get_updates(state: &ServerState, call: &Call) -> Response {
+    let offset = call.param::<i64>("offset").unwrap_or(0);
+    let limit = call.param::<usize>("limit").unwrap_or(100).clamp(1, 100);
+    let timeout = call.param::<u64>("timeout").unwrap_or(0);
+    let deadline = Instant::now() + Duration::from_secs(timeout);
+
+    loop {
+        let mut notified = pin!(state.updates_changed.notified());
+        notified.as_mut().enable();
+
+        {
+            let mut inner = state.lock();
+            if inner.webhook.is_some() {
+                return Response::error(
+                    409,
+                    "Conflict: can't use getUpdates method while webhook is active; use \
+                     deleteWebhook to delete the webhook first",
+                );
+            }
+
+            // Updates with ids less than `offset` are confirmed
+            inner.updates.retain(|u| u["update_id"].as_i64().is_some_and(|id| id >= offset));
+
+            if !inner.updates.is_empty() {
+                return Response::ok(inner.updates.iter().take(limit).collect::<Vec<_>>());
+            }
+        }
+
+        if tokio::time::timeout_at(deadline, notified).await.is_err() {
+            return Response::ok(Vec::<Value>::new());
+        }
+    }
+}
+
+fn set_webhook(state: &ServerState, call: &Call) -> Response {
+    let url = call.param::<String>("url").unwrap_or_default();
+    let webhook = match &*url {
+        "" => None,
+        url => match Url::parse(url) {
+            Ok(url) => Some(Webhook { url, secret_token: call.param("secret_token") }),
+            Err(_) => return Response::error(400, "Bad Request: invalid webhook URL specified"),
+        },
+    };
+
+    {
+        let mut inner = state.lock();
+        inner.webhook = webhook;
+        if call.param("drop_pending_updates") == Some(true) {
+            inner.updates.clear();
+        }
+    }
+    state.updates_changed.notify_waiters();
+
+    Response::ok(true)
+}
+
+fn delete_webhook(state: &ServerState, call: &Call) -> Response {
+    {
+        let mut inner = state.lock();
+        inner.webhook = None;
+        if call.param("drop_pending_updates") == Some(true) {
+            inner.updates.clear();
+        }
+    }
+    state.updates_changed.notify_waiters();
+
+    Response::ok(true)
+}
+
+fn get_webhook_info(state: &ServerState) -> Response {
+    let inner = state.lock();
+    let url = inner.webhook.as_ref().map(|w| w.url.as_str()).unwrap_or_default();
+
+    Response::ok(json!({
+        "url": url,
+        "has_custom_certificate": false,
+        "pending_update_count": inner.updates.len(),
+    }))
+}
+
+fn send_message(state: &ServerState, call: &Call) -> Response {
+    let chat = match call.params.get("chat_id") {
+        Some(Value::Number(id)) => match id.as_i64() {
+            Some(id) => update::chat(ChatId(id)),
+            None => return Response::error(400, "Bad Request: chat not found"),
+        },
+        Some(Value::String(username)) => json!({
+            "id": -1_000_000_000_001_i64,
+            "type": "channel",
+            "title": username,
+            "username": username.trim_start_matches('@'),
+        }),
+        _ => return Response::error(400, "Bad Request: chat not found"),
+    };
+    let Some(text) = call.param::<String>("text").filter(|t| !t.is_empty()) else {
+        return Response::error(400, "Bad Request: message text is empty");
+    };
+
+    let mut inner = state.lock();
+    let message_id = inner.next_message_id;
+    inner.next_message_id += 1;
+
+    Response::ok(json!({
+        "message_id": message_id,
+        "date": SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+        "chat": chat,
+        "from": inner.me.user,
+        "text": text,
+    }))
+}
+
+/// Delivers pending updates to the webhook, while it's set.
+async fn deliver_updates(state: Arc<ServerState>) {
+    loop {
+        let mut notified = pin!(state.updates_changed.notified());
+        notified.as_mut().enable();
+
+        let next = {
+            let inner = state.lock();
+            inner.webhook.clone().zip(inner.updates.front().cloned())
+        };
+        let Some((webhook, update)) = next else {
+            notified.await;
+            continue;
+        };
+
+        let mut request = state.http.post(webhook.url).json(&update);
+        if let Some(secret_token) = webhook.secret_token {
+            request = request.header("X-Telegram-Bot-Api-Secret-Token", secret_token);
+        }
+
+        match request.send().await.and_then(|r| r.error_for_status()) {
+            Ok(_) => {
+                let mut inner = state.lock();
+                // The queue could've been cleared in the meantime
+                if inner.updates.front().map(|u| &u["update_id"]) == Some(&update["update_id"]) {
+                    inner.updates.pop_front();
+                }
+            }
+            Err(err) => {
+                log::warn!("Failed to deliver an update to the webhook: {err}");
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        }
+    }
+}
+
+fn default_me() -> Me {
+    serde_json::from_value(json!({
+        "id": 1_234_567,
+        "is_bot": true,
+        "first_name": "Test",
+        "username": "test_bot",
+        "can_join_groups": true,
+        "can_read_all_group_messages": false,
+        "supports_inline_queries": false,
+    }))
+    .expect("default `Me` is valid")
+}
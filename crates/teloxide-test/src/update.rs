@@ -0,0 +1,64 @@
+//! Helpers for building updates to feed into a [`FakeServer`].
+//!
+//! Updates are built as JSON, so they can be tweaked before being pushed. Any
+//! value which serializes into an update (e.g. [`Update`]) can be pushed as
+//! well.
+//!
+//! [`FakeServer`]: crate::FakeServer
+//! [`Update`]: teloxide_core::types::Update
+
+use serde_json::{json, Value};
+use teloxide_core::types::{ChatId, UserId};
+
+/// Id of the user, sending messages built by this module.
+pub const USER_ID: UserId = UserId(1_000_001);
+
+/// Builds a text message update from the [`USER_ID`] user in the `chat_id`
+/// chat.
+///
+/// Private chat is used for positive `chat_id`s, group or supergroup chat for
+/// negative ones.
+///
+/// Note that `update_id` is assigned by [`FakeServer::push_update`].
+///
+/// [`FakeServer::push_update`]: crate::FakeServer::push_update
+#[must_use]
+pub fn text_message(chat_id: ChatId, text: &str) -> Value {
+    json!({
+        "message": {
+            "message_id": 1,
+            "date": 0,
+            "chat": chat(chat_id),
+            "from": user(),
+            "text": text,
+        }
+    })
+}
+
+/// Builds a callback query update from the [`USER_ID`] user with the given
+/// `data`.
+#[must_use]
+pub fn callback_query(id: &str, data: &str) -> Value {
+    json!({
+        "callback_query": {
+            "id": id,
+            "from": user(),
+            "chat_instance": "0",
+            "data": data,
+        }
+    })
+}
+
+pub(crate) fn chat(chat_id: ChatId) -> Value {
+    if chat_id.is_user() {
+        json!({ "id": chat_id.0, "type": "private", "first_name": "User" })
+    } else if chat_id.is_group() {
+        json!({ "id": chat_id.0, "type": "group", "title": "Group" })
+    } else {
+        json!({ "id": chat_id.0, "type": "supergroup", "title": "Supergroup" })
+    }
+}
+
+fn user() -> Value {
+    json!({ "id": USER_ID.0, "is_bot": false, "first_name": "User" })
+}
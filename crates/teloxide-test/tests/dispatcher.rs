@@ -0,0 +1,237 @@
+use std::{net::TcpListener, time::Duration};
+
+use teloxide::{
+    dispatching::{Dispatcher, UpdateFilterExt},
+    payloads::SendMessage,
+    prelude::*,
+    types::{ChatId, Me, Message, Update, UserId},
+    update_listeners::{webhooks, MultiBotListener, Polling},
+    utils::command::BotCommands,
+    RequestError,
+};
+use teloxide_test::{update, FakeServer};
+
+#[derive(BotCommands, Clone)]
+#[command(rename_rule = "lowercase")]
+enum Command {
+    Start,
+    Echo(String),
+}
+
+async fn answer(bot: Bot, msg: Message, cmd: Command) -> ResponseResult<()> {
+    let text = match cmd {
+        Command::Start => String::from("Welcome!"),
+        Command::Echo(text) => text,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+async fn introduce(bot: Bot, me: Me, msg: Message) -> ResponseResult<()> {
+    bot.send_message(msg.chat.id, me.username()).await?;
+
+    Ok(())
+}
+
+/// Runs a dispatcher on a blocking thread, since it blocks the thread it's
+/// running on.
+fn spawn_dispatcher(
+    dispatch: impl std::future::Future<Output = ()> + Send + 'static,
+) -> tokio::task::JoinHandle<()> {
+    let runtime = tokio::runtime::Handle::current();
+    tokio::task::spawn_blocking(move || runtime.block_on(dispatch))
+}
+
+fn handler() -> teloxide::dispatching::UpdateHandler<RequestError> {
+    Update::filter_message().filter_command::<Command>().endpoint(answer)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn polling() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+    server.push_update(update::text_message(ChatId(1), "/start"));
+    server.push_update(update::text_message(ChatId(2), "/echo hi"));
+    server.push_update(update::text_message(ChatId(3), "not a command"));
+
+    let mut dispatcher = Dispatcher::builder(bot.clone(), handler()).build();
+    let token = dispatcher.shutdown_token();
+    let listener = Polling::builder(bot).timeout(Duration::from_secs(1)).build();
+    let dispatch = spawn_dispatcher(async move {
+        dispatcher.dispatch_with_listener(listener, LoggingErrorHandler::new()).await
+    });
+
+    let calls =
+        tokio::time::timeout(Duration::from_secs(10), server.wait_for_calls::<SendMessage>(2))
+            .await
+            .unwrap();
+
+    let mut replies: Vec<_> = calls
+        .iter()
+        .map(|c| (c.param::<i64>("chat_id").unwrap(), c.param::<String>("text").unwrap()))
+        .collect();
+    replies.sort();
+    assert_eq!(replies, [(1, String::from("Welcome!")), (2, String::from("hi"))]);
+
+    token.shutdown().unwrap().await;
+    dispatch.await.unwrap();
+    assert_eq!(server.pending_updates(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn shutdown_while_processing_update() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+    for _ in 0..3 {
+        server.push_update(update::text_message(ChatId(1), "hi"));
+    }
+
+    // The handler blocks until released, so with a queue of one update the
+    // dispatcher gets stuck sending the third update to the worker of the chat
+    let release = std::sync::Arc::new(tokio::sync::Semaphore::new(0));
+    let (started_tx, mut started_rx) = tokio::sync::mpsc::unbounded_channel();
+    let handler = Update::filter_message().endpoint({
+        let release = std::sync::Arc::clone(&release);
+        move || {
+            let release = std::sync::Arc::clone(&release);
+            let started_tx = started_tx.clone();
+            async move {
+                let _ = started_tx.send(());
+                release.acquire().await.unwrap().forget();
+                Ok::<_, RequestError>(())
+            }
+        }
+    });
+
+    let mut dispatcher = Dispatcher::builder(bot.clone(), handler).worker_queue_size(1).build();
+    let token = dispatcher.shutdown_token();
+    let listener = Polling::builder(bot).timeout(Duration::from_secs(1)).build();
+    // Not `spawn_dispatcher`, so that the test fails instead of hanging if the
+    // dispatcher doesn't stop
+    let (stopped_tx, stopped_rx) = tokio::sync::oneshot::channel();
+    let runtime = tokio::runtime::Handle::current();
+    std::thread::spawn(move || {
+        runtime.block_on(dispatcher.dispatch_with_listener(listener, LoggingErrorHandler::new()));
+        let _ = stopped_tx.send(());
+    });
+
+    started_rx.recv().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // Shutdown is requested while the dispatcher isn't waiting for it
+    let shutdown = token.shutdown().unwrap();
+    release.add_permits(3);
+
+    tokio::time::timeout(Duration::from_secs(10), shutdown).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(10), stopped_rx).await.unwrap().unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn webhook() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+
+    let address = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+    let url = format!("http://{address}/webhook").parse().unwrap();
+    let listener = webhooks::axum(bot.clone(), webhooks::Options::new(address, url)).await.unwrap();
+    assert!(server.webhook_url().is_some());
+
+    let mut dispatcher = Dispatcher::builder(bot, handler()).build();
+    let token = dispatcher.shutdown_token();
+    let dispatch = spawn_dispatcher(async move {
+        dispatcher.dispatch_with_listener(listener, LoggingErrorHandler::new()).await
+    });
+
+    server.push_update(update::text_message(ChatId(1), "/echo from webhook"));
+
+    let calls =
+        tokio::time::timeout(Duration::from_secs(10), server.wait_for_calls::<SendMessage>(1))
+            .await
+            .unwrap();
+    assert_eq!(calls[0].param::<String>("text").unwrap(), "from webhook");
+
+    token.shutdown().unwrap().await;
+    dispatch.await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn multi_bot() {
+    let first = FakeServer::start().await;
+    let second = FakeServer::start().await;
+    let mut me = second.me();
+    me.user.id = UserId(7_654_321);
+    me.user.username = Some(String::from("second_bot"));
+    second.set_me(me);
+
+    let polling = |bot: Bot| Polling::builder(bot).timeout(Duration::from_secs(1)).build();
+    let (listener, bots) = MultiBotListener::<Bot, RequestError>::new();
+    bots.add(first.bot(), polling(first.bot())).await.unwrap();
+
+    let handler = Update::filter_message().filter_command::<Command>().endpoint(introduce);
+    let mut dispatcher = Dispatcher::builder_multi_bot(handler).build();
+    let token = dispatcher.shutdown_token();
+    let dispatch = spawn_dispatcher(async move {
+        dispatcher.dispatch_with_multi_bot_listener(listener, LoggingErrorHandler::new()).await
+    });
+
+    // Bots can be added while dispatching
+    bots.add(second.bot(), polling(second.bot())).await.unwrap();
+
+    first.push_update(update::text_message(ChatId(1), "/start"));
+    second.push_update(update::text_message(ChatId(1), "/start@second_bot"));
+
+    for (server, username) in [(&first, "test_bot"), (&second, "second_bot")] {
+        let calls =
+            tokio::time::timeout(Duration::from_secs(10), server.wait_for_calls::<SendMessage>(1))
+                .await
+                .unwrap();
+        assert_eq!(calls[0].param::<String>("text").unwrap(), username);
+    }
+
+    assert!(bots.remove(UserId(1_234_567)));
+    assert_eq!(bots.bots(), [UserId(7_654_321)]);
+
+    token.shutdown().unwrap().await;
+    dispatch.await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn unknown_fields_handler() {
+    let server = FakeServer::start().await;
+    let bot = server.bot().set_capture_unknown_fields(true);
+    let mut message = update::text_message(ChatId(1), "/echo hi");
+    message["message"]["some_new_field"] = serde_json::json!(1);
+    server.push_update(message);
+    server.push_update(serde_json::json!({ "some_new_kind": { "a": "b" } }));
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut dispatcher = Dispatcher::builder(bot.clone(), handler())
+        .unknown_fields_handler(move |upd| {
+            let tx = tx.clone();
+            async move {
+                let mut keys: Vec<_> = upd.extra.keys().cloned().collect();
+                keys.sort();
+                tx.send(keys).unwrap();
+            }
+        })
+        .build();
+    let token = dispatcher.shutdown_token();
+    let listener = Polling::builder(bot).timeout(Duration::from_secs(1)).build();
+    let dispatch = spawn_dispatcher(async move {
+        dispatcher.dispatch_with_listener(listener, LoggingErrorHandler::new()).await
+    });
+
+    // The update is still dispatched
+    let calls =
+        tokio::time::timeout(Duration::from_secs(10), server.wait_for_calls::<SendMessage>(1))
+            .await
+            .unwrap();
+    assert_eq!(calls[0].param::<String>("text").unwrap(), "hi");
+
+    assert_eq!(rx.recv().await.unwrap(), ["/message/some_new_field"]);
+    assert_eq!(rx.recv().await.unwrap(), ["/some_new_kind"]);
+
+    token.shutdown().unwrap().await;
+    dispatch.await.unwrap();
+}
@@ -0,0 +1,169 @@
+use std::io::Cursor;
+
+use teloxide_core::{
+    net::{Download, ResumableDownload},
+    payloads::{EditMessageTextInline, GetChatMemberCount, SendMessage, SetChatPhoto},
+    prelude::*,
+    types::{ChatId, File, FileMeta, InputFile, Seconds, True},
+    ApiError, RequestError,
+};
+use teloxide_test::{update, FakeServer, Response};
+
+#[tokio::test]
+async fn records_calls() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+
+    let message = bot.send_message(ChatId(42), "hi").await.unwrap();
+    assert_eq!(message.chat.id, ChatId(42));
+    assert_eq!(message.text(), Some("hi"));
+
+    bot.send_message(ChatId(-42), "hello").await.unwrap();
+    bot.set_chat_photo(ChatId(-42), InputFile::memory(&b"photo"[..]).file_name("photo.png"))
+        .await
+        .unwrap_err();
+
+    let calls = server.calls();
+    assert_eq!(calls.len(), 3);
+    assert!(calls[0].is("sendMessage"));
+    assert_eq!(calls[0].token, FakeServer::TOKEN);
+    assert_eq!(calls[1].param::<i64>("chat_id"), Some(-42));
+    assert_eq!(calls[1].param::<String>("text").as_deref(), Some("hello"));
+
+    let calls = server.calls_of::<SetChatPhoto>();
+    let photo = calls[0].file("photo").unwrap();
+    assert_eq!(photo.file_name.as_deref(), Some("photo.png"));
+    assert_eq!(&photo.data[..], b"photo");
+}
+
+#[tokio::test]
+async fn scripted_responses() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+
+    server.respond::<SendMessage>(Response::retry_after(Seconds::from_seconds(3)));
+    server.respond::<SendMessage>(Response::migrate_to_chat_id(ChatId(-1001)));
+    server.respond::<SendMessage>(Response::api_error(ApiError::BotBlocked));
+
+    let err = bot.send_message(ChatId(1), "1").await.unwrap_err();
+    assert!(matches!(err, RequestError::RetryAfter(s) if s.seconds() == 3));
+
+    let err = bot.send_message(ChatId(1), "2").await.unwrap_err();
+    assert!(matches!(err, RequestError::MigrateToChatId(ChatId(-1001))));
+
+    let err = bot.send_message(ChatId(1), "3").await.unwrap_err();
+    assert!(matches!(err, RequestError::Api(ApiError::BotBlocked)));
+
+    // One-off responses are used up, back to the default behaviour
+    bot.send_message(ChatId(1), "4").await.unwrap();
+
+    // Methods without a built-in default fail, unless scripted
+    let err = bot.get_chat(ChatId(1)).await.unwrap_err();
+    assert!(matches!(err, RequestError::Api(ApiError::Unknown(_))));
+
+    server.respond_always::<GetChatMemberCount>(Response::ok(5));
+    assert_eq!(bot.get_chat_member_count(ChatId(1)).await.unwrap(), 5);
+    assert_eq!(bot.get_chat_member_count(ChatId(1)).await.unwrap(), 5);
+}
+
+#[tokio::test]
+async fn inline_methods() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+
+    // `Bot` sends `EditMessageTextInline` as `editMessageText`
+    server.respond::<EditMessageTextInline>(Response::ok(True));
+    bot.edit_message_text_inline("inline", "edited").await.unwrap();
+
+    let calls = server.calls_of::<EditMessageTextInline>();
+    assert_eq!(calls.len(), 1);
+    assert!(calls[0].is("editMessageText"));
+    assert_eq!(calls[0].param::<String>("inline_message_id").as_deref(), Some("inline"));
+    assert_eq!(calls[0].param::<String>("text").as_deref(), Some("edited"));
+}
+
+#[tokio::test]
+async fn api_errors_round_trip() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+
+    let errors = [
+        ApiError::InvalidToken,
+        ApiError::BotBlocked,
+        ApiError::MessageNotModified,
+        ApiError::ChatNotFound,
+        ApiError::CantParseEntities(
+            "Bad Request: can't parse entities: Unsupported start tag \"foo\" at byte offset 0"
+                .to_owned(),
+        ),
+        ApiError::Unknown("Some new error".to_owned()),
+    ];
+
+    for error in errors {
+        server.respond::<SendMessage>(Response::api_error(error.clone()));
+
+        let err = bot.send_message(ChatId(1), "hi").await.unwrap_err();
+        assert!(matches!(err, RequestError::Api(actual) if actual == error));
+    }
+}
+
+#[tokio::test]
+async fn get_updates() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+
+    let first = server.push_update(update::text_message(ChatId(1), "a"));
+    server.push_update(update::text_message(ChatId(1), "b"));
+
+    let updates = bot.get_updates().await.unwrap();
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[0].id, first);
+
+    // Confirm the first update
+    let updates = bot.get_updates().offset(first.0 as i32 + 1).await.unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(server.pending_updates(), 1);
+
+    // Long polling returns as soon as an update arrives
+    let poll = tokio::spawn({
+        let bot = bot.clone();
+        async move { bot.get_updates().offset(first.0 as i32 + 2).timeout(10).await }
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    server.push_update(update::callback_query("1", "data"));
+
+    let updates = poll.await.unwrap().unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].id.0, first.0 + 2);
+}
+
+#[tokio::test]
+async fn download() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+
+    server.add_file("photos/file_0.jpg", &b"data"[..]);
+
+    let mut buf = Vec::new();
+    bot.download_file("photos/file_0.jpg", &mut buf).await.unwrap();
+    assert_eq!(buf, b"data");
+
+    bot.download_file("photos/missing.jpg", &mut buf).await.unwrap_err();
+}
+
+#[tokio::test]
+async fn download_resumable() {
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+
+    server.add_file("documents/file_1.txt", &b"0123456789"[..]);
+    let file = File {
+        meta: FileMeta { id: String::from("id"), unique_id: String::from("unique"), size: 10 },
+        path: String::from("documents/file_1.txt"),
+    };
+
+    let mut dst = Cursor::new(b"0123".to_vec());
+    let size = ResumableDownload::new(&file).run(&bot, &mut dst).await.unwrap();
+    assert_eq!(size, 10);
+    assert_eq!(dst.into_inner(), b"0123456789");
+}
@@ -0,0 +1,138 @@
+//! Spans, created by `Dispatcher` and the `Tracing` adaptor.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use teloxide::{
+    adaptors::Tracing,
+    dispatching::{Dispatcher, UpdateFilterExt},
+    payloads::SendMessage,
+    prelude::*,
+    types::{ChatId, Message, Update},
+    update_listeners::Polling,
+    RequestError,
+};
+use teloxide_test::{update, FakeServer};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    Layer, Registry,
+};
+
+/// A closed span, captured by [`Spans`].
+#[derive(Debug, Default)]
+struct SpanData {
+    id: u64,
+    name: &'static str,
+    parent: Option<u64>,
+    fields: HashMap<&'static str, String>,
+}
+
+impl Visit for SpanData {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields.insert(field.name(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.fields.insert(field.name(), format!("{value:?}"));
+    }
+}
+
+/// A layer which captures spans, when they are closed.
+#[derive(Clone, Default)]
+struct Spans(Arc<Mutex<Vec<SpanData>>>);
+
+impl<S> Layer<S> for Spans
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut data = SpanData {
+            id: id.into_u64(),
+            name: attrs.metadata().name(),
+            parent: span.parent().map(|parent| parent.id().into_u64()),
+            ..SpanData::default()
+        };
+        attrs.record(&mut data);
+        span.extensions_mut().insert(data);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        values.record(span.extensions_mut().get_mut::<SpanData>().unwrap());
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = ctx.span(&id).unwrap();
+        let data = span.extensions_mut().remove::<SpanData>().unwrap();
+        self.0.lock().unwrap().push(data);
+    }
+}
+
+async fn reply(bot: Tracing<Bot>, msg: Message) -> ResponseResult<()> {
+    bot.send_message(msg.chat.id, "reply").await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn requests_are_children_of_updates() {
+    let spans = Spans::default();
+    // Updates are handled on other threads, so the subscriber must be global
+    tracing::subscriber::set_global_default(Registry::default().with(spans.clone())).unwrap();
+
+    let server = FakeServer::start().await;
+    let bot = server.bot();
+    let update_id = server.push_update(update::text_message(ChatId(1), "hi"));
+
+    let handler = Update::filter_message().endpoint(reply);
+    let mut dispatcher =
+        Dispatcher::<_, RequestError, _>::builder(bot.clone().tracing(), handler).build();
+    let token = dispatcher.shutdown_token();
+    let listener = Polling::builder(bot).timeout(Duration::from_secs(1)).build();
+
+    let runtime = tokio::runtime::Handle::current();
+    let dispatch = tokio::task::spawn_blocking(move || {
+        runtime.block_on(dispatcher.dispatch_with_listener(listener, LoggingErrorHandler::new()))
+    });
+
+    tokio::time::timeout(Duration::from_secs(10), server.wait_for_calls::<SendMessage>(1))
+        .await
+        .unwrap();
+    token.shutdown().unwrap().await;
+    dispatch.await.unwrap();
+
+    let spans = spans.0.lock().unwrap();
+    let updates: Vec<_> = spans.iter().filter(|s| s.name == "update").collect();
+    assert_eq!(updates.len(), 1);
+    let update = updates[0];
+    assert_eq!(update.fields["update_id"], update_id.0.to_string());
+    assert_eq!(update.fields["kind"], "message");
+    assert_eq!(update.fields["chat_id"], "1");
+    assert_eq!(update.fields["user_id"], update::USER_ID.0.to_string());
+    // There is a single bot, which isn't known to the dispatcher
+    assert!(!update.fields.contains_key("bot_id"));
+
+    // `GetMe`, made by the dispatcher itself, isn't a child of any update
+    let requests: HashMap<_, _> = spans
+        .iter()
+        .filter(|s| s.name == "request")
+        .map(|s| (s.fields["method"].as_str(), s))
+        .collect();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests["GetMe"].parent, None);
+
+    let request = requests["SendMessage"];
+    assert_eq!(request.parent, Some(update.id));
+    assert_eq!(request.fields["chat_id"], "1");
+    assert_eq!(request.fields["outcome"], "ok");
+}
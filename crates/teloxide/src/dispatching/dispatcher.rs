@@ -6,15 +6,15 @@ use crate::{
     error_handlers::{ErrorHandler, LoggingErrorHandler},
     requests::{Request, Requester},
     stop::StopToken,
-    types::{Update, UpdateKind},
-    update_listeners::{self, UpdateListener},
+    types::{Update, UpdateKind, UserId},
+    update_listeners::{self, BotContext, BotUpdate, MultiBotListener, UpdateListener},
 };
 
 use dptree::di::{DependencyMap, DependencySupplier};
 use either::Either;
 use futures::{
     future::{self, BoxFuture},
-    stream::FuturesUnordered,
+    stream::{FuturesUnordered, Stream},
     FutureExt as _, StreamExt as _,
 };
 use tokio_stream::wrappers::ReceiverStream;
@@ -37,11 +37,12 @@ use std::{
 /// See also: ["Dispatching or
 /// REPLs?"](../dispatching/index.html#dispatching-or-repls)
 pub struct DispatcherBuilder<R, Err, Key> {
-    bot: R,
+    bot: Option<R>,
     dependencies: DependencyMap,
     handler: Arc<UpdateHandler<Err>>,
     default_handler: DefaultHandler,
     error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync>,
+    unknown_fields_handler: Option<UnknownFieldsHandler>,
     ctrlc_handler: bool,
     distribution_f: fn(&Update) -> Option<Key>,
     worker_queue_size: usize,
@@ -81,6 +82,58 @@ where
         Self { error_handler: handler, ..self }
     }
 
+    /// Specifies a handler that will be called for updates which contain data
+    /// `teloxide` doesn't know about.
+    ///
+    /// The handler is called for updates with a non-empty [`Update::extra`]
+    /// and for updates that couldn't be parsed at all
+    /// ([`UpdateKind::Error`]), before they are dispatched. This can be used to
+    /// find out what is missing in `teloxide` in production.
+    ///
+    /// Only unknown top-level fields of updates are collected by default. To
+    /// also collect unknown fields of nested objects (e.g. of messages),
+    /// enable [`Bot::set_capture_unknown_fields`] (or
+    /// `webhooks::Options::capture_unknown_fields` for webhooks).
+    ///
+    /// The handler is awaited before the update is dispatched, so it should
+    /// be fast (e.g. just log the update). If the handler keeps the update,
+    /// it's cloned for dispatching.
+    ///
+    /// By default, there is no such handler.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide::{dispatching::Dispatcher, dptree, Bot};
+    ///
+    /// let bot = Bot::new("TOKEN").set_capture_unknown_fields(true);
+    /// let handler = dptree::entry() /* ... */;
+    /// let dp = Dispatcher::builder(bot, handler)
+    ///     .unknown_fields_handler(|upd| async move {
+    ///         log::warn!("Update {:?} has unknown fields: {:?}", upd.id, upd.extra);
+    ///     })
+    ///     .build();
+    /// # let _: Dispatcher<_, (), _> = dp;
+    /// ```
+    ///
+    /// [`Bot::set_capture_unknown_fields`]: crate::Bot::set_capture_unknown_fields
+    #[must_use]
+    pub fn unknown_fields_handler<H, Fut>(self, handler: H) -> Self
+    where
+        H: Fn(Arc<Update>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+
+        Self {
+            unknown_fields_handler: Some(Arc::new(move |upd| {
+                let handler = Arc::clone(&handler);
+                Box::pin(handler(upd))
+            })),
+            ..self
+        }
+    }
+
     /// Specifies dependencies that can be used inside of handlers.
     ///
     /// By default, there is no dependencies.
@@ -183,6 +236,7 @@ where
             handler,
             default_handler,
             error_handler,
+            unknown_fields_handler,
             ctrlc_handler,
             distribution_f: _,
             worker_queue_size,
@@ -195,6 +249,7 @@ where
             handler,
             default_handler,
             error_handler,
+            unknown_fields_handler,
             ctrlc_handler,
             distribution_f: f,
             worker_queue_size,
@@ -211,6 +266,7 @@ where
             handler,
             default_handler,
             error_handler,
+            unknown_fields_handler,
             distribution_f,
             worker_queue_size,
             ctrlc_handler,
@@ -226,6 +282,7 @@ where
             handler,
             default_handler,
             error_handler,
+            unknown_fields_handler,
             state: ShutdownToken::new(),
             distribution_f,
             worker_queue_size,
@@ -264,7 +321,8 @@ where
 ///
 /// [`distribution_function`]: DispatcherBuilder::distribution_function
 pub struct Dispatcher<R, Err, Key> {
-    bot: R,
+    // `None` if the dispatcher dispatches updates of many bots
+    bot: Option<R>,
     dependencies: DependencyMap,
 
     handler: Arc<UpdateHandler<Err>>,
@@ -275,18 +333,20 @@ pub struct Dispatcher<R, Err, Key> {
     stack_size: usize,
     current_number_of_active_workers: Arc<AtomicU32>,
     max_number_of_active_workers: Arc<AtomicU32>,
-    // Tokio TX channel parts associated with chat IDs that consume updates sequentially.
-    workers: HashMap<Key, Worker>,
+    // Tokio TX channel parts associated with chat IDs (and bot IDs, when dispatching updates of
+    // many bots) that consume updates sequentially.
+    workers: HashMap<(Option<UserId>, Key), Worker>,
     // The default TX part that consume updates concurrently.
     default_worker: Option<Worker>,
 
     error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync>,
+    unknown_fields_handler: Option<UnknownFieldsHandler>,
 
     state: ShutdownToken,
 }
 
 struct Worker {
-    tx: tokio::sync::mpsc::Sender<Update>,
+    tx: tokio::sync::mpsc::Sender<(UpdateSpan, Update, Option<Arc<BotContext>>)>,
     handle: tokio::task::JoinHandle<()>,
     is_waiting: Arc<AtomicBool>,
 }
@@ -300,6 +360,8 @@ pub type UpdateHandler<Err> =
 
 type DefaultHandler = Arc<dyn Fn(Arc<Update>) -> BoxFuture<'static, ()> + Send + Sync>;
 
+type UnknownFieldsHandler = Arc<dyn Fn(Arc<Update>) -> BoxFuture<'static, ()> + Send + Sync>;
+
 impl<R, Err> Dispatcher<R, Err, DefaultKey>
 where
     R: Requester + Clone + Send + Sync + 'static,
@@ -308,6 +370,30 @@ where
     /// Constructs a new [`DispatcherBuilder`] with `bot` and `handler`.
     #[must_use]
     pub fn builder(bot: R, handler: UpdateHandler<Err>) -> DispatcherBuilder<R, Err, DefaultKey>
+    where
+        Err: Debug,
+    {
+        Self::builder_inner(Some(bot), handler)
+    }
+
+    /// Constructs a new [`DispatcherBuilder`] with `handler`, for dispatching
+    /// updates of many bots with
+    /// [`Dispatcher::dispatch_with_multi_bot_listener`].
+    ///
+    /// Each update is handled with the bot which received it, so no bot is
+    /// needed upfront.
+    #[must_use]
+    pub fn builder_multi_bot(handler: UpdateHandler<Err>) -> DispatcherBuilder<R, Err, DefaultKey>
+    where
+        Err: Debug,
+    {
+        Self::builder_inner(None, handler)
+    }
+
+    fn builder_inner(
+        bot: Option<R>,
+        handler: UpdateHandler<Err>,
+    ) -> DispatcherBuilder<R, Err, DefaultKey>
     where
         Err: Debug,
     {
@@ -323,6 +409,7 @@ where
                 Box::pin(async {})
             }),
             error_handler: LoggingErrorHandler::new(),
+            unknown_fields_handler: None,
             ctrlc_handler: false,
             worker_queue_size: DEFAULT_WORKER_QUEUE_SIZE,
             distribution_f: default_distribution_function,
@@ -350,13 +437,17 @@ where
     ///  - An update from Telegram;
     ///  - [`crate::types::Me`] (can be used in [`HandlerExt::filter_command`]).
     ///
+    /// ## Panics
+    ///
+    /// If the dispatcher was built with [`Dispatcher::builder_multi_bot`].
+    ///
     /// [`HandlerExt::filter_command`]: crate::dispatching::HandlerExt::filter_command
     pub async fn dispatch(&mut self)
     where
         R: Requester + Clone,
         <R as Requester>::GetUpdates: Send,
     {
-        let listener = update_listeners::polling_default(self.bot.clone()).await;
+        let listener = update_listeners::polling_default(self.bot().clone()).await;
         let error_handler =
             LoggingErrorHandler::with_custom_text("An error from the update listener");
 
@@ -367,6 +458,10 @@ where
     /// `update_listener_error_handler`.
     ///
     /// This method adds the same dependencies as [`Dispatcher::dispatch`].
+    ///
+    /// ## Panics
+    ///
+    /// If the dispatcher was built with [`Dispatcher::builder_multi_bot`].
     pub async fn dispatch_with_listener<'a, UListener, Eh>(
         &'a mut self,
         update_listener: UListener,
@@ -388,6 +483,10 @@ where
     /// `update_listener_error_handler`.
     ///
     /// This method adds the same dependencies as [`Dispatcher::dispatch`].
+    ///
+    /// ## Panics
+    ///
+    /// If the dispatcher was built with [`Dispatcher::builder_multi_bot`].
     pub async fn try_dispatch_with_listener<'a, UListener, Eh>(
         &'a mut self,
         mut update_listener: UListener,
@@ -399,9 +498,10 @@ where
         UListener::Err: Debug,
     {
         // FIXME: there should be a way to check if dependency is already inserted
-        let me = self.bot.get_me().send().await?;
+        let bot = self.bot().clone();
+        let me = bot.get_me().send().await?;
         self.dependencies.insert(me);
-        self.dependencies.insert(self.bot.clone());
+        self.dependencies.insert(bot);
 
         let description = self.handler.description();
         let allowed_updates = description.allowed_updates();
@@ -410,6 +510,65 @@ where
 
         let stop_token = Some(update_listener.stop_token());
 
+        self.block_on_own_runtime(|this| async move {
+            let stream = update_listener.as_stream().map(|upd| upd.map(|upd| (upd, None)));
+            this.start_listening(stream, update_listener_error_handler, stop_token).await
+        });
+        Ok(())
+    }
+
+    /// Starts dispatching updates of many bots, received by a
+    /// [`MultiBotListener`].
+    ///
+    /// Each time a handler is invoked, [`Dispatcher`] adds the following
+    /// dependencies (in addition to those passed to
+    /// [`DispatcherBuilder::dependencies`]):
+    ///
+    ///  - The bot, which received the update (the bot passed to
+    ///    [`Dispatcher::builder`], if any, is not used);
+    ///  - An update from Telegram;
+    ///  - [`crate::types::Me`] of the bot, which received the update.
+    ///
+    /// Updates of different bots are always processed concurrently, i.e. the
+    /// distribution key (see [`DispatcherBuilder::distribution_function`]) is
+    /// per-bot.
+    pub async fn dispatch_with_multi_bot_listener<'a, E, Eh>(
+        &'a mut self,
+        mut update_listener: MultiBotListener<R, E>,
+        update_listener_error_handler: Arc<Eh>,
+    ) where
+        E: Send + 'static,
+        Eh: ErrorHandler<E> + Send + Sync + 'a,
+    {
+        let description = self.handler.description();
+        let allowed_updates = description.allowed_updates();
+        log::debug!("hinting allowed updates: {:?}", allowed_updates);
+        update_listener.hint_allowed_updates(&mut allowed_updates.into_iter());
+
+        let stop_token = Some(update_listener.stop_token());
+
+        self.block_on_own_runtime(|this| async move {
+            let stream = update_listener
+                .into_stream()
+                .map(|upd| upd.map(|BotUpdate { bot, update }| (update, Some(bot))));
+            this.start_listening(stream, update_listener_error_handler, stop_token).await
+        });
+    }
+
+    /// Returns the bot passed to [`Dispatcher::builder`].
+    fn bot(&self) -> &R {
+        self.bot.as_ref().expect(
+            "the dispatcher was built with `Dispatcher::builder_multi_bot`, use \
+             `Dispatcher::dispatch_with_multi_bot_listener` to dispatch updates",
+        )
+    }
+
+    /// Runs the future returned by `f` to completion on a new Tokio runtime.
+    fn block_on_own_runtime<'a, F, Fut>(&'a mut self, f: F)
+    where
+        F: FnOnce(&'a mut Self) -> Fut + Send + 'a,
+        Fut: Future<Output = ()>,
+    {
         // We create a new Tokio runtime in order to set the correct stack size. We do
         // it a scoped thread because Tokio runtimes cannot be nested. We need a scoped
         // thread because of the lifetime `'a` in `&'a mut self` and because scoped
@@ -424,35 +583,38 @@ where
                     .build()
                     .unwrap();
 
-                runtime.block_on(self.start_listening(
-                    update_listener,
-                    update_listener_error_handler,
-                    stop_token,
-                ));
+                runtime.block_on(f(self));
             });
         });
-        Ok(())
     }
 
-    async fn start_listening<'a, UListener, Eh>(
-        &'a mut self,
-        mut update_listener: UListener,
+    async fn start_listening<S, LErr, Eh>(
+        &mut self,
+        stream: S,
         update_listener_error_handler: Arc<Eh>,
         mut stop_token: Option<StopToken>,
     ) where
-        UListener: UpdateListener + 'a,
-        Eh: ErrorHandler<UListener::Err> + 'a,
-        UListener::Err: Debug,
+        S: Stream<Item = Result<(Update, Option<Arc<BotContext>>), LErr>>,
+        Eh: ErrorHandler<LErr>,
     {
         self.state.start_dispatching();
 
-        let stream = update_listener.as_stream();
         tokio::pin!(stream);
 
         loop {
             self.remove_inactive_workers_if_needed().await;
 
-            let res = future::select(stream.next(), pin!(self.state.wait_for_changes()))
+            // Wait for changes before checking the state, so that a shutdown
+            // which happens in between is not missed
+            let changes = self.state.wait_for_changes();
+            if self.state.is_shutting_down() {
+                if let Some(token) = stop_token.take() {
+                    log::debug!("Start shutting down dispatching...");
+                    token.stop();
+                }
+            }
+
+            let res = future::select(stream.next(), pin!(changes))
                 .map(either)
                 .await
                 .map_either(|l| l.0, |r| r.0);
@@ -462,14 +624,8 @@ where
                     Some(upd) => self.process_update(upd, &update_listener_error_handler).await,
                     None => break,
                 },
-                Either::Right(()) => {
-                    if self.state.is_shutting_down() {
-                        if let Some(token) = stop_token.take() {
-                            log::debug!("Start shutting down dispatching...");
-                            token.stop();
-                        }
-                    }
-                }
+                // The state is checked at the start of the next iteration
+                Either::Right(()) => {}
             }
         }
 
@@ -488,13 +644,24 @@ where
 
     async fn process_update<LErr, LErrHandler>(
         &mut self,
-        update: Result<Update, LErr>,
+        update: Result<(Update, Option<Arc<BotContext>>), LErr>,
         err_handler: &Arc<LErrHandler>,
     ) where
         LErrHandler: ErrorHandler<LErr>,
     {
         match update {
-            Ok(upd) => {
+            Ok((upd, bot)) => {
+                let upd = match &self.unknown_fields_handler {
+                    Some(handler)
+                        if !upd.extra.is_empty() || matches!(upd.kind, UpdateKind::Error(_)) =>
+                    {
+                        let upd = Arc::new(upd);
+                        handler(Arc::clone(&upd)).await;
+                        Arc::try_unwrap(upd).unwrap_or_else(|upd| Update::clone(&upd))
+                    }
+                    _ => upd,
+                };
+
                 if let UpdateKind::Error(err) = upd.kind {
                     log::error!(
                         "Cannot parse an update.\nError: {:?}\n\
@@ -506,22 +673,24 @@ where
                 }
 
                 let worker = match (self.distribution_f)(&upd) {
-                    Some(key) => self.workers.entry(key).or_insert_with(|| {
-                        let deps = self.dependencies.clone();
-                        let handler = Arc::clone(&self.handler);
-                        let default_handler = Arc::clone(&self.default_handler);
-                        let error_handler = Arc::clone(&self.error_handler);
-
-                        spawn_worker(
-                            deps,
-                            handler,
-                            default_handler,
-                            error_handler,
-                            Arc::clone(&self.current_number_of_active_workers),
-                            Arc::clone(&self.max_number_of_active_workers),
-                            self.worker_queue_size,
-                        )
-                    }),
+                    Some(key) => {
+                        self.workers.entry((bot.as_ref().map(|b| b.id), key)).or_insert_with(|| {
+                            let deps = self.dependencies.clone();
+                            let handler = Arc::clone(&self.handler);
+                            let default_handler = Arc::clone(&self.default_handler);
+                            let error_handler = Arc::clone(&self.error_handler);
+
+                            spawn_worker(
+                                deps,
+                                handler,
+                                default_handler,
+                                error_handler,
+                                Arc::clone(&self.current_number_of_active_workers),
+                                Arc::clone(&self.max_number_of_active_workers),
+                                self.worker_queue_size,
+                            )
+                        })
+                    }
                     None => self.default_worker.get_or_insert_with(|| {
                         let deps = self.dependencies.clone();
                         let handler = Arc::clone(&self.handler);
@@ -538,7 +707,11 @@ where
                     }),
                 };
 
-                worker.tx.send(upd).await.expect("TX is dead");
+                worker
+                    .tx
+                    .send((update_span(&upd, bot.as_deref()), upd, bot))
+                    .await
+                    .expect("TX is dead");
             }
             Err(err) => err_handler.clone().handle_error(err).await,
         }
@@ -635,7 +808,7 @@ where
     let deps = Arc::new(deps);
 
     let handle = tokio::spawn(async move {
-        while let Some(update) = rx.recv().await {
+        while let Some((span, update, bot)) = rx.recv().await {
             is_waiting_local.store(false, Ordering::Relaxed);
             {
                 let current = current_number_of_active_workers.fetch_add(1, Ordering::Relaxed) + 1;
@@ -647,7 +820,8 @@ where
             let default_handler = Arc::clone(&default_handler);
             let error_handler = Arc::clone(&error_handler);
 
-            handle_update(update, deps, handler, default_handler, error_handler).await;
+            let fut = handle_update(update, bot, deps, handler, default_handler, error_handler);
+            instrument(fut, span).await;
 
             current_number_of_active_workers.fetch_sub(1, Ordering::Relaxed);
             is_waiting_local.store(true, Ordering::Relaxed);
@@ -671,20 +845,49 @@ where
 
     let deps = Arc::new(deps);
 
-    let handle = tokio::spawn(ReceiverStream::new(rx).for_each_concurrent(None, move |update| {
-        let deps = Arc::clone(&deps);
-        let handler = Arc::clone(&handler);
-        let default_handler = Arc::clone(&default_handler);
-        let error_handler = Arc::clone(&error_handler);
+    let handle = tokio::spawn(ReceiverStream::new(rx).for_each_concurrent(
+        None,
+        move |(span, update, bot)| {
+            let deps = Arc::clone(&deps);
+            let handler = Arc::clone(&handler);
+            let default_handler = Arc::clone(&default_handler);
+            let error_handler = Arc::clone(&error_handler);
 
-        handle_update(update, deps, handler, default_handler, error_handler)
-    }));
+            let fut = handle_update(update, bot, deps, handler, default_handler, error_handler);
+            instrument(fut, span)
+        },
+    ));
 
     Worker { tx, handle, is_waiting: Arc::new(AtomicBool::new(true)) }
 }
 
+/// A span, in which an update is handled (if the `tracing` feature is enabled).
+#[cfg(feature = "tracing")]
+type UpdateSpan = tracing::Span;
+#[cfg(not(feature = "tracing"))]
+type UpdateSpan = ();
+
+#[cfg(feature = "tracing")]
+fn update_span(update: &Update, bot: Option<&BotContext>) -> UpdateSpan {
+    super::tracing::update_span(update, bot)
+}
+
+#[cfg(not(feature = "tracing"))]
+fn update_span(_: &Update, _: Option<&BotContext>) -> UpdateSpan {}
+
+#[cfg(feature = "tracing")]
+fn instrument<F: Future>(fut: F, span: UpdateSpan) -> impl Future<Output = F::Output> {
+    tracing::Instrument::instrument(fut, span)
+}
+
+#[cfg(not(feature = "tracing"))]
+fn instrument<F: Future>(fut: F, (): UpdateSpan) -> F {
+    fut
+}
+
 async fn handle_update<Err>(
     update: Update,
+    bot: Option<Arc<BotContext>>,
     deps: Arc<DependencyMap>,
     handler: Arc<UpdateHandler<Err>>,
     default_handler: DefaultHandler,
@@ -693,6 +896,9 @@ async fn handle_update<Err>(
     Err: Send + Sync + 'static,
 {
     let mut deps = deps.deref().clone();
+    if let Some(bot) = bot {
+        deps.insert_container(bot.dependencies.clone());
+    }
     deps.insert(update);
 
     match handler.dispatch(deps).await {
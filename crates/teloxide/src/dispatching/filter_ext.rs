@@ -78,11 +78,13 @@ define_message_ext! {
     // MediaKind variants of the MessageKind::Common
     (filter_animation, Message::animation),
     (filter_audio, Message::audio),
+    (filter_checklist, Message::checklist),
     (filter_contact, Message::contact),
     (filter_document, Message::document),
     (filter_game, Message::game),
     (filter_venue, Message::venue),
     (filter_location, Message::location),
+    (filter_paid_media, Message::paid_media),
     (filter_photo, Message::photo),
     (filter_poll, Message::poll),
     (filter_sticker, Message::sticker),
@@ -117,6 +119,15 @@ define_message_ext! {
     (filter_proximity_alert_triggered, Message::proximity_alert_triggered),
     (filter_boost_added, Message::boost_added),
     (filter_chat_background_set, Message::chat_background_set),
+    (filter_gift, Message::gift),
+    (filter_unique_gift, Message::unique_gift),
+    (filter_checklist_tasks_done, Message::checklist_tasks_done),
+    (filter_checklist_tasks_added, Message::checklist_tasks_added),
+    (filter_suggested_post_approved, Message::suggested_post_approved),
+    (filter_suggested_post_approval_failed, Message::suggested_post_approval_failed),
+    (filter_suggested_post_declined, Message::suggested_post_declined),
+    (filter_suggested_post_paid, Message::suggested_post_paid),
+    (filter_suggested_post_refunded, Message::suggested_post_refunded),
     (filter_forum_topic_created, Message::forum_topic_created),
     (filter_forum_topic_edited, Message::forum_topic_edited),
     (filter_forum_topic_closed, Message::forum_topic_closed),
@@ -167,6 +178,7 @@ define_update_ext! {
     (filter_callback_query, UpdateKind::CallbackQuery, CallbackQuery),
     (filter_shipping_query, UpdateKind::ShippingQuery, ShippingQuery),
     (filter_pre_checkout_query, UpdateKind::PreCheckoutQuery, PreCheckoutQuery),
+    (filter_purchased_paid_media, UpdateKind::PurchasedPaidMedia, PurchasedPaidMedia),
     (filter_poll, UpdateKind::Poll, Poll),
     (filter_poll_answer, UpdateKind::PollAnswer, PollAnswer),
     (filter_my_chat_member, UpdateKind::MyChatMember, MyChatMember),
@@ -172,6 +172,7 @@ mod tests {
         let date = DateTime::from_timestamp(timestamp, 0).unwrap();
         Update {
             id: UpdateId(326_170_274),
+            extra: Default::default(),
             kind: UpdateKind::Message(Message {
                 via_bot: None,
                 id: MessageId(5042),
@@ -188,6 +189,7 @@ mod tests {
                 }),
                 sender_chat: None,
                 is_topic_message: false,
+                direct_messages_topic: None,
                 sender_business_bot: None,
                 date,
                 chat: Chat {
@@ -224,6 +226,7 @@ mod tests {
                     sender_boost_count: None,
                     is_from_offline: false,
                     business_connection_id: None,
+                    suggested_post_info: None,
                 }),
             }),
         }
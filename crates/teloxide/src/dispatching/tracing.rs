@@ -1,11 +1,15 @@
 use super::UpdateHandler;
 
+use crate::{
+    types::{Update, UpdateKind},
+    update_listeners::BotContext,
+};
 use dptree::{
     di::{Asyncify, Injectable},
     prelude::DependencyMap,
 };
 use std::sync::Arc;
-use tracing::{Instrument, Span};
+use tracing::{field::Empty, Instrument, Span};
 
 pub trait UpdateHandlerTracingExt<E> {
     /// Returns an `UpdateHandler` wrapped in an async span.
@@ -50,3 +54,59 @@ impl<E: 'static> UpdateHandlerTracingExt<E> for UpdateHandler<E> {
         self.instrument_with_async(Asyncify(f))
     }
 }
+
+/// Creates the span, in which `Dispatcher` handles an update.
+///
+/// Spans of requests made by handlers (see `teloxide_core::adaptors::Tracing`)
+/// are children of this span.
+pub(crate) fn update_span(update: &Update, bot: Option<&BotContext>) -> Span {
+    let span = tracing::info_span!(
+        "update",
+        update_id = update.id.0,
+        kind = kind_name(&update.kind),
+        chat_id = Empty,
+        user_id = Empty,
+        bot_id = Empty,
+    );
+
+    if let Some(chat) = update.chat() {
+        span.record("chat_id", chat.id.0);
+    }
+    if let Some(user) = update.from() {
+        span.record("user_id", user.id.0);
+    }
+    if let Some(bot) = bot {
+        span.record("bot_id", bot.id.0);
+    }
+
+    span
+}
+
+fn kind_name(kind: &UpdateKind) -> &'static str {
+    match kind {
+        UpdateKind::Message(_) => "message",
+        UpdateKind::EditedMessage(_) => "edited_message",
+        UpdateKind::ChannelPost(_) => "channel_post",
+        UpdateKind::EditedChannelPost(_) => "edited_channel_post",
+        UpdateKind::BusinessConnection(_) => "business_connection",
+        UpdateKind::BusinessMessage(_) => "business_message",
+        UpdateKind::EditedBusinessMessage(_) => "edited_business_message",
+        UpdateKind::DeletedBusinessMessages(_) => "deleted_business_messages",
+        UpdateKind::MessageReaction(_) => "message_reaction",
+        UpdateKind::MessageReactionCount(_) => "message_reaction_count",
+        UpdateKind::InlineQuery(_) => "inline_query",
+        UpdateKind::ChosenInlineResult(_) => "chosen_inline_result",
+        UpdateKind::CallbackQuery(_) => "callback_query",
+        UpdateKind::ShippingQuery(_) => "shipping_query",
+        UpdateKind::PreCheckoutQuery(_) => "pre_checkout_query",
+        UpdateKind::PurchasedPaidMedia(_) => "purchased_paid_media",
+        UpdateKind::Poll(_) => "poll",
+        UpdateKind::PollAnswer(_) => "poll_answer",
+        UpdateKind::MyChatMember(_) => "my_chat_member",
+        UpdateKind::ChatMember(_) => "chat_member",
+        UpdateKind::ChatJoinRequest(_) => "chat_join_request",
+        UpdateKind::ChatBoost(_) => "chat_boost",
+        UpdateKind::RemovedChatBoost(_) => "removed_chat_boost",
+        UpdateKind::Error(_) => "error",
+    }
+}
@@ -1,7 +1,7 @@
 //! A full-featured framework that empowers you to easily build [Telegram bots]
 //! using [Rust]. It handles all the difficult stuff so you can focus only on
-//! your business logic. Currently, version `7.5` of [Telegram Bot API] is
-//! supported.
+//! your business logic. Currently, version `9.2` of [Telegram Bot API] is
+//! partially supported.
 //!
 //! For a high-level overview, see [our GitHub repository](https://github.com/teloxide/teloxide).
 //!
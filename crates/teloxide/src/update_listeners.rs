@@ -8,6 +8,8 @@
 //! - [`Polling`] function, which returns a long polling listener with your
 //!   configuration.
 //! - Various functions in the [`webhooks`] module that return webhook listeners
+//! - [`MultiBotListener`], which merges updates of many bots, each with its own
+//!   listener.
 //!
 //! And then you can extract updates from it or pass them directly to a
 //! [`Dispatcher`].
@@ -36,11 +38,15 @@ use crate::{
     types::{AllowedUpdate, Update},
 };
 
+mod multi_bot;
 mod polling;
 mod stateful_listener;
 
+pub(crate) use self::multi_bot::{BotContext, BotUpdate};
+
 #[allow(deprecated)]
 pub use self::{
+    multi_bot::{MultiBotHandle, MultiBotListener},
     polling::{polling_default, Polling, PollingBuilder, PollingStream},
     stateful_listener::StatefulListener,
 };
@@ -0,0 +1,327 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::{pin, Pin},
+    sync::{Arc, Mutex, MutexGuard},
+    task::{self, Poll},
+};
+
+use dptree::di::DependencyMap;
+use futures::{
+    future::BoxFuture,
+    stream::{FuturesUnordered, Stream},
+    FutureExt as _, StreamExt as _,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    requests::{Request, Requester},
+    stop::{mk_stop_token, StopFlag, StopToken},
+    types::{AllowedUpdate, Me, Update, UserId},
+    update_listeners::UpdateListener,
+};
+
+/// An update listener, which merges updates of many bots.
+///
+/// Bots are added (each with its own update listener, e.g. [`Polling`] or a
+/// webhook listener) and removed with a [`MultiBotHandle`], which can be used
+/// both before and while dispatching. Updates are dispatched with
+/// [`Dispatcher::dispatch_with_multi_bot_listener`] (with a dispatcher built by
+/// [`Dispatcher::builder_multi_bot`]), which provides the bot that received an
+/// update and its [`Me`] to handlers.
+///
+/// Bots are identified by their user id (i.e. [`Me::id`]).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide::{prelude::*, types::Me, update_listeners::MultiBotListener, RequestError};
+///
+/// # async fn run(tokens: Vec<String>) -> Result<(), RequestError> {
+/// // Errors of all listeners are converted into `RequestError`
+/// let (listener, bots) = MultiBotListener::<Bot, RequestError>::new();
+///
+/// for token in tokens {
+///     let bot = Bot::new(token);
+///     let polling = teloxide::update_listeners::polling_default(bot.clone()).await;
+///     bots.add(bot, polling).await?;
+/// }
+///
+/// let handler = Update::filter_message().endpoint(|bot: Bot, me: Me, msg: Message| async move {
+///     bot.send_message(msg.chat.id, format!("I'm @{}", me.username())).await?;
+///     respond(())
+/// });
+///
+/// Dispatcher::builder_multi_bot(handler)
+///     .build()
+///     .dispatch_with_multi_bot_listener(listener, LoggingErrorHandler::new())
+///     .await;
+/// # Ok(()) }
+/// ```
+///
+/// [`Polling`]: crate::update_listeners::Polling
+/// [`Dispatcher::dispatch_with_multi_bot_listener`]: crate::dispatching::Dispatcher::dispatch_with_multi_bot_listener
+/// [`Dispatcher::builder_multi_bot`]: crate::dispatching::Dispatcher::builder_multi_bot
+pub struct MultiBotListener<R, E> {
+    shared: Arc<Shared<E>>,
+    added: mpsc::UnboundedReceiver<AddedBot<E>>,
+    stop_token: StopToken,
+    allowed_updates: Option<Vec<AllowedUpdate>>,
+    _bot: std::marker::PhantomData<fn() -> R>,
+}
+
+/// A handle, which adds bots to and removes bots from a [`MultiBotListener`].
+pub struct MultiBotHandle<R, E> {
+    shared: Arc<Shared<E>>,
+    _bot: std::marker::PhantomData<fn() -> R>,
+}
+
+/// An update received by a [`MultiBotListener`], with the context of the bot
+/// which received it.
+pub(crate) struct BotUpdate {
+    pub(crate) bot: Arc<BotContext>,
+    pub(crate) update: Update,
+}
+
+/// Dependencies of a bot, added by a [`MultiBotListener`].
+pub(crate) struct BotContext {
+    pub(crate) id: UserId,
+    pub(crate) dependencies: DependencyMap,
+}
+
+/// Starts listening for updates of a bot, given the allowed updates hint.
+type AddedBot<E> = Box<
+    dyn FnOnce(
+            Option<&[AllowedUpdate]>,
+            mpsc::Sender<Result<BotUpdate, E>>,
+        ) -> BoxFuture<'static, ()>
+        + Send,
+>;
+
+struct Shared<E> {
+    bots: Mutex<HashMap<UserId, Registered>>,
+    add: mpsc::UnboundedSender<AddedBot<E>>,
+    stop_flag: StopFlag,
+}
+
+struct Registered {
+    stop_token: StopToken,
+    /// Distinguishes bots re-added with the same id.
+    generation: u64,
+}
+
+impl<R, E> MultiBotListener<R, E>
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    E: Send + 'static,
+{
+    /// Creates a listener without bots and a handle to add them.
+    #[must_use]
+    pub fn new() -> (Self, MultiBotHandle<R, E>) {
+        let (add, added) = mpsc::unbounded_channel();
+        let (stop_token, stop_flag) = mk_stop_token();
+        let shared = Arc::new(Shared { bots: Mutex::default(), add, stop_flag });
+
+        let handle = MultiBotHandle { shared: Arc::clone(&shared), _bot: Default::default() };
+        let listener =
+            Self { shared, added, stop_token, allowed_updates: None, _bot: Default::default() };
+
+        (listener, handle)
+    }
+
+    /// Returns a handle, which adds bots to and removes bots from this
+    /// listener.
+    #[must_use]
+    pub fn handle(&self) -> MultiBotHandle<R, E> {
+        MultiBotHandle { shared: Arc::clone(&self.shared), _bot: Default::default() }
+    }
+
+    /// Returns a token which stops listeners of all bots (including bots
+    /// which are added later).
+    ///
+    /// The update stream ends only after this token is stopped and listeners
+    /// of all bots have stopped. Until then the stream doesn't end, even if
+    /// there are no bots, since bots can be added later.
+    #[must_use = "This function doesn't stop listening, to stop listening you need to call `stop` \
+                  on the returned token"]
+    pub fn stop_token(&mut self) -> StopToken {
+        self.stop_token.clone()
+    }
+
+    /// Hints listeners of all bots which updates they should listen for, see
+    /// [`UpdateListener::hint_allowed_updates`].
+    ///
+    /// Note that the hint is only passed to listeners when they start, i.e.
+    /// when dispatching starts or when a bot is added.
+    pub fn hint_allowed_updates(&mut self, hint: &mut dyn Iterator<Item = AllowedUpdate>) {
+        self.allowed_updates = Some(hint.collect());
+    }
+
+    /// Returns a stream of updates of all bots.
+    pub(crate) fn into_stream(self) -> impl Stream<Item = Result<BotUpdate, E>> + Send {
+        // Listeners wait until their updates are taken from the stream, to not
+        // fetch more updates than dispatcher can process
+        let (tx, rx) = mpsc::channel(1);
+
+        MultiBotStream {
+            stop_flag: self.shared.stop_flag.clone(),
+            shared: self.shared,
+            added: self.added,
+            listeners: FuturesUnordered::new(),
+            tx,
+            rx,
+            allowed_updates: self.allowed_updates,
+            stopping: false,
+        }
+    }
+}
+
+impl<R, E> MultiBotHandle<R, E>
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    E: Send + 'static,
+{
+    /// Adds a bot, which receives updates with `listener`.
+    ///
+    /// Calls [`get_me`] to identify the bot, its result is provided to
+    /// handlers along with the bot. If a bot with the same id was already
+    /// added, it's replaced.
+    ///
+    /// [`get_me`]: crate::requests::Requester::get_me
+    pub async fn add<L>(&self, bot: R, mut listener: L) -> Result<Me, R::Err>
+    where
+        L: UpdateListener + Send + 'static,
+        L::Err: Into<E>,
+    {
+        let me = bot.get_me().send().await?;
+        let id = me.id;
+
+        let mut dependencies = DependencyMap::new();
+        dependencies.insert(bot);
+        dependencies.insert(me.clone());
+        let context = Arc::new(BotContext { id, dependencies });
+
+        let stop_token = listener.stop_token();
+        let generation = {
+            let mut bots = self.shared.lock();
+
+            let generation = bots.get(&id).map_or(0, |old| {
+                old.stop_token.stop();
+                old.generation + 1
+            });
+            bots.insert(id, Registered { stop_token: stop_token.clone(), generation });
+
+            generation
+        };
+
+        if self.shared.stop_flag.is_stopped() {
+            stop_token.stop();
+        }
+
+        let shared = Arc::clone(&self.shared);
+        let added: AddedBot<E> = Box::new(move |allowed_updates, tx| {
+            if let Some(allowed_updates) = allowed_updates {
+                listener.hint_allowed_updates(&mut allowed_updates.iter().cloned());
+            }
+
+            async move {
+                {
+                    let mut stream = pin!(listener.as_stream());
+                    loop {
+                        let update = match stream.next().await {
+                            Some(update) => update
+                                .map(|update| BotUpdate { bot: Arc::clone(&context), update })
+                                .map_err(Into::into),
+                            None => break,
+                        };
+
+                        if tx.send(update).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+
+                let mut bots = shared.lock();
+                if bots.get(&id).is_some_and(|bot| bot.generation == generation) {
+                    bots.remove(&id);
+                }
+            }
+            .boxed()
+        });
+
+        // Fails only if the `MultiBotListener` was dropped, so nobody is
+        // interested in the updates anyway
+        let _ = self.shared.add.send(added);
+
+        Ok(me)
+    }
+
+    /// Removes the bot with the given id, stopping its listener.
+    ///
+    /// Returns `false` if there is no such bot. Note that updates, which the
+    /// listener has already received, are still dispatched.
+    pub fn remove(&self, id: UserId) -> bool {
+        match self.shared.lock().remove(&id) {
+            Some(bot) => {
+                bot.stop_token.stop();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns ids of all bots, which are receiving updates.
+    #[must_use]
+    pub fn bots(&self) -> Vec<UserId> {
+        self.shared.lock().keys().copied().collect()
+    }
+}
+
+impl<R, E> Clone for MultiBotHandle<R, E> {
+    fn clone(&self) -> Self {
+        Self { shared: Arc::clone(&self.shared), _bot: Default::default() }
+    }
+}
+
+impl<E> Shared<E> {
+    fn lock(&self) -> MutexGuard<'_, HashMap<UserId, Registered>> {
+        self.bots.lock().unwrap()
+    }
+}
+
+/// Drives listeners of all bots and merges their updates.
+struct MultiBotStream<E> {
+    shared: Arc<Shared<E>>,
+    stop_flag: StopFlag,
+    added: mpsc::UnboundedReceiver<AddedBot<E>>,
+    listeners: FuturesUnordered<BoxFuture<'static, ()>>,
+    tx: mpsc::Sender<Result<BotUpdate, E>>,
+    rx: mpsc::Receiver<Result<BotUpdate, E>>,
+    allowed_updates: Option<Vec<AllowedUpdate>>,
+    stopping: bool,
+}
+
+impl<E> Stream for MultiBotStream<E> {
+    type Item = Result<BotUpdate, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if !this.stopping && Pin::new(&mut this.stop_flag).poll(cx).is_ready() {
+            this.stopping = true;
+            this.shared.lock().values().for_each(|bot| bot.stop_token.stop());
+        }
+
+        while let Poll::Ready(Some(added)) = this.added.poll_recv(cx) {
+            this.listeners.push(added(this.allowed_updates.as_deref(), this.tx.clone()));
+        }
+
+        while let Poll::Ready(Some(())) = this.listeners.poll_next_unpin(cx) {}
+
+        match this.rx.poll_recv(cx) {
+            Poll::Ready(update) => Poll::Ready(update),
+            Poll::Pending if this.stopping && this.listeners.is_empty() => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -46,10 +46,12 @@ where
     ///
     /// ## Note
     ///
-    /// `timeout` should not be bigger than http client timeout, see
-    /// [`default_reqwest_settings`] for default http client settings.
+    /// [`Bot`] extends the timeout of `get_updates` requests by the polling
+    /// `timeout` (see [`default_timeout`]), other requesters may need their
+    /// http client timeout to be bigger than `timeout`.
     ///
-    /// [`default_reqwest_settings`]: crate::net::default_reqwest_settings
+    /// [`Bot`]: crate::Bot
+    /// [`default_timeout`]: crate::net::default_timeout
     pub fn timeout(self, timeout: Duration) -> Self {
         Self { timeout: Some(timeout), ..self }
     }
@@ -58,6 +58,15 @@ pub struct Options {
     ///
     /// Default - `teloxide` will generate a random token.
     pub secret_token: Option<String>,
+
+    /// Fill [`Update::extra`] of received updates with all the data
+    /// `teloxide` doesn't know about, see [`Update::fill_unknown_fields`].
+    ///
+    /// Default - false.
+    ///
+    /// [`Update::extra`]: crate::types::Update::extra
+    /// [`Update::fill_unknown_fields`]: crate::types::Update::fill_unknown_fields
+    pub capture_unknown_fields: bool,
 }
 
 impl Options {
@@ -73,6 +82,7 @@ impl Options {
             max_connections: None,
             drop_pending_updates: false,
             secret_token: None,
+            capture_unknown_fields: false,
         }
     }
 
@@ -104,6 +114,16 @@ impl Options {
         Self { drop_pending_updates: true, ..self }
     }
 
+    /// Fill [`Update::extra`] of received updates with all the data
+    /// `teloxide` doesn't know about, including unknown fields of nested
+    /// objects, see [`Update::fill_unknown_fields`].
+    ///
+    /// [`Update::extra`]: crate::types::Update::extra
+    /// [`Update::fill_unknown_fields`]: crate::types::Update::fill_unknown_fields
+    pub fn capture_unknown_fields(self) -> Self {
+        Self { capture_unknown_fields: true, ..self }
+    }
+
     /// A secret token to be sent in a header “X-Telegram-Bot-Api-Secret-Token”
     /// in every webhook request, 1-256 characters. Only characters `A-Z`,
     /// `a-z`, `0-9`, `_` and `-` are allowed. The header is useful to ensure
@@ -134,6 +154,9 @@ impl Options {
 #[cfg(feature = "webhooks-axum")]
 pub use self::axum::{axum, axum_no_setup, axum_to_router};
 
+#[cfg(all(feature = "webhooks-axum", feature = "metrics"))]
+pub use self::axum::prometheus_handler;
+
 #[cfg(feature = "webhooks-axum")]
 mod axum;
 
@@ -9,7 +9,7 @@ use tokio::sync::mpsc;
 use crate::{
     requests::Requester,
     stop::StopFlag,
-    types::{Update, UpdateKind},
+    types::Update,
     update_listeners::{webhooks::Options, UpdateListener},
 };
 
@@ -138,6 +138,34 @@ where
     Ok((listener, stop_flag, router))
 }
 
+/// Returns a handler, which serves metrics from `registry` in the
+/// [Prometheus text format].
+///
+/// The handler can be mounted on the router returned by [`axum_to_router`]:
+///
+/// ```no_run
+/// use teloxide::{prelude::*, update_listeners::webhooks};
+///
+/// # async fn run(options: webhooks::Options) -> Result<(), teloxide::RequestError> {
+/// let bot = Bot::from_env().metrics();
+///
+/// let (listener, stop_flag, router) = webhooks::axum_to_router(bot.clone(), options).await?;
+/// let router = router.route("/metrics", webhooks::prometheus_handler(bot.registry().clone()));
+/// # Ok(()) }
+/// ```
+///
+/// [Prometheus text format]: https://prometheus.io/docs/instrumenting/exposition_formats/#text-based-format
+#[cfg(feature = "metrics")]
+pub fn prometheus_handler(
+    registry: crate::adaptors::metrics::Registry,
+) -> axum::routing::MethodRouter {
+    use axum::http::header::CONTENT_TYPE;
+
+    axum::routing::get(move || async move {
+        ([(CONTENT_TYPE, "text/plain; version=0.0.4")], registry.snapshot().to_prometheus())
+    })
+}
+
 /// Webhook implementation based on the [mod@axum] framework that doesn't
 /// perform any setup work.
 ///
@@ -166,7 +194,7 @@ pub fn axum_no_setup(
     let (tx, rx): (UpdateSender, _) = mpsc::unbounded_channel();
 
     async fn telegram_request(
-        State(WebhookState { secret, flag, mut tx }): State<WebhookState>,
+        State(WebhookState { secret, flag, capture_unknown_fields, mut tx }): State<WebhookState>,
         secret_header: XTelegramBotApiSecretToken,
         input: String,
     ) -> impl IntoResponse {
@@ -188,10 +216,10 @@ pub fn axum_no_setup(
 
         match serde_json::from_str::<Update>(&input) {
             Ok(mut update) => {
-                // See HACK comment in
-                // `teloxide_core::net::request::process_response::{closure#0}`
-                if let UpdateKind::Error(value) = &mut update.kind {
-                    *value = serde_json::from_str(&input).unwrap_or_default();
+                if capture_unknown_fields {
+                    if let Ok(raw) = serde_json::from_str(&input) {
+                        update.fill_unknown_fields(&raw);
+                    }
                 }
 
                 tx.send(Ok(update)).expect("Cannot send an incoming update from the webhook")
@@ -219,6 +247,7 @@ pub fn axum_no_setup(
             tx: ClosableSender::new(tx),
             flag: stop_flag.clone(),
             secret: options.secret_token,
+            capture_unknown_fields: options.capture_unknown_fields,
         });
 
     let stream = UnboundedReceiverStream::new(rx);
@@ -241,6 +270,7 @@ struct WebhookState {
     tx: UpdateCSender,
     flag: StopFlag,
     secret: Option<String>,
+    capture_unknown_fields: bool,
 }
 
 /// A terrible workaround to drop axum extension
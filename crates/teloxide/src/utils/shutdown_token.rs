@@ -7,7 +7,7 @@ use std::{
     },
 };
 
-use tokio::sync::Notify;
+use tokio::sync::{futures::Notified, Notify};
 
 /// A token which used to shutdown [`Dispatcher`].
 ///
@@ -34,10 +34,14 @@ impl ShutdownToken {
     /// If you don't need to wait for shutdown, the returned future can be
     /// ignored.
     pub fn shutdown(&self) -> Result<impl Future<Output = ()> + '_, IdleShutdownError> {
+        // Created before the state changes, so that the notification is not
+        // missed even if dispatching stops before the future is polled
+        let notified = self.shutdown_notify_back.notified();
+
         match shutdown_inner(&self.dispatcher_state) {
             Ok(()) | Err(Ok(AlreadyShuttingDown)) => Ok(async move {
                 log::info!("Trying to shutdown the dispatcher...");
-                self.shutdown_notify_back.notified().await
+                notified.await
             }),
             Err(Err(err)) => Err(err),
         }
@@ -53,8 +57,10 @@ impl ShutdownToken {
         }
     }
 
-    pub(crate) async fn wait_for_changes(&self) {
-        self.dispatcher_state.notify.notified().await;
+    /// Returns a future which resolves when the state changes after this
+    /// function is called.
+    pub(crate) fn wait_for_changes(&self) -> Notified<'_> {
+        self.dispatcher_state.notify.notified()
     }
 
     pub(crate) fn start_dispatching(&self) {